@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_attempt_answer_fk_question_id;
+DROP INDEX IF EXISTS idx_attempt_answer_fk_attempt_id;
+DROP INDEX IF EXISTS idx_exam_attempt_fk_exam_id;
+
+DROP TABLE IF EXISTS attempt_answer;
+DROP TABLE IF EXISTS exam_attempt;
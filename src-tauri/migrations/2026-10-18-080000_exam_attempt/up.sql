@@ -0,0 +1,45 @@
+CREATE TABLE IF NOT EXISTS exam_attempt
+(
+    id          INTEGER      NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_exam_id  INTEGER      NOT NULL,
+    mode        VARCHAR(255) NOT NULL,
+    started_at  TIMESTAMP    NOT NULL,
+    finished_at TIMESTAMP,
+    score       INTEGER,
+    created_at  TIMESTAMP,
+    updated_at  TIMESTAMP,
+    CONSTRAINT fk_exam_attempt_exam
+        FOREIGN KEY (fk_exam_id)
+            REFERENCES exam (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS attempt_answer
+(
+    id                 INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_attempt_id      INTEGER NOT NULL,
+    fk_question_id     INTEGER NOT NULL,
+    fk_answer_id       INTEGER,
+    assigned_option_id INTEGER,
+    created_at         TIMESTAMP,
+    CONSTRAINT fk_attempt_answer_attempt
+        FOREIGN KEY (fk_attempt_id)
+            REFERENCES exam_attempt (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE,
+    CONSTRAINT fk_attempt_answer_question
+        FOREIGN KEY (fk_question_id)
+            REFERENCES question (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE,
+    CONSTRAINT fk_attempt_answer_answer
+        FOREIGN KEY (fk_answer_id)
+            REFERENCES answer (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+CREATE INDEX idx_exam_attempt_fk_exam_id ON exam_attempt (fk_exam_id);
+CREATE INDEX idx_attempt_answer_fk_attempt_id ON attempt_answer (fk_attempt_id);
+CREATE INDEX idx_attempt_answer_fk_question_id ON attempt_answer (fk_question_id);
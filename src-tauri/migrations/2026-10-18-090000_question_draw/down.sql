@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_question_draw_item_fk_draw_id;
+DROP INDEX IF EXISTS idx_question_draw_fk_exam_id;
+DROP TABLE IF EXISTS question_draw_item;
+DROP TABLE IF EXISTS question_draw;
@@ -0,0 +1,34 @@
+CREATE TABLE IF NOT EXISTS question_draw
+(
+    id         INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_exam_id INTEGER NOT NULL,
+    seed       BIGINT  NOT NULL,
+    stratified BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP,
+    CONSTRAINT fk_question_draw_exam
+        FOREIGN KEY (fk_exam_id)
+            REFERENCES exam (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS question_draw_item
+(
+    id             INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_draw_id     INTEGER NOT NULL,
+    fk_question_id INTEGER NOT NULL,
+    position       INTEGER NOT NULL,
+    CONSTRAINT fk_question_draw_item_draw
+        FOREIGN KEY (fk_draw_id)
+            REFERENCES question_draw (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE,
+    CONSTRAINT fk_question_draw_item_question
+        FOREIGN KEY (fk_question_id)
+            REFERENCES question (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+CREATE INDEX idx_question_draw_fk_exam_id ON question_draw (fk_exam_id);
+CREATE INDEX idx_question_draw_item_fk_draw_id ON question_draw_item (fk_draw_id);
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS setting;
+
+ALTER TABLE exam_attempt DROP COLUMN expired;
+ALTER TABLE exam_attempt DROP COLUMN deadline_at;
@@ -0,0 +1,9 @@
+ALTER TABLE exam_attempt ADD COLUMN deadline_at TIMESTAMP;
+ALTER TABLE exam_attempt ADD COLUMN expired BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE IF NOT EXISTS setting
+(
+    key        VARCHAR(255) NOT NULL PRIMARY KEY,
+    value      TEXT         NOT NULL,
+    updated_at TIMESTAMP
+);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_review_state_due_at;
+DROP TABLE IF EXISTS review_state;
@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS review_state
+(
+    id               INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_question_id   INTEGER NOT NULL UNIQUE,
+    ease_factor      DOUBLE  NOT NULL DEFAULT 2.5,
+    interval_days    INTEGER NOT NULL DEFAULT 0,
+    repetitions      INTEGER NOT NULL DEFAULT 0,
+    lapses           INTEGER NOT NULL DEFAULT 0,
+    due_at           TIMESTAMP NOT NULL,
+    last_reviewed_at TIMESTAMP,
+    created_at       TIMESTAMP,
+    updated_at       TIMESTAMP,
+    CONSTRAINT fk_review_state_question
+        FOREIGN KEY (fk_question_id)
+            REFERENCES question (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+CREATE INDEX idx_review_state_due_at ON review_state (due_at);
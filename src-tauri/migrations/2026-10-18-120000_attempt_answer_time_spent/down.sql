@@ -0,0 +1 @@
+ALTER TABLE attempt_answer DROP COLUMN time_spent_seconds;
@@ -0,0 +1 @@
+ALTER TABLE attempt_answer ADD COLUMN time_spent_seconds INTEGER;
@@ -0,0 +1,78 @@
+-- settings and review states are kept for the active profile only
+CREATE TABLE setting_old
+(
+    key        VARCHAR(255) NOT NULL PRIMARY KEY,
+    value      TEXT         NOT NULL,
+    updated_at TIMESTAMP
+);
+
+INSERT INTO setting_old (key, value, updated_at)
+SELECT key, value, updated_at
+FROM setting
+WHERE fk_profile_id = (SELECT id FROM profile WHERE active = TRUE LIMIT 1);
+
+DROP TABLE setting;
+ALTER TABLE setting_old RENAME TO setting;
+
+CREATE TABLE review_state_old
+(
+    id               INTEGER   NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_question_id   INTEGER   NOT NULL UNIQUE,
+    ease_factor      DOUBLE    NOT NULL DEFAULT 2.5,
+    interval_days    INTEGER   NOT NULL DEFAULT 0,
+    repetitions      INTEGER   NOT NULL DEFAULT 0,
+    lapses           INTEGER   NOT NULL DEFAULT 0,
+    due_at           TIMESTAMP NOT NULL,
+    last_reviewed_at TIMESTAMP,
+    created_at       TIMESTAMP,
+    updated_at       TIMESTAMP,
+    CONSTRAINT fk_review_state_question
+        FOREIGN KEY (fk_question_id)
+            REFERENCES question (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+INSERT INTO review_state_old (id, fk_question_id, ease_factor, interval_days, repetitions, lapses,
+                              due_at, last_reviewed_at, created_at, updated_at)
+SELECT id, fk_question_id, ease_factor, interval_days, repetitions, lapses,
+       due_at, last_reviewed_at, created_at, updated_at
+FROM review_state
+WHERE fk_profile_id = (SELECT id FROM profile WHERE active = TRUE LIMIT 1);
+
+DROP TABLE review_state;
+ALTER TABLE review_state_old RENAME TO review_state;
+
+CREATE INDEX idx_review_state_due_at ON review_state (due_at);
+
+CREATE TABLE exam_attempt_old
+(
+    id          INTEGER      NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_exam_id  INTEGER      NOT NULL,
+    mode        VARCHAR(255) NOT NULL,
+    started_at  TIMESTAMP    NOT NULL,
+    finished_at TIMESTAMP,
+    score       INTEGER,
+    created_at  TIMESTAMP,
+    updated_at  TIMESTAMP,
+    deadline_at TIMESTAMP,
+    expired     BOOLEAN      NOT NULL DEFAULT FALSE,
+    CONSTRAINT fk_exam_attempt_exam
+        FOREIGN KEY (fk_exam_id)
+            REFERENCES exam (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+INSERT INTO exam_attempt_old (id, fk_exam_id, mode, started_at, finished_at, score, created_at,
+                              updated_at, deadline_at, expired)
+SELECT id, fk_exam_id, mode, started_at, finished_at, score, created_at,
+       updated_at, deadline_at, expired
+FROM exam_attempt;
+
+DROP TABLE exam_attempt;
+ALTER TABLE exam_attempt_old RENAME TO exam_attempt;
+
+CREATE INDEX idx_exam_attempt_fk_exam_id ON exam_attempt (fk_exam_id);
+
+DROP TABLE IF EXISTS profile;
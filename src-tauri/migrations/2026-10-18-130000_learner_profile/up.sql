@@ -0,0 +1,107 @@
+CREATE TABLE IF NOT EXISTS profile
+(
+    id         INTEGER      NOT NULL PRIMARY KEY AUTOINCREMENT,
+    name       VARCHAR(255) NOT NULL UNIQUE,
+    active     BOOLEAN      NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP,
+    updated_at TIMESTAMP
+);
+
+-- existing personal data is moved to a default profile
+INSERT INTO profile (id, name, active, created_at)
+VALUES (1, 'Default', TRUE, CURRENT_TIMESTAMP);
+
+CREATE TABLE exam_attempt_new
+(
+    id            INTEGER      NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_exam_id    INTEGER      NOT NULL,
+    mode          VARCHAR(255) NOT NULL,
+    started_at    TIMESTAMP    NOT NULL,
+    finished_at   TIMESTAMP,
+    score         INTEGER,
+    created_at    TIMESTAMP,
+    updated_at    TIMESTAMP,
+    deadline_at   TIMESTAMP,
+    expired       BOOLEAN      NOT NULL DEFAULT FALSE,
+    fk_profile_id INTEGER      NOT NULL,
+    CONSTRAINT fk_exam_attempt_exam
+        FOREIGN KEY (fk_exam_id)
+            REFERENCES exam (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE,
+    CONSTRAINT fk_exam_attempt_profile
+        FOREIGN KEY (fk_profile_id)
+            REFERENCES profile (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+INSERT INTO exam_attempt_new (id, fk_exam_id, mode, started_at, finished_at, score, created_at,
+                              updated_at, deadline_at, expired, fk_profile_id)
+SELECT id, fk_exam_id, mode, started_at, finished_at, score, created_at,
+       updated_at, deadline_at, expired, 1
+FROM exam_attempt;
+
+DROP TABLE exam_attempt;
+ALTER TABLE exam_attempt_new RENAME TO exam_attempt;
+
+CREATE INDEX idx_exam_attempt_fk_exam_id ON exam_attempt (fk_exam_id);
+CREATE INDEX idx_exam_attempt_fk_profile_id ON exam_attempt (fk_profile_id);
+
+CREATE TABLE review_state_new
+(
+    id               INTEGER   NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_question_id   INTEGER   NOT NULL,
+    ease_factor      DOUBLE    NOT NULL DEFAULT 2.5,
+    interval_days    INTEGER   NOT NULL DEFAULT 0,
+    repetitions      INTEGER   NOT NULL DEFAULT 0,
+    lapses           INTEGER   NOT NULL DEFAULT 0,
+    due_at           TIMESTAMP NOT NULL,
+    last_reviewed_at TIMESTAMP,
+    created_at       TIMESTAMP,
+    updated_at       TIMESTAMP,
+    fk_profile_id    INTEGER   NOT NULL,
+    CONSTRAINT uq_review_state_profile_question UNIQUE (fk_profile_id, fk_question_id),
+    CONSTRAINT fk_review_state_question
+        FOREIGN KEY (fk_question_id)
+            REFERENCES question (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE,
+    CONSTRAINT fk_review_state_profile
+        FOREIGN KEY (fk_profile_id)
+            REFERENCES profile (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+INSERT INTO review_state_new (id, fk_question_id, ease_factor, interval_days, repetitions, lapses,
+                              due_at, last_reviewed_at, created_at, updated_at, fk_profile_id)
+SELECT id, fk_question_id, ease_factor, interval_days, repetitions, lapses,
+       due_at, last_reviewed_at, created_at, updated_at, 1
+FROM review_state;
+
+DROP TABLE review_state;
+ALTER TABLE review_state_new RENAME TO review_state;
+
+CREATE INDEX idx_review_state_due_at ON review_state (due_at);
+
+CREATE TABLE setting_new
+(
+    fk_profile_id INTEGER      NOT NULL,
+    key           VARCHAR(255) NOT NULL,
+    value         TEXT         NOT NULL,
+    updated_at    TIMESTAMP,
+    PRIMARY KEY (fk_profile_id, key),
+    CONSTRAINT fk_setting_profile
+        FOREIGN KEY (fk_profile_id)
+            REFERENCES profile (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+INSERT INTO setting_new (fk_profile_id, key, value, updated_at)
+SELECT 1, key, value, updated_at
+FROM setting;
+
+DROP TABLE setting;
+ALTER TABLE setting_new RENAME TO setting;
@@ -0,0 +1 @@
+ALTER TABLE exam_attempt DROP COLUMN shuffle_seed;
@@ -0,0 +1 @@
+ALTER TABLE exam_attempt ADD COLUMN shuffle_seed BIGINT;
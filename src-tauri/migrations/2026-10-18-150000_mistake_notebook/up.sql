@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS mistake
+(
+    id             INTEGER   NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_profile_id  INTEGER   NOT NULL,
+    fk_question_id INTEGER   NOT NULL,
+    miss_count     INTEGER   NOT NULL DEFAULT 1,
+    correct_streak INTEGER   NOT NULL DEFAULT 0,
+    last_missed_at TIMESTAMP NOT NULL,
+    created_at     TIMESTAMP,
+    updated_at     TIMESTAMP,
+    CONSTRAINT uq_mistake_profile_question UNIQUE (fk_profile_id, fk_question_id),
+    CONSTRAINT fk_mistake_profile
+        FOREIGN KEY (fk_profile_id)
+            REFERENCES profile (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE,
+    CONSTRAINT fk_mistake_question
+        FOREIGN KEY (fk_question_id)
+            REFERENCES question (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+ALTER TABLE attempt_answer DROP COLUMN text_response;
+ALTER TABLE answer DROP COLUMN match_mode;
@@ -0,0 +1,2 @@
+ALTER TABLE answer ADD COLUMN match_mode VARCHAR;
+ALTER TABLE attempt_answer ADD COLUMN text_response TEXT;
@@ -0,0 +1,4 @@
+ALTER TABLE answer DROP COLUMN unit;
+ALTER TABLE answer DROP COLUMN tolerance_mode;
+ALTER TABLE answer DROP COLUMN tolerance;
+ALTER TABLE answer DROP COLUMN numeric_value;
@@ -0,0 +1,4 @@
+ALTER TABLE answer ADD COLUMN numeric_value DOUBLE;
+ALTER TABLE answer ADD COLUMN tolerance DOUBLE;
+ALTER TABLE answer ADD COLUMN tolerance_mode VARCHAR;
+ALTER TABLE answer ADD COLUMN unit VARCHAR;
@@ -0,0 +1,3 @@
+ALTER TABLE question DROP COLUMN ordering_scoring;
+ALTER TABLE attempt_answer DROP COLUMN position;
+ALTER TABLE answer DROP COLUMN position;
@@ -0,0 +1,3 @@
+ALTER TABLE answer ADD COLUMN position INTEGER;
+ALTER TABLE attempt_answer ADD COLUMN position INTEGER;
+ALTER TABLE question ADD COLUMN ordering_scoring VARCHAR;
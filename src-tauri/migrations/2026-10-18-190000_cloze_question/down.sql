@@ -0,0 +1,2 @@
+ALTER TABLE attempt_answer DROP COLUMN blank;
+ALTER TABLE answer DROP COLUMN blank;
@@ -0,0 +1,2 @@
+ALTER TABLE answer ADD COLUMN blank INTEGER;
+ALTER TABLE attempt_answer ADD COLUMN blank INTEGER;
@@ -0,0 +1,38 @@
+CREATE TABLE IF NOT EXISTS attachment_blob
+(
+    hash       TEXT      NOT NULL PRIMARY KEY,
+    data       BLOB      NOT NULL,
+    size       INTEGER   NOT NULL,
+    created_at TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS attachment
+(
+    id             INTEGER      NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_question_id INTEGER,
+    fk_answer_id   INTEGER,
+    file_name      VARCHAR(255) NOT NULL,
+    mime_type      VARCHAR(100) NOT NULL,
+    hash           TEXT         NOT NULL,
+    size           INTEGER      NOT NULL,
+    created_at     TIMESTAMP,
+    CONSTRAINT ck_attachment_owner
+        CHECK ((fk_question_id IS NULL) <> (fk_answer_id IS NULL)),
+    CONSTRAINT fk_attachment_question
+        FOREIGN KEY (fk_question_id)
+            REFERENCES question (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE,
+    CONSTRAINT fk_attachment_answer
+        FOREIGN KEY (fk_answer_id)
+            REFERENCES answer (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE,
+    CONSTRAINT fk_attachment_blob
+        FOREIGN KEY (hash)
+            REFERENCES attachment_blob (hash)
+);
+
+CREATE INDEX IF NOT EXISTS idx_attachment_question ON attachment (fk_question_id);
+CREATE INDEX IF NOT EXISTS idx_attachment_answer ON attachment (fk_answer_id);
+CREATE INDEX IF NOT EXISTS idx_attachment_hash ON attachment (hash);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS source_reference;
+ALTER TABLE question DROP COLUMN explanation;
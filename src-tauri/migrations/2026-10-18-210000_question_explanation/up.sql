@@ -0,0 +1,19 @@
+ALTER TABLE question ADD COLUMN explanation TEXT;
+
+CREATE TABLE IF NOT EXISTS source_reference
+(
+    id             INTEGER       NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_question_id INTEGER       NOT NULL,
+    title          VARCHAR(255)  NOT NULL,
+    chapter        VARCHAR(255),
+    page           VARCHAR(50),
+    url            VARCHAR(2048),
+    position       INTEGER       NOT NULL DEFAULT 0,
+    CONSTRAINT fk_source_reference_question
+        FOREIGN KEY (fk_question_id)
+            REFERENCES question (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_source_reference_question ON source_reference (fk_question_id);
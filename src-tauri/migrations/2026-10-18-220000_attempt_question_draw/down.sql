@@ -0,0 +1 @@
+ALTER TABLE exam_attempt DROP COLUMN fk_draw_id;
@@ -0,0 +1 @@
+ALTER TABLE exam_attempt ADD COLUMN fk_draw_id INTEGER REFERENCES question_draw (id) ON DELETE SET NULL;
@@ -0,0 +1,22 @@
+CREATE TABLE question_draw_old
+(
+    id         INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_exam_id INTEGER NOT NULL,
+    seed       BIGINT  NOT NULL,
+    stratified BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP,
+    CONSTRAINT fk_question_draw_exam
+        FOREIGN KEY (fk_exam_id)
+            REFERENCES exam (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+INSERT INTO question_draw_old (id, fk_exam_id, seed, stratified, created_at)
+SELECT id, fk_exam_id, seed, stratified, created_at
+FROM question_draw;
+
+DROP TABLE question_draw;
+ALTER TABLE question_draw_old RENAME TO question_draw;
+
+CREATE INDEX idx_question_draw_fk_exam_id ON question_draw (fk_exam_id);
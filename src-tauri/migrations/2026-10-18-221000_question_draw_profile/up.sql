@@ -0,0 +1,31 @@
+-- draws belong to the learner who drew them, existing draws to the active profile
+CREATE TABLE question_draw_new
+(
+    id            INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_exam_id    INTEGER NOT NULL,
+    seed          BIGINT  NOT NULL,
+    stratified    BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at    TIMESTAMP,
+    fk_profile_id INTEGER NOT NULL,
+    CONSTRAINT fk_question_draw_exam
+        FOREIGN KEY (fk_exam_id)
+            REFERENCES exam (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE,
+    CONSTRAINT fk_question_draw_profile
+        FOREIGN KEY (fk_profile_id)
+            REFERENCES profile (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+INSERT INTO question_draw_new (id, fk_exam_id, seed, stratified, created_at, fk_profile_id)
+SELECT id, fk_exam_id, seed, stratified, created_at,
+       COALESCE((SELECT id FROM profile WHERE active = TRUE LIMIT 1), 1)
+FROM question_draw;
+
+DROP TABLE question_draw;
+ALTER TABLE question_draw_new RENAME TO question_draw;
+
+CREATE INDEX idx_question_draw_fk_exam_id ON question_draw (fk_exam_id);
+CREATE INDEX idx_question_draw_fk_profile_id ON question_draw (fk_profile_id);
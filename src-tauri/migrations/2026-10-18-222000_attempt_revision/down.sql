@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS attempt_question;
+ALTER TABLE exam_attempt DROP COLUMN content_revision;
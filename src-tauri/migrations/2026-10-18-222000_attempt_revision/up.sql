@@ -0,0 +1,18 @@
+ALTER TABLE exam_attempt ADD COLUMN content_revision VARCHAR(64);
+
+-- question order at the start of an attempt; questions are not referenced, so the ids
+-- of questions deleted later are kept and the changed exam is detected on resume
+CREATE TABLE IF NOT EXISTS attempt_question
+(
+    id             INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_attempt_id  INTEGER NOT NULL,
+    fk_question_id INTEGER NOT NULL,
+    position       INTEGER NOT NULL,
+    CONSTRAINT fk_attempt_question_attempt
+        FOREIGN KEY (fk_attempt_id)
+            REFERENCES exam_attempt (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
+
+CREATE INDEX idx_attempt_question_fk_attempt_id ON attempt_question (fk_attempt_id);
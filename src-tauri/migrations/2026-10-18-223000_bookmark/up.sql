@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS bookmark
+(
+    id             INTEGER   NOT NULL PRIMARY KEY AUTOINCREMENT,
+    fk_profile_id  INTEGER   NOT NULL,
+    fk_question_id INTEGER   NOT NULL,
+    created_at     TIMESTAMP,
+    CONSTRAINT uq_bookmark_profile_question UNIQUE (fk_profile_id, fk_question_id),
+    CONSTRAINT fk_bookmark_profile
+        FOREIGN KEY (fk_profile_id)
+            REFERENCES profile (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE,
+    CONSTRAINT fk_bookmark_question
+        FOREIGN KEY (fk_question_id)
+            REFERENCES question (id)
+            ON UPDATE CASCADE
+            ON DELETE CASCADE
+);
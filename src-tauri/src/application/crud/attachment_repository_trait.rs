@@ -0,0 +1,17 @@
+use crate::application::crud::crud_repository_trait::CRUDResult;
+use crate::domain::model::attachment::Attachment;
+
+pub trait AttachmentRepository {
+    /// Stores content under its hash unless content with the same hash already exists.
+    fn save_content(&mut self, hash: &str, content: &[u8]) -> CRUDResult<()>;
+    fn find_content(&mut self, hash: &str) -> CRUDResult<Option<Vec<u8>>>;
+    fn create(&mut self, attachment: &Attachment) -> CRUDResult<Attachment>;
+    fn find_by_id(&mut self, id: i32) -> CRUDResult<Option<Attachment>>;
+    /// Attachments of the given questions and of their answers.
+    fn find_by_question_ids(&mut self, question_ids: &[i32]) -> CRUDResult<Vec<Attachment>>;
+    fn find_by_answer_id(&mut self, answer_id: i32) -> CRUDResult<Vec<Attachment>>;
+    fn delete(&mut self, id: i32) -> CRUDResult<usize>;
+    /// Removes attachments whose question or answer no longer exists and afterwards all
+    /// content no attachment refers to. Returns the number of removed contents.
+    fn delete_orphans(&mut self) -> CRUDResult<usize>;
+}
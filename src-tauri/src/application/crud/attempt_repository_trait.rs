@@ -0,0 +1,19 @@
+use crate::application::crud::crud_repository_trait::{CRUDRepository, CRUDResult};
+use crate::domain::model::attempt_answer::AttemptAnswer;
+
+pub trait AttemptRepository<T>: CRUDRepository<T> {
+    fn find_by_exam_id(&mut self, profile_id: i32, exam_id: i32) -> CRUDResult<Vec<T>>;
+    /// Unfinished attempts of the profile, most recently started first.
+    fn find_open(&mut self, profile_id: i32) -> CRUDResult<Vec<T>>;
+    /// Finished attempts of the profile, most recently started first.
+    fn find_finished(&mut self, profile_id: i32) -> CRUDResult<Vec<T>>;
+    /// Finished attempts of the exam by all profiles, most recently started first.
+    fn find_finished_by_exam_id(&mut self, exam_id: i32) -> CRUDResult<Vec<T>>;
+    fn find_answers_by_attempt_id(&mut self, attempt_id: i32) -> CRUDResult<Vec<AttemptAnswer>>;
+    fn replace_answers_for_question(
+        &mut self,
+        attempt_id: i32,
+        question_id: i32,
+        answers: &[AttemptAnswer],
+    ) -> CRUDResult<Vec<AttemptAnswer>>;
+}
@@ -0,0 +1,14 @@
+use crate::application::crud::crud_repository_trait::CRUDResult;
+use crate::domain::model::bookmark::Bookmark;
+
+pub trait BookmarkRepository {
+    /// Bookmarks of a profile, most recent first.
+    fn find_by_profile_id(&mut self, profile_id: i32) -> CRUDResult<Vec<Bookmark>>;
+    fn find_by_question_id(
+        &mut self,
+        profile_id: i32,
+        question_id: i32,
+    ) -> CRUDResult<Option<Bookmark>>;
+    fn create(&mut self, bookmark: &Bookmark) -> CRUDResult<Bookmark>;
+    fn delete(&mut self, id: i32) -> CRUDResult<usize>;
+}
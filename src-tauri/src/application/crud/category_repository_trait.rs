@@ -10,4 +10,7 @@ pub trait CategoryRepository<T>: CRUDRepository<T>  {
         filter: &[FilterTree],
         page_options: Option<PageOptions>,
     ) -> CRUDResult<PagedResult<Category>>;
+
+    /// First category with exactly this name, used to resolve categories of imported exams.
+    fn find_by_name(&mut self, name: &str) -> CRUDResult<Option<Category>>;
 }
\ No newline at end of file
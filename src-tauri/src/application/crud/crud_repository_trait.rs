@@ -1,5 +1,6 @@
 use crate::domain::model::page_options::PageOptions;
 use crate::domain::model::paged_result::PagedResult;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
 use diesel::result::Error;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -47,6 +48,17 @@ impl fmt::Display for CRUDError {
 
 impl std::error::Error for CRUDError {}
 
+impl From<CRUDError> for ExtendedValidationError {
+    fn from(value: CRUDError) -> Self {
+        Self::new(
+            0,
+            value.message,
+            value.validation_errors.unwrap_or_default(),
+            vec![],
+        )
+    }
+}
+
 pub type CRUDResult<T> = Result<T, CRUDError>;
 
 pub trait CRUDRepository<T> {
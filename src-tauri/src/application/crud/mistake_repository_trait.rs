@@ -0,0 +1,15 @@
+use crate::application::crud::crud_repository_trait::CRUDResult;
+use crate::domain::model::mistake::Mistake;
+
+pub trait MistakeRepository {
+    /// Mistakes of a profile, most missed first.
+    fn find_by_profile_id(&mut self, profile_id: i32) -> CRUDResult<Vec<Mistake>>;
+    fn find_by_question_ids(
+        &mut self,
+        profile_id: i32,
+        question_ids: &[i32],
+    ) -> CRUDResult<Vec<Mistake>>;
+    /// Creates the entry of a question or updates the existing one.
+    fn save(&mut self, mistake: &Mistake) -> CRUDResult<Mistake>;
+    fn delete(&mut self, id: i32) -> CRUDResult<usize>;
+}
@@ -1,10 +1,19 @@
 pub mod answer_repository_trait;
 pub mod assignment_option_repository_trait;
+pub mod attachment_repository_trait;
+pub mod attempt_repository_trait;
+pub mod bookmark_repository_trait;
 pub(crate) mod crud_repository_trait;
 pub mod enum_converter_trait;
 pub mod exam_repository_trait;
 pub mod execute_transactionally;
+pub mod mistake_repository_trait;
+pub mod profile_repository_trait;
+pub mod question_draw_repository_trait;
 pub mod question_repository_trait;
+pub mod review_state_repository_trait;
+pub mod setting_repository_trait;
+pub mod source_reference_repository_trait;
 pub mod category_repository_trait;
 
 #[cfg(test)]
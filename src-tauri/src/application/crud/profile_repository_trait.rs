@@ -0,0 +1,14 @@
+use crate::application::crud::crud_repository_trait::CRUDResult;
+use crate::domain::model::profile::Profile;
+
+pub trait ProfileRepository {
+    fn find_all(&mut self) -> CRUDResult<Vec<Profile>>;
+    fn find_by_id(&mut self, id: i32) -> CRUDResult<Option<Profile>>;
+    fn find_by_name(&mut self, name: &str) -> CRUDResult<Option<Profile>>;
+    fn find_active(&mut self) -> CRUDResult<Option<Profile>>;
+    fn create(&mut self, profile: &Profile) -> CRUDResult<Profile>;
+    fn update(&mut self, profile: &Profile) -> CRUDResult<Profile>;
+    fn delete(&mut self, id: i32) -> CRUDResult<usize>;
+    /// Marks the profile as active and deactivates every other profile.
+    fn activate(&mut self, id: i32) -> CRUDResult<Profile>;
+}
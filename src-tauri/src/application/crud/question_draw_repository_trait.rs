@@ -0,0 +1,9 @@
+use crate::application::crud::crud_repository_trait::CRUDResult;
+use crate::domain::model::question_draw::QuestionDraw;
+
+pub trait QuestionDrawRepository {
+    fn create(&mut self, draw: &QuestionDraw) -> CRUDResult<QuestionDraw>;
+    fn find_by_id(&mut self, id: i32) -> CRUDResult<Option<QuestionDraw>>;
+    /// Returns the draws of an exam by the profile, most recent first.
+    fn find_by_exam_id(&mut self, profile_id: i32, exam_id: i32) -> CRUDResult<Vec<QuestionDraw>>;
+}
@@ -9,4 +9,13 @@ pub trait QuestionRepository {
         exam_id: i32,
         page_options: Option<PageOptions>,
     ) -> CRUDResult<PagedResult<Question>>;
+    /// Loads questions with category, answers and assignment options, optionally
+    /// restricted to an exam and/or a category.
+    fn find_with_relations(
+        &mut self,
+        exam_id: Option<i32>,
+        category_id: Option<i32>,
+    ) -> CRUDResult<Vec<Question>>;
+    /// Loads the given questions with category, answers and assignment options.
+    fn find_by_ids_with_relations(&mut self, question_ids: &[i32]) -> CRUDResult<Vec<Question>>;
 }
@@ -0,0 +1,17 @@
+use crate::application::crud::crud_repository_trait::CRUDResult;
+use crate::domain::model::review_state::ReviewState;
+
+pub trait ReviewStateRepository {
+    fn find_by_question_id(
+        &mut self,
+        profile_id: i32,
+        question_id: i32,
+    ) -> CRUDResult<Option<ReviewState>>;
+    fn find_by_question_ids(
+        &mut self,
+        profile_id: i32,
+        question_ids: &[i32],
+    ) -> CRUDResult<Vec<ReviewState>>;
+    /// Creates the state of a question or updates the existing one.
+    fn save(&mut self, state: &ReviewState) -> CRUDResult<ReviewState>;
+}
@@ -0,0 +1,9 @@
+use crate::application::crud::crud_repository_trait::CRUDResult;
+use crate::domain::model::setting::Setting;
+
+pub trait SettingRepository {
+    fn find_all(&mut self, profile_id: i32) -> CRUDResult<Vec<Setting>>;
+    fn find_by_key(&mut self, profile_id: i32, key: &str) -> CRUDResult<Option<Setting>>;
+    /// Inserts the setting or replaces the value stored under its key for its profile.
+    fn save(&mut self, setting: &Setting) -> CRUDResult<Setting>;
+}
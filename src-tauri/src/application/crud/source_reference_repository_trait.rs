@@ -0,0 +1,13 @@
+use crate::application::crud::crud_repository_trait::CRUDResult;
+use crate::domain::model::source_reference::SourceReference;
+
+pub trait SourceReferenceRepository {
+    /// Sources of a question in their stored order.
+    fn find_by_question_id(&mut self, question_id: i32) -> CRUDResult<Vec<SourceReference>>;
+    /// Replaces all sources of a question, keeping the given order.
+    fn replace_for_question(
+        &mut self,
+        question_id: i32,
+        sources: &[SourceReference],
+    ) -> CRUDResult<Vec<SourceReference>>;
+}
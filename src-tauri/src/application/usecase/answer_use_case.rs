@@ -3,6 +3,7 @@ use crate::application::crud::crud_repository_trait::{CRUDError, CRUDRepository}
 use crate::application::crud::execute_transactionally::{
     execute_transactionally, execute_transactionally_mut,
 };
+use crate::application::usecase::attachment_use_case::AttachmentUseCase;
 use crate::domain::model::answer::Answer;
 use crate::infrastructure::repositories::sqlite_answer_crud_repository::SQLiteAnswerCrudRepository;
 use diesel::SqliteConnection;
@@ -100,7 +101,9 @@ impl AnswerUseCase {
     pub fn delete_answer(conn: &mut SqliteConnection, answer_id: i32) -> Result<usize, CRUDError> {
         execute_transactionally_mut(conn, |conn| {
             let mut answer_repository = SQLiteAnswerCrudRepository::new(conn);
-            answer_repository.delete(answer_id)
+            let deleted = answer_repository.delete(answer_id)?;
+            AttachmentUseCase::collect_garbage(conn)?;
+            Ok(deleted)
         })
     }
 }
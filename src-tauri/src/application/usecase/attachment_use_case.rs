@@ -0,0 +1,123 @@
+use crate::application::crud::attachment_repository_trait::AttachmentRepository;
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDRepository, CRUDResult};
+use crate::application::crud::execute_transactionally::execute_transactionally_mut;
+use crate::domain::model::attachment::{Attachment, MAX_ATTACHMENT_SIZE};
+use crate::domain::traits::validation::Validation;
+use crate::infrastructure::repositories::sqlite_answer_crud_repository::SQLiteAnswerCrudRepository;
+use crate::infrastructure::repositories::sqlite_attachment_repository::SQLiteAttachmentRepository;
+use crate::infrastructure::repositories::sqlite_question_crud_repository::SQLiteQuestionCrudRepository;
+use diesel::SqliteConnection;
+
+const LOG_TAG: &str = "[AttachmentUseCase]";
+
+pub struct AttachmentUseCase();
+
+impl AttachmentUseCase {
+    /// Stores the base64 encoded data of the attachment content-addressed and links it to
+    /// its question or answer. The returned attachment carries no data.
+    pub fn upload_attachment(
+        conn: &mut SqliteConnection,
+        attachment: Attachment,
+    ) -> CRUDResult<Attachment> {
+        attachment
+            .validate()
+            .map_err(|e| CRUDError::new("Validation errors: ", Some(e)))?;
+        let content = attachment
+            .decode_data()
+            .map_err(|e| CRUDError::new(e, None))?
+            .ok_or(CRUDError::new("Attachment data is missing", None))?;
+        if content.is_empty() {
+            return Err(CRUDError::new("Attachment data is empty", None));
+        }
+        if content.len() > MAX_ATTACHMENT_SIZE {
+            return Err(CRUDError::new(
+                format!("Attachments may not be larger than {MAX_ATTACHMENT_SIZE} bytes"),
+                None,
+            ));
+        }
+
+        execute_transactionally_mut(conn, |conn| {
+            if let Some(question_id) = attachment.question_id {
+                SQLiteQuestionCrudRepository::new(conn)
+                    .find_by_id(question_id)?
+                    .ok_or(CRUDError::new("Question not found", None))?;
+            }
+            if let Some(answer_id) = attachment.answer_id {
+                SQLiteAnswerCrudRepository::new(conn)
+                    .find_by_id(answer_id)?
+                    .ok_or(CRUDError::new("Answer not found", None))?;
+            }
+
+            let hash = Attachment::content_hash(&content);
+            let mut repository = SQLiteAttachmentRepository::new(conn);
+            repository.save_content(&hash, &content)?;
+
+            log::info!(
+                "{LOG_TAG} Attaching {} ({} bytes, {hash})",
+                attachment.file_name,
+                content.len()
+            );
+            repository.create(&Attachment {
+                hash,
+                size: content.len() as i32,
+                data: None,
+                ..attachment
+            })
+        })
+    }
+
+    /// Attachments of a question including those of its answers, or of a single answer.
+    pub fn get_attachments(
+        conn: &mut SqliteConnection,
+        question_id: Option<i32>,
+        answer_id: Option<i32>,
+    ) -> CRUDResult<Vec<Attachment>> {
+        let mut repository = SQLiteAttachmentRepository::new(conn);
+        match (question_id, answer_id) {
+            (Some(question_id), None) => repository.find_by_question_ids(&[question_id]),
+            (None, Some(answer_id)) => repository.find_by_answer_id(answer_id),
+            _ => Err(CRUDError::new(
+                "Either a question id or an answer id is required",
+                None,
+            )),
+        }
+    }
+
+    /// Attachment with its base64 encoded data.
+    pub fn get_attachment(
+        conn: &mut SqliteConnection,
+        attachment_id: i32,
+    ) -> CRUDResult<Attachment> {
+        let mut repository = SQLiteAttachmentRepository::new(conn);
+        let mut attachment = repository
+            .find_by_id(attachment_id)?
+            .ok_or(CRUDError::new("Attachment not found", None))?;
+        let content = repository
+            .find_content(&attachment.hash)?
+            .ok_or(CRUDError::new(
+                format!("Content of attachment {attachment_id} is missing"),
+                None,
+            ))?;
+
+        attachment.data = Some(Attachment::encode_data(&content));
+        Ok(attachment)
+    }
+
+    pub fn delete_attachment(conn: &mut SqliteConnection, attachment_id: i32) -> CRUDResult<usize> {
+        execute_transactionally_mut(conn, |conn| {
+            let deleted = SQLiteAttachmentRepository::new(conn).delete(attachment_id)?;
+            AttachmentUseCase::collect_garbage(conn)?;
+            Ok(deleted)
+        })
+    }
+
+    /// Removes attachments left behind by deleted questions and answers and all content
+    /// no longer referenced by any attachment.
+    pub fn collect_garbage(conn: &mut SqliteConnection) -> CRUDResult<usize> {
+        let removed = SQLiteAttachmentRepository::new(conn).delete_orphans()?;
+        if removed > 0 {
+            log::info!("{LOG_TAG} Removed {removed} unreferenced attachment contents");
+        }
+        Ok(removed)
+    }
+}
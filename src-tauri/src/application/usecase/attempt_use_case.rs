@@ -0,0 +1,517 @@
+use crate::application::crud::answer_repository_trait::AnswerRepository;
+use crate::application::crud::assignment_option_repository_trait::AssignmentOptionRepository;
+use crate::application::crud::attempt_repository_trait::AttemptRepository;
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDRepository, CRUDResult};
+use crate::application::crud::exam_repository_trait::ExamRepository;
+use crate::application::crud::execute_transactionally::execute_transactionally_mut;
+use crate::application::crud::question_draw_repository_trait::QuestionDrawRepository;
+use crate::application::usecase::mistake_use_case::MistakeUseCase;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::application::usecase::question_draw_use_case::QuestionDrawUseCase;
+use crate::application::usecase::setting_use_case::SettingUseCase;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::exam_attempt::ExamAttempt;
+use crate::domain::model::exam_score::ExamScore;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_draw::QuestionDraw;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::resumed_attempt::ResumedAttempt;
+use crate::domain::sampling::attempt_shuffler::AttemptShuffler;
+use crate::domain::sampling::seeded_random::SeededRandom;
+use crate::domain::scoring::scoring_engine::ScoringEngine;
+use crate::infrastructure::repositories::sqlite_answer_crud_repository::SQLiteAnswerCrudRepository;
+use crate::infrastructure::repositories::sqlite_assignment_option_crud_repository::SQLiteAssignmentOptionCrudRepository;
+use crate::infrastructure::repositories::sqlite_attempt_crud_repository::SQLiteAttemptCrudRepository;
+use crate::infrastructure::repositories::sqlite_exam_crud_repository::SQLiteExamCrudRepository;
+use crate::infrastructure::repositories::sqlite_question_crud_repository::SQLiteQuestionCrudRepository;
+use crate::infrastructure::repositories::sqlite_question_draw_repository::SQLiteQuestionDrawRepository;
+use chrono::{Duration, Utc};
+use diesel::SqliteConnection;
+use std::collections::HashSet;
+
+const LOG_TAG: &str = "[AttemptUseCase]";
+
+pub struct AttemptUseCase();
+
+impl AttemptUseCase {
+    /// Starts an attempt over the questions of `draw_id`, or over all questions of the
+    /// exam without a draw. Certification attempts without a draw get a new one, so they
+    /// cover `max_questions_real_exam` questions.
+    pub fn start_attempt(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        mode: AttemptMode,
+        draw_id: Option<i32>,
+    ) -> CRUDResult<ExamAttempt> {
+        execute_transactionally_mut(conn, |conn| {
+            let exam = SQLiteExamCrudRepository::new(conn)
+                .find_by_id(exam_id)?
+                .ok_or(CRUDError::new("Exam not found", None))?;
+            let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+
+            let draw_id = match (draw_id, &mode) {
+                (Some(draw_id), _) => {
+                    AttemptUseCase::find_draw(conn, draw_id, exam_id, profile_id)?.id
+                }
+                (None, AttemptMode::Certification) => {
+                    QuestionDrawUseCase::draw_questions(conn, exam_id, None, false)?.id
+                }
+                (None, _) => None,
+            };
+
+            let started_at = Utc::now();
+            let deadline_at = match (&mode, exam.duration) {
+                (AttemptMode::Certification, Some(minutes)) if minutes > 0 => {
+                    Some(started_at + Duration::minutes(minutes as i64))
+                }
+                _ => None,
+            };
+
+            log::info!("{LOG_TAG} Starting {mode:?} attempt for exam with id: {exam_id}");
+            let mut attempt = ExamAttempt {
+                id: None,
+                exam_id,
+                profile_id,
+                mode,
+                started_at,
+                finished_at: None,
+                score: None,
+                created_at: None,
+                updated_at: None,
+                deadline_at,
+                expired: false,
+                shuffle_seed: Some(SeededRandom::generate_seed()),
+                draw_id,
+                content_revision: None,
+                question_ids: vec![],
+                answers: vec![],
+            };
+            // the content and the question order are kept to detect changed exams on resume
+            let attempt_exam = AttemptUseCase::find_exam(conn, &attempt)?;
+            attempt.content_revision = Some(attempt_exam.content_revision());
+            attempt.question_ids =
+                AttemptUseCase::ordered_questions(&attempt, attempt_exam.questions)
+                    .iter()
+                    .filter_map(|q| q.id)
+                    .collect();
+            SQLiteAttemptCrudRepository::new(conn).create(&attempt)
+        })
+    }
+
+    pub fn answer_question(
+        conn: &mut SqliteConnection,
+        attempt_id: i32,
+        question_id: i32,
+        answers: Vec<AttemptAnswer>,
+    ) -> CRUDResult<Vec<AttemptAnswer>> {
+        let saved = execute_transactionally_mut(conn, |conn| {
+            let attempt = AttemptUseCase::find_open_attempt(conn, attempt_id)?;
+            let attempt = AttemptUseCase::finalize_if_expired(conn, attempt)?;
+            if attempt.expired {
+                // keeps the finalized attempt, the answer is rejected below
+                return Ok(None);
+            }
+
+            let question = SQLiteQuestionCrudRepository::new(conn)
+                .find_by_id(question_id)?
+                .ok_or(CRUDError::new("Question not found", None))?;
+            if question.exam_id != Some(attempt.exam_id) {
+                return Err(CRUDError::new(
+                    format!(
+                        "Question with id: {question_id} does not belong to the exam of attempt {attempt_id}"
+                    ),
+                    None,
+                ));
+            }
+            if let Some(draw_id) = attempt.draw_id {
+                let draw =
+                    AttemptUseCase::find_draw(conn, draw_id, attempt.exam_id, attempt.profile_id)?;
+                if !draw.question_ids.contains(&question_id) {
+                    return Err(CRUDError::new(
+                        format!(
+                            "Question with id: {question_id} was not drawn for attempt {attempt_id}"
+                        ),
+                        None,
+                    ));
+                }
+            }
+
+            let question_answers =
+                SQLiteAnswerCrudRepository::new(conn).get_all_for_question(question_id)?;
+            let assignment_options = if question.r#type == QuestionType::Assignment {
+                SQLiteAssignmentOptionCrudRepository::new(conn)
+                    .get_assigment_options_by_question_id(question_id)?
+            } else {
+                vec![]
+            };
+            let mut answers_to_save: Vec<AttemptAnswer> = Vec::with_capacity(answers.len());
+            for mut answer in answers {
+                if let Some(answer_id) = answer.answer_id {
+                    if !question_answers.iter().any(|a| a.id == Some(answer_id)) {
+                        return Err(CRUDError::new(
+                            format!(
+                                "Answer with id: {answer_id} does not belong to question {question_id}"
+                            ),
+                            None,
+                        ));
+                    }
+                }
+                if let Some(option_id) = answer.assigned_option_id {
+                    if question.r#type != QuestionType::Assignment {
+                        return Err(CRUDError::new(
+                            format!(
+                                "Question with id: {question_id} is not an assignment question"
+                            ),
+                            None,
+                        ));
+                    }
+                    if !assignment_options.iter().any(|o| o.id == option_id) {
+                        return Err(CRUDError::new(
+                            format!(
+                                "Assignment option with id: {option_id} does not belong to question {question_id}"
+                            ),
+                            None,
+                        ));
+                    }
+                }
+                answer.id = None;
+                answer.attempt_id = Some(attempt_id);
+                answer.question_id = question_id;
+                answers_to_save.push(answer);
+            }
+
+            if question.r#type == QuestionType::Ordering {
+                AttemptUseCase::check_permutation(
+                    question_id,
+                    &answers_to_save,
+                    question_answers.len(),
+                )?;
+            }
+
+            log::info!(
+                "{LOG_TAG} Saving {} answers for question {question_id} in attempt {attempt_id}",
+                answers_to_save.len()
+            );
+            SQLiteAttemptCrudRepository::new(conn)
+                .replace_answers_for_question(attempt_id, question_id, &answers_to_save)
+                .map(Some)
+        })?;
+        saved.ok_or(CRUDError::new(
+            format!("Attempt with id: {attempt_id} ran out of time"),
+            None,
+        ))
+    }
+
+    pub fn submit_attempt(conn: &mut SqliteConnection, attempt_id: i32) -> CRUDResult<ExamAttempt> {
+        execute_transactionally_mut(conn, |conn| {
+            let attempt = AttemptUseCase::find_open_attempt(conn, attempt_id)?;
+            let grace_period = SettingUseCase::get_attempt_grace_period(conn)?;
+            let expired = attempt.is_past_deadline(Utc::now(), grace_period);
+            AttemptUseCase::finalize(conn, attempt, expired, grace_period)
+        })
+    }
+
+    pub fn get_attempt_score(
+        conn: &mut SqliteConnection,
+        attempt_id: i32,
+    ) -> CRUDResult<ExamScore> {
+        let attempt = AttemptUseCase::get_attempt_by_id(conn, attempt_id)?
+            .ok_or(CRUDError::new("Attempt not found", None))?;
+        let grace_period = SettingUseCase::get_attempt_grace_period(conn)?;
+        AttemptUseCase::score(conn, &attempt, grace_period)
+    }
+
+    /// Questions of the attempt's exam in the attempt's shuffled order. Answers and
+    /// assignment options are shuffled as well but keep their ids, so scoring is not
+    /// affected.
+    pub fn get_attempt_questions(
+        conn: &mut SqliteConnection,
+        attempt_id: i32,
+    ) -> CRUDResult<Vec<Question>> {
+        let attempt = AttemptUseCase::find_attempt(conn, attempt_id)?
+            .ok_or(CRUDError::new("Attempt not found", None))?;
+        let exam = AttemptUseCase::find_exam(conn, &attempt)?;
+
+        Ok(AttemptUseCase::ordered_questions(&attempt, exam.questions))
+    }
+
+    /// Unfinished attempts of the active profile. Attempts that ran out of time in the
+    /// meantime are finalized and left out.
+    pub fn get_open_attempts(conn: &mut SqliteConnection) -> CRUDResult<Vec<ExamAttempt>> {
+        execute_transactionally_mut(conn, |conn| {
+            let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+            let mut open_attempts = vec![];
+            for attempt in SQLiteAttemptCrudRepository::new(conn).find_open(profile_id)? {
+                let attempt = AttemptUseCase::finalize_if_expired(conn, attempt)?;
+                if !attempt.is_finished() {
+                    open_attempts.push(attempt);
+                }
+            }
+            Ok(open_attempts)
+        })
+    }
+
+    /// Returns an open attempt with the answers given so far, its exam with the
+    /// questions in the attempt's order and the time left. Attempts whose exam was
+    /// changed after they started can not be resumed.
+    pub fn resume_attempt(
+        conn: &mut SqliteConnection,
+        attempt_id: i32,
+    ) -> CRUDResult<ResumedAttempt> {
+        let attempt = AttemptUseCase::get_attempt_by_id(conn, attempt_id)?
+            .ok_or(CRUDError::new("Attempt not found", None))?;
+        if attempt.expired {
+            return Err(CRUDError::new(
+                format!("Attempt with id: {attempt_id} ran out of time"),
+                None,
+            ));
+        }
+        if attempt.is_finished() {
+            return Err(CRUDError::new(
+                format!("Attempt with id: {attempt_id} was already submitted"),
+                None,
+            ));
+        }
+
+        let mut exam = AttemptUseCase::find_exam(conn, &attempt)?;
+        let changed = match &attempt.content_revision {
+            Some(revision) => {
+                let question_ids: Vec<i32> =
+                    AttemptUseCase::seeded_order(&attempt, &exam.questions)
+                        .iter()
+                        .filter_map(|q| q.id)
+                        .collect();
+                *revision != exam.content_revision() || question_ids != attempt.question_ids
+            }
+            // attempts started before revisions were stored
+            None => exam
+                .last_modified_at()
+                .is_some_and(|modified| modified > attempt.started_at),
+        };
+        if changed {
+            return Err(CRUDError::new(
+                format!(
+                    "The exam of attempt {attempt_id} was changed after the attempt started and can not be resumed"
+                ),
+                None,
+            ));
+        }
+
+        exam.questions = AttemptUseCase::ordered_questions(&attempt, exam.questions);
+        let remaining_seconds = attempt
+            .deadline_at
+            .map(|deadline| (deadline - Utc::now()).num_seconds().max(0));
+        log::info!(
+            "{LOG_TAG} Resuming attempt {attempt_id} with {} answers given",
+            attempt.answers.len()
+        );
+        Ok(ResumedAttempt {
+            attempt,
+            exam,
+            remaining_seconds,
+        })
+    }
+
+    pub fn get_attempt_by_id(
+        conn: &mut SqliteConnection,
+        attempt_id: i32,
+    ) -> CRUDResult<Option<ExamAttempt>> {
+        execute_transactionally_mut(conn, |conn| {
+            match AttemptUseCase::find_attempt(conn, attempt_id)? {
+                Some(attempt) => Ok(Some(AttemptUseCase::finalize_if_expired(conn, attempt)?)),
+                None => Ok(None),
+            }
+        })
+    }
+
+    pub fn get_attempts_by_exam_id(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+    ) -> CRUDResult<Vec<ExamAttempt>> {
+        execute_transactionally_mut(conn, |conn| {
+            let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+            SQLiteAttemptCrudRepository::new(conn)
+                .find_by_exam_id(profile_id, exam_id)?
+                .into_iter()
+                .map(|attempt| AttemptUseCase::finalize_if_expired(conn, attempt))
+                .collect()
+        })
+    }
+
+    pub fn delete_attempt(conn: &mut SqliteConnection, attempt_id: i32) -> CRUDResult<usize> {
+        execute_transactionally_mut(conn, |conn| {
+            AttemptUseCase::find_attempt(conn, attempt_id)?
+                .ok_or(CRUDError::new("Attempt not found", None))?;
+            SQLiteAttemptCrudRepository::new(conn).delete(attempt_id)
+        })
+    }
+
+    /// Finalizes an open attempt whose deadline and grace period have passed.
+    fn finalize_if_expired(
+        conn: &mut SqliteConnection,
+        attempt: ExamAttempt,
+    ) -> CRUDResult<ExamAttempt> {
+        if attempt.is_finished() || attempt.deadline_at.is_none() {
+            return Ok(attempt);
+        }
+
+        let grace_period = SettingUseCase::get_attempt_grace_period(conn)?;
+        if !attempt.is_past_deadline(Utc::now(), grace_period) {
+            return Ok(attempt);
+        }
+        AttemptUseCase::finalize(conn, attempt, true, grace_period)
+    }
+
+    fn finalize(
+        conn: &mut SqliteConnection,
+        mut attempt: ExamAttempt,
+        expired: bool,
+        grace_period: Duration,
+    ) -> CRUDResult<ExamAttempt> {
+        let score = AttemptUseCase::score(conn, &attempt, grace_period)?;
+        let answered: HashSet<i32> = attempt
+            .answers_in_time(grace_period)
+            .iter()
+            .map(|a| a.question_id)
+            .collect();
+        let outcomes: Vec<(i32, bool)> = score
+            .questions
+            .iter()
+            .filter_map(|q| {
+                q.question_id
+                    .filter(|id| answered.contains(id))
+                    .map(|id| (id, q.is_fully_correct()))
+            })
+            .collect();
+        MistakeUseCase::record_outcomes(conn, attempt.profile_id, &outcomes)?;
+
+        attempt.finished_at = Some(Utc::now());
+        attempt.expired = expired;
+        attempt.score = Some(score.points_earned);
+        log::info!(
+            "{LOG_TAG} Finalizing {}attempt {:?} with score: {}/{}",
+            if expired { "expired " } else { "" },
+            attempt.id,
+            score.points_earned,
+            score.points_total
+        );
+        SQLiteAttemptCrudRepository::new(conn).update(&attempt)
+    }
+
+    /// Scores the answers given in time; late answers of an expired attempt are ignored.
+    fn score(
+        conn: &mut SqliteConnection,
+        attempt: &ExamAttempt,
+        grace_period: Duration,
+    ) -> CRUDResult<ExamScore> {
+        let exam = AttemptUseCase::find_exam(conn, attempt)?;
+        Ok(ScoringEngine::score_exam(
+            &exam,
+            &attempt.answers_in_time(grace_period),
+        ))
+    }
+
+    /// Exam of the attempt with its relations. For attempts over a question draw only the
+    /// drawn questions are kept, in the order they were drawn.
+    fn find_exam(conn: &mut SqliteConnection, attempt: &ExamAttempt) -> CRUDResult<Exam> {
+        let mut exam = SQLiteExamCrudRepository::new(conn)
+            .find_by_id_with_relations(attempt.exam_id)?
+            .ok_or(CRUDError::new("Exam not found", None))?;
+        if let Some(draw_id) = attempt.draw_id {
+            let draw =
+                AttemptUseCase::find_draw(conn, draw_id, attempt.exam_id, attempt.profile_id)?;
+            exam.questions = draw
+                .question_ids
+                .iter()
+                .filter_map(|id| exam.questions.iter().find(|q| q.id == Some(*id)).cloned())
+                .collect();
+        }
+        Ok(exam)
+    }
+
+    /// Draw of the exam by the profile, draws of other exams or profiles are not found.
+    fn find_draw(
+        conn: &mut SqliteConnection,
+        draw_id: i32,
+        exam_id: i32,
+        profile_id: i32,
+    ) -> CRUDResult<QuestionDraw> {
+        SQLiteQuestionDrawRepository::new(conn)
+            .find_by_id(draw_id)?
+            .filter(|draw| draw.exam_id == exam_id && draw.profile_id == profile_id)
+            .ok_or(CRUDError::new("Question draw not found", None))
+    }
+
+    /// Attempts of other profiles are treated as if they did not exist.
+    fn find_attempt(
+        conn: &mut SqliteConnection,
+        attempt_id: i32,
+    ) -> CRUDResult<Option<ExamAttempt>> {
+        let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+        Ok(SQLiteAttemptCrudRepository::new(conn)
+            .find_by_id(attempt_id)?
+            .filter(|attempt| attempt.profile_id == profile_id))
+    }
+
+    /// Questions in the order stored at the start of the attempt, questions added later
+    /// at the end. Answers and assignment options keep the order of the attempt's seed.
+    fn ordered_questions(attempt: &ExamAttempt, questions: Vec<Question>) -> Vec<Question> {
+        let shuffled = AttemptUseCase::seeded_order(attempt, &questions);
+        if attempt.question_ids.is_empty() {
+            return shuffled;
+        }
+
+        let (mut ordered, added): (Vec<Question>, Vec<Question>) = shuffled
+            .into_iter()
+            .partition(|q| q.id.is_some_and(|id| attempt.question_ids.contains(&id)));
+        ordered.sort_by_key(|q| attempt.question_ids.iter().position(|id| q.id == Some(*id)));
+        ordered.extend(added);
+        ordered
+    }
+
+    fn seeded_order(attempt: &ExamAttempt, questions: &[Question]) -> Vec<Question> {
+        match attempt.shuffle_seed {
+            Some(seed) => AttemptShuffler::shuffle(questions, seed),
+            None => questions.to_vec(),
+        }
+    }
+
+    fn find_open_attempt(conn: &mut SqliteConnection, attempt_id: i32) -> CRUDResult<ExamAttempt> {
+        let attempt = AttemptUseCase::find_attempt(conn, attempt_id)?
+            .ok_or(CRUDError::new("Attempt not found", None))?;
+
+        if attempt.is_finished() {
+            return Err(CRUDError::new(
+                format!("Attempt with id: {attempt_id} was already submitted"),
+                None,
+            ));
+        }
+        Ok(attempt)
+    }
+
+    /// The submitted positions of an ordering question have to be distinct positions of
+    /// the sequence, items left out are graded as unplaced.
+    fn check_permutation(
+        question_id: i32,
+        answers: &[AttemptAnswer],
+        count: usize,
+    ) -> CRUDResult<()> {
+        let mut seen: HashSet<i32> = HashSet::new();
+        for answer in answers {
+            let valid = answer
+                .position
+                .is_some_and(|p| p >= 1 && p as usize <= count && seen.insert(p));
+            if !valid {
+                return Err(CRUDError::new(
+                    format!(
+                        "Positions for question {question_id} must be distinct and between 1 and {count}"
+                    ),
+                    None,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
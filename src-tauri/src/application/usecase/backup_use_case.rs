@@ -0,0 +1,171 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::domain::model::backup::Backup;
+use diesel::migration::MigrationSource;
+use diesel::sql_types::Text;
+use diesel::sqlite::Sqlite;
+use diesel::{Connection, QueryableByName, RunQueryDsl, SqliteConnection};
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const LOG_TAG: &str = "[BackupUseCase]";
+
+#[derive(QueryableByName)]
+struct IntegrityCheck {
+    #[diesel(sql_type = Text)]
+    integrity_check: String,
+}
+
+pub struct BackupUseCase();
+
+impl BackupUseCase {
+    /// Writes a consistent copy of the database to `path` with `VACUUM INTO`. Attachments
+    /// and settings are stored in the database and therefore part of the copy. The copy is
+    /// written next to `path` first, so an existing file is only replaced by a complete
+    /// backup.
+    pub fn create_backup(conn: &mut SqliteConnection, path: &Path) -> CRUDResult<Backup> {
+        let partial = Self::with_suffix(path, ".partial");
+        let _ = fs::remove_file(&partial);
+
+        diesel::sql_query("VACUUM INTO ?")
+            .bind::<Text, _>(partial.display().to_string())
+            .execute(conn)
+            .map_err(|e| {
+                let _ = fs::remove_file(&partial);
+                CRUDError::new(format!("Unable to create backup: {e}"), None)
+            })?;
+        fs::rename(&partial, path).map_err(|e| {
+            let _ = fs::remove_file(&partial);
+            CRUDError::new(format!("Unable to write {}: {e}", path.display()), None)
+        })?;
+
+        let backup = Backup {
+            path: path.display().to_string(),
+            size: Self::size(path)?,
+            schema_version: Self::schema_version(conn)?,
+        };
+        log::info!(
+            "{LOG_TAG} Created backup with schema version {} ({} bytes) at {}",
+            backup.schema_version,
+            backup.size,
+            backup.path
+        );
+        Ok(backup)
+    }
+
+    /// Replaces the database at `database_path` with the backup at `path`.
+    ///
+    /// The backup is copied next to the database and checked there: it has to pass
+    /// `PRAGMA integrity_check` and may only contain migrations known to `migrations`.
+    /// Backups of older versions are migrated. Only then the copy is renamed over the
+    /// database, so a rejected backup leaves the database untouched.
+    pub fn restore_backup(
+        database_path: &Path,
+        path: &Path,
+        migrations: EmbeddedMigrations,
+    ) -> CRUDResult<Backup> {
+        if !path.is_file() {
+            return Err(CRUDError::new(
+                format!("Backup {} does not exist", path.display()),
+                None,
+            ));
+        }
+
+        let restoring = Self::with_suffix(database_path, ".restoring");
+        fs::copy(path, &restoring)
+            .map_err(|e| CRUDError::new(format!("Unable to read {}: {e}", path.display()), None))?;
+
+        let schema_version = Self::prepare_restore(&restoring, migrations).inspect_err(|_| {
+            let _ = fs::remove_file(&restoring);
+        })?;
+
+        // A journal left behind belongs to the replaced database.
+        for suffix in ["-journal", "-wal", "-shm"] {
+            let _ = fs::remove_file(Self::with_suffix(database_path, suffix));
+        }
+        fs::rename(&restoring, database_path).map_err(|e| {
+            let _ = fs::remove_file(&restoring);
+            CRUDError::new(format!("Unable to replace database: {e}"), None)
+        })?;
+
+        let backup = Backup {
+            path: path.display().to_string(),
+            size: Self::size(database_path)?,
+            schema_version,
+        };
+        log::info!(
+            "{LOG_TAG} Restored backup with schema version {} from {}",
+            backup.schema_version,
+            backup.path
+        );
+        Ok(backup)
+    }
+
+    /// Verifies the copy of a backup and migrates it to the current schema. Returns the
+    /// schema version of the backup as it was created.
+    fn prepare_restore(path: &Path, migrations: EmbeddedMigrations) -> CRUDResult<String> {
+        let mut conn = SqliteConnection::establish(&path.display().to_string())
+            .map_err(|e| CRUDError::new(format!("Unable to open backup: {e}"), None))?;
+
+        let problems = diesel::sql_query("PRAGMA integrity_check")
+            .load::<IntegrityCheck>(&mut conn)
+            .map_err(|e| CRUDError::new(format!("Backup is not a valid database: {e}"), None))?;
+        if problems.len() != 1 || problems[0].integrity_check != "ok" {
+            let problems: Vec<String> = problems.into_iter().map(|p| p.integrity_check).collect();
+            return Err(CRUDError::new(
+                format!("Backup is damaged: {}", problems.join(", ")),
+                None,
+            ));
+        }
+
+        let schema_version = Self::schema_version(&mut conn)?;
+        let known: Vec<String> = MigrationSource::<Sqlite>::migrations(&migrations)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?
+            .iter()
+            .map(|m| m.name().version().to_string())
+            .collect();
+        let unknown: Vec<String> = conn
+            .applied_migrations()
+            .map_err(|e| CRUDError::new(e.to_string(), None))?
+            .into_iter()
+            .map(|v| v.to_string())
+            .filter(|v| !known.contains(v))
+            .collect();
+        if !unknown.is_empty() {
+            return Err(CRUDError::new(
+                format!(
+                    "Backup was created by a newer version, unknown migrations: {}",
+                    unknown.join(", ")
+                ),
+                None,
+            ));
+        }
+
+        conn.run_pending_migrations(migrations)
+            .map_err(|e| CRUDError::new(format!("Unable to migrate backup: {e}"), None))?;
+        Ok(schema_version)
+    }
+
+    /// Latest applied migration, fails for databases without migrations.
+    fn schema_version(conn: &mut SqliteConnection) -> CRUDResult<String> {
+        conn.applied_migrations()
+            .map_err(|e| CRUDError::new(e.to_string(), None))?
+            .into_iter()
+            .max()
+            .map(|v| v.to_string())
+            .ok_or(CRUDError::new("Backup is not an open-xam database", None))
+    }
+
+    fn size(path: &Path) -> CRUDResult<u64> {
+        fs::metadata(path)
+            .map(|m| m.len())
+            .map_err(|e| CRUDError::new(format!("Unable to read {}: {e}", path.display()), None))
+    }
+
+    /// `path` with `suffix` appended, in the same directory so that renaming is atomic.
+    fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+        let mut name = path.as_os_str().to_owned();
+        name.push(suffix);
+        PathBuf::from(name)
+    }
+}
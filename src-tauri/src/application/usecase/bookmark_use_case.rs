@@ -0,0 +1,70 @@
+use crate::application::crud::bookmark_repository_trait::BookmarkRepository;
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::application::crud::execute_transactionally::execute_transactionally_mut;
+use crate::application::crud::question_repository_trait::QuestionRepository;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::domain::model::bookmark::Bookmark;
+use crate::domain::model::question::Question;
+use crate::infrastructure::repositories::sqlite_bookmark_repository::SQLiteBookmarkRepository;
+use crate::infrastructure::repositories::sqlite_question_crud_repository::SQLiteQuestionCrudRepository;
+use diesel::SqliteConnection;
+use std::collections::HashMap;
+
+const LOG_TAG: &str = "[BookmarkUseCase]";
+
+pub struct BookmarkUseCase();
+
+impl BookmarkUseCase {
+    /// Bookmarks a question for the active profile. Bookmarking a question twice
+    /// returns the existing bookmark.
+    pub fn add_bookmark(conn: &mut SqliteConnection, question_id: i32) -> CRUDResult<Bookmark> {
+        execute_transactionally_mut(conn, |conn| {
+            if SQLiteQuestionCrudRepository::new(conn)
+                .find_by_ids_with_relations(&[question_id])?
+                .is_empty()
+            {
+                return Err(CRUDError::new("Question not found", None));
+            }
+            let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+
+            let mut repository = SQLiteBookmarkRepository::new(conn);
+            if let Some(existing) = repository.find_by_question_id(profile_id, question_id)? {
+                return Ok(existing);
+            }
+            log::info!("{LOG_TAG} Bookmarking question {question_id} for profile {profile_id}");
+            repository.create(&Bookmark::new(profile_id, question_id))
+        })
+    }
+
+    /// Removes the bookmark of a question of the active profile, returns the number of
+    /// removed bookmarks.
+    pub fn remove_bookmark(conn: &mut SqliteConnection, question_id: i32) -> CRUDResult<usize> {
+        let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+        let mut repository = SQLiteBookmarkRepository::new(conn);
+        match repository.find_by_question_id(profile_id, question_id)? {
+            Some(existing) => repository.delete(existing.id.unwrap_or_default()),
+            None => Ok(0),
+        }
+    }
+
+    /// Bookmarks of the active profile with their questions, most recent first.
+    pub fn get_bookmarks(conn: &mut SqliteConnection) -> CRUDResult<Vec<Bookmark>> {
+        let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+        let bookmarks = SQLiteBookmarkRepository::new(conn).find_by_profile_id(profile_id)?;
+
+        let question_ids: Vec<i32> = bookmarks.iter().map(|b| b.question_id).collect();
+        let mut questions: HashMap<i32, Question> = SQLiteQuestionCrudRepository::new(conn)
+            .find_by_ids_with_relations(&question_ids)?
+            .into_iter()
+            .filter_map(|q| q.id.map(|id| (id, q)))
+            .collect();
+
+        Ok(bookmarks
+            .into_iter()
+            .map(|mut b| {
+                b.question = questions.remove(&b.question_id);
+                b
+            })
+            .collect())
+    }
+}
@@ -1,18 +1,62 @@
+use crate::application::crud::attachment_repository_trait::AttachmentRepository;
+use crate::application::crud::attempt_repository_trait::AttemptRepository;
+use crate::application::crud::category_repository_trait::CategoryRepository;
 use crate::application::crud::crud_repository_trait::{CRUDError, CRUDRepository, CRUDResult};
 use crate::application::crud::exam_repository_trait::ExamRepository;
 use crate::application::crud::execute_transactionally::execute_transactionally_mut;
+use crate::application::crud::question_draw_repository_trait::QuestionDrawRepository;
+use crate::application::usecase::attachment_use_case::AttachmentUseCase;
 use crate::application::usecase::question_use_case::QuestionUseCase;
+use crate::application::usecase::setting_use_case::SettingUseCase;
+use crate::domain::export::exported_file::ExportedFile;
+use crate::domain::export::gift_writer::GiftWriter;
+use crate::domain::export::moodle_xml_writer::MoodleXmlWriter;
+use crate::domain::export::qti_package_writer::QtiPackageWriter;
+use crate::domain::import::gift_parser::GiftParser;
+use crate::domain::import::moodle_xml_reader::MoodleXmlReader;
+use crate::domain::import::qti_package_reader::QtiPackageReader;
+use crate::domain::import::spreadsheet_parser::{
+    ParsedSpreadsheet, SpreadsheetFormat, SpreadsheetParser, SpreadsheetRow,
+};
+use crate::domain::import::text_exam_parser::TextExamParser;
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::category::Category;
 use crate::domain::model::exam::Exam;
+use crate::domain::model::exam_bundle::ExamBundle;
 use crate::domain::model::exam_overall_statistics::ExamOverallStatistics;
+use crate::domain::model::exam_score::ExamScore;
+use crate::domain::model::export_report::ExportReport;
 use crate::domain::model::filter_option::FilterTree;
+use crate::domain::model::import_report::ImportReport;
 use crate::domain::model::page_options::PageOptions;
 use crate::domain::model::paged_result::PagedResult;
+use crate::domain::model::print_format::PrintFormat;
+use crate::domain::model::print_options::PrintOptions;
 use crate::domain::model::question::Question;
+use crate::domain::model::question_draw::QuestionDraw;
+use crate::domain::model::question_statistics::QuestionStatistics;
+use crate::domain::model::spreadsheet_import_report::SpreadsheetImportReport;
+use crate::domain::model::spreadsheet_mapping::SpreadsheetMapping;
+use crate::domain::rendering::html_renderer::HtmlRenderer;
+use crate::domain::rendering::markdown_renderer::MarkdownRenderer;
+use crate::domain::rendering::pdf_renderer::PdfRenderer;
+use crate::domain::rendering::printable_exam::PrintableExam;
+use crate::domain::scoring::scoring_engine::ScoringEngine;
+use crate::domain::statistics::item_analysis::ItemAnalysis;
 use crate::domain::traits::validation::Validation;
 use crate::domain::validation::extended_validation_error::ExtendedValidationError;
 use crate::domain::validation::validation_error::ValidationError;
+use crate::infrastructure::anki::anki_package_writer::AnkiPackageWriter;
+use crate::infrastructure::repositories::sqlite_attachment_repository::SQLiteAttachmentRepository;
+use crate::infrastructure::repositories::sqlite_attempt_crud_repository::SQLiteAttemptCrudRepository;
+use crate::infrastructure::repositories::sqlite_category_crud_repository::SQLiteCategoryCrudRepository;
 use crate::infrastructure::repositories::sqlite_exam_crud_repository::SQLiteExamCrudRepository;
+use crate::infrastructure::repositories::sqlite_question_draw_repository::SQLiteQuestionDrawRepository;
+use chrono::{Duration, Utc};
 use diesel::SqliteConnection;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 const LOG_TAG: &str = "[ExamUseCase]";
 
@@ -28,70 +72,548 @@ impl ExamUseCase {
         }
 
         exam.questions.iter().enumerate().for_each(|(index, q)| {
-            let mut question_errors_vec: Vec<ValidationError> = vec![];
-            let question_errors = q.validate().err();
-            let validated_answer_errors = q.validate_answers().err();
-            let validated_option_errors = q.validate_options(false).err();
-            if let Some(question_errors) = question_errors {
-                question_errors_vec.extend(question_errors);
-            }
-            if let Some(question_answer_errors) = validated_answer_errors {
-                question_errors_vec.extend(question_answer_errors);
+            if let Err(question_extended_error) = Self::validate_question(index, q) {
+                extended_result.nested_errors.push(question_extended_error);
             }
+        });
 
-            if let Some(option_errors) = validated_option_errors {
-                question_errors_vec.extend(option_errors);
-            }
+        if extended_result.errors.is_empty() && extended_result.nested_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(extended_result)
+        }
+    }
 
-            let mut question_extended_error = ExtendedValidationError::new(
-                index as i32,
-                format!("Question: {}", q.question_text),
-                question_errors_vec,
-                vec![],
-            );
+    /// Validates the question with its answers and options, `index` is its position in
+    /// the exam.
+    pub fn validate_question(index: usize, q: &Question) -> Result<(), ExtendedValidationError> {
+        let mut question_errors_vec: Vec<ValidationError> = vec![];
+        let question_errors = q.validate().err();
+        let validated_answer_errors = q.validate_answers().err();
+        let validated_option_errors = q.validate_options(false).err();
+        if let Some(question_errors) = question_errors {
+            question_errors_vec.extend(question_errors);
+        }
+        if let Some(question_answer_errors) = validated_answer_errors {
+            question_errors_vec.extend(question_answer_errors);
+        }
+
+        if let Some(option_errors) = validated_option_errors {
+            question_errors_vec.extend(option_errors);
+        }
+
+        let mut question_extended_error = ExtendedValidationError::new(
+            index as i32,
+            format!("Question: {}", q.question_text),
+            question_errors_vec,
+            vec![],
+        );
+
+        q.answers.iter().enumerate().for_each(|(index, a)| {
+            let answer_errors = a.validate().err();
+            if let Some(answer_errors) = answer_errors {
+                question_extended_error
+                    .nested_errors
+                    .push(ExtendedValidationError::new(
+                        index as i32,
+                        format!("Answer: {}", a.answer_text),
+                        answer_errors,
+                        vec![],
+                    ));
+            }
+        });
 
-            q.answers.iter().enumerate().for_each(|(index, a)| {
-                let answer_errors = a.validate().err();
-                if let Some(answer_errors) = answer_errors {
+        if let Some(options) = &q.options {
+            options.iter().enumerate().for_each(|(index, o)| {
+                let option_errors = o.validate().err();
+                if let Some(options_errors) = option_errors {
                     question_extended_error
                         .nested_errors
                         .push(ExtendedValidationError::new(
                             index as i32,
-                            format!("Answer: {}", a.answer_text),
-                            answer_errors,
+                            format!("Option: {}", o.text),
+                            options_errors,
                             vec![],
                         ));
                 }
             });
+        }
+
+        if question_extended_error.errors.is_empty()
+            && question_extended_error.nested_errors.is_empty()
+        {
+            Ok(())
+        } else {
+            Err(question_extended_error)
+        }
+    }
+
+    /// Parses an exam in the text format, validates it and saves it. Parse and validation
+    /// errors carry the line and column of the offending part of `content`.
+    pub fn import_exam_text(
+        conn: &mut SqliteConnection,
+        content: &str,
+    ) -> Result<Exam, ExtendedValidationError> {
+        let parsed = TextExamParser::parse(content)?;
+        log::info!(
+            "{LOG_TAG} Parsed exam with {} questions from text",
+            parsed.exam.questions.len()
+        );
+        Self::validate_exam(&parsed.exam).map_err(|e| parsed.locate(e))?;
+        let mut exam = parsed.exam;
+        Ok(Self::create_exam(conn, &mut exam)?)
+    }
+
+    /// The exam with its relations and attachments as a portable bundle. Attachments whose
+    /// content is missing are left out.
+    pub fn export_exam_bundle(conn: &mut SqliteConnection, exam_id: i32) -> CRUDResult<ExamBundle> {
+        Ok(Self::bundle_exam(conn, exam_id)?.0)
+    }
+
+    /// Writes the bundle of the exam as pretty printed JSON to `path`. Attachments whose
+    /// content is missing are listed in the report.
+    pub fn export_exam(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        path: &Path,
+    ) -> CRUDResult<ExportReport> {
+        let (bundle, skipped) = Self::bundle_exam(conn, exam_id)?;
+        let json = serde_json::to_string_pretty(&bundle)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+        std::fs::write(path, json).map_err(|e| {
+            CRUDError::new(format!("Unable to write {}: {e}", path.display()), None)
+        })?;
+        log::info!(
+            "{LOG_TAG} Exported exam with id: {exam_id} and {} attachments to {}, skipped {}",
+            bundle.attachments.len(),
+            path.display(),
+            skipped.len()
+        );
+        Ok(ExportReport {
+            path: path.display().to_string(),
+            skipped,
+        })
+    }
 
-            if let Some(options) = &q.options {
-                options.iter().enumerate().for_each(|(index, o)| {
-                    let option_errors = o.validate().err();
-                    if let Some(options_errors) = option_errors {
-                        question_extended_error
-                            .nested_errors
-                            .push(ExtendedValidationError::new(
-                                index as i32,
-                                format!("Option: {}", o.text),
-                                options_errors,
-                                vec![],
-                            ));
-                    }
-                });
+    fn bundle_exam(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+    ) -> CRUDResult<(ExamBundle, Vec<ExtendedValidationError>)> {
+        let exam = SQLiteExamCrudRepository::new(conn)
+            .find_by_id_with_relations(exam_id)?
+            .ok_or(CRUDError::new("Exam not found", None))?;
+        let (attachments, missing) = Self::load_attachments(conn, &exam)?;
+        Ok((ExamBundle::new(&exam, &attachments, Utc::now()), missing))
+    }
+
+    /// Attachments of the exam's questions and answers with their data. Attachments whose
+    /// content is missing are returned as report entries instead, with the position of
+    /// their question as `index`.
+    fn load_attachments(
+        conn: &mut SqliteConnection,
+        exam: &Exam,
+    ) -> CRUDResult<(Vec<Attachment>, Vec<ExtendedValidationError>)> {
+        let question_ids: Vec<i32> = exam.questions.iter().filter_map(|q| q.id).collect();
+        let mut repository = SQLiteAttachmentRepository::new(conn);
+        let mut attachments = vec![];
+        let mut missing = vec![];
+        for mut attachment in repository.find_by_question_ids(&question_ids)? {
+            match repository.find_content(&attachment.hash)? {
+                Some(content) => {
+                    attachment.data = Some(Attachment::encode_data(&content));
+                    attachments.push(attachment);
+                }
+                None => {
+                    let index = exam
+                        .questions
+                        .iter()
+                        .position(|q| {
+                            q.id == attachment.question_id
+                                || q.answers
+                                    .iter()
+                                    .any(|a| a.id.is_some() && a.id == attachment.answer_id)
+                        })
+                        .unwrap_or_default();
+                    log::warn!(
+                        "{LOG_TAG} Content of attachment {} is missing",
+                        attachment.file_name
+                    );
+                    missing.push(ExtendedValidationError::new(
+                        index as i32,
+                        format!(
+                            "Content of attachment {} is missing, it was not exported",
+                            attachment.file_name
+                        ),
+                        vec![],
+                        vec![],
+                    ));
+                }
             }
+        }
+        Ok((attachments, missing))
+    }
 
-            if !question_extended_error.errors.is_empty()
-                || !question_extended_error.nested_errors.is_empty()
-            {
-                extended_result.nested_errors.push(question_extended_error);
+    /// Recreates an exam from a bundle written by `export_exam`. Categories are matched by
+    /// name and created if this installation doesn't know them yet.
+    pub fn import_exam_bundle(
+        conn: &mut SqliteConnection,
+        content: &str,
+    ) -> Result<Exam, ExtendedValidationError> {
+        let bundle: ExamBundle = serde_json::from_str(content).map_err(|e| {
+            ExtendedValidationError::new(
+                0,
+                format!("The exam bundle could not be read: {e}"),
+                vec![],
+                vec![],
+            )
+        })?;
+        bundle.validate().map_err(|e| {
+            ExtendedValidationError::new(0, "Exam bundle validation errors", e, vec![])
+        })?;
+        Self::validate_exam(&bundle.exam)?;
+
+        let created = execute_transactionally_mut(conn, |conn| {
+            let mut exam = bundle.exam.clone();
+            Self::resolve_category(conn, &mut exam.category)?;
+            for question in &mut exam.questions {
+                Self::resolve_category(conn, &mut question.category)?;
             }
-        });
+            let created = Self::create_exam(conn, &mut exam)?;
 
-        if extended_result.errors.is_empty() && extended_result.nested_errors.is_empty() {
+            for attachment in &bundle.attachments {
+                let question = &created.questions[attachment.question];
+                let (question_id, answer_id) = match attachment.answer {
+                    Some(answer) => (None, question.answers[answer].id),
+                    None => (question.id, None),
+                };
+                AttachmentUseCase::upload_attachment(
+                    conn,
+                    Attachment {
+                        id: None,
+                        question_id,
+                        answer_id,
+                        file_name: attachment.file_name.clone(),
+                        mime_type: attachment.mime_type.clone(),
+                        hash: String::new(),
+                        size: 0,
+                        created_at: None,
+                        data: Some(attachment.data.clone()),
+                    },
+                )?;
+            }
+            Ok(created)
+        })?;
+
+        log::info!(
+            "{LOG_TAG} Imported exam bundle as exam with id: {}",
+            created.id.unwrap()
+        );
+        Ok(created)
+    }
+
+    /// Imports the items of a QTI 2.1 package as a new exam. Items without an equivalent
+    /// question type are left out and listed in the report, the rest is validated and
+    /// saved only if all of it is valid.
+    pub fn import_qti_package(
+        conn: &mut SqliteConnection,
+        data: &[u8],
+    ) -> Result<ImportReport, ExtendedValidationError> {
+        Self::save_import_report(conn, QtiPackageReader::read(data)?, "QTI package")
+    }
+
+    /// Imports the questions of a Moodle GIFT file as a new exam, see
+    /// `import_qti_package`. `$CATEGORY` lines assign the questions to categories, which
+    /// are created if missing.
+    pub fn import_gift(
+        conn: &mut SqliteConnection,
+        content: &str,
+    ) -> Result<ImportReport, ExtendedValidationError> {
+        Self::save_import_report(conn, GiftParser::parse(content)?, "GIFT file")
+    }
+
+    /// Imports the questions of a Moodle XML quiz file as a new exam, see `import_gift`.
+    pub fn import_moodle_xml(
+        conn: &mut SqliteConnection,
+        content: &str,
+    ) -> Result<ImportReport, ExtendedValidationError> {
+        Self::save_import_report(conn, MoodleXmlReader::read(content)?, "Moodle XML file")
+    }
+
+    /// Adds the questions of a CSV or XLSX spreadsheet to the exam, see
+    /// `SpreadsheetParser` for the layout. Rows with a question the exam already has are
+    /// skipped, rows that can't be mapped or fail validation are reported as invalid and
+    /// the remaining rows are created, together with missing categories, in one
+    /// transaction.
+    pub fn import_spreadsheet(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        path: &Path,
+        mapping: &SpreadsheetMapping,
+    ) -> Result<SpreadsheetImportReport, ExtendedValidationError> {
+        let format = SpreadsheetFormat::from_path(path).ok_or_else(|| {
+            ExtendedValidationError::new(
+                0,
+                format!(
+                    "{} is not a spreadsheet, expected a .csv or .xlsx file",
+                    path.display()
+                ),
+                vec![],
+                vec![],
+            )
+        })?;
+        let data = std::fs::read(path).map_err(|e| {
+            ExtendedValidationError::new(
+                0,
+                format!("Unable to read {}: {e}", path.display()),
+                vec![],
+                vec![],
+            )
+        })?;
+        let parsed = SpreadsheetParser::parse(&data, format, mapping)?;
+
+        let mut report = SpreadsheetImportReport {
+            created: vec![],
+            skipped: vec![],
+            invalid: parsed.invalid,
+        };
+        execute_transactionally_mut(conn, |conn| {
+            let exam = SQLiteExamCrudRepository::new(conn)
+                .find_by_id_with_relations(exam_id)?
+                .ok_or(CRUDError::new("Exam not found", None))?;
+            let mut known: HashSet<String> = exam
+                .questions
+                .iter()
+                .map(|q| q.question_text.to_lowercase())
+                .collect();
+
+            for SpreadsheetRow {
+                index,
+                row,
+                mut question,
+            } in parsed.rows
+            {
+                if !known.insert(question.question_text.to_lowercase()) {
+                    report.skipped.push(
+                        ExtendedValidationError::new(
+                            index as i32,
+                            format!(
+                                "Row {row} was skipped, the exam already has the question `{}`",
+                                question.question_text
+                            ),
+                            vec![],
+                            vec![],
+                        )
+                        .at(row, 1),
+                    );
+                    continue;
+                }
+                if let Err(error) = Self::validate_question(index, &question) {
+                    report
+                        .invalid
+                        .push(ParsedSpreadsheet::row_error(index, row, vec![error]));
+                    continue;
+                }
+
+                Self::resolve_category(conn, &mut question.category)?;
+                question.exam_id = Some(exam_id);
+                report
+                    .created
+                    .push(QuestionUseCase::create_question(conn, question)?);
+            }
             Ok(())
-        } else {
-            Err(extended_result)
-        }
+        })?;
+
+        report.invalid.sort_by_key(|error| error.index);
+        log::info!(
+            "{LOG_TAG} Imported {} questions from {} into exam with id: {exam_id}, skipped {}, invalid {}",
+            report.created.len(),
+            path.display(),
+            report.skipped.len(),
+            report.invalid.len()
+        );
+        Ok(report)
+    }
+
+    fn save_import_report(
+        conn: &mut SqliteConnection,
+        mut report: ImportReport,
+        source: &str,
+    ) -> Result<ImportReport, ExtendedValidationError> {
+        log::info!(
+            "{LOG_TAG} Read {} questions from {source}, skipped {}",
+            report.exam.questions.len(),
+            report.skipped.len()
+        );
+        Self::validate_exam(&report.exam)?;
+        report.exam = execute_transactionally_mut(conn, |conn| {
+            let mut exam = report.exam.clone();
+            for question in &mut exam.questions {
+                Self::resolve_category(conn, &mut question.category)?;
+            }
+            Self::create_exam(conn, &mut exam)
+        })?;
+        Ok(report)
+    }
+
+    /// Writes the exam as QTI 2.1 package to `path`. Questions QTI can't express are
+    /// listed in the report.
+    pub fn export_exam_to_qti(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        path: &Path,
+    ) -> CRUDResult<ExportReport> {
+        Self::write_exported_file_with_attachments(
+            conn,
+            exam_id,
+            path,
+            "QTI package",
+            |exam, attachments| {
+                QtiPackageWriter::write(exam, attachments)
+                    .map_err(|e| CRUDError::new(format!("Unable to write QTI package: {e}"), None))
+            },
+        )
+    }
+
+    /// Writes the exam as Moodle GIFT file to `path`. Questions GIFT can't express are
+    /// listed in the report.
+    pub fn export_exam_to_gift(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        path: &Path,
+    ) -> CRUDResult<ExportReport> {
+        Self::write_exported_file(conn, exam_id, path, "GIFT file", |exam| {
+            Ok(GiftWriter::write(exam))
+        })
+    }
+
+    /// Writes the exam as Moodle XML quiz file to `path`. Questions Moodle XML can't
+    /// express are listed in the report.
+    pub fn export_exam_to_moodle_xml(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        path: &Path,
+    ) -> CRUDResult<ExportReport> {
+        Self::write_exported_file(conn, exam_id, path, "Moodle XML file", |exam| {
+            Ok(MoodleXmlWriter::write(exam))
+        })
+    }
+
+    /// Writes the exam as Anki package to `path`, with one flashcard per question in a
+    /// deck named after the exam.
+    pub fn export_exam_to_anki(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        path: &Path,
+    ) -> CRUDResult<ExportReport> {
+        Self::write_exported_file_with_attachments(
+            conn,
+            exam_id,
+            path,
+            "Anki package",
+            |exam, attachments| AnkiPackageWriter::write(exam, attachments, Utc::now()),
+        )
+    }
+
+    /// Writes a printable version of the exam to `path`, as Markdown, standalone HTML or
+    /// PDF depending on `options.format`.
+    pub fn render_exam(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        path: &Path,
+        options: &PrintOptions,
+    ) -> CRUDResult<ExportReport> {
+        let format = format!("printable {}", options.format);
+        Self::write_exported_file(conn, exam_id, path, &format, |exam| {
+            let printable = PrintableExam::new(exam, options);
+            let data = match options.format {
+                PrintFormat::Markdown => MarkdownRenderer::render(&printable).into_bytes(),
+                PrintFormat::Html => HtmlRenderer::render(&printable).into_bytes(),
+                PrintFormat::Pdf => PdfRenderer::render(&printable)
+                    .map_err(|e| CRUDError::new(format!("Unable to render PDF: {e}"), None))?,
+            };
+            Ok(ExportedFile {
+                data,
+                skipped: vec![],
+            })
+        })
+    }
+
+    fn write_exported_file(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        path: &Path,
+        format: &str,
+        write: impl FnOnce(&Exam) -> CRUDResult<ExportedFile>,
+    ) -> CRUDResult<ExportReport> {
+        let exam = SQLiteExamCrudRepository::new(conn)
+            .find_by_id_with_relations(exam_id)?
+            .ok_or(CRUDError::new("Exam not found", None))?;
+        let exported = write(&exam)?;
+        Self::save_exported_file(exam_id, path, format, exported)
+    }
+
+    /// Like `write_exported_file`, for formats that include the attachments. Attachments
+    /// whose content is missing are added to the report.
+    fn write_exported_file_with_attachments(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        path: &Path,
+        format: &str,
+        write: impl FnOnce(&Exam, &[Attachment]) -> CRUDResult<ExportedFile>,
+    ) -> CRUDResult<ExportReport> {
+        let exam = SQLiteExamCrudRepository::new(conn)
+            .find_by_id_with_relations(exam_id)?
+            .ok_or(CRUDError::new("Exam not found", None))?;
+        let (attachments, missing) = Self::load_attachments(conn, &exam)?;
+        let mut exported = write(&exam, &attachments)?;
+        exported.skipped.extend(missing);
+        exported.skipped.sort_by_key(|e| e.index);
+        Self::save_exported_file(exam_id, path, format, exported)
+    }
+
+    fn save_exported_file(
+        exam_id: i32,
+        path: &Path,
+        format: &str,
+        exported: ExportedFile,
+    ) -> CRUDResult<ExportReport> {
+        std::fs::write(path, &exported.data).map_err(|e| {
+            CRUDError::new(format!("Unable to write {}: {e}", path.display()), None)
+        })?;
+        log::info!(
+            "{LOG_TAG} Exported exam with id: {exam_id} as {format} to {}, skipped {} questions",
+            path.display(),
+            exported.skipped.len()
+        );
+        Ok(ExportReport {
+            path: path.display().to_string(),
+            skipped: exported.skipped,
+        })
+    }
+
+    fn resolve_category(
+        conn: &mut SqliteConnection,
+        category: &mut Option<Category>,
+    ) -> CRUDResult<()> {
+        let Some(name) = category.as_ref().map(|c| c.name.clone()) else {
+            return Ok(());
+        };
+        let mut category_repository = SQLiteCategoryCrudRepository::new(conn);
+        let resolved = match category_repository.find_by_name(&name)? {
+            Some(existing) => existing,
+            None => category_repository.create(&Category {
+                id: None,
+                name,
+                created_at: None,
+                updated_at: None,
+            })?,
+        };
+        *category = Some(resolved);
+        Ok(())
+    }
+
+    pub fn score_exam(exam: &Exam, responses: &[AttemptAnswer]) -> ExamScore {
+        ScoringEngine::score_exam(exam, responses)
     }
 
     pub fn get_exam_overall_statistics(
@@ -100,6 +622,46 @@ impl ExamUseCase {
         let mut exam_repository = SQLiteExamCrudRepository::new(conn);
         exam_repository.get_overall_statistics()
     }
+
+    /// Item analysis of every question of the exam, based on the finished attempts of
+    /// all profiles. Late answers are dropped with the grace period of the attempt's
+    /// profile, attempts over a question draw only present the drawn questions.
+    pub fn get_question_statistics(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+    ) -> CRUDResult<Vec<QuestionStatistics>> {
+        let exam = SQLiteExamCrudRepository::new(conn)
+            .find_by_id_with_relations(exam_id)?
+            .ok_or(CRUDError::new("Exam not found", None))?;
+        let mut attempts =
+            SQLiteAttemptCrudRepository::new(conn).find_finished_by_exam_id(exam_id)?;
+        let mut grace_periods: HashMap<i32, Duration> = HashMap::new();
+        for attempt in attempts.iter_mut() {
+            let grace_period = match grace_periods.get(&attempt.profile_id) {
+                Some(grace_period) => *grace_period,
+                None => {
+                    let grace_period =
+                        SettingUseCase::get_attempt_grace_period_of(conn, attempt.profile_id)?;
+                    grace_periods.insert(attempt.profile_id, grace_period);
+                    grace_period
+                }
+            };
+            attempt.answers = attempt.answers_in_time(grace_period);
+        }
+        let mut draws: Vec<QuestionDraw> = vec![];
+        for draw_id in attempts.iter().filter_map(|a| a.draw_id) {
+            if draws.iter().all(|d| d.id != Some(draw_id)) {
+                draws.extend(SQLiteQuestionDrawRepository::new(conn).find_by_id(draw_id)?);
+            }
+        }
+
+        log::info!(
+            "{LOG_TAG} Analyzing {} attempts of exam with id: {exam_id}",
+            attempts.len()
+        );
+        Ok(ItemAnalysis::analyze(&exam, &attempts, &draws))
+    }
+
     pub fn create_exam(
         conn: &mut SqliteConnection,
         exam_to_create: &mut Exam,
@@ -195,7 +757,9 @@ impl ExamUseCase {
     pub fn delete_exam(conn: &mut SqliteConnection, exam_id: i32) -> Result<usize, CRUDError> {
         execute_transactionally_mut(conn, |conn| {
             let mut exam_repository = SQLiteExamCrudRepository::new(conn);
-            exam_repository.delete(exam_id)
+            let deleted = exam_repository.delete(exam_id)?;
+            AttachmentUseCase::collect_garbage(conn)?;
+            Ok(deleted)
         })
     }
 
@@ -0,0 +1,189 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDRepository, CRUDResult};
+use crate::application::crud::execute_transactionally::execute_transactionally_mut;
+use crate::application::crud::mistake_repository_trait::MistakeRepository;
+use crate::application::crud::question_repository_trait::QuestionRepository;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::application::usecase::setting_use_case::SettingUseCase;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::mistake::Mistake;
+use crate::domain::model::mistake_group::MistakeGroup;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_score::QuestionScore;
+use crate::domain::scoring::scoring_engine::ScoringEngine;
+use crate::infrastructure::repositories::sqlite_exam_crud_repository::SQLiteExamCrudRepository;
+use crate::infrastructure::repositories::sqlite_mistake_repository::SQLiteMistakeRepository;
+use crate::infrastructure::repositories::sqlite_question_crud_repository::SQLiteQuestionCrudRepository;
+use chrono::Utc;
+use diesel::SqliteConnection;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+const LOG_TAG: &str = "[MistakeUseCase]";
+
+pub const DEFAULT_PRACTICE_SET_LIMIT: usize = 20;
+
+pub struct MistakeUseCase();
+
+impl MistakeUseCase {
+    /// Updates the mistake notebook of a profile with graded questions given as
+    /// `(question_id, correct)`. Missed questions are added or counted again; correct
+    /// answers extend the streak and remove the entry once the clear streak is reached.
+    pub fn record_outcomes(
+        conn: &mut SqliteConnection,
+        profile_id: i32,
+        outcomes: &[(i32, bool)],
+    ) -> CRUDResult<()> {
+        let clear_streak = SettingUseCase::get_mistake_clear_streak(conn)?;
+        let question_ids: Vec<i32> = outcomes.iter().map(|(id, _)| *id).collect();
+
+        let mut repository = SQLiteMistakeRepository::new(conn);
+        let mut mistakes: HashMap<i32, Mistake> = repository
+            .find_by_question_ids(profile_id, &question_ids)?
+            .into_iter()
+            .map(|m| (m.question_id, m))
+            .collect();
+
+        let now = Utc::now();
+        for (question_id, correct) in outcomes {
+            match (mistakes.remove(question_id), correct) {
+                (Some(mut mistake), true) => {
+                    mistake.record_correct();
+                    if mistake.is_cleared(clear_streak) {
+                        log::info!("{LOG_TAG} Question {question_id} left the mistake notebook");
+                        repository.delete(mistake.id.unwrap_or_default())?;
+                    } else {
+                        repository.save(&mistake)?;
+                    }
+                }
+                (Some(mut mistake), false) => {
+                    mistake.record_miss(now);
+                    repository.save(&mistake)?;
+                }
+                (None, false) => {
+                    repository.save(&Mistake::new(profile_id, *question_id, now))?;
+                }
+                (None, true) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Mistakes of the active profile grouped by exam and question category, ordered by
+    /// exam name and category name.
+    pub fn get_mistake_notebook(conn: &mut SqliteConnection) -> CRUDResult<Vec<MistakeGroup>> {
+        let mistakes = MistakeUseCase::load_mistakes(conn)?;
+
+        let mut exam_names: HashMap<i32, String> = HashMap::new();
+        let mut groups: Vec<MistakeGroup> = vec![];
+        for mistake in mistakes {
+            let Some(question) = &mistake.question else {
+                continue;
+            };
+            let Some(exam_id) = question.exam_id else {
+                continue;
+            };
+            let category = question.category.clone();
+
+            if let Entry::Vacant(entry) = exam_names.entry(exam_id) {
+                let exam = SQLiteExamCrudRepository::new(conn).find_by_id(exam_id)?;
+                entry.insert(exam.map(|e| e.name).unwrap_or_default());
+            }
+
+            let category_id = category.as_ref().and_then(|c| c.id);
+            match groups.iter_mut().find(|g| {
+                g.exam_id == exam_id && g.category.as_ref().and_then(|c| c.id) == category_id
+            }) {
+                Some(group) => group.mistakes.push(mistake),
+                None => groups.push(MistakeGroup {
+                    exam_id,
+                    exam_name: exam_names[&exam_id].clone(),
+                    category,
+                    mistakes: vec![mistake],
+                }),
+            }
+        }
+
+        groups.sort_by(|a, b| {
+            (&a.exam_name, a.category.as_ref().map(|c| &c.name))
+                .cmp(&(&b.exam_name, b.category.as_ref().map(|c| &c.name)))
+        });
+        Ok(groups)
+    }
+
+    /// Questions of the mistake notebook to practice, most missed and most recently
+    /// missed first, optionally restricted to an exam and/or a category.
+    pub fn get_mistake_practice_set(
+        conn: &mut SqliteConnection,
+        exam_id: Option<i32>,
+        category_id: Option<i32>,
+        limit: Option<usize>,
+    ) -> CRUDResult<Vec<Question>> {
+        let limit = limit.unwrap_or(DEFAULT_PRACTICE_SET_LIMIT);
+        let questions: Vec<Question> = MistakeUseCase::load_mistakes(conn)?
+            .into_iter()
+            .filter_map(|m| m.question)
+            .filter(|q| exam_id.is_none() || q.exam_id == exam_id)
+            .filter(|q| {
+                category_id.is_none() || q.category.as_ref().and_then(|c| c.id) == category_id
+            })
+            .take(limit)
+            .collect();
+
+        log::info!(
+            "{LOG_TAG} Returning {} questions of the mistake notebook",
+            questions.len()
+        );
+        Ok(questions)
+    }
+
+    /// Grades a practice answer and updates the mistake notebook of the active profile.
+    pub fn record_practice_answer(
+        conn: &mut SqliteConnection,
+        question_id: i32,
+        answers: Vec<AttemptAnswer>,
+    ) -> CRUDResult<QuestionScore> {
+        execute_transactionally_mut(conn, |conn| {
+            let question = SQLiteQuestionCrudRepository::new(conn)
+                .find_by_ids_with_relations(&[question_id])?
+                .pop()
+                .ok_or(CRUDError::new("Question not found", None))?;
+            let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+
+            let response: Vec<AttemptAnswer> = answers
+                .into_iter()
+                .map(|answer| AttemptAnswer {
+                    question_id,
+                    ..answer
+                })
+                .collect();
+            let score = ScoringEngine::score_question(&question, &response);
+            MistakeUseCase::record_outcomes(
+                conn,
+                profile_id,
+                &[(question_id, score.is_fully_correct())],
+            )?;
+            Ok(score)
+        })
+    }
+
+    /// Mistakes of the active profile with their questions, most missed first.
+    fn load_mistakes(conn: &mut SqliteConnection) -> CRUDResult<Vec<Mistake>> {
+        let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+        let mistakes = SQLiteMistakeRepository::new(conn).find_by_profile_id(profile_id)?;
+
+        let question_ids: Vec<i32> = mistakes.iter().map(|m| m.question_id).collect();
+        let mut questions: HashMap<i32, Question> = SQLiteQuestionCrudRepository::new(conn)
+            .find_by_ids_with_relations(&question_ids)?
+            .into_iter()
+            .filter_map(|q| q.id.map(|id| (id, q)))
+            .collect();
+
+        Ok(mistakes
+            .into_iter()
+            .map(|mut m| {
+                m.question = questions.remove(&m.question_id);
+                m
+            })
+            .collect())
+    }
+}
@@ -1,8 +1,18 @@
 pub mod answer_use_case;
 pub mod assignment_option_use_case;
+pub mod attachment_use_case;
+pub mod attempt_use_case;
+pub mod backup_use_case;
+pub mod bookmark_use_case;
 pub mod category_use_case;
 pub mod exam_use_case;
+pub mod mistake_use_case;
+pub mod practice_use_case;
+pub mod profile_use_case;
+pub mod question_draw_use_case;
 pub mod question_use_case;
+pub mod review_use_case;
+pub mod setting_use_case;
 
 #[cfg(test)]
 mod tests;
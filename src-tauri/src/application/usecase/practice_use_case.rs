@@ -0,0 +1,119 @@
+use crate::application::crud::attempt_repository_trait::AttemptRepository;
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::application::crud::exam_repository_trait::ExamRepository;
+use crate::application::crud::question_repository_trait::QuestionRepository;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::application::usecase::setting_use_case::SettingUseCase;
+use crate::domain::model::adaptive_practice_set::AdaptivePracticeSet;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::sampling::adaptive_sampler::AdaptiveSampler;
+use crate::domain::sampling::seeded_random::SeededRandom;
+use crate::domain::scoring::scoring_engine::ScoringEngine;
+use crate::domain::statistics::mastery_estimator::MasteryEstimator;
+use crate::infrastructure::repositories::sqlite_attempt_crud_repository::SQLiteAttemptCrudRepository;
+use crate::infrastructure::repositories::sqlite_exam_crud_repository::SQLiteExamCrudRepository;
+use crate::infrastructure::repositories::sqlite_question_crud_repository::SQLiteQuestionCrudRepository;
+use diesel::SqliteConnection;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+const LOG_TAG: &str = "[PracticeUseCase]";
+
+pub const DEFAULT_ADAPTIVE_PRACTICE_SIZE: usize = 20;
+
+pub struct PracticeUseCase();
+
+impl PracticeUseCase {
+    /// Draws practice questions of an exam weighted toward the categories and question
+    /// types the active profile performed worst in. Performance is taken from all of
+    /// the profile's finished attempts, so categories shared between exams count
+    /// together. Without a seed a new one is generated.
+    pub fn generate_adaptive_practice_set(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        count: Option<usize>,
+        seed: Option<i64>,
+    ) -> CRUDResult<AdaptivePracticeSet> {
+        let pool = SQLiteQuestionCrudRepository::new(conn)
+            .find_by_exam_id(exam_id, None)?
+            .data;
+        if pool.is_empty() {
+            return Err(CRUDError::new(
+                format!("No questions found for exam with id: {exam_id}"),
+                None,
+            ));
+        }
+
+        let graded = PracticeUseCase::graded_responses(conn)?;
+        let responses: Vec<(&Question, f64)> = graded.iter().map(|(q, r)| (q, *r)).collect();
+        let category_mastery = MasteryEstimator::by_category(&pool, &responses);
+        let question_type_mastery = MasteryEstimator::by_question_type(&pool, &responses);
+
+        let seed = seed.unwrap_or_else(SeededRandom::generate_seed);
+        let count = count.unwrap_or(DEFAULT_ADAPTIVE_PRACTICE_SIZE);
+        let drawn: Vec<i32> = AdaptiveSampler::sample(
+            &pool,
+            count,
+            seed,
+            &category_mastery,
+            &question_type_mastery,
+        )
+        .iter()
+        .filter_map(|q| q.id)
+        .collect();
+
+        let mut loaded: HashMap<i32, Question> = SQLiteQuestionCrudRepository::new(conn)
+            .find_by_ids_with_relations(&drawn)?
+            .into_iter()
+            .filter_map(|q| q.id.map(|id| (id, q)))
+            .collect();
+        let questions: Vec<Question> = drawn.iter().filter_map(|id| loaded.remove(id)).collect();
+
+        log::info!(
+            "{LOG_TAG} Drew {} practice questions of exam {exam_id} from {} graded responses with seed {seed}",
+            questions.len(),
+            responses.len()
+        );
+        Ok(AdaptivePracticeSet {
+            seed,
+            questions,
+            category_mastery,
+            question_type_mastery,
+        })
+    }
+
+    /// Questions answered in time in the finished attempts of the active profile, with
+    /// the share of their points that was earned.
+    fn graded_responses(conn: &mut SqliteConnection) -> CRUDResult<Vec<(Question, f64)>> {
+        let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+        let grace_period = SettingUseCase::get_attempt_grace_period(conn)?;
+        let attempts = SQLiteAttemptCrudRepository::new(conn).find_finished(profile_id)?;
+
+        let mut exams: HashMap<i32, Option<Exam>> = HashMap::new();
+        let mut responses: Vec<(Question, f64)> = vec![];
+        for attempt in attempts {
+            if let Entry::Vacant(entry) = exams.entry(attempt.exam_id) {
+                entry.insert(
+                    SQLiteExamCrudRepository::new(conn)
+                        .find_by_id_with_relations(attempt.exam_id)?,
+                );
+            }
+            let Some(exam) = &exams[&attempt.exam_id] else {
+                continue;
+            };
+
+            let answers = attempt.answers_in_time(grace_period);
+            let score = ScoringEngine::score_exam(exam, &answers);
+            for (question, question_score) in exam.questions.iter().zip(score.questions) {
+                let answered = answers.iter().any(|a| Some(a.question_id) == question.id);
+                if answered && question_score.points_total > 0 {
+                    let ratio =
+                        question_score.points_earned as f64 / question_score.points_total as f64;
+                    responses.push((question.clone(), ratio));
+                }
+            }
+        }
+        Ok(responses)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::application::crud::execute_transactionally::execute_transactionally_mut;
+use crate::application::crud::profile_repository_trait::ProfileRepository;
+use crate::domain::model::profile::Profile;
+use crate::domain::traits::validation::Validation;
+use crate::infrastructure::repositories::sqlite_profile_repository::SQLiteProfileRepository;
+use diesel::SqliteConnection;
+
+const LOG_TAG: &str = "[ProfileUseCase]";
+
+pub struct ProfileUseCase();
+
+impl ProfileUseCase {
+    pub fn get_profiles(conn: &mut SqliteConnection) -> CRUDResult<Vec<Profile>> {
+        SQLiteProfileRepository::new(conn).find_all()
+    }
+
+    pub fn get_active_profile(conn: &mut SqliteConnection) -> CRUDResult<Profile> {
+        SQLiteProfileRepository::new(conn)
+            .find_active()?
+            .ok_or(CRUDError::new("No active profile found", None))
+    }
+
+    /// Id of the active profile, which owns all personal data that is read or written.
+    pub fn get_active_profile_id(conn: &mut SqliteConnection) -> CRUDResult<i32> {
+        ProfileUseCase::get_active_profile(conn)?
+            .id
+            .ok_or(CRUDError::new("Active profile has no id", None))
+    }
+
+    pub fn create_profile(conn: &mut SqliteConnection, name: &str) -> CRUDResult<Profile> {
+        let profile = Profile::new(name);
+        profile
+            .validate()
+            .map_err(|e| CRUDError::new("Validation errors: ", Some(e)))?;
+
+        execute_transactionally_mut(conn, |conn| {
+            let mut repository = SQLiteProfileRepository::new(conn);
+            ProfileUseCase::ensure_name_is_free(&mut repository, &profile.name, None)?;
+
+            log::info!("{LOG_TAG} Creating profile: {}", profile.name);
+            repository.create(&profile)
+        })
+    }
+
+    pub fn rename_profile(
+        conn: &mut SqliteConnection,
+        profile_id: i32,
+        name: &str,
+    ) -> CRUDResult<Profile> {
+        execute_transactionally_mut(conn, |conn| {
+            let mut repository = SQLiteProfileRepository::new(conn);
+            let mut profile = ProfileUseCase::find_profile(&mut repository, profile_id)?;
+            profile.name = name.trim().to_string();
+            profile
+                .validate()
+                .map_err(|e| CRUDError::new("Validation errors: ", Some(e)))?;
+            ProfileUseCase::ensure_name_is_free(&mut repository, &profile.name, Some(profile_id))?;
+
+            log::info!(
+                "{LOG_TAG} Renaming profile {profile_id} to: {}",
+                profile.name
+            );
+            repository.update(&profile)
+        })
+    }
+
+    /// Deletes a profile together with its attempts, question draws, review states,
+    /// mistakes, bookmarks and settings. The active profile can not be deleted, switch to
+    /// another profile first.
+    pub fn delete_profile(conn: &mut SqliteConnection, profile_id: i32) -> CRUDResult<usize> {
+        execute_transactionally_mut(conn, |conn| {
+            let mut repository = SQLiteProfileRepository::new(conn);
+            let profile = ProfileUseCase::find_profile(&mut repository, profile_id)?;
+            if profile.active {
+                return Err(CRUDError::new(
+                    "The active profile can not be deleted, switch to another profile first",
+                    None,
+                ));
+            }
+
+            log::info!("{LOG_TAG} Deleting profile {profile_id} and its personal data");
+            repository.delete(profile_id)
+        })
+    }
+
+    pub fn switch_profile(conn: &mut SqliteConnection, profile_id: i32) -> CRUDResult<Profile> {
+        execute_transactionally_mut(conn, |conn| {
+            let mut repository = SQLiteProfileRepository::new(conn);
+            ProfileUseCase::find_profile(&mut repository, profile_id)?;
+
+            log::info!("{LOG_TAG} Switching to profile {profile_id}");
+            repository.activate(profile_id)
+        })
+    }
+
+    fn find_profile(
+        repository: &mut SQLiteProfileRepository,
+        profile_id: i32,
+    ) -> CRUDResult<Profile> {
+        repository.find_by_id(profile_id)?.ok_or(CRUDError::new(
+            format!("Profile with id: {profile_id} not found"),
+            None,
+        ))
+    }
+
+    fn ensure_name_is_free(
+        repository: &mut SQLiteProfileRepository,
+        name: &str,
+        profile_id: Option<i32>,
+    ) -> CRUDResult<()> {
+        match repository.find_by_name(name)? {
+            Some(existing) if existing.id != profile_id => Err(CRUDError::new(
+                format!("A profile named: {name} already exists"),
+                None,
+            )),
+            _ => Ok(()),
+        }
+    }
+}
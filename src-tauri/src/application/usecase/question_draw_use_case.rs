@@ -0,0 +1,133 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::application::crud::exam_repository_trait::ExamRepository;
+use crate::application::crud::execute_transactionally::execute_transactionally_mut;
+use crate::application::crud::question_draw_repository_trait::QuestionDrawRepository;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_draw::QuestionDraw;
+use crate::domain::sampling::question_sampler::QuestionSampler;
+use crate::domain::sampling::seeded_random::SeededRandom;
+use crate::infrastructure::repositories::sqlite_exam_crud_repository::SQLiteExamCrudRepository;
+use crate::infrastructure::repositories::sqlite_question_draw_repository::SQLiteQuestionDrawRepository;
+use diesel::SqliteConnection;
+use std::collections::HashSet;
+
+const LOG_TAG: &str = "[QuestionDrawUseCase]";
+
+/// Number of previous draws whose questions are avoided in a new draw.
+pub const RECENT_DRAWS_TO_AVOID: usize = 3;
+
+pub struct QuestionDrawUseCase();
+
+impl QuestionDrawUseCase {
+    /// Draws the question set of a real exam run. Without a seed a new one is
+    /// generated; the seed is returned with the draw so it can be replayed.
+    pub fn draw_questions(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+        seed: Option<i64>,
+        stratify: bool,
+    ) -> CRUDResult<QuestionDraw> {
+        execute_transactionally_mut(conn, |conn| {
+            let exam = QuestionDrawUseCase::find_exam(conn, exam_id)?;
+            let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+            let previous_draws =
+                SQLiteQuestionDrawRepository::new(conn).find_by_exam_id(profile_id, exam_id)?;
+            let seed = seed.unwrap_or_else(SeededRandom::generate_seed);
+
+            let questions = QuestionDrawUseCase::sample(&exam, seed, stratify, &previous_draws);
+            log::info!(
+                "{LOG_TAG} Drew {} of {} questions for exam {exam_id} with seed: {seed}",
+                questions.len(),
+                exam.questions.len()
+            );
+
+            let draw = QuestionDraw {
+                id: None,
+                exam_id,
+                profile_id,
+                seed,
+                stratified: stratify,
+                question_ids: questions.iter().filter_map(|q| q.id).collect(),
+                created_at: None,
+                questions: vec![],
+            };
+            let mut created = SQLiteQuestionDrawRepository::new(conn).create(&draw)?;
+            created.questions = questions;
+            Ok(created)
+        })
+    }
+
+    /// Repeats a stored draw of the active profile with its seed and the draws that
+    /// preceded it. Fails if the exam changed in a way that leads to a different
+    /// question set.
+    pub fn replay_draw(conn: &mut SqliteConnection, draw_id: i32) -> CRUDResult<QuestionDraw> {
+        let mut draw = QuestionDrawUseCase::find_draw(conn, draw_id)?;
+        let exam = QuestionDrawUseCase::find_exam(conn, draw.exam_id)?;
+        let previous_draws: Vec<QuestionDraw> = SQLiteQuestionDrawRepository::new(conn)
+            .find_by_exam_id(draw.profile_id, draw.exam_id)?
+            .into_iter()
+            .filter(|d| d.id < draw.id)
+            .collect();
+
+        let questions =
+            QuestionDrawUseCase::sample(&exam, draw.seed, draw.stratified, &previous_draws);
+        let replayed_ids: Vec<i32> = questions.iter().filter_map(|q| q.id).collect();
+        if replayed_ids != draw.question_ids {
+            log::warn!("{LOG_TAG} Replay of draw {draw_id} differs from the stored questions");
+            return Err(CRUDError::new(
+                format!(
+                    "Questions of exam {} changed since draw {draw_id}",
+                    draw.exam_id
+                ),
+                None,
+            ));
+        }
+
+        draw.questions = questions;
+        Ok(draw)
+    }
+
+    pub fn get_draws_by_exam_id(
+        conn: &mut SqliteConnection,
+        exam_id: i32,
+    ) -> CRUDResult<Vec<QuestionDraw>> {
+        let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+        SQLiteQuestionDrawRepository::new(conn).find_by_exam_id(profile_id, exam_id)
+    }
+
+    /// Stored draw of the active profile, draws of other profiles are not found.
+    fn find_draw(conn: &mut SqliteConnection, draw_id: i32) -> CRUDResult<QuestionDraw> {
+        let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+        SQLiteQuestionDrawRepository::new(conn)
+            .find_by_id(draw_id)?
+            .filter(|draw| draw.profile_id == profile_id)
+            .ok_or(CRUDError::new("Question draw not found", None))
+    }
+
+    fn find_exam(conn: &mut SqliteConnection, exam_id: i32) -> CRUDResult<Exam> {
+        SQLiteExamCrudRepository::new(conn)
+            .find_by_id_with_relations(exam_id)?
+            .ok_or(CRUDError::new("Exam not found", None))
+    }
+
+    fn sample(
+        exam: &Exam,
+        seed: i64,
+        stratify: bool,
+        previous_draws: &[QuestionDraw],
+    ) -> Vec<Question> {
+        let recently_drawn: HashSet<i32> = previous_draws
+            .iter()
+            .take(RECENT_DRAWS_TO_AVOID)
+            .flat_map(|d| d.question_ids.iter().copied())
+            .collect();
+        let count = exam
+            .max_questions_real_exam
+            .filter(|max| *max > 0)
+            .map_or(exam.questions.len(), |max| max as usize);
+
+        QuestionSampler::sample(&exam.questions, count, seed, stratify, &recently_drawn)
+    }
+}
@@ -1,15 +1,21 @@
 use crate::application::crud::crud_repository_trait::{CRUDError, CRUDRepository, CRUDResult};
 use crate::application::crud::execute_transactionally::execute_transactionally;
 use crate::application::crud::question_repository_trait::QuestionRepository;
+use crate::application::crud::source_reference_repository_trait::SourceReferenceRepository;
 use crate::application::usecase::answer_use_case::AnswerUseCase;
 use crate::application::usecase::assignment_option_use_case::AssignmentOptionUseCase;
+use crate::application::usecase::attachment_use_case::AttachmentUseCase;
 use crate::domain::model::answer::Answer;
 use crate::domain::model::assignment_option::AssignmentOption;
+use crate::domain::model::attempt_answer::AttemptAnswer;
 use crate::domain::model::page_options::PageOptions;
 use crate::domain::model::paged_result::PagedResult;
 use crate::domain::model::question::Question;
+use crate::domain::model::question_score::QuestionScore;
 use crate::domain::model::question_type::QuestionType;
+use crate::domain::scoring::scoring_engine::ScoringEngine;
 use crate::infrastructure::repositories::sqlite_question_crud_repository::SQLiteQuestionCrudRepository;
+use crate::infrastructure::repositories::sqlite_source_reference_repository::SQLiteSourceReferenceRepository;
 use diesel::SqliteConnection;
 const LOG_TAG: &str = "[QuestionUseCase]";
 
@@ -28,6 +34,9 @@ impl QuestionUseCase {
             question_to_update
                 .validate_options(true)
                 .map_err(|e| CRUDError::new("AssignmentOptions validation errors: ", Some(e)))?;
+            question_to_update
+                .validate_sources()
+                .map_err(|e| CRUDError::new("Source validation errors: ", Some(e)))?;
 
             let (old_type, existing_id) = {
                 {
@@ -90,8 +99,17 @@ impl QuestionUseCase {
                 )?)
             }
 
+            AttachmentUseCase::collect_garbage(conn)?;
+
+            let mut source_repository = SQLiteSourceReferenceRepository::new(conn);
+            let updated_sources = match &q_to_up.sources {
+                Some(sources) => source_repository.replace_for_question(existing_id, sources)?,
+                None => source_repository.find_by_question_id(existing_id)?,
+            };
+
             updated_question.answers = updated_answers;
             updated_question.options = updated_assignment_option;
+            updated_question.sources = Some(updated_sources);
             Ok(updated_question)
         })
     }
@@ -108,6 +126,9 @@ impl QuestionUseCase {
             question
                 .validate_options(false)
                 .map_err(|e| CRUDError::new("AssignmentOptions validation errors: ", Some(e)))?;
+            question
+                .validate_sources()
+                .map_err(|e| CRUDError::new("Source validation errors: ", Some(e)))?;
 
             let mut question_repository = SQLiteQuestionCrudRepository::new(conn);
             let mut created_question = question_repository.create(&question)?;
@@ -130,8 +151,13 @@ impl QuestionUseCase {
                 }
             }
 
+            let sources = question.sources.unwrap_or_default();
+            let created_sources = SQLiteSourceReferenceRepository::new(conn)
+                .replace_for_question(question_id, &sources)?;
+
             created_question.answers = created_answers;
             created_question.options = Some(created_assignment_options);
+            created_question.sources = Some(created_sources);
 
             Ok(created_question)
         })
@@ -151,7 +177,9 @@ impl QuestionUseCase {
     ) -> Result<usize, CRUDError> {
         execute_transactionally(conn, |conn| {
             let mut question_repository = SQLiteQuestionCrudRepository::new(conn);
-            question_repository.delete(question_id)
+            let deleted = question_repository.delete(question_id)?;
+            AttachmentUseCase::collect_garbage(conn)?;
+            Ok(deleted)
         })
     }
 
@@ -164,6 +192,10 @@ impl QuestionUseCase {
         question_repository.find_by_exam_id(exam_id, page_options)
     }
 
+    pub fn score_question(question: &Question, response: &[AttemptAnswer]) -> QuestionScore {
+        ScoringEngine::score_question(question, response)
+    }
+
     fn validate_fks(question: &Question) -> Result<(), CRUDError> {
         if question.exam_id.is_none() {
             return Err(CRUDError::new("Exam id is required", None));
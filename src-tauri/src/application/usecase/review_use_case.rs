@@ -0,0 +1,95 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDRepository, CRUDResult};
+use crate::application::crud::execute_transactionally::execute_transactionally_mut;
+use crate::application::crud::question_repository_trait::QuestionRepository;
+use crate::application::crud::review_state_repository_trait::ReviewStateRepository;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::domain::model::question::Question;
+use crate::domain::model::review_state::ReviewState;
+use crate::domain::scheduling::spaced_repetition::SpacedRepetition;
+use crate::infrastructure::repositories::sqlite_question_crud_repository::SQLiteQuestionCrudRepository;
+use crate::infrastructure::repositories::sqlite_review_state_repository::SQLiteReviewStateRepository;
+use chrono::Utc;
+use diesel::SqliteConnection;
+use std::collections::HashMap;
+
+const LOG_TAG: &str = "[ReviewUseCase]";
+
+pub const DEFAULT_DUE_QUESTIONS_LIMIT: usize = 20;
+
+pub struct ReviewUseCase();
+
+impl ReviewUseCase {
+    /// Returns the questions of an exam or category that are due for review.
+    /// Overdue questions come first, ordered by due date, followed by questions
+    /// that were never reviewed.
+    pub fn get_due_questions(
+        conn: &mut SqliteConnection,
+        exam_id: Option<i32>,
+        category_id: Option<i32>,
+        limit: Option<usize>,
+    ) -> CRUDResult<Vec<Question>> {
+        if exam_id.is_none() && category_id.is_none() {
+            return Err(CRUDError::new(
+                "Either an exam id or a category id is required",
+                None,
+            ));
+        }
+
+        let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+        let questions =
+            SQLiteQuestionCrudRepository::new(conn).find_with_relations(exam_id, category_id)?;
+        let question_ids: Vec<i32> = questions.iter().filter_map(|q| q.id).collect();
+        let states: HashMap<i32, ReviewState> = SQLiteReviewStateRepository::new(conn)
+            .find_by_question_ids(profile_id, &question_ids)?
+            .into_iter()
+            .map(|s| (s.question_id, s))
+            .collect();
+
+        let now = Utc::now();
+        let mut due: Vec<(Option<ReviewState>, Question)> = questions
+            .into_iter()
+            .filter_map(|q| {
+                let state = q.id.and_then(|id| states.get(&id)).cloned();
+                match &state {
+                    Some(s) if !s.is_due(now) => None,
+                    _ => Some((state, q)),
+                }
+            })
+            .collect();
+        due.sort_by_key(|(state, _)| (state.is_none(), state.as_ref().map(|s| s.due_at)));
+
+        let limit = limit.unwrap_or(DEFAULT_DUE_QUESTIONS_LIMIT);
+        log::info!(
+            "{LOG_TAG} {} questions are due, returning at most {limit}",
+            due.len()
+        );
+        Ok(due.into_iter().take(limit).map(|(_, q)| q).collect())
+    }
+
+    pub fn record_review(
+        conn: &mut SqliteConnection,
+        question_id: i32,
+        grade: i32,
+    ) -> CRUDResult<ReviewState> {
+        SpacedRepetition::validate_grade(grade)
+            .map_err(|e| CRUDError::new("Validation errors: ", Some(e)))?;
+
+        execute_transactionally_mut(conn, |conn| {
+            SQLiteQuestionCrudRepository::new(conn).find_by_id(question_id)?;
+            let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+
+            let now = Utc::now();
+            let mut repository = SQLiteReviewStateRepository::new(conn);
+            let state = repository
+                .find_by_question_id(profile_id, question_id)?
+                .unwrap_or_else(|| ReviewState::new(profile_id, question_id, now));
+            let next = SpacedRepetition::review(&state, grade, now);
+
+            log::info!(
+                "{LOG_TAG} Question {question_id} graded {grade}, next review in {} days",
+                next.interval_days
+            );
+            repository.save(&next)
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::application::crud::execute_transactionally::execute_transactionally_mut;
+use crate::application::crud::setting_repository_trait::SettingRepository;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::domain::model::setting::{
+    Setting, ATTEMPT_GRACE_PERIOD_SECONDS, DEFAULT_ATTEMPT_GRACE_PERIOD_SECONDS,
+    DEFAULT_MISTAKE_CLEAR_STREAK, MAX_ATTEMPT_GRACE_PERIOD_SECONDS, MISTAKE_CLEAR_STREAK,
+};
+use crate::domain::traits::validation::Validation;
+use crate::infrastructure::repositories::sqlite_setting_repository::SQLiteSettingRepository;
+use chrono::Duration;
+use diesel::SqliteConnection;
+
+pub struct SettingUseCase();
+
+impl SettingUseCase {
+    pub fn get_settings(conn: &mut SqliteConnection) -> CRUDResult<Vec<Setting>> {
+        let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+        SQLiteSettingRepository::new(conn).find_all(profile_id)
+    }
+
+    pub fn get_setting(conn: &mut SqliteConnection, key: &str) -> CRUDResult<Option<Setting>> {
+        let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+        SQLiteSettingRepository::new(conn).find_by_key(profile_id, key)
+    }
+
+    /// Saves the setting for the active profile.
+    pub fn update_setting(
+        conn: &mut SqliteConnection,
+        mut setting: Setting,
+    ) -> CRUDResult<Setting> {
+        setting
+            .validate()
+            .map_err(|e| CRUDError::new("Validation errors: ", Some(e)))?;
+
+        execute_transactionally_mut(conn, |conn| {
+            setting.profile_id = Some(ProfileUseCase::get_active_profile_id(conn)?);
+            SQLiteSettingRepository::new(conn).save(&setting)
+        })
+    }
+
+    /// Grace period of the active profile, values stored before the limit existed are
+    /// clamped to it.
+    pub fn get_attempt_grace_period(conn: &mut SqliteConnection) -> CRUDResult<Duration> {
+        let profile_id = ProfileUseCase::get_active_profile_id(conn)?;
+        SettingUseCase::get_attempt_grace_period_of(conn, profile_id)
+    }
+
+    /// Grace period of the given profile, used where attempts of several profiles are
+    /// evaluated.
+    pub fn get_attempt_grace_period_of(
+        conn: &mut SqliteConnection,
+        profile_id: i32,
+    ) -> CRUDResult<Duration> {
+        let seconds = SQLiteSettingRepository::new(conn)
+            .find_by_key(profile_id, ATTEMPT_GRACE_PERIOD_SECONDS)?
+            .and_then(|s| s.value.parse::<i64>().ok())
+            .unwrap_or(DEFAULT_ATTEMPT_GRACE_PERIOD_SECONDS)
+            .clamp(0, MAX_ATTEMPT_GRACE_PERIOD_SECONDS);
+        Ok(Duration::seconds(seconds))
+    }
+
+    pub fn get_mistake_clear_streak(conn: &mut SqliteConnection) -> CRUDResult<i32> {
+        Ok(SettingUseCase::get_setting(conn, MISTAKE_CLEAR_STREAK)?
+            .and_then(|s| s.value.parse::<i32>().ok())
+            .unwrap_or(DEFAULT_MISTAKE_CLEAR_STREAK))
+    }
+}
@@ -30,6 +30,9 @@ fn create_question(conn: &mut SqliteConnection) -> Question {
         updated_at: None,
         options: None,
         exam_id: Some(1),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
     };
     repo.create(&q).unwrap()
 }
@@ -50,6 +53,10 @@ fn create_update_delete_answer_usecase() {
         created_at: None,
         updated_at: None,
         question_id: Some(qid),
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
     };
     let created = AnswerUseCase::create_answer(&mut conn, new_answer).unwrap();
     assert!(created.id.unwrap() > 0);
@@ -87,6 +94,10 @@ fn update_answers_by_question_id_creates_updates_and_deletes() {
             created_at: None,
             updated_at: None,
             question_id: Some(qid),
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
         },
     )
     .unwrap();
@@ -101,6 +112,10 @@ fn update_answers_by_question_id_creates_updates_and_deletes() {
             created_at: None,
             updated_at: None,
             question_id: Some(qid),
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
         },
     )
     .unwrap();
@@ -122,6 +137,10 @@ fn update_answers_by_question_id_creates_updates_and_deletes() {
                 created_at: None,
                 updated_at: None,
                 question_id: Some(qid),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             },
         ],
     )
@@ -153,6 +172,10 @@ fn it_should_remove_all_for_question() {
         created_at: None,
         updated_at: None,
         question_id: Some(qid),
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
     }).unwrap();
 
     let removed = AnswerUseCase::remove_all_for_question(&mut conn, qid).unwrap();
@@ -178,6 +201,10 @@ fn it_should_fail_on_validation_error() {
         created_at: None,
         updated_at: None,
         question_id: Some(qid),
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
     };
 
     let result = AnswerUseCase::create_answer(&mut conn, invalid_answer);
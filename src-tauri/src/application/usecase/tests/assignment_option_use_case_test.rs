@@ -30,6 +30,9 @@ fn create_question(conn: &mut SqliteConnection) -> Question {
         updated_at: None,
         options: None,
         exam_id: Some(1),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
     };
     repo.create(&q).unwrap()
 }
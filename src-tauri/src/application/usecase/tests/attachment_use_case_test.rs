@@ -0,0 +1,233 @@
+use crate::application::crud::attachment_repository_trait::AttachmentRepository;
+use crate::application::crud::crud_repository_trait::CRUDRepository;
+use crate::application::usecase::attachment_use_case::AttachmentUseCase;
+use crate::application::usecase::question_use_case::QuestionUseCase;
+use crate::domain::model::attachment::{Attachment, MAX_ATTACHMENT_SIZE};
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::tests::fixtures::answer;
+use crate::infrastructure::repositories::sqlite_attachment_repository::SQLiteAttachmentRepository;
+use crate::infrastructure::repositories::sqlite_exam_crud_repository::SQLiteExamCrudRepository;
+use diesel::{Connection, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+fn get_connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    connection.run_pending_migrations(MIGRATIONS).unwrap();
+    connection
+}
+
+fn create_question(conn: &mut SqliteConnection) -> Question {
+    let exam = SQLiteExamCrudRepository::new(conn)
+        .create(&Exam {
+            id: None,
+            name: "Exam with attachments".into(),
+            description: None,
+            points_to_succeeded: Some(1),
+            duration: None,
+            status_type: None,
+            created_at: None,
+            updated_at: None,
+            category: None,
+            max_questions_real_exam: None,
+            questions: vec![],
+        })
+        .unwrap();
+
+    QuestionUseCase::create_question(
+        conn,
+        Question {
+            id: None,
+            question_text: "Which diagram shows a star topology?".into(),
+            points_total: 1,
+            r#type: QuestionType::SingleChoice,
+            answers: vec![
+                answer("The first diagram", true),
+                answer("The second diagram", false),
+            ],
+            points_per_correct_answer: None,
+            category: None,
+            created_at: None,
+            updated_at: None,
+            options: None,
+            exam_id: exam.id,
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+        },
+    )
+    .unwrap()
+}
+
+fn attachment(question_id: Option<i32>, answer_id: Option<i32>, content: &[u8]) -> Attachment {
+    Attachment {
+        id: None,
+        question_id,
+        answer_id,
+        file_name: "diagram.png".into(),
+        mime_type: "image/png".into(),
+        hash: String::new(),
+        size: 0,
+        created_at: None,
+        data: Some(Attachment::encode_data(content)),
+    }
+}
+
+fn content_exists(conn: &mut SqliteConnection, content: &[u8]) -> bool {
+    SQLiteAttachmentRepository::new(conn)
+        .find_content(&Attachment::content_hash(content))
+        .unwrap()
+        .is_some()
+}
+
+#[test]
+fn it_should_upload_list_and_fetch_attachments() {
+    let mut conn = get_connection();
+    let question = create_question(&mut conn);
+    let question_id = question.id.unwrap();
+    let answer_id = question.answers[0].id.unwrap();
+
+    let uploaded = AttachmentUseCase::upload_attachment(
+        &mut conn,
+        attachment(Some(question_id), None, b"question image"),
+    )
+    .unwrap();
+    assert!(uploaded.id.is_some());
+    assert!(uploaded.data.is_none());
+    assert_eq!(uploaded.size, 14);
+    assert_eq!(uploaded.hash, Attachment::content_hash(b"question image"));
+
+    AttachmentUseCase::upload_attachment(
+        &mut conn,
+        attachment(None, Some(answer_id), b"answer image"),
+    )
+    .unwrap();
+
+    let of_question =
+        AttachmentUseCase::get_attachments(&mut conn, Some(question_id), None).unwrap();
+    assert_eq!(of_question.len(), 2);
+    assert!(of_question.iter().all(|a| a.data.is_none()));
+    let of_answer = AttachmentUseCase::get_attachments(&mut conn, None, Some(answer_id)).unwrap();
+    assert_eq!(of_answer.len(), 1);
+    assert!(AttachmentUseCase::get_attachments(&mut conn, None, None).is_err());
+
+    let fetched = AttachmentUseCase::get_attachment(&mut conn, uploaded.id.unwrap()).unwrap();
+    assert_eq!(
+        fetched.decode_data().unwrap(),
+        Some(b"question image".to_vec())
+    );
+    assert!(AttachmentUseCase::get_attachment(&mut conn, 999).is_err());
+}
+
+#[test]
+fn it_should_reject_invalid_uploads() {
+    let mut conn = get_connection();
+    let question_id = create_question(&mut conn).id.unwrap();
+
+    let without_data = Attachment {
+        data: None,
+        ..attachment(Some(question_id), None, b"")
+    };
+    assert!(AttachmentUseCase::upload_attachment(&mut conn, without_data).is_err());
+    assert!(AttachmentUseCase::upload_attachment(
+        &mut conn,
+        attachment(Some(question_id), None, b"")
+    )
+    .is_err());
+    assert!(AttachmentUseCase::upload_attachment(
+        &mut conn,
+        attachment(Some(question_id), None, &vec![0; MAX_ATTACHMENT_SIZE + 1])
+    )
+    .is_err());
+    assert!(AttachmentUseCase::upload_attachment(
+        &mut conn,
+        attachment(Some(999), None, b"content")
+    )
+    .is_err());
+    assert!(AttachmentUseCase::upload_attachment(
+        &mut conn,
+        attachment(None, Some(999), b"content")
+    )
+    .is_err());
+    assert!(!content_exists(&mut conn, b"content"));
+}
+
+#[test]
+fn it_should_keep_shared_content_until_the_last_attachment_is_deleted() {
+    let mut conn = get_connection();
+    let question_id = create_question(&mut conn).id.unwrap();
+
+    let first = AttachmentUseCase::upload_attachment(
+        &mut conn,
+        attachment(Some(question_id), None, b"shared"),
+    )
+    .unwrap();
+    let second = AttachmentUseCase::upload_attachment(
+        &mut conn,
+        attachment(Some(question_id), None, b"shared"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        AttachmentUseCase::delete_attachment(&mut conn, first.id.unwrap()).unwrap(),
+        1
+    );
+    assert!(content_exists(&mut conn, b"shared"));
+
+    AttachmentUseCase::delete_attachment(&mut conn, second.id.unwrap()).unwrap();
+    assert!(!content_exists(&mut conn, b"shared"));
+}
+
+#[test]
+fn it_should_collect_garbage_when_a_question_is_deleted() {
+    let mut conn = get_connection();
+    let question = create_question(&mut conn);
+    let question_id = question.id.unwrap();
+
+    AttachmentUseCase::upload_attachment(
+        &mut conn,
+        attachment(Some(question_id), None, b"question image"),
+    )
+    .unwrap();
+    AttachmentUseCase::upload_attachment(
+        &mut conn,
+        attachment(None, Some(question.answers[1].id.unwrap()), b"answer image"),
+    )
+    .unwrap();
+
+    QuestionUseCase::delete_question(&mut conn, question_id).unwrap();
+
+    assert!(!content_exists(&mut conn, b"question image"));
+    assert!(
+        AttachmentUseCase::get_attachments(&mut conn, Some(question_id), None)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn it_should_collect_garbage_when_an_answer_is_removed_by_an_update() {
+    let mut conn = get_connection();
+    let mut question = create_question(&mut conn);
+    let removed_answer_id = question.answers[1].id.unwrap();
+
+    AttachmentUseCase::upload_attachment(
+        &mut conn,
+        attachment(None, Some(removed_answer_id), b"answer image"),
+    )
+    .unwrap();
+
+    question.answers.truncate(1);
+    question.answers.push(answer("The third diagram", false));
+    QuestionUseCase::update_question(&mut conn, &question).unwrap();
+
+    assert!(!content_exists(&mut conn, b"answer image"));
+    assert!(
+        AttachmentUseCase::get_attachments(&mut conn, None, Some(removed_answer_id))
+            .unwrap()
+            .is_empty()
+    );
+}
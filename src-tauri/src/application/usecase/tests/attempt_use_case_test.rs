@@ -0,0 +1,991 @@
+use crate::application::usecase::attempt_use_case::AttemptUseCase;
+use crate::application::usecase::exam_use_case::ExamUseCase;
+use crate::application::usecase::question_draw_use_case::QuestionDrawUseCase;
+use crate::application::usecase::question_use_case::QuestionUseCase;
+use crate::application::usecase::setting_use_case::SettingUseCase;
+use crate::domain::model::answer::Answer;
+use crate::domain::model::assignment_option::AssignmentOption;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::ordering_scoring::OrderingScoring;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::setting::{Setting, ATTEMPT_GRACE_PERIOD_SECONDS};
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures::{self, answer};
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::sampling::attempt_shuffler::AttemptShuffler;
+use chrono::{Duration, Utc};
+use diesel::{Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+fn get_connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    connection.run_pending_migrations(MIGRATIONS).unwrap();
+    connection
+}
+
+fn question(text: &str) -> Question {
+    Question {
+        points_total: 2,
+        points_per_correct_answer: Some(2),
+        ..fixtures::question(text)
+    }
+}
+
+fn create_exam(conn: &mut SqliteConnection) -> Exam {
+    let mut exam = Exam {
+        id: None,
+        name: "Attempt Exam".into(),
+        description: Some("Exam used for attempts".into()),
+        points_to_succeeded: Some(2),
+        duration: Some(30),
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![question("First question?"), question("Second question?")],
+    };
+    ExamUseCase::create_exam(conn, &mut exam).unwrap()
+}
+
+fn move_deadline(conn: &mut SqliteConnection, attempt_id: i32, minutes_from_now: i64) {
+    use crate::schema::exam_attempt::dsl::*;
+
+    diesel::update(exam_attempt.find(attempt_id))
+        .set(deadline_at.eq((Utc::now() + Duration::minutes(minutes_from_now)).naive_utc()))
+        .execute(conn)
+        .unwrap();
+}
+
+fn selection(question: &Question, answer_index: usize) -> Vec<AttemptAnswer> {
+    vec![AttemptAnswer {
+        id: None,
+        attempt_id: None,
+        question_id: question.id.unwrap(),
+        answer_id: question.answers[answer_index].id,
+        assigned_option_id: None,
+        time_spent_seconds: None,
+        created_at: None,
+        text_response: None,
+        position: None,
+        blank: None,
+    }]
+}
+
+#[test]
+fn it_should_start_answer_and_submit_attempt() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let exam_id = exam.id.unwrap();
+
+    let attempt =
+        AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Simulation, None).unwrap();
+    let attempt_id = attempt.id.unwrap();
+    assert_eq!(attempt.exam_id, exam_id);
+    assert!(!attempt.is_finished());
+
+    let first = &exam.questions[0];
+    let saved = AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        first.id.unwrap(),
+        selection(first, 0),
+    )
+    .unwrap();
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].attempt_id, Some(attempt_id));
+
+    // answering again replaces the previous selection
+    AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        first.id.unwrap(),
+        selection(first, 1),
+    )
+    .unwrap();
+
+    let submitted = AttemptUseCase::submit_attempt(&mut conn, attempt_id).unwrap();
+    assert!(submitted.is_finished());
+    assert_eq!(submitted.score, Some(0));
+    assert_eq!(submitted.answers.len(), 1);
+    assert_eq!(submitted.answers[0].answer_id, first.answers[1].id);
+}
+
+#[test]
+fn it_should_fail_to_start_attempt_for_unknown_exam() {
+    let mut conn = get_connection();
+    assert!(AttemptUseCase::start_attempt(&mut conn, 999, AttemptMode::Simulation, None).is_err());
+}
+
+#[test]
+fn it_should_reject_answers_after_submit() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let attempt_id = AttemptUseCase::start_attempt(
+        &mut conn,
+        exam.id.unwrap(),
+        AttemptMode::Certification,
+        None,
+    )
+    .unwrap()
+    .id
+    .unwrap();
+    AttemptUseCase::submit_attempt(&mut conn, attempt_id).unwrap();
+
+    let first = &exam.questions[0];
+    let result = AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        first.id.unwrap(),
+        selection(first, 0),
+    );
+    assert!(result.is_err());
+    assert!(AttemptUseCase::submit_attempt(&mut conn, attempt_id).is_err());
+}
+
+#[test]
+fn it_should_reject_answer_of_other_question() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let attempt_id =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap()
+            .id
+            .unwrap();
+
+    let first = &exam.questions[0];
+    let second = &exam.questions[1];
+    let result = AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        first.id.unwrap(),
+        selection(second, 0),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn it_should_reject_question_of_other_exam() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let other_exam = create_exam(&mut conn);
+    let attempt_id =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap()
+            .id
+            .unwrap();
+
+    let foreign = &other_exam.questions[0];
+    let result = AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        foreign.id.unwrap(),
+        selection(foreign, 0),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn it_should_reject_assigned_options_of_other_questions() {
+    let mut conn = get_connection();
+    let option = |id: i32, text: &str| AssignmentOption {
+        row_id: None,
+        id,
+        text: text.into(),
+        question_id: None,
+    };
+    let mut assignment = question("Assign the protocols");
+    assignment.r#type = QuestionType::Assignment;
+    assignment.options = Some(vec![option(1, "Transport"), option(2, "Application")]);
+    assignment.answers = vec![
+        Answer {
+            assigned_option_id: Some(1),
+            ..answer("TCP segment", true)
+        },
+        Answer {
+            assigned_option_id: Some(2),
+            ..answer("HTTP request", true)
+        },
+    ];
+    let mut exam = Exam {
+        id: None,
+        name: "Assignment Exam".into(),
+        description: None,
+        points_to_succeeded: None,
+        duration: None,
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![assignment, question("Single choice?")],
+    };
+    let exam = ExamUseCase::create_exam(&mut conn, &mut exam).unwrap();
+    let attempt_id =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap()
+            .id
+            .unwrap();
+
+    let assign = |question: &Question, option_id: i32| {
+        let mut answers = selection(question, 0);
+        answers[0].assigned_option_id = Some(option_id);
+        answers
+    };
+    let assignment = &exam.questions[0];
+    let single_choice = &exam.questions[1];
+    assert!(AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        assignment.id.unwrap(),
+        assign(assignment, 2),
+    )
+    .is_ok());
+    assert!(AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        assignment.id.unwrap(),
+        assign(assignment, 3),
+    )
+    .is_err());
+    assert!(AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        single_choice.id.unwrap(),
+        assign(single_choice, 1),
+    )
+    .is_err());
+}
+
+#[test]
+fn it_should_list_and_delete_attempts_by_exam() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let exam_id = exam.id.unwrap();
+
+    let first =
+        AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Simulation, None).unwrap();
+    AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Certification, None).unwrap();
+
+    assert_eq!(
+        AttemptUseCase::get_attempts_by_exam_id(&mut conn, exam_id)
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(
+        AttemptUseCase::delete_attempt(&mut conn, first.id.unwrap()).unwrap(),
+        1
+    );
+    assert_eq!(
+        AttemptUseCase::get_attempts_by_exam_id(&mut conn, exam_id)
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn it_should_return_questions_in_shuffled_attempt_order() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let attempt =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap();
+    let attempt_id = attempt.id.unwrap();
+    let seed = attempt.shuffle_seed.expect("attempts get a shuffle seed");
+
+    let questions = AttemptUseCase::get_attempt_questions(&mut conn, attempt_id).unwrap();
+    let expected = AttemptShuffler::shuffle(&exam.questions, seed);
+    let ids = |questions: &[Question]| -> Vec<(Option<i32>, Vec<Option<i32>>)> {
+        questions
+            .iter()
+            .map(|q| (q.id, q.answers.iter().map(|a| a.id).collect()))
+            .collect()
+    };
+    assert_eq!(ids(&questions), ids(&expected));
+    assert_eq!(
+        ids(&questions),
+        ids(&AttemptUseCase::get_attempt_questions(&mut conn, attempt_id).unwrap())
+    );
+
+    // picking the correct answer of the shuffled list still scores
+    let first = &questions[0];
+    let correct = first
+        .answers
+        .iter()
+        .position(|a| a.is_correct == Some(true))
+        .unwrap();
+    AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        first.id.unwrap(),
+        selection(first, correct),
+    )
+    .unwrap();
+    let score = AttemptUseCase::get_attempt_score(&mut conn, attempt_id).unwrap();
+    assert_eq!(score.points_earned, 2);
+}
+
+#[test]
+fn it_should_keep_exam_order_for_attempts_without_seed() {
+    use crate::schema::exam_attempt::dsl::*;
+
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let attempt_id =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap()
+            .id
+            .unwrap();
+    // attempts started before seeds and question orders were stored
+    diesel::update(exam_attempt.find(attempt_id))
+        .set((
+            shuffle_seed.eq(None::<i64>),
+            content_revision.eq(None::<String>),
+        ))
+        .execute(&mut conn)
+        .unwrap();
+    diesel::delete(crate::schema::attempt_question::table)
+        .execute(&mut conn)
+        .unwrap();
+
+    let questions = AttemptUseCase::get_attempt_questions(&mut conn, attempt_id).unwrap();
+    let question_ids: Vec<Option<i32>> = questions.iter().map(|q| q.id).collect();
+    let exam_ids: Vec<Option<i32>> = exam.questions.iter().map(|q| q.id).collect();
+    assert_eq!(question_ids, exam_ids);
+    assert!(AttemptUseCase::get_attempt_questions(&mut conn, 42).is_err());
+}
+
+#[test]
+fn it_should_score_attempt_on_the_server() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let attempt_id = AttemptUseCase::start_attempt(
+        &mut conn,
+        exam.id.unwrap(),
+        AttemptMode::Certification,
+        None,
+    )
+    .unwrap()
+    .id
+    .unwrap();
+
+    let first = &exam.questions[0];
+    AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        first.id.unwrap(),
+        selection(first, 0),
+    )
+    .unwrap();
+
+    let score = AttemptUseCase::get_attempt_score(&mut conn, attempt_id).unwrap();
+    assert_eq!(score.points_earned, 2);
+    assert_eq!(score.points_total, 4);
+    assert_eq!(score.passed, Some(true));
+    assert_eq!(score.questions.len(), 2);
+    let second = score
+        .questions
+        .iter()
+        .find(|q| q.question_id == exam.questions[1].id)
+        .unwrap();
+    assert_eq!(second.points_earned, 0);
+
+    let submitted = AttemptUseCase::submit_attempt(&mut conn, attempt_id).unwrap();
+    assert_eq!(submitted.score, Some(2));
+}
+
+#[test]
+fn it_should_score_typed_short_answers() {
+    let mut conn = get_connection();
+    let mut short_answer = question("Which command lists hidden files?");
+    short_answer.r#type = QuestionType::ShortAnswer;
+    short_answer.answers = vec![Answer {
+        answer_text: "ls -a".into(),
+        match_mode: Some(MatchMode::WhitespaceNormalized),
+        ..answer("", true)
+    }];
+    let mut exam = Exam {
+        questions: vec![short_answer],
+        ..create_exam(&mut conn)
+    };
+    exam.id = None;
+    let exam = ExamUseCase::create_exam(&mut conn, &mut exam).unwrap();
+    let question = &exam.questions[0];
+
+    let attempt_id =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap()
+            .id
+            .unwrap();
+    let saved = AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        question.id.unwrap(),
+        vec![AttemptAnswer {
+            id: None,
+            attempt_id: None,
+            question_id: question.id.unwrap(),
+            answer_id: None,
+            assigned_option_id: None,
+            time_spent_seconds: None,
+            created_at: None,
+            text_response: Some(" ls  -a".into()),
+            position: None,
+            blank: None,
+        }],
+    )
+    .unwrap();
+    assert_eq!(saved[0].text_response.as_deref(), Some(" ls  -a"));
+
+    let submitted = AttemptUseCase::submit_attempt(&mut conn, attempt_id).unwrap();
+    assert_eq!(submitted.score, Some(2));
+}
+
+#[test]
+fn it_should_score_numeric_answers_within_tolerance() {
+    let mut conn = get_connection();
+    let mut numeric = question("What throughput does the link reach?");
+    numeric.r#type = QuestionType::Numeric;
+    numeric.answers = vec![Answer {
+        answer_text: "950".into(),
+        numeric: Some(NumericAnswer {
+            expected_value: 950.0,
+            tolerance: 0.05,
+            tolerance_mode: ToleranceMode::Relative,
+            unit: Some("Mbit/s".into()),
+        }),
+        ..answer("", true)
+    }];
+    let mut exam = Exam {
+        questions: vec![numeric],
+        ..create_exam(&mut conn)
+    };
+    exam.id = None;
+    let exam = ExamUseCase::create_exam(&mut conn, &mut exam).unwrap();
+    let question = &exam.questions[0];
+
+    let attempt_id =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap()
+            .id
+            .unwrap();
+    AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        question.id.unwrap(),
+        vec![AttemptAnswer {
+            id: None,
+            attempt_id: None,
+            question_id: question.id.unwrap(),
+            answer_id: None,
+            assigned_option_id: None,
+            time_spent_seconds: None,
+            created_at: None,
+            text_response: Some("980 Mbit/s".into()),
+            position: None,
+            blank: None,
+        }],
+    )
+    .unwrap();
+
+    let submitted = AttemptUseCase::submit_attempt(&mut conn, attempt_id).unwrap();
+    assert_eq!(submitted.score, Some(2));
+}
+
+#[test]
+fn it_should_score_cloze_blanks_independently() {
+    let mut conn = get_connection();
+    let cloze = Question {
+        question_text: "HTTPS runs on port {{1}} and uses {{2}} as transport.".into(),
+        r#type: QuestionType::Cloze,
+        points_per_correct_answer: Some(1),
+        answers: vec![
+            Answer {
+                match_mode: Some(MatchMode::Exact),
+                blank: Some(1),
+                ..answer("443", true)
+            },
+            Answer {
+                blank: Some(2),
+                ..answer("TCP segment", true)
+            },
+            Answer {
+                blank: Some(2),
+                ..answer("UDP", false)
+            },
+        ],
+        ..question("")
+    };
+    let mut exam = Exam {
+        questions: vec![cloze],
+        ..create_exam(&mut conn)
+    };
+    exam.id = None;
+    let exam = ExamUseCase::create_exam(&mut conn, &mut exam).unwrap();
+    let question = &exam.questions[0];
+    let response = |answer_id: Option<i32>, blank: Option<i32>, text: Option<&str>| AttemptAnswer {
+        id: None,
+        attempt_id: None,
+        question_id: question.id.unwrap(),
+        answer_id,
+        assigned_option_id: None,
+        time_spent_seconds: None,
+        created_at: None,
+        text_response: text.map(String::from),
+        position: None,
+        blank,
+    };
+
+    let attempt_id =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap()
+            .id
+            .unwrap();
+    let saved = AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        question.id.unwrap(),
+        vec![
+            response(None, Some(1), Some("443")),
+            response(question.answers[2].id, None, None),
+        ],
+    )
+    .unwrap();
+    assert_eq!(saved[0].blank, Some(1));
+
+    // the typed blank is right, the dropdown is wrong
+    let submitted = AttemptUseCase::submit_attempt(&mut conn, attempt_id).unwrap();
+    assert_eq!(submitted.score, Some(1));
+}
+
+#[test]
+fn it_should_score_submitted_ordering_permutations() {
+    let mut conn = get_connection();
+    let step = |text: &str, position: i32| Answer {
+        position: Some(position),
+        ..answer(text, true)
+    };
+    let ordering = Question {
+        r#type: QuestionType::Ordering,
+        ordering_scoring: Some(OrderingScoring::KendallTau),
+        points_per_correct_answer: None,
+        points_total: 3,
+        answers: vec![
+            step("Request the change", 1),
+            step("Assess the impact", 2),
+            step("Roll out the change", 3),
+        ],
+        ..question("Put the change process in order")
+    };
+    let mut exam = Exam {
+        questions: vec![ordering],
+        ..create_exam(&mut conn)
+    };
+    exam.id = None;
+    let exam = ExamUseCase::create_exam(&mut conn, &mut exam).unwrap();
+    let question = &exam.questions[0];
+    let place = |index: usize, position: i32| AttemptAnswer {
+        id: None,
+        attempt_id: None,
+        question_id: question.id.unwrap(),
+        answer_id: question.answers[index].id,
+        assigned_option_id: None,
+        time_spent_seconds: None,
+        created_at: None,
+        text_response: None,
+        position: Some(position),
+        blank: None,
+    };
+
+    let attempt_id =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap()
+            .id
+            .unwrap();
+
+    let err = AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        question.id.unwrap(),
+        vec![place(0, 1), place(1, 1), place(2, 3)],
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("must be distinct"));
+    let err = AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        question.id.unwrap(),
+        vec![place(0, 4)],
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("between 1 and 3"));
+
+    // the last two steps are swapped, which keeps 2 of 3 pairs in order
+    let saved = AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        question.id.unwrap(),
+        vec![place(0, 1), place(1, 3), place(2, 2)],
+    )
+    .unwrap();
+    assert_eq!(saved[1].position, Some(3));
+
+    let submitted = AttemptUseCase::submit_attempt(&mut conn, attempt_id).unwrap();
+    assert_eq!(submitted.score, Some(2));
+}
+
+#[test]
+fn it_should_cover_only_drawn_questions_in_certification_attempts() {
+    let mut conn = get_connection();
+    let mut exam = create_exam(&mut conn);
+    exam.max_questions_real_exam = Some(1);
+    let exam = ExamUseCase::update_exam(&mut conn, &mut exam).unwrap();
+    let exam_id = exam.id.unwrap();
+
+    let attempt =
+        AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Certification, None)
+            .unwrap();
+    let attempt_id = attempt.id.unwrap();
+    let draw = QuestionDrawUseCase::get_draws_by_exam_id(&mut conn, exam_id)
+        .unwrap()
+        .remove(0);
+    assert_eq!(attempt.draw_id, draw.id);
+    assert_eq!(draw.question_ids.len(), 1);
+
+    let questions = AttemptUseCase::get_attempt_questions(&mut conn, attempt_id).unwrap();
+    assert_eq!(questions.len(), 1);
+    assert_eq!(questions[0].id, Some(draw.question_ids[0]));
+
+    let (drawn, other): (Vec<&Question>, Vec<&Question>) =
+        exam.questions.iter().partition(|q| q.id == questions[0].id);
+    let (drawn, other) = (drawn[0], other[0]);
+    assert!(AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        other.id.unwrap(),
+        selection(other, 0),
+    )
+    .is_err());
+    AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        drawn.id.unwrap(),
+        selection(drawn, 0),
+    )
+    .unwrap();
+
+    let score = AttemptUseCase::get_attempt_score(&mut conn, attempt_id).unwrap();
+    assert_eq!(score.points_total, 2);
+    assert_eq!(score.points_earned, 2);
+    assert_eq!(score.questions.len(), 1);
+}
+
+#[test]
+fn it_should_start_attempts_over_a_given_draw() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let other_exam = create_exam(&mut conn);
+    let draw =
+        QuestionDrawUseCase::draw_questions(&mut conn, exam.id.unwrap(), Some(7), false).unwrap();
+    let other_draw =
+        QuestionDrawUseCase::draw_questions(&mut conn, other_exam.id.unwrap(), Some(7), false)
+            .unwrap();
+
+    let attempt = AttemptUseCase::start_attempt(
+        &mut conn,
+        exam.id.unwrap(),
+        AttemptMode::Simulation,
+        draw.id,
+    )
+    .unwrap();
+    assert_eq!(attempt.draw_id, draw.id);
+    assert!(AttemptUseCase::start_attempt(
+        &mut conn,
+        exam.id.unwrap(),
+        AttemptMode::Certification,
+        other_draw.id
+    )
+    .is_err());
+
+    // Simulations without a draw cover the whole exam.
+    let simulation =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap();
+    assert_eq!(simulation.draw_id, None);
+}
+
+#[test]
+fn it_should_set_deadline_for_timed_certification_attempts() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+
+    let certification = AttemptUseCase::start_attempt(
+        &mut conn,
+        exam.id.unwrap(),
+        AttemptMode::Certification,
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        certification.deadline_at,
+        Some(certification.started_at + Duration::minutes(30))
+    );
+    assert!(!certification.expired);
+
+    let simulation =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap();
+    assert!(simulation.deadline_at.is_none());
+}
+
+#[test]
+fn it_should_reject_answers_after_deadline_and_finalize_attempt() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let attempt_id = AttemptUseCase::start_attempt(
+        &mut conn,
+        exam.id.unwrap(),
+        AttemptMode::Certification,
+        None,
+    )
+    .unwrap()
+    .id
+    .unwrap();
+
+    let first = &exam.questions[0];
+    AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        first.id.unwrap(),
+        selection(first, 0),
+    )
+    .unwrap();
+    {
+        use crate::schema::attempt_answer::dsl::*;
+        diesel::update(attempt_answer.filter(fk_attempt_id.eq(attempt_id)))
+            .set(created_at.eq((Utc::now() - Duration::minutes(2)).naive_utc()))
+            .execute(&mut conn)
+            .unwrap();
+    }
+    move_deadline(&mut conn, attempt_id, -1);
+
+    let second = &exam.questions[1];
+    let result = AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        second.id.unwrap(),
+        selection(second, 0),
+    );
+    assert!(result.unwrap_err().to_string().contains("ran out of time"));
+
+    let attempt = AttemptUseCase::get_attempt_by_id(&mut conn, attempt_id)
+        .unwrap()
+        .unwrap();
+    assert!(attempt.expired);
+    assert!(attempt.is_finished());
+    // only the answer given before the deadline counts
+    assert_eq!(attempt.score, Some(2));
+}
+
+#[test]
+fn it_should_accept_answers_within_grace_period() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    SettingUseCase::update_setting(
+        &mut conn,
+        Setting {
+            profile_id: None,
+            key: ATTEMPT_GRACE_PERIOD_SECONDS.into(),
+            value: "300".into(),
+            updated_at: None,
+        },
+    )
+    .unwrap();
+    let attempt_id = AttemptUseCase::start_attempt(
+        &mut conn,
+        exam.id.unwrap(),
+        AttemptMode::Certification,
+        None,
+    )
+    .unwrap()
+    .id
+    .unwrap();
+    move_deadline(&mut conn, attempt_id, -1);
+
+    let first = &exam.questions[0];
+    AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        first.id.unwrap(),
+        selection(first, 0),
+    )
+    .unwrap();
+
+    let submitted = AttemptUseCase::submit_attempt(&mut conn, attempt_id).unwrap();
+    assert!(!submitted.expired);
+    assert_eq!(submitted.score, Some(2));
+}
+
+#[test]
+fn it_should_finalize_expired_attempts_when_listing() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let exam_id = exam.id.unwrap();
+    let attempt_id =
+        AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Certification, None)
+            .unwrap()
+            .id
+            .unwrap();
+    AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Certification, None).unwrap();
+    move_deadline(&mut conn, attempt_id, -60);
+
+    let attempts = AttemptUseCase::get_attempts_by_exam_id(&mut conn, exam_id).unwrap();
+    let expired: Vec<_> = attempts.iter().filter(|a| a.expired).collect();
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].id, Some(attempt_id));
+    assert!(expired[0].is_finished());
+}
+
+#[test]
+fn it_should_list_open_attempts() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let exam_id = exam.id.unwrap();
+
+    let open =
+        AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Simulation, None).unwrap();
+    let submitted =
+        AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Simulation, None).unwrap();
+    AttemptUseCase::submit_attempt(&mut conn, submitted.id.unwrap()).unwrap();
+    let expired =
+        AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Certification, None)
+            .unwrap();
+    move_deadline(&mut conn, expired.id.unwrap(), -60);
+
+    let open_attempts = AttemptUseCase::get_open_attempts(&mut conn).unwrap();
+    assert_eq!(open_attempts.len(), 1);
+    assert_eq!(open_attempts[0].id, open.id);
+}
+
+#[test]
+fn it_should_resume_attempt_with_answers_order_and_remaining_time() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let attempt_id = AttemptUseCase::start_attempt(
+        &mut conn,
+        exam.id.unwrap(),
+        AttemptMode::Certification,
+        None,
+    )
+    .unwrap()
+    .id
+    .unwrap();
+    let first = &exam.questions[0];
+    AttemptUseCase::answer_question(
+        &mut conn,
+        attempt_id,
+        first.id.unwrap(),
+        selection(first, 1),
+    )
+    .unwrap();
+
+    let resumed = AttemptUseCase::resume_attempt(&mut conn, attempt_id).unwrap();
+    assert_eq!(resumed.attempt.id, Some(attempt_id));
+    assert_eq!(resumed.attempt.answers.len(), 1);
+    assert_eq!(resumed.attempt.answers[0].answer_id, first.answers[1].id);
+
+    let order: Vec<Option<i32>> = resumed.exam.questions.iter().map(|q| q.id).collect();
+    let expected: Vec<Option<i32>> = AttemptUseCase::get_attempt_questions(&mut conn, attempt_id)
+        .unwrap()
+        .iter()
+        .map(|q| q.id)
+        .collect();
+    assert_eq!(order, expected);
+
+    let remaining = resumed.remaining_seconds.unwrap();
+    assert!(remaining > 29 * 60 && remaining <= 30 * 60);
+
+    let untimed =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap();
+    let resumed = AttemptUseCase::resume_attempt(&mut conn, untimed.id.unwrap()).unwrap();
+    assert!(resumed.remaining_seconds.is_none());
+}
+
+#[test]
+fn it_should_reject_resuming_finished_or_expired_attempts() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let exam_id = exam.id.unwrap();
+
+    let submitted =
+        AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Simulation, None).unwrap();
+    AttemptUseCase::submit_attempt(&mut conn, submitted.id.unwrap()).unwrap();
+    assert!(AttemptUseCase::resume_attempt(&mut conn, submitted.id.unwrap()).is_err());
+
+    let expired =
+        AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Certification, None)
+            .unwrap();
+    move_deadline(&mut conn, expired.id.unwrap(), -60);
+    assert!(AttemptUseCase::resume_attempt(&mut conn, expired.id.unwrap()).is_err());
+}
+
+#[test]
+fn it_should_reject_resuming_after_exam_was_edited() {
+    let mut conn = get_connection();
+    let mut exam = create_exam(&mut conn);
+    let attempt_id =
+        AttemptUseCase::start_attempt(&mut conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap()
+            .id
+            .unwrap();
+    assert!(AttemptUseCase::resume_attempt(&mut conn, attempt_id).is_ok());
+
+    exam.name = "Edited Attempt Exam".into();
+    ExamUseCase::update_exam(&mut conn, &mut exam).unwrap();
+
+    let result = AttemptUseCase::resume_attempt(&mut conn, attempt_id);
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("changed"));
+}
+
+#[test]
+fn it_should_reject_resuming_after_questions_or_answers_changed() {
+    fn start(conn: &mut SqliteConnection) -> (Exam, i32) {
+        let exam = create_exam(conn);
+        let attempt_id =
+            AttemptUseCase::start_attempt(conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+                .unwrap()
+                .id
+                .unwrap();
+        assert!(AttemptUseCase::resume_attempt(conn, attempt_id).is_ok());
+        (exam, attempt_id)
+    }
+    let mut conn = get_connection();
+
+    // deleted answers do not leave a modification time behind
+    let (exam, attempt_id) = start(&mut conn);
+    diesel::delete(crate::schema::answer::table.find(exam.questions[0].answers[0].id.unwrap()))
+        .execute(&mut conn)
+        .unwrap();
+    assert!(AttemptUseCase::resume_attempt(&mut conn, attempt_id).is_err());
+
+    let (exam, attempt_id) = start(&mut conn);
+    QuestionUseCase::delete_question(&mut conn, exam.questions[1].id.unwrap()).unwrap();
+    assert!(AttemptUseCase::resume_attempt(&mut conn, attempt_id).is_err());
+
+    let (exam, attempt_id) = start(&mut conn);
+    let mut added = question("Which question was added later?");
+    added.exam_id = exam.id;
+    QuestionUseCase::create_question(&mut conn, added).unwrap();
+    assert!(AttemptUseCase::resume_attempt(&mut conn, attempt_id).is_err());
+}
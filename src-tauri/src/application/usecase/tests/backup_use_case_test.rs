@@ -0,0 +1,144 @@
+use crate::application::crud::attachment_repository_trait::AttachmentRepository;
+use crate::application::usecase::backup_use_case::BackupUseCase;
+use crate::application::usecase::setting_use_case::SettingUseCase;
+use crate::domain::model::setting::{Setting, ATTEMPT_GRACE_PERIOD_SECONDS};
+use crate::infrastructure::repositories::sqlite_attachment_repository::SQLiteAttachmentRepository;
+use chrono::Duration;
+use diesel::{Connection, RunQueryDsl, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+fn temp_path(name: &str) -> PathBuf {
+    let n = std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("{name}_{}_{n}.db", std::process::id()))
+}
+
+/// Database file with a setting of the default profile and an attachment blob.
+fn create_database(path: &Path) -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(&path.display().to_string()).unwrap();
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+    SettingUseCase::update_setting(
+        &mut conn,
+        Setting {
+            profile_id: None,
+            key: ATTEMPT_GRACE_PERIOD_SECONDS.into(),
+            value: "90".into(),
+            updated_at: None,
+        },
+    )
+    .unwrap();
+    SQLiteAttachmentRepository::new(&mut conn)
+        .save_content("hash", b"diagram")
+        .unwrap();
+    conn
+}
+
+fn latest_migration(conn: &mut SqliteConnection) -> String {
+    conn.applied_migrations()
+        .unwrap()
+        .into_iter()
+        .max()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn it_should_restore_settings_and_attachments() {
+    let source = temp_path("backup_source");
+    let backup_path = temp_path("backup");
+    let target = temp_path("backup_target");
+    let mut conn = create_database(&source);
+
+    let backup = BackupUseCase::create_backup(&mut conn, &backup_path).unwrap();
+    assert!(backup.size > 0);
+    assert_eq!(backup.schema_version, latest_migration(&mut conn));
+
+    // An existing file is replaced.
+    let again = BackupUseCase::create_backup(&mut conn, &backup_path).unwrap();
+    assert_eq!(again.size, backup.size);
+
+    fs::write(&target, b"").unwrap();
+    let restored = BackupUseCase::restore_backup(&target, &backup_path, MIGRATIONS).unwrap();
+    assert_eq!(restored.schema_version, backup.schema_version);
+
+    let mut restored_conn = SqliteConnection::establish(&target.display().to_string()).unwrap();
+    assert_eq!(
+        SettingUseCase::get_attempt_grace_period(&mut restored_conn).unwrap(),
+        Duration::seconds(90)
+    );
+    assert_eq!(
+        SQLiteAttachmentRepository::new(&mut restored_conn)
+            .find_content("hash")
+            .unwrap(),
+        Some(b"diagram".to_vec())
+    );
+
+    drop(conn);
+    for path in [source, backup_path, target] {
+        let _ = fs::remove_file(path);
+    }
+}
+
+#[test]
+fn it_should_reject_damaged_and_newer_backups() {
+    let target = temp_path("backup_target");
+    let damaged = temp_path("backup_damaged");
+    let newer = temp_path("backup_newer");
+    drop(create_database(&target));
+    let original = fs::read(&target).unwrap();
+
+    fs::write(&damaged, b"This is not a database, just some text.").unwrap();
+    assert!(BackupUseCase::restore_backup(&target, &damaged, MIGRATIONS).is_err());
+
+    let mut conn = create_database(&newer);
+    diesel::sql_query("INSERT INTO __diesel_schema_migrations (version) VALUES ('99991231000000')")
+        .execute(&mut conn)
+        .unwrap();
+    drop(conn);
+    let error = BackupUseCase::restore_backup(&target, &newer, MIGRATIONS).unwrap_err();
+    assert!(error.to_string().contains("99991231000000"));
+
+    let empty = temp_path("backup_empty");
+    fs::write(&empty, b"").unwrap();
+    assert!(BackupUseCase::restore_backup(&target, &empty, MIGRATIONS).is_err());
+    assert!(BackupUseCase::restore_backup(&target, &temp_path("missing"), MIGRATIONS).is_err());
+
+    assert_eq!(fs::read(&target).unwrap(), original);
+    let mut restoring = target.clone().into_os_string();
+    restoring.push(".restoring");
+    assert!(!PathBuf::from(restoring).exists());
+
+    for path in [target, damaged, newer, empty] {
+        let _ = fs::remove_file(path);
+    }
+}
+
+#[test]
+fn it_should_migrate_backups_of_older_versions() {
+    let source = temp_path("backup_source");
+    let backup_path = temp_path("backup");
+    let target = temp_path("backup_target");
+    let mut conn = create_database(&source);
+    let current = latest_migration(&mut conn);
+    conn.revert_last_migration(MIGRATIONS).unwrap();
+    let older = latest_migration(&mut conn);
+    BackupUseCase::create_backup(&mut conn, &backup_path).unwrap();
+
+    fs::write(&target, b"").unwrap();
+    let restored = BackupUseCase::restore_backup(&target, &backup_path, MIGRATIONS).unwrap();
+    assert_eq!(restored.schema_version, older);
+
+    let mut restored_conn = SqliteConnection::establish(&target.display().to_string()).unwrap();
+    assert_eq!(latest_migration(&mut restored_conn), current);
+
+    drop(conn);
+    for path in [source, backup_path, target] {
+        let _ = fs::remove_file(path);
+    }
+}
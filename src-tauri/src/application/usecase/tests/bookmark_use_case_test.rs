@@ -0,0 +1,94 @@
+use crate::application::usecase::bookmark_use_case::BookmarkUseCase;
+use crate::application::usecase::exam_use_case::ExamUseCase;
+use crate::application::usecase::question_use_case::QuestionUseCase;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures::question;
+use diesel::{Connection, RunQueryDsl, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+/// Foreign keys are turned on like in the application, so deletes cascade.
+fn get_connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    connection.run_pending_migrations(MIGRATIONS).unwrap();
+    diesel::sql_query("PRAGMA foreign_keys = ON;")
+        .execute(&mut connection)
+        .unwrap();
+    connection
+}
+
+fn create_exam(conn: &mut SqliteConnection) -> Exam {
+    let mut exam = Exam {
+        id: None,
+        name: "Exam with bookmarks".into(),
+        description: None,
+        points_to_succeeded: Some(1),
+        duration: None,
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![question("First question?"), question("Second question?")],
+    };
+    ExamUseCase::create_exam(conn, &mut exam).unwrap()
+}
+
+#[test]
+fn it_should_add_bookmark_once() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let question_id = exam.questions[0].id.unwrap();
+
+    let first = BookmarkUseCase::add_bookmark(&mut conn, question_id).unwrap();
+    let second = BookmarkUseCase::add_bookmark(&mut conn, question_id).unwrap();
+    assert_eq!(first.id, second.id);
+
+    let bookmarks = BookmarkUseCase::get_bookmarks(&mut conn).unwrap();
+    assert_eq!(bookmarks.len(), 1);
+    assert_eq!(
+        bookmarks[0].question.as_ref().unwrap().question_text,
+        "First question?"
+    );
+}
+
+#[test]
+fn it_should_fail_bookmark_of_unknown_question() {
+    let mut conn = get_connection();
+    assert!(BookmarkUseCase::add_bookmark(&mut conn, 42).is_err());
+}
+
+#[test]
+fn it_should_remove_bookmark() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let question_id = exam.questions[1].id.unwrap();
+
+    BookmarkUseCase::add_bookmark(&mut conn, question_id).unwrap();
+    assert_eq!(
+        BookmarkUseCase::remove_bookmark(&mut conn, question_id).unwrap(),
+        1
+    );
+    assert_eq!(
+        BookmarkUseCase::remove_bookmark(&mut conn, question_id).unwrap(),
+        0
+    );
+    assert!(BookmarkUseCase::get_bookmarks(&mut conn)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn it_should_delete_bookmark_with_question() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let question_id = exam.questions[0].id.unwrap();
+
+    BookmarkUseCase::add_bookmark(&mut conn, question_id).unwrap();
+    QuestionUseCase::delete_question(&mut conn, question_id).unwrap();
+    assert!(BookmarkUseCase::get_bookmarks(&mut conn)
+        .unwrap()
+        .is_empty());
+}
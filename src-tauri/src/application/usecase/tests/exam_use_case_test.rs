@@ -1,12 +1,27 @@
+use crate::application::usecase::attachment_use_case::AttachmentUseCase;
+use crate::application::usecase::attempt_use_case::AttemptUseCase;
+use crate::application::usecase::category_use_case::CategoryUseCase;
 use crate::application::usecase::exam_use_case::ExamUseCase;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::domain::export::qti_package_writer::QtiPackageWriter;
+use crate::domain::import::text_exam_parser::TextExamParser;
 use crate::domain::model::answer::Answer;
 use crate::domain::model::assignment_option::AssignmentOption;
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::category::Category;
 use crate::domain::model::exam::Exam;
+use crate::domain::model::exam_bundle::EXAM_BUNDLE_SCHEMA_VERSION;
 use crate::domain::model::question::Question;
 use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::source_reference::SourceReference;
+use crate::domain::model::spreadsheet_mapping::SpreadsheetMapping;
 use crate::domain::model::status_type::StatusType;
-use diesel::{Connection, SqliteConnection};
+use diesel::{Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection};
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use std::io::Read;
+use std::path::Path;
 
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
 
@@ -44,6 +59,10 @@ fn sample_exam() -> Exam {
                         created_at: None,
                         updated_at: None,
                         question_id: Some(0),
+                        match_mode: None,
+                        numeric: None,
+                        position: None,
+                        blank: None,
                     },
                     Answer {
                         id: None,
@@ -54,6 +73,10 @@ fn sample_exam() -> Exam {
                         created_at: None,
                         updated_at: None,
                         question_id: Some(0),
+                        match_mode: None,
+                        numeric: None,
+                        position: None,
+                        blank: None,
                     },
                 ],
                 points_per_correct_answer: Some(10),
@@ -75,6 +98,9 @@ fn sample_exam() -> Exam {
                     },
                 ]),
                 exam_id: None,
+                ordering_scoring: None,
+                explanation: None,
+                sources: None,
             },
         ],
     }
@@ -125,6 +151,10 @@ fn it_should_add_new_question_to_exam() {
                 created_at: None,
                 updated_at: None,
                 question_id: None,
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             },
             Answer {
                 id: None,
@@ -135,6 +165,10 @@ fn it_should_add_new_question_to_exam() {
                 created_at: None,
                 updated_at: None,
                 question_id: None,
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             },
         ],
         points_per_correct_answer: Some(5),
@@ -143,6 +177,9 @@ fn it_should_add_new_question_to_exam() {
         updated_at: None,
         options: None,
         exam_id: Some(exam_to_update.id.unwrap()),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
     };
 
     exam_to_update.questions.push(new_question);
@@ -160,6 +197,65 @@ fn it_should_get_overall_statistics() {
     assert_eq!(stats.exam_count, 1);
 }
 
+#[test]
+fn it_should_get_question_statistics() {
+    let mut conn = get_connection();
+    let exam = ExamUseCase::create_exam(&mut conn, &mut sample_exam()).unwrap();
+    let exam_id = exam.id.unwrap();
+    let question = &exam.questions[0];
+    let default_profile = ProfileUseCase::get_active_profile_id(&mut conn).unwrap();
+    let other_profile = ProfileUseCase::create_profile(&mut conn, "Other").unwrap();
+
+    for (answer_index, time_spent_seconds) in [(0, 20), (0, 40), (1, 30)] {
+        // the last attempt is made by another learner
+        if answer_index == 1 {
+            ProfileUseCase::switch_profile(&mut conn, other_profile.id.unwrap()).unwrap();
+        }
+        let attempt =
+            AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Simulation, None)
+                .unwrap();
+        let attempt_id = attempt.id.unwrap();
+        AttemptUseCase::answer_question(
+            &mut conn,
+            attempt_id,
+            question.id.unwrap(),
+            vec![AttemptAnswer {
+                id: None,
+                attempt_id: None,
+                question_id: question.id.unwrap(),
+                answer_id: question.answers[answer_index].id,
+                assigned_option_id: None,
+                time_spent_seconds: Some(time_spent_seconds),
+                created_at: None,
+                text_response: None,
+                position: None,
+                blank: None,
+            }],
+        )
+        .unwrap();
+        AttemptUseCase::submit_attempt(&mut conn, attempt_id).unwrap();
+    }
+    // open attempts are not part of the analysis
+    AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Simulation, None).unwrap();
+    ProfileUseCase::switch_profile(&mut conn, default_profile).unwrap();
+
+    let statistics = ExamUseCase::get_question_statistics(&mut conn, exam_id).unwrap();
+    assert_eq!(statistics.len(), 1);
+    assert_eq!(statistics[0].question_id, question.id);
+    assert_eq!(statistics[0].responses, 3);
+    assert_eq!(statistics[0].difficulty_index, Some(2.0 / 3.0));
+    assert_eq!(statistics[0].discrimination_index, Some(1.0));
+    assert_eq!(statistics[0].average_time_seconds, Some(30.0));
+    assert_eq!(statistics[0].answers[0].selection_count, 2);
+    assert_eq!(statistics[0].answers[1].selection_count, 1);
+}
+
+#[test]
+fn it_should_fail_question_statistics_for_unknown_exam() {
+    let mut conn = get_connection();
+    assert!(ExamUseCase::get_question_statistics(&mut conn, 42).is_err());
+}
+
 #[test]
 fn it_should_validate_exam_successfully() {
     let exam = sample_exam();
@@ -189,11 +285,29 @@ fn it_should_search_exams() {
 fn it_should_find_by_id_with_relations() {
     let mut conn = get_connection();
     let mut exam = sample_exam();
+    exam.questions[0].explanation = Some("Explained in *detail*".into());
+    exam.questions[0].sources = Some(vec![SourceReference {
+        id: None,
+        question_id: None,
+        title: "Exam guide".into(),
+        chapter: Some("2".into()),
+        page: None,
+        url: None,
+    }]);
     let created = ExamUseCase::create_exam(&mut conn, &mut exam).unwrap();
 
     let found = ExamUseCase::find_by_id_with_relations(&mut conn, created.id.unwrap()).unwrap();
     assert!(found.is_some());
-    assert_eq!(found.unwrap().questions.len(), 1);
+    let found = found.unwrap();
+    assert_eq!(found.questions.len(), 1);
+    assert_eq!(
+        found.questions[0].explanation.as_deref(),
+        Some("Explained in *detail*")
+    );
+    let sources = found.questions[0].sources.as_ref().unwrap();
+    assert_eq!(sources.len(), 1);
+    assert_eq!(sources[0].title, "Exam guide");
+    assert_eq!(sources[0].question_id, found.questions[0].id);
 }
 
 #[test]
@@ -216,6 +330,10 @@ fn it_should_remove_question_from_exam_on_update() {
                 created_at: None,
                 updated_at: None,
                 question_id: None,
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             },
             Answer {
                 id: None,
@@ -226,6 +344,10 @@ fn it_should_remove_question_from_exam_on_update() {
                 created_at: None,
                 updated_at: None,
                 question_id: None,
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             },
         ],
         points_per_correct_answer: Some(5),
@@ -234,6 +356,9 @@ fn it_should_remove_question_from_exam_on_update() {
         updated_at: None,
         options: None,
         exam_id: None,
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
     });
 
     let created = ExamUseCase::create_exam(&mut conn, &mut exam).unwrap();
@@ -285,3 +410,383 @@ fn it_should_fail_validation_for_answers_and_options() {
         .unwrap();
     assert_eq!(option_err.errors[0].field, "text");
 }
+
+#[test]
+fn it_should_import_exam_from_text() {
+    let mut conn = get_connection();
+    let content = include_str!("../../../../../templates/import_template_txt.txt");
+
+    let created = ExamUseCase::import_exam_text(&mut conn, content).unwrap();
+    assert!(created.id.is_some());
+    assert_eq!(created.questions.len(), 5);
+
+    let found = ExamUseCase::find_by_id_with_relations(&mut conn, created.id.unwrap())
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.questions[2].options.as_ref().unwrap().len(), 2);
+    assert_eq!(found.questions[3].sources.as_ref().unwrap().len(), 2);
+}
+
+#[test]
+fn it_should_locate_validation_errors_of_imported_text() {
+    let mut conn = get_connection();
+    let content = "Q: Select one P: 1\nWhich one is right?\n[X] The first answer\n[ ] No";
+
+    let err = ExamUseCase::import_exam_text(&mut conn, content).unwrap_err();
+    let question_err = &err.nested_errors[0];
+    assert_eq!((question_err.line, question_err.column), (Some(1), Some(1)));
+    let answer_err = &question_err.nested_errors[0];
+    assert_eq!(answer_err.index, 1);
+    assert_eq!((answer_err.line, answer_err.column), (Some(4), Some(1)));
+    assert!(ExamUseCase::find_all_exams(&mut conn, None)
+        .unwrap()
+        .data
+        .is_empty());
+}
+
+fn create_exam_with_category_and_attachments(conn: &mut SqliteConnection) -> Exam {
+    let category = CategoryUseCase::create_category(
+        conn,
+        Category {
+            id: None,
+            name: "Networking".into(),
+            created_at: None,
+            updated_at: None,
+        },
+    )
+    .unwrap();
+    let content = include_str!("../../../../../templates/import_template_txt.txt");
+    let mut exam = TextExamParser::parse(content).unwrap().exam;
+    exam.category = Some(category.clone());
+    exam.questions[3].category = Some(category);
+    let created = ExamUseCase::create_exam(conn, &mut exam).unwrap();
+
+    let attach = |conn: &mut SqliteConnection, question_id, answer_id, file_name: &str| {
+        AttachmentUseCase::upload_attachment(
+            conn,
+            Attachment {
+                id: None,
+                question_id,
+                answer_id,
+                file_name: file_name.into(),
+                mime_type: "image/png".into(),
+                hash: String::new(),
+                size: 0,
+                created_at: None,
+                data: Some(Attachment::encode_data(file_name.as_bytes())),
+            },
+        )
+        .unwrap();
+    };
+    attach(conn, created.questions[3].id, None, "subnet.png");
+    attach(
+        conn,
+        None,
+        created.questions[2].answers[1].id,
+        "template.png",
+    );
+    created
+}
+
+#[test]
+fn it_should_export_a_portable_bundle() {
+    let mut conn = get_connection();
+    let created = create_exam_with_category_and_attachments(&mut conn);
+
+    let bundle = ExamUseCase::export_exam_bundle(&mut conn, created.id.unwrap()).unwrap();
+    assert_eq!(bundle.schema_version, EXAM_BUNDLE_SCHEMA_VERSION);
+    assert_eq!(bundle.exam.questions.len(), 5);
+    assert!(bundle.exam.id.is_none());
+    assert!(bundle.exam.category.as_ref().unwrap().id.is_none());
+    assert!(bundle.exam.questions.iter().all(|q| q.id.is_none()
+        && q.answers
+            .iter()
+            .all(|a| a.id.is_none() && a.question_id.is_none())));
+    let positions: Vec<(usize, Option<usize>)> = bundle
+        .attachments
+        .iter()
+        .map(|a| (a.question, a.answer))
+        .collect();
+    assert!(positions.contains(&(3, None)));
+    assert!(positions.contains(&(2, Some(1))));
+
+    let path = std::env::temp_dir().join(format!("exam_bundle_{}.json", std::process::id()));
+    ExamUseCase::export_exam(&mut conn, created.id.unwrap(), &path).unwrap();
+    let written = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(written.contains("\"schemaVersion\": 1"));
+}
+
+#[test]
+fn it_should_report_attachments_without_content() {
+    use crate::schema::attachment_blob::dsl::*;
+
+    let mut conn = get_connection();
+    let created = create_exam_with_category_and_attachments(&mut conn);
+    diesel::delete(attachment_blob.filter(hash.eq(Attachment::content_hash(b"template.png"))))
+        .execute(&mut conn)
+        .unwrap();
+
+    let path = std::env::temp_dir().join(format!("exam_missing_{}.json", std::process::id()));
+    let report = ExamUseCase::export_exam(&mut conn, created.id.unwrap(), &path).unwrap();
+    let written = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(report.skipped.len(), 1);
+    assert_eq!(report.skipped[0].index, 2);
+    assert!(report.skipped[0].message.contains("template.png"));
+    assert!(written.contains("subnet.png"));
+    assert!(!written.contains("template.png"));
+
+    let path = std::env::temp_dir().join(format!("exam_missing_{}.zip", std::process::id()));
+    let report = ExamUseCase::export_exam_to_qti(&mut conn, created.id.unwrap(), &path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let skipped: Vec<i32> = report.skipped.iter().map(|e| e.index).collect();
+    assert_eq!(skipped, vec![2, 3, 4]);
+    assert!(report.skipped[0].message.contains("template.png"));
+}
+
+#[test]
+fn it_should_recreate_the_exam_from_a_bundle_in_another_database() {
+    let mut source = get_connection();
+    let created = create_exam_with_category_and_attachments(&mut source);
+    let bundle = ExamUseCase::export_exam_bundle(&mut source, created.id.unwrap()).unwrap();
+    let json = serde_json::to_string(&bundle).unwrap();
+
+    let mut target = get_connection();
+    let imported = ExamUseCase::import_exam_bundle(&mut target, &json).unwrap();
+    let found = ExamUseCase::find_by_id_with_relations(&mut target, imported.id.unwrap())
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(found.name, created.name);
+    assert_eq!(found.category.as_ref().unwrap().name, "Networking");
+    assert_eq!(found.questions.len(), created.questions.len());
+    assert_eq!(
+        found.questions[3].category.as_ref().unwrap().name,
+        "Networking"
+    );
+    assert_eq!(found.questions[3].sources.as_ref().unwrap().len(), 2);
+    let assigned: Vec<Option<i32>> = found.questions[2]
+        .answers
+        .iter()
+        .map(|a| a.assigned_option_id)
+        .collect();
+    assert_eq!(assigned, vec![Some(1), Some(1), Some(2)]);
+
+    let answer_attachments =
+        AttachmentUseCase::get_attachments(&mut target, None, found.questions[2].answers[1].id)
+            .unwrap();
+    assert_eq!(answer_attachments[0].file_name, "template.png");
+    let question_attachments =
+        AttachmentUseCase::get_attachments(&mut target, found.questions[3].id, None).unwrap();
+    let attachment =
+        AttachmentUseCase::get_attachment(&mut target, question_attachments[0].id.unwrap())
+            .unwrap();
+    assert_eq!(attachment.decode_data().unwrap().unwrap(), b"subnet.png");
+
+    // The category is reused on a second import.
+    ExamUseCase::import_exam_bundle(&mut target, &json).unwrap();
+    let categories = CategoryUseCase::get_all_categories(&mut target, None).unwrap();
+    assert_eq!(categories.data.len(), 1);
+}
+
+#[test]
+fn it_should_reject_bundles_of_unknown_versions() {
+    let mut conn = get_connection();
+    let created = create_exam_with_category_and_attachments(&mut conn);
+    let mut bundle = ExamUseCase::export_exam_bundle(&mut conn, created.id.unwrap()).unwrap();
+    bundle.schema_version = EXAM_BUNDLE_SCHEMA_VERSION + 1;
+
+    let err = ExamUseCase::import_exam_bundle(&mut conn, &serde_json::to_string(&bundle).unwrap())
+        .unwrap_err();
+    assert_eq!(err.errors[0].field, "schema_version");
+
+    let err = ExamUseCase::import_exam_bundle(&mut conn, "{}").unwrap_err();
+    assert!(err.message.starts_with("The exam bundle could not be read"));
+}
+
+#[test]
+fn it_should_move_an_exam_between_databases_as_qti_package() {
+    let mut source = get_connection();
+    let created = create_exam_with_category_and_attachments(&mut source);
+    let path = std::env::temp_dir().join(format!("exam_qti_{}.zip", std::process::id()));
+
+    let exported =
+        ExamUseCase::export_exam_to_qti(&mut source, created.id.unwrap(), &path).unwrap();
+    let data = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let skipped: Vec<i32> = exported.skipped.iter().map(|e| e.index).collect();
+    assert_eq!(skipped, vec![3, 4]);
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data.clone())).unwrap();
+    let mut media = vec![];
+    archive
+        .by_name("media/1_template.png")
+        .unwrap()
+        .read_to_end(&mut media)
+        .unwrap();
+    assert_eq!(media, b"template.png");
+
+    let mut target = get_connection();
+    let report = ExamUseCase::import_qti_package(&mut target, &data).unwrap();
+    assert!(report.skipped.is_empty());
+    let found = ExamUseCase::find_by_id_with_relations(&mut target, report.exam.id.unwrap())
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.name, created.name);
+    assert_eq!(found.questions.len(), 3);
+    assert_eq!(found.questions[2].options.as_ref().unwrap().len(), 2);
+}
+
+#[test]
+fn it_should_not_save_qti_items_that_fail_validation() {
+    let mut conn = get_connection();
+    let content = include_str!("../../../../../templates/import_template_txt.txt");
+    let mut exam = TextExamParser::parse(content).unwrap().exam;
+    exam.questions[0].answers[0].answer_text = "One".into();
+    let package = QtiPackageWriter::write(&exam, &[]).unwrap();
+
+    let err = ExamUseCase::import_qti_package(&mut conn, &package.data).unwrap_err();
+
+    assert_eq!(err.nested_errors[0].nested_errors[0].message, "Answer: One");
+    assert!(ExamUseCase::find_all_exams(&mut conn, None)
+        .unwrap()
+        .data
+        .is_empty());
+}
+
+#[test]
+fn it_should_move_questions_between_databases_as_gift_file() {
+    let mut source = get_connection();
+    let created = create_exam_with_category_and_attachments(&mut source);
+    let path = std::env::temp_dir().join(format!("exam_gift_{}.gift", std::process::id()));
+
+    let exported =
+        ExamUseCase::export_exam_to_gift(&mut source, created.id.unwrap(), &path).unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(exported.skipped.is_empty());
+
+    let mut target = get_connection();
+    let report = ExamUseCase::import_gift(&mut target, &content).unwrap();
+    let found = ExamUseCase::find_by_id_with_relations(&mut target, report.exam.id.unwrap())
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.questions.len(), 5);
+    assert_eq!(
+        found.questions[3]
+            .category
+            .as_ref()
+            .map(|c| c.name.as_str()),
+        Some("Networking")
+    );
+
+    // The category is reused on a second import.
+    ExamUseCase::import_gift(&mut target, &content).unwrap();
+    let categories = CategoryUseCase::get_all_categories(&mut target, None).unwrap();
+    assert_eq!(categories.data.len(), 1);
+}
+
+#[test]
+fn it_should_move_questions_between_databases_as_moodle_xml_file() {
+    let mut source = get_connection();
+    let created = create_exam_with_category_and_attachments(&mut source);
+    let path = std::env::temp_dir().join(format!("exam_moodle_{}.xml", std::process::id()));
+
+    ExamUseCase::export_exam_to_moodle_xml(&mut source, created.id.unwrap(), &path).unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let mut target = get_connection();
+    let report = ExamUseCase::import_moodle_xml(&mut target, &content).unwrap();
+    assert!(report.skipped.is_empty());
+    let found = ExamUseCase::find_by_id_with_relations(&mut target, report.exam.id.unwrap())
+        .unwrap()
+        .unwrap();
+    let types: Vec<QuestionType> = found.questions.iter().map(|q| q.r#type.clone()).collect();
+    let expected: Vec<QuestionType> = created.questions.iter().map(|q| q.r#type.clone()).collect();
+    assert_eq!(types, expected);
+    assert_eq!(found.questions[4].points_total, 2);
+}
+
+#[test]
+fn it_should_not_save_gift_questions_that_fail_validation() {
+    let mut conn = get_connection();
+
+    let err = ExamUseCase::import_gift(&mut conn, "Pick one {=One ~Two ~Three}").unwrap_err();
+
+    assert_eq!(err.nested_errors[0].nested_errors[0].message, "Answer: One");
+    assert!(ExamUseCase::find_all_exams(&mut conn, None)
+        .unwrap()
+        .data
+        .is_empty());
+}
+
+#[test]
+fn it_should_import_spreadsheet_rows_into_an_exam() {
+    let mut conn = get_connection();
+    let created = create_exam_with_category_and_attachments(&mut conn);
+    let path = std::env::temp_dir().join(format!("questions_{}.csv", std::process::id()));
+    std::fs::write(
+        &path,
+        format!(
+            "{}\
+             How many usable host addresses does a /26 subnet provide?,numeric,1,62,,,,\n\
+             Which layer routes packets?,single_choice,1,Network|Link,x|,Networking,,\n",
+            include_str!("../../../../../templates/import_template_csv.csv")
+        ),
+    )
+    .unwrap();
+
+    let report = ExamUseCase::import_spreadsheet(
+        &mut conn,
+        created.id.unwrap(),
+        &path,
+        &SpreadsheetMapping::default(),
+    )
+    .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(report.created.len(), 2);
+    let skipped: Vec<(i32, Option<i32>)> =
+        report.skipped.iter().map(|e| (e.index, e.line)).collect();
+    assert_eq!(
+        skipped,
+        vec![(0, Some(2)), (1, Some(3)), (4, Some(6)), (5, Some(7))]
+    );
+    assert_eq!(report.invalid.len(), 1);
+    assert_eq!(report.invalid[0].line, Some(8));
+    assert_eq!(
+        report.invalid[0].nested_errors[0].nested_errors[0].message,
+        "Answer: Link"
+    );
+
+    let found = ExamUseCase::find_by_id_with_relations(&mut conn, created.id.unwrap())
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.questions.len(), created.questions.len() + 2);
+    let categories = CategoryUseCase::get_all_categories(&mut conn, None).unwrap();
+    let mut names: Vec<String> = categories.data.into_iter().map(|c| c.name).collect();
+    names.sort();
+    // categories of skipped and invalid rows are not created
+    assert_eq!(names, vec!["Documentation", "Networking", "Tooling"]);
+}
+
+#[test]
+fn it_should_reject_files_that_are_not_spreadsheets() {
+    let mut conn = get_connection();
+    let created = create_exam_with_category_and_attachments(&mut conn);
+
+    let err = ExamUseCase::import_spreadsheet(
+        &mut conn,
+        created.id.unwrap(),
+        Path::new("questions.ods"),
+        &SpreadsheetMapping::default(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.message,
+        "questions.ods is not a spreadsheet, expected a .csv or .xlsx file"
+    );
+}
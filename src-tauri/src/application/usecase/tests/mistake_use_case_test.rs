@@ -0,0 +1,229 @@
+use crate::application::usecase::attempt_use_case::AttemptUseCase;
+use crate::application::usecase::category_use_case::CategoryUseCase;
+use crate::application::usecase::exam_use_case::ExamUseCase;
+use crate::application::usecase::mistake_use_case::MistakeUseCase;
+use crate::application::usecase::setting_use_case::SettingUseCase;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::category::Category;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::setting::{Setting, MISTAKE_CLEAR_STREAK};
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures;
+use diesel::{Connection, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+fn get_connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    connection.run_pending_migrations(MIGRATIONS).unwrap();
+    connection
+}
+
+fn question(text: &str, category: Option<Category>) -> Question {
+    Question {
+        category,
+        ..fixtures::question(text)
+    }
+}
+
+fn create_exam(conn: &mut SqliteConnection, name: &str, questions: Vec<Question>) -> Exam {
+    let mut exam = Exam {
+        id: None,
+        name: name.into(),
+        description: Some("Exam used for the mistake notebook".into()),
+        points_to_succeeded: Some(1),
+        duration: None,
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions,
+    };
+    ExamUseCase::create_exam(conn, &mut exam).unwrap()
+}
+
+fn selection(question: &Question, answer_index: usize) -> Vec<AttemptAnswer> {
+    vec![AttemptAnswer {
+        id: None,
+        attempt_id: None,
+        question_id: question.id.unwrap(),
+        answer_id: question.answers[answer_index].id,
+        assigned_option_id: None,
+        time_spent_seconds: None,
+        created_at: None,
+        text_response: None,
+        position: None,
+        blank: None,
+    }]
+}
+
+/// Answers the questions of a new attempt with the given answer indexes and submits it.
+fn run_attempt(conn: &mut SqliteConnection, exam: &Exam, picks: &[Option<usize>]) {
+    let attempt =
+        AttemptUseCase::start_attempt(conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap();
+    let attempt_id = attempt.id.unwrap();
+    for (question, pick) in exam.questions.iter().zip(picks) {
+        if let Some(index) = pick {
+            AttemptUseCase::answer_question(
+                conn,
+                attempt_id,
+                question.id.unwrap(),
+                selection(question, *index),
+            )
+            .unwrap();
+        }
+    }
+    AttemptUseCase::submit_attempt(conn, attempt_id).unwrap();
+}
+
+#[test]
+fn it_should_add_wrong_answers_of_a_finished_attempt() {
+    let mut conn = get_connection();
+    let exam = create_exam(
+        &mut conn,
+        "Mistake Exam",
+        (1..=3)
+            .map(|i| question(&format!("Question {i}?"), None))
+            .collect(),
+    );
+
+    run_attempt(&mut conn, &exam, &[Some(0), Some(1), None]);
+
+    let notebook = MistakeUseCase::get_mistake_notebook(&mut conn).unwrap();
+    assert_eq!(notebook.len(), 1);
+    assert_eq!(notebook[0].exam_id, exam.id.unwrap());
+    assert_eq!(notebook[0].exam_name, "Mistake Exam");
+    assert_eq!(notebook[0].mistakes.len(), 1);
+
+    let mistake = &notebook[0].mistakes[0];
+    assert_eq!(mistake.question_id, exam.questions[1].id.unwrap());
+    assert_eq!(mistake.miss_count, 1);
+    assert!(mistake.question.is_some());
+
+    run_attempt(&mut conn, &exam, &[Some(0), Some(1), None]);
+    let notebook = MistakeUseCase::get_mistake_notebook(&mut conn).unwrap();
+    assert_eq!(notebook[0].mistakes[0].miss_count, 2);
+}
+
+#[test]
+fn it_should_clear_mistake_after_consecutive_correct_answers() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn, "Mistake Exam", vec![question("Question?", None)]);
+    let question_id = exam.questions[0].id.unwrap();
+    SettingUseCase::update_setting(
+        &mut conn,
+        Setting {
+            profile_id: None,
+            key: MISTAKE_CLEAR_STREAK.into(),
+            value: "2".into(),
+            updated_at: None,
+        },
+    )
+    .unwrap();
+
+    run_attempt(&mut conn, &exam, &[Some(1)]);
+
+    let score = MistakeUseCase::record_practice_answer(
+        &mut conn,
+        question_id,
+        selection(&exam.questions[0], 0),
+    )
+    .unwrap();
+    assert!(score.is_fully_correct());
+    let notebook = MistakeUseCase::get_mistake_notebook(&mut conn).unwrap();
+    assert_eq!(notebook[0].mistakes[0].correct_streak, 1);
+
+    // a miss resets the streak
+    MistakeUseCase::record_practice_answer(
+        &mut conn,
+        question_id,
+        selection(&exam.questions[0], 1),
+    )
+    .unwrap();
+    run_attempt(&mut conn, &exam, &[Some(0)]);
+    assert!(!MistakeUseCase::get_mistake_notebook(&mut conn)
+        .unwrap()
+        .is_empty());
+
+    run_attempt(&mut conn, &exam, &[Some(0)]);
+    assert!(MistakeUseCase::get_mistake_notebook(&mut conn)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn it_should_group_notebook_by_exam_and_category() {
+    let mut conn = get_connection();
+    let category = CategoryUseCase::create_category(
+        &mut conn,
+        Category {
+            id: None,
+            name: "Networking".into(),
+            created_at: None,
+            updated_at: None,
+        },
+    )
+    .unwrap();
+    let second = create_exam(
+        &mut conn,
+        "B Exam",
+        vec![
+            question("Question 1?", None),
+            question("Question 2?", Some(category.clone())),
+        ],
+    );
+    let first = create_exam(&mut conn, "A Exam", vec![question("Question 3?", None)]);
+
+    run_attempt(&mut conn, &second, &[Some(1), Some(1)]);
+    run_attempt(&mut conn, &first, &[Some(1)]);
+
+    let notebook = MistakeUseCase::get_mistake_notebook(&mut conn).unwrap();
+    assert_eq!(notebook.len(), 3);
+    assert_eq!(notebook[0].exam_name, "A Exam");
+    assert!(notebook[1].category.is_none());
+    assert_eq!(notebook[2].exam_name, "B Exam");
+    assert_eq!(notebook[2].category.as_ref().unwrap().id, category.id);
+}
+
+#[test]
+fn it_should_generate_practice_set_from_notebook() {
+    let mut conn = get_connection();
+    let exam = create_exam(
+        &mut conn,
+        "Mistake Exam",
+        (1..=3)
+            .map(|i| question(&format!("Question {i}?"), None))
+            .collect(),
+    );
+    let other = create_exam(&mut conn, "Other Exam", vec![question("Question?", None)]);
+
+    run_attempt(&mut conn, &exam, &[Some(1), Some(1), Some(0)]);
+    run_attempt(&mut conn, &exam, &[Some(0), Some(1), Some(0)]);
+    run_attempt(&mut conn, &other, &[Some(1)]);
+
+    let practice =
+        MistakeUseCase::get_mistake_practice_set(&mut conn, exam.id, None, None).unwrap();
+    assert_eq!(practice.len(), 2);
+    assert_eq!(practice[0].id, exam.questions[1].id);
+    assert_eq!(practice[0].answers.len(), 2);
+
+    let all = MistakeUseCase::get_mistake_practice_set(&mut conn, None, None, Some(2)).unwrap();
+    assert_eq!(all.len(), 2);
+    assert!(
+        MistakeUseCase::get_mistake_practice_set(&mut conn, None, Some(99), None)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn it_should_fail_practice_answer_for_unknown_question() {
+    let mut conn = get_connection();
+    let err = MistakeUseCase::record_practice_answer(&mut conn, 999, vec![]).unwrap_err();
+    assert!(err.to_string().contains("Question not found"));
+}
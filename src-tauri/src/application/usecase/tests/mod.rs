@@ -3,8 +3,28 @@ mod answer_use_case_test;
 #[cfg(test)]
 mod assignment_option_use_case_test;
 #[cfg(test)]
+mod attachment_use_case_test;
+#[cfg(test)]
+mod attempt_use_case_test;
+#[cfg(test)]
+mod backup_use_case_test;
+#[cfg(test)]
+mod bookmark_use_case_test;
+#[cfg(test)]
 mod category_use_case_test;
 #[cfg(test)]
 mod exam_use_case_test;
 #[cfg(test)]
+mod mistake_use_case_test;
+#[cfg(test)]
+mod practice_use_case_test;
+#[cfg(test)]
+mod profile_use_case_test;
+#[cfg(test)]
+mod question_draw_use_case_test;
+#[cfg(test)]
 mod question_use_case_test;
+#[cfg(test)]
+mod review_use_case_test;
+#[cfg(test)]
+mod setting_use_case_test;
@@ -0,0 +1,173 @@
+use crate::application::usecase::attempt_use_case::AttemptUseCase;
+use crate::application::usecase::category_use_case::CategoryUseCase;
+use crate::application::usecase::exam_use_case::ExamUseCase;
+use crate::application::usecase::practice_use_case::PracticeUseCase;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::category::Category;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures;
+use diesel::{Connection, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+fn get_connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    connection.run_pending_migrations(MIGRATIONS).unwrap();
+    connection
+}
+
+fn question(text: &str, category: &Category) -> Question {
+    Question {
+        category: Some(category.clone()),
+        ..fixtures::question(text)
+    }
+}
+
+fn create_category(conn: &mut SqliteConnection, name: &str) -> Category {
+    CategoryUseCase::create_category(
+        conn,
+        Category {
+            id: None,
+            name: name.into(),
+            created_at: None,
+            updated_at: None,
+        },
+    )
+    .unwrap()
+}
+
+/// Exam with five questions of a strong and five of a weak category.
+fn create_exam(conn: &mut SqliteConnection, strong: &Category, weak: &Category) -> Exam {
+    let mut exam = Exam {
+        id: None,
+        name: "Adaptive Exam".into(),
+        description: Some("Exam used for adaptive practice".into()),
+        points_to_succeeded: Some(1),
+        duration: None,
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: (1..=10)
+            .map(|i| {
+                question(
+                    &format!("Question {i}?"),
+                    if i <= 5 { strong } else { weak },
+                )
+            })
+            .collect(),
+    };
+    ExamUseCase::create_exam(conn, &mut exam).unwrap()
+}
+
+/// Answers every question of the strong category correctly and every question of the
+/// weak one wrongly.
+fn run_attempt(conn: &mut SqliteConnection, exam: &Exam) {
+    let attempt =
+        AttemptUseCase::start_attempt(conn, exam.id.unwrap(), AttemptMode::Simulation, None)
+            .unwrap();
+    let attempt_id = attempt.id.unwrap();
+    for (position, question) in exam.questions.iter().enumerate() {
+        // the first five questions belong to the strong category
+        let index = if position < 5 { 0 } else { 1 };
+        let selection = vec![AttemptAnswer {
+            id: None,
+            attempt_id: None,
+            question_id: question.id.unwrap(),
+            answer_id: question.answers[index].id,
+            assigned_option_id: None,
+            time_spent_seconds: None,
+            created_at: None,
+            text_response: None,
+            position: None,
+            blank: None,
+        }];
+        AttemptUseCase::answer_question(conn, attempt_id, question.id.unwrap(), selection).unwrap();
+    }
+    AttemptUseCase::submit_attempt(conn, attempt_id).unwrap();
+}
+
+#[test]
+fn it_should_estimate_mastery_from_finished_attempts() {
+    let mut conn = get_connection();
+    let strong = create_category(&mut conn, "Strong category");
+    let weak = create_category(&mut conn, "Weak category");
+    let exam = create_exam(&mut conn, &strong, &weak);
+    run_attempt(&mut conn, &exam);
+
+    let set =
+        PracticeUseCase::generate_adaptive_practice_set(&mut conn, exam.id.unwrap(), Some(4), None)
+            .unwrap();
+
+    assert_eq!(set.questions.len(), 4);
+    assert!(set.questions.iter().all(|q| q.answers.len() == 2));
+    assert_eq!(set.category_mastery.len(), 2);
+    let strong_mastery = &set.category_mastery[0];
+    let weak_mastery = &set.category_mastery[1];
+    assert_eq!(strong_mastery.category.as_ref().unwrap().id, strong.id);
+    assert_eq!(strong_mastery.responses, 5);
+    assert!(strong_mastery.mastered);
+    assert!(!weak_mastery.mastered);
+    assert!(weak_mastery.mastery < strong_mastery.mastery);
+    assert_eq!(set.question_type_mastery.len(), 1);
+    assert_eq!(set.question_type_mastery[0].responses, 10);
+}
+
+#[test]
+fn it_should_prefer_questions_of_weak_categories() {
+    let mut conn = get_connection();
+    let strong = create_category(&mut conn, "Strong category");
+    let weak = create_category(&mut conn, "Weak category");
+    let exam = create_exam(&mut conn, &strong, &weak);
+    run_attempt(&mut conn, &exam);
+    run_attempt(&mut conn, &exam);
+
+    let mut weak_questions = 0;
+    for seed in 0..20 {
+        let set = PracticeUseCase::generate_adaptive_practice_set(
+            &mut conn,
+            exam.id.unwrap(),
+            Some(3),
+            Some(seed),
+        )
+        .unwrap();
+        assert_eq!(set.seed, seed);
+        weak_questions += set
+            .questions
+            .iter()
+            .filter(|q| q.category.as_ref().unwrap().id == weak.id)
+            .count();
+    }
+    assert!(weak_questions > 40);
+}
+
+#[test]
+fn it_should_draw_the_same_set_for_the_same_seed() {
+    let mut conn = get_connection();
+    let strong = create_category(&mut conn, "Strong category");
+    let weak = create_category(&mut conn, "Weak category");
+    let exam = create_exam(&mut conn, &strong, &weak);
+
+    let ids = |conn: &mut SqliteConnection| -> Vec<Option<i32>> {
+        PracticeUseCase::generate_adaptive_practice_set(conn, exam.id.unwrap(), Some(5), Some(7))
+            .unwrap()
+            .questions
+            .iter()
+            .map(|q| q.id)
+            .collect()
+    };
+    assert_eq!(ids(&mut conn), ids(&mut conn));
+}
+
+#[test]
+fn it_should_fail_for_exam_without_questions() {
+    let mut conn = get_connection();
+    let err =
+        PracticeUseCase::generate_adaptive_practice_set(&mut conn, 999, None, None).unwrap_err();
+    assert!(err.to_string().contains("No questions found"));
+}
@@ -0,0 +1,207 @@
+use crate::application::usecase::attempt_use_case::AttemptUseCase;
+use crate::application::usecase::bookmark_use_case::BookmarkUseCase;
+use crate::application::usecase::exam_use_case::ExamUseCase;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::application::usecase::review_use_case::ReviewUseCase;
+use crate::application::usecase::setting_use_case::SettingUseCase;
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::setting::{Setting, ATTEMPT_GRACE_PERIOD_SECONDS};
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures::answer;
+use diesel::{Connection, RunQueryDsl, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+/// Foreign keys are turned on like in the application, so deletes cascade.
+fn get_connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    connection.run_pending_migrations(MIGRATIONS).unwrap();
+    diesel::sql_query("PRAGMA foreign_keys = ON;")
+        .execute(&mut connection)
+        .unwrap();
+    connection
+}
+
+fn create_exam(conn: &mut SqliteConnection) -> Exam {
+    let mut exam = Exam {
+        id: None,
+        name: "Shared exam".into(),
+        description: None,
+        points_to_succeeded: Some(1),
+        duration: None,
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![Question {
+            id: None,
+            question_text: "Shared question?".into(),
+            points_total: 1,
+            r#type: QuestionType::SingleChoice,
+            answers: vec![answer("Correct", true), answer("Wrong", false)],
+            points_per_correct_answer: None,
+            category: None,
+            created_at: None,
+            updated_at: None,
+            options: None,
+            exam_id: None,
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+        }],
+    };
+    ExamUseCase::create_exam(conn, &mut exam).unwrap()
+}
+
+fn grace_period(value: &str) -> Setting {
+    Setting {
+        profile_id: None,
+        key: ATTEMPT_GRACE_PERIOD_SECONDS.into(),
+        value: value.into(),
+        updated_at: None,
+    }
+}
+
+#[test]
+fn it_should_start_with_default_profile() {
+    let mut conn = get_connection();
+    let active = ProfileUseCase::get_active_profile(&mut conn).unwrap();
+    assert_eq!(active.name, "Default");
+    assert!(active.active);
+    assert_eq!(ProfileUseCase::get_profiles(&mut conn).unwrap().len(), 1);
+}
+
+#[test]
+fn it_should_create_rename_and_switch_profiles() {
+    let mut conn = get_connection();
+
+    let created = ProfileUseCase::create_profile(&mut conn, " Alice ").unwrap();
+    assert_eq!(created.name, "Alice");
+    assert!(!created.active);
+    let profile_id = created.id.unwrap();
+
+    let renamed = ProfileUseCase::rename_profile(&mut conn, profile_id, "Bob").unwrap();
+    assert_eq!(renamed.name, "Bob");
+    // keeping the own name is not a conflict
+    assert!(ProfileUseCase::rename_profile(&mut conn, profile_id, "Bob").is_ok());
+
+    let switched = ProfileUseCase::switch_profile(&mut conn, profile_id).unwrap();
+    assert!(switched.active);
+    assert_eq!(
+        ProfileUseCase::get_active_profile_id(&mut conn).unwrap(),
+        profile_id
+    );
+}
+
+#[test]
+fn it_should_reject_invalid_profiles() {
+    let mut conn = get_connection();
+
+    assert!(ProfileUseCase::create_profile(&mut conn, "  ").is_err());
+    assert!(ProfileUseCase::create_profile(&mut conn, "Default").is_err());
+
+    let created = ProfileUseCase::create_profile(&mut conn, "Alice").unwrap();
+    assert!(ProfileUseCase::rename_profile(&mut conn, created.id.unwrap(), "Default").is_err());
+    assert!(ProfileUseCase::rename_profile(&mut conn, 42, "Carol").is_err());
+    assert!(ProfileUseCase::switch_profile(&mut conn, 42).is_err());
+}
+
+#[test]
+fn it_should_not_delete_active_profile() {
+    let mut conn = get_connection();
+    let active_id = ProfileUseCase::get_active_profile_id(&mut conn).unwrap();
+    assert!(ProfileUseCase::delete_profile(&mut conn, active_id).is_err());
+    assert!(ProfileUseCase::delete_profile(&mut conn, 42).is_err());
+}
+
+#[test]
+fn it_should_scope_personal_data_to_active_profile() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let exam_id = exam.id.unwrap();
+    let question_id = exam.questions[0].id.unwrap();
+    let default_id = ProfileUseCase::get_active_profile_id(&mut conn).unwrap();
+
+    let attempt =
+        AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Simulation, None).unwrap();
+    assert_eq!(attempt.profile_id, default_id);
+    ReviewUseCase::record_review(&mut conn, question_id, 5).unwrap();
+    BookmarkUseCase::add_bookmark(&mut conn, question_id).unwrap();
+    SettingUseCase::update_setting(&mut conn, grace_period("10")).unwrap();
+
+    let alice = ProfileUseCase::create_profile(&mut conn, "Alice").unwrap();
+    ProfileUseCase::switch_profile(&mut conn, alice.id.unwrap()).unwrap();
+
+    assert!(AttemptUseCase::get_attempts_by_exam_id(&mut conn, exam_id)
+        .unwrap()
+        .is_empty());
+    assert!(
+        AttemptUseCase::get_attempt_by_id(&mut conn, attempt.id.unwrap())
+            .unwrap()
+            .is_none()
+    );
+    assert!(AttemptUseCase::submit_attempt(&mut conn, attempt.id.unwrap()).is_err());
+    assert!(AttemptUseCase::delete_attempt(&mut conn, attempt.id.unwrap()).is_err());
+    assert!(SettingUseCase::get_settings(&mut conn).unwrap().is_empty());
+    assert!(BookmarkUseCase::get_bookmarks(&mut conn)
+        .unwrap()
+        .is_empty());
+    // the question is due again for the new profile
+    let due = ReviewUseCase::get_due_questions(&mut conn, Some(exam_id), None, None).unwrap();
+    assert_eq!(due.len(), 1);
+    // exams stay shared
+    assert!(ExamUseCase::find_exam_by_id(&mut conn, exam_id)
+        .unwrap()
+        .is_some());
+
+    ProfileUseCase::switch_profile(&mut conn, default_id).unwrap();
+    assert_eq!(
+        AttemptUseCase::get_attempts_by_exam_id(&mut conn, exam_id)
+            .unwrap()
+            .len(),
+        1
+    );
+    assert_eq!(SettingUseCase::get_settings(&mut conn).unwrap().len(), 1);
+    assert_eq!(BookmarkUseCase::get_bookmarks(&mut conn).unwrap().len(), 1);
+}
+
+#[test]
+fn it_should_delete_personal_data_with_profile() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let exam_id = exam.id.unwrap();
+    let default_id = ProfileUseCase::get_active_profile_id(&mut conn).unwrap();
+
+    let alice = ProfileUseCase::create_profile(&mut conn, "Alice").unwrap();
+    let alice_id = alice.id.unwrap();
+    ProfileUseCase::switch_profile(&mut conn, alice_id).unwrap();
+    AttemptUseCase::start_attempt(&mut conn, exam_id, AttemptMode::Simulation, None).unwrap();
+    ReviewUseCase::record_review(&mut conn, exam.questions[0].id.unwrap(), 2).unwrap();
+    BookmarkUseCase::add_bookmark(&mut conn, exam.questions[0].id.unwrap()).unwrap();
+    SettingUseCase::update_setting(&mut conn, grace_period("5")).unwrap();
+
+    ProfileUseCase::switch_profile(&mut conn, default_id).unwrap();
+    assert_eq!(
+        ProfileUseCase::delete_profile(&mut conn, alice_id).unwrap(),
+        1
+    );
+
+    for table in ["exam_attempt", "review_state", "bookmark", "setting"] {
+        let query = format!("SELECT COUNT(*) FROM {table} WHERE fk_profile_id = {alice_id}");
+        let remaining: i64 = diesel::dsl::sql::<diesel::sql_types::BigInt>(&query)
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(
+            remaining, 0,
+            "{table} still holds data of the deleted profile"
+        );
+    }
+    assert!(ExamUseCase::find_exam_by_id(&mut conn, exam_id)
+        .unwrap()
+        .is_some());
+}
@@ -0,0 +1,152 @@
+use crate::application::crud::question_draw_repository_trait::QuestionDrawRepository;
+use crate::application::usecase::exam_use_case::ExamUseCase;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::application::usecase::question_draw_use_case::QuestionDrawUseCase;
+use crate::application::usecase::question_use_case::QuestionUseCase;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures::question;
+use crate::infrastructure::repositories::sqlite_question_draw_repository::SQLiteQuestionDrawRepository;
+use diesel::{Connection, RunQueryDsl, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use std::collections::HashSet;
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+fn get_connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    connection.run_pending_migrations(MIGRATIONS).unwrap();
+    connection
+}
+
+fn create_exam(conn: &mut SqliteConnection, max_questions_real_exam: Option<i32>) -> Exam {
+    let mut exam = Exam {
+        id: None,
+        name: "Draw Exam".into(),
+        description: Some("Exam used for draws".into()),
+        points_to_succeeded: Some(1),
+        duration: Some(30),
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam,
+        questions: (1..=6)
+            .map(|i| question(&format!("Question {i}?")))
+            .collect(),
+    };
+    ExamUseCase::create_exam(conn, &mut exam).unwrap()
+}
+
+#[test]
+fn it_should_draw_max_questions_real_exam() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn, Some(3));
+
+    let draw =
+        QuestionDrawUseCase::draw_questions(&mut conn, exam.id.unwrap(), None, false).unwrap();
+    assert!(draw.id.is_some());
+    assert_eq!(draw.questions.len(), 3);
+    assert_eq!(
+        draw.question_ids,
+        draw.questions
+            .iter()
+            .map(|q| q.id.unwrap())
+            .collect::<Vec<i32>>()
+    );
+}
+
+#[test]
+fn it_should_draw_all_questions_without_limit() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn, None);
+
+    let draw =
+        QuestionDrawUseCase::draw_questions(&mut conn, exam.id.unwrap(), Some(1), false).unwrap();
+    assert_eq!(draw.questions.len(), 6);
+}
+
+#[test]
+fn it_should_avoid_recently_drawn_questions() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn, Some(3));
+    let exam_id = exam.id.unwrap();
+
+    let first = QuestionDrawUseCase::draw_questions(&mut conn, exam_id, Some(5), false).unwrap();
+    let second = QuestionDrawUseCase::draw_questions(&mut conn, exam_id, Some(5), false).unwrap();
+
+    let first_ids: HashSet<i32> = first.question_ids.into_iter().collect();
+    assert!(second.question_ids.iter().all(|id| !first_ids.contains(id)));
+}
+
+#[test]
+fn it_should_keep_draws_per_profile() {
+    let mut conn = get_connection();
+    diesel::sql_query("PRAGMA foreign_keys = ON;")
+        .execute(&mut conn)
+        .unwrap();
+    let exam = create_exam(&mut conn, Some(3));
+    let exam_id = exam.id.unwrap();
+    let default_profile = ProfileUseCase::get_active_profile_id(&mut conn).unwrap();
+    let first = QuestionDrawUseCase::draw_questions(&mut conn, exam_id, Some(5), false).unwrap();
+
+    let other = ProfileUseCase::create_profile(&mut conn, "Other").unwrap();
+    ProfileUseCase::switch_profile(&mut conn, other.id.unwrap()).unwrap();
+    assert!(
+        QuestionDrawUseCase::get_draws_by_exam_id(&mut conn, exam_id)
+            .unwrap()
+            .is_empty()
+    );
+    assert!(QuestionDrawUseCase::replay_draw(&mut conn, first.id.unwrap()).is_err());
+
+    // The draw of the other profile does not count as recently drawn.
+    let other_draw =
+        QuestionDrawUseCase::draw_questions(&mut conn, exam_id, Some(5), false).unwrap();
+    assert_eq!(other_draw.question_ids, first.question_ids);
+
+    ProfileUseCase::switch_profile(&mut conn, default_profile).unwrap();
+    ProfileUseCase::delete_profile(&mut conn, other.id.unwrap()).unwrap();
+    let draws = QuestionDrawUseCase::get_draws_by_exam_id(&mut conn, exam_id).unwrap();
+    assert_eq!(draws.len(), 1);
+    assert!(SQLiteQuestionDrawRepository::new(&mut conn)
+        .find_by_id(other_draw.id.unwrap())
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn it_should_replay_a_draw() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn, Some(4));
+    let exam_id = exam.id.unwrap();
+
+    let first = QuestionDrawUseCase::draw_questions(&mut conn, exam_id, None, true).unwrap();
+    let second = QuestionDrawUseCase::draw_questions(&mut conn, exam_id, None, true).unwrap();
+
+    let replayed = QuestionDrawUseCase::replay_draw(&mut conn, second.id.unwrap()).unwrap();
+    assert_eq!(replayed.seed, second.seed);
+    assert_eq!(replayed.question_ids, second.question_ids);
+    assert_eq!(replayed.questions.len(), 4);
+
+    let replayed = QuestionDrawUseCase::replay_draw(&mut conn, first.id.unwrap()).unwrap();
+    assert_eq!(replayed.question_ids, first.question_ids);
+}
+
+#[test]
+fn it_should_fail_replay_when_questions_changed() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn, Some(3));
+
+    let draw =
+        QuestionDrawUseCase::draw_questions(&mut conn, exam.id.unwrap(), None, false).unwrap();
+    QuestionUseCase::delete_question(&mut conn, draw.question_ids[0]).unwrap();
+
+    assert!(QuestionDrawUseCase::replay_draw(&mut conn, draw.id.unwrap()).is_err());
+}
+
+#[test]
+fn it_should_fail_for_unknown_exam() {
+    let mut conn = get_connection();
+    assert!(QuestionDrawUseCase::draw_questions(&mut conn, 999, None, false).is_err());
+    assert!(QuestionDrawUseCase::replay_draw(&mut conn, 999).is_err());
+}
@@ -6,6 +6,7 @@ use crate::domain::model::assignment_option::AssignmentOption;
 use crate::domain::model::exam::Exam;
 use crate::domain::model::question::Question;
 use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::source_reference::SourceReference;
 use crate::infrastructure::repositories::sqlite_answer_crud_repository::SQLiteAnswerCrudRepository;
 use crate::infrastructure::repositories::sqlite_exam_crud_repository::SQLiteExamCrudRepository;
 use diesel::{Connection, SqliteConnection};
@@ -53,6 +54,10 @@ fn base_question(exam_id: i32) -> Question {
                 created_at: None,
                 updated_at: None,
                 question_id: Some(0),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             },
             Answer {
                 id: None,
@@ -63,6 +68,10 @@ fn base_question(exam_id: i32) -> Question {
                 created_at: None,
                 updated_at: None,
                 question_id: Some(0),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             },
         ],
         points_per_correct_answer: Some(10),
@@ -84,6 +93,9 @@ fn base_question(exam_id: i32) -> Question {
             },
         ]),
         exam_id: Some(exam_id),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
     }
 }
 
@@ -141,6 +153,10 @@ fn it_should_handle_type_switch() {
             created_at: None,
             updated_at: None,
             question_id: Some(q_id),
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
         },
         Answer {
             id: None,
@@ -151,6 +167,10 @@ fn it_should_handle_type_switch() {
             created_at: None,
             updated_at: None,
             question_id: Some(q_id),
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
         },
     ];
 
@@ -208,3 +228,77 @@ fn it_should_fail_when_getting_non_existing_question() {
     let fetched = QuestionUseCase::get_question_by_id(&mut conn, 999);
     assert!(fetched.is_err());
 }
+
+fn source(title: &str) -> SourceReference {
+    SourceReference {
+        id: None,
+        question_id: None,
+        title: title.into(),
+        chapter: None,
+        page: None,
+        url: None,
+    }
+}
+
+#[test]
+fn it_should_store_explanation_and_sources() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+
+    let mut q = base_question(exam.id.unwrap());
+    q.explanation = Some("Because the **first** answer is right.".into());
+    q.sources = Some(vec![source("Study guide"), source("Lecture notes")]);
+    let created = QuestionUseCase::create_question(&mut conn, q).unwrap();
+    let question_id = created.id.unwrap();
+    assert_eq!(created.sources.as_ref().unwrap().len(), 2);
+
+    let fetched = QuestionUseCase::get_question_by_id(&mut conn, question_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        fetched.explanation.as_deref(),
+        Some("Because the **first** answer is right.")
+    );
+
+    let mut to_update = created.clone();
+    to_update.sources = None;
+    let kept = QuestionUseCase::update_question(&mut conn, &to_update).unwrap();
+    assert_eq!(
+        kept.sources
+            .unwrap()
+            .iter()
+            .map(|s| s.title.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Study guide", "Lecture notes"]
+    );
+
+    to_update.explanation = None;
+    to_update.sources = Some(vec![source("Lecture notes"), source("Errata")]);
+    let updated = QuestionUseCase::update_question(&mut conn, &to_update).unwrap();
+    assert_eq!(
+        updated
+            .sources
+            .unwrap()
+            .iter()
+            .map(|s| s.title.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Lecture notes", "Errata"]
+    );
+    let fetched = QuestionUseCase::get_question_by_id(&mut conn, question_id)
+        .unwrap()
+        .unwrap();
+    assert!(fetched.explanation.is_none());
+}
+
+#[test]
+fn it_should_reject_invalid_sources() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+
+    let mut q = base_question(exam.id.unwrap());
+    q.sources = Some(vec![SourceReference {
+        url: Some("not a url".into()),
+        ..source("Study guide")
+    }]);
+    assert!(QuestionUseCase::create_question(&mut conn, q).is_err());
+}
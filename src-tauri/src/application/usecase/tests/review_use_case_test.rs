@@ -0,0 +1,102 @@
+use crate::application::usecase::exam_use_case::ExamUseCase;
+use crate::application::usecase::review_use_case::ReviewUseCase;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures::question;
+use chrono::{Duration, Utc};
+use diesel::{Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+fn get_connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    connection.run_pending_migrations(MIGRATIONS).unwrap();
+    connection
+}
+
+fn create_exam(conn: &mut SqliteConnection) -> Exam {
+    let mut exam = Exam {
+        id: None,
+        name: "Review Exam".into(),
+        description: Some("Exam used for reviews".into()),
+        points_to_succeeded: Some(1),
+        duration: None,
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: (1..=3)
+            .map(|i| question(&format!("Question {i}?")))
+            .collect(),
+    };
+    ExamUseCase::create_exam(conn, &mut exam).unwrap()
+}
+
+fn ids(questions: &[Question]) -> Vec<i32> {
+    questions.iter().map(|q| q.id.unwrap()).collect()
+}
+
+#[test]
+fn it_should_return_never_reviewed_questions_as_due() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+
+    let due = ReviewUseCase::get_due_questions(&mut conn, exam.id, None, None).unwrap();
+    assert_eq!(ids(&due), ids(&exam.questions));
+    assert_eq!(due[0].answers.len(), 2);
+
+    let limited = ReviewUseCase::get_due_questions(&mut conn, exam.id, None, Some(1)).unwrap();
+    assert_eq!(limited.len(), 1);
+}
+
+#[test]
+fn it_should_schedule_reviewed_questions() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let first = exam.questions[0].id.unwrap();
+
+    let state = ReviewUseCase::record_review(&mut conn, first, 5).unwrap();
+    assert_eq!(state.question_id, first);
+    assert_eq!(state.repetitions, 1);
+    assert_eq!(state.interval_days, 1);
+
+    let due = ReviewUseCase::get_due_questions(&mut conn, exam.id, None, None).unwrap();
+    assert!(!ids(&due).contains(&first));
+
+    let state = ReviewUseCase::record_review(&mut conn, first, 1).unwrap();
+    assert_eq!(state.lapses, 1);
+    assert_eq!(state.repetitions, 0);
+}
+
+#[test]
+fn it_should_put_overdue_questions_first() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+    let last = exam.questions[2].id.unwrap();
+
+    ReviewUseCase::record_review(&mut conn, last, 4).unwrap();
+    {
+        use crate::schema::review_state::dsl::*;
+        diesel::update(review_state.filter(fk_question_id.eq(last)))
+            .set(due_at.eq((Utc::now() - Duration::days(1)).naive_utc()))
+            .execute(&mut conn)
+            .unwrap();
+    }
+
+    let due = ReviewUseCase::get_due_questions(&mut conn, exam.id, None, None).unwrap();
+    assert_eq!(due.len(), 3);
+    assert_eq!(due[0].id, Some(last));
+}
+
+#[test]
+fn it_should_reject_invalid_reviews() {
+    let mut conn = get_connection();
+    let exam = create_exam(&mut conn);
+
+    assert!(ReviewUseCase::record_review(&mut conn, exam.questions[0].id.unwrap(), 6).is_err());
+    assert!(ReviewUseCase::record_review(&mut conn, 999, 3).is_err());
+    assert!(ReviewUseCase::get_due_questions(&mut conn, None, None, None).is_err());
+}
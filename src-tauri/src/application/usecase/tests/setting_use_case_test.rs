@@ -0,0 +1,94 @@
+use crate::application::crud::setting_repository_trait::SettingRepository;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::application::usecase::setting_use_case::SettingUseCase;
+use crate::domain::model::setting::{Setting, ATTEMPT_GRACE_PERIOD_SECONDS};
+use crate::infrastructure::repositories::sqlite_setting_repository::SQLiteSettingRepository;
+use chrono::Duration;
+use diesel::{Connection, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+fn get_connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    connection.run_pending_migrations(MIGRATIONS).unwrap();
+    connection
+}
+
+fn grace_period(value: &str) -> Setting {
+    Setting {
+        profile_id: None,
+        key: ATTEMPT_GRACE_PERIOD_SECONDS.into(),
+        value: value.into(),
+        updated_at: None,
+    }
+}
+
+#[test]
+fn it_should_default_grace_period() {
+    let mut conn = get_connection();
+    assert_eq!(
+        SettingUseCase::get_attempt_grace_period(&mut conn).unwrap(),
+        Duration::seconds(30)
+    );
+}
+
+#[test]
+fn it_should_update_grace_period() {
+    let mut conn = get_connection();
+    SettingUseCase::update_setting(&mut conn, grace_period("90")).unwrap();
+
+    assert_eq!(
+        SettingUseCase::get_attempt_grace_period(&mut conn).unwrap(),
+        Duration::seconds(90)
+    );
+    assert_eq!(SettingUseCase::get_settings(&mut conn).unwrap().len(), 1);
+}
+
+#[test]
+fn it_should_reject_invalid_setting() {
+    let mut conn = get_connection();
+    let result = SettingUseCase::update_setting(&mut conn, grace_period("-5"));
+    assert!(result.is_err());
+    let result = SettingUseCase::update_setting(&mut conn, grace_period("301"));
+    assert!(result.is_err());
+    assert!(
+        SettingUseCase::get_setting(&mut conn, ATTEMPT_GRACE_PERIOD_SECONDS)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn it_should_clamp_stored_grace_period() {
+    let mut conn = get_connection();
+    let mut setting = grace_period("3600");
+    setting.profile_id = Some(ProfileUseCase::get_active_profile_id(&mut conn).unwrap());
+    SQLiteSettingRepository::new(&mut conn)
+        .save(&setting)
+        .unwrap();
+
+    assert_eq!(
+        SettingUseCase::get_attempt_grace_period(&mut conn).unwrap(),
+        Duration::seconds(300)
+    );
+}
+
+#[test]
+fn it_should_get_grace_period_of_other_profiles() {
+    let mut conn = get_connection();
+    let default_profile = ProfileUseCase::get_active_profile_id(&mut conn).unwrap();
+    let other = ProfileUseCase::create_profile(&mut conn, "Other").unwrap();
+    ProfileUseCase::switch_profile(&mut conn, other.id.unwrap()).unwrap();
+    SettingUseCase::update_setting(&mut conn, grace_period("120")).unwrap();
+    ProfileUseCase::switch_profile(&mut conn, default_profile).unwrap();
+
+    assert_eq!(
+        SettingUseCase::get_attempt_grace_period(&mut conn).unwrap(),
+        Duration::seconds(30)
+    );
+    assert_eq!(
+        SettingUseCase::get_attempt_grace_period_of(&mut conn, other.id.unwrap()).unwrap(),
+        Duration::seconds(120)
+    );
+}
@@ -18,6 +18,13 @@ pub struct AnswerEntity {
     pub updated_at: Option<NaiveDateTime>,
     pub assigned_option_id: Option<i32>,
     pub fk_question_id: i32,
+    pub match_mode: Option<String>,
+    pub numeric_value: Option<f64>,
+    pub tolerance: Option<f64>,
+    pub tolerance_mode: Option<String>,
+    pub unit: Option<String>,
+    pub position: Option<i32>,
+    pub blank: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
@@ -29,6 +36,13 @@ pub struct NewAnswer<'a> {
     pub assigned_option_id: Option<i32>,
     pub fk_question_id: i32,
     pub created_at: Option<NaiveDateTime>,
+    pub match_mode: Option<&'a str>,
+    pub numeric_value: Option<f64>,
+    pub tolerance: Option<f64>,
+    pub tolerance_mode: Option<&'a str>,
+    pub unit: Option<&'a str>,
+    pub position: Option<i32>,
+    pub blank: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, AsChangeset)]
@@ -40,4 +54,11 @@ pub struct UpdateAnswer<'a> {
     pub assigned_option_id: Option<i32>,
     pub fk_question_id: Option<i32>,
     pub updated_at: Option<NaiveDateTime>,
+    pub match_mode: Option<&'a str>,
+    pub numeric_value: Option<f64>,
+    pub tolerance: Option<f64>,
+    pub tolerance_mode: Option<&'a str>,
+    pub unit: Option<Option<&'a str>>,
+    pub position: Option<i32>,
+    pub blank: Option<i32>,
 }
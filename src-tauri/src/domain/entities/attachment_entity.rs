@@ -0,0 +1,38 @@
+use crate::schema::{attachment, attachment_blob};
+use chrono::NaiveDateTime;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Identifiable, Selectable)]
+#[diesel(table_name = attachment)]
+pub struct AttachmentEntity {
+    pub id: i32,
+    pub fk_question_id: Option<i32>,
+    pub fk_answer_id: Option<i32>,
+    pub file_name: String,
+    pub mime_type: String,
+    pub hash: String,
+    pub size: i32,
+    pub created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
+#[diesel(table_name = attachment)]
+pub struct NewAttachment {
+    pub fk_question_id: Option<i32>,
+    pub fk_answer_id: Option<i32>,
+    pub file_name: String,
+    pub mime_type: String,
+    pub hash: String,
+    pub size: i32,
+    pub created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = attachment_blob)]
+pub struct NewAttachmentBlob<'a> {
+    pub hash: &'a str,
+    pub data: &'a [u8],
+    pub size: i32,
+    pub created_at: Option<NaiveDateTime>,
+}
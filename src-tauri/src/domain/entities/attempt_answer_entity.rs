@@ -0,0 +1,37 @@
+use crate::domain::entities::exam_attempt_entity::ExamAttemptEntity;
+use crate::schema::attempt_answer;
+use chrono::NaiveDateTime;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Queryable, Identifiable, Associations, Selectable,
+)]
+#[diesel(table_name = attempt_answer)]
+#[diesel(belongs_to(ExamAttemptEntity, foreign_key = fk_attempt_id))]
+pub struct AttemptAnswerEntity {
+    pub id: i32,
+    pub fk_attempt_id: i32,
+    pub fk_question_id: i32,
+    pub fk_answer_id: Option<i32>,
+    pub assigned_option_id: Option<i32>,
+    pub created_at: Option<NaiveDateTime>,
+    pub time_spent_seconds: Option<i32>,
+    pub text_response: Option<String>,
+    pub position: Option<i32>,
+    pub blank: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
+#[diesel(table_name = attempt_answer)]
+pub struct NewAttemptAnswer {
+    pub fk_attempt_id: i32,
+    pub fk_question_id: i32,
+    pub fk_answer_id: Option<i32>,
+    pub assigned_option_id: Option<i32>,
+    pub created_at: Option<NaiveDateTime>,
+    pub time_spent_seconds: Option<i32>,
+    pub text_response: Option<String>,
+    pub position: Option<i32>,
+    pub blank: Option<i32>,
+}
@@ -0,0 +1,25 @@
+use crate::domain::entities::question_entity::QuestionEntity;
+use crate::schema::bookmark;
+use chrono::NaiveDateTime;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Queryable, Identifiable, Associations, Selectable,
+)]
+#[diesel(table_name = bookmark)]
+#[diesel(belongs_to(QuestionEntity, foreign_key = fk_question_id))]
+pub struct BookmarkEntity {
+    pub id: i32,
+    pub fk_profile_id: i32,
+    pub fk_question_id: i32,
+    pub created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
+#[diesel(table_name = bookmark)]
+pub struct NewBookmark {
+    pub fk_profile_id: i32,
+    pub fk_question_id: i32,
+    pub created_at: Option<NaiveDateTime>,
+}
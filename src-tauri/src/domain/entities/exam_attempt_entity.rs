@@ -0,0 +1,70 @@
+use crate::domain::entities::exam_entity::ExamEntity;
+use crate::schema::{attempt_question, exam_attempt};
+use chrono::NaiveDateTime;
+use diesel::{AsChangeset, Associations, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Queryable, Identifiable, Associations, Selectable,
+)]
+#[diesel(table_name = exam_attempt)]
+#[diesel(belongs_to(ExamEntity, foreign_key = fk_exam_id))]
+pub struct ExamAttemptEntity {
+    pub id: i32,
+    pub fk_exam_id: i32,
+    pub mode: String,
+    pub started_at: NaiveDateTime,
+    pub finished_at: Option<NaiveDateTime>,
+    pub score: Option<i32>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub deadline_at: Option<NaiveDateTime>,
+    pub expired: bool,
+    pub fk_profile_id: i32,
+    pub shuffle_seed: Option<i64>,
+    pub fk_draw_id: Option<i32>,
+    pub content_revision: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
+#[diesel(table_name = exam_attempt)]
+pub struct NewExamAttempt<'a> {
+    pub fk_exam_id: i32,
+    pub mode: &'a str,
+    pub started_at: NaiveDateTime,
+    pub created_at: Option<NaiveDateTime>,
+    pub deadline_at: Option<NaiveDateTime>,
+    pub fk_profile_id: i32,
+    pub shuffle_seed: Option<i64>,
+    pub fk_draw_id: Option<i32>,
+    pub content_revision: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, AsChangeset)]
+#[diesel(table_name = exam_attempt)]
+pub struct UpdateExamAttempt {
+    pub finished_at: Option<NaiveDateTime>,
+    pub score: Option<i32>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub expired: bool,
+}
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Queryable, Identifiable, Associations, Selectable,
+)]
+#[diesel(table_name = attempt_question)]
+#[diesel(belongs_to(ExamAttemptEntity, foreign_key = fk_attempt_id))]
+pub struct AttemptQuestionEntity {
+    pub id: i32,
+    pub fk_attempt_id: i32,
+    pub fk_question_id: i32,
+    pub position: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
+#[diesel(table_name = attempt_question)]
+pub struct NewAttemptQuestion {
+    pub fk_attempt_id: i32,
+    pub fk_question_id: i32,
+    pub position: i32,
+}
@@ -0,0 +1,41 @@
+use crate::domain::entities::question_entity::QuestionEntity;
+use crate::schema::mistake;
+use chrono::NaiveDateTime;
+use diesel::{AsChangeset, Associations, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Queryable, Identifiable, Associations, Selectable,
+)]
+#[diesel(table_name = mistake)]
+#[diesel(belongs_to(QuestionEntity, foreign_key = fk_question_id))]
+pub struct MistakeEntity {
+    pub id: i32,
+    pub fk_profile_id: i32,
+    pub fk_question_id: i32,
+    pub miss_count: i32,
+    pub correct_streak: i32,
+    pub last_missed_at: NaiveDateTime,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
+#[diesel(table_name = mistake)]
+pub struct NewMistake {
+    pub fk_profile_id: i32,
+    pub fk_question_id: i32,
+    pub miss_count: i32,
+    pub correct_streak: i32,
+    pub last_missed_at: NaiveDateTime,
+    pub created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, AsChangeset)]
+#[diesel(table_name = mistake)]
+pub struct UpdateMistake {
+    pub miss_count: i32,
+    pub correct_streak: i32,
+    pub last_missed_at: NaiveDateTime,
+    pub updated_at: Option<NaiveDateTime>,
+}
@@ -1,5 +1,15 @@
 pub(crate) mod answer_entity;
 pub(crate) mod assignment_option_entity;
+pub(crate) mod attachment_entity;
+pub(crate) mod attempt_answer_entity;
+pub(crate) mod bookmark_entity;
 pub(crate) mod category_entity;
+pub(crate) mod exam_attempt_entity;
 pub(crate) mod exam_entity;
+pub(crate) mod mistake_entity;
+pub(crate) mod profile_entity;
+pub(crate) mod question_draw_entity;
 pub(crate) mod question_entity;
+pub(crate) mod review_state_entity;
+pub(crate) mod setting_entity;
+pub(crate) mod source_reference_entity;
@@ -0,0 +1,29 @@
+use crate::schema::profile;
+use chrono::NaiveDateTime;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Identifiable, Selectable)]
+#[diesel(table_name = profile)]
+pub struct ProfileEntity {
+    pub id: i32,
+    pub name: String,
+    pub active: bool,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
+#[diesel(table_name = profile)]
+pub struct NewProfile<'a> {
+    pub name: &'a str,
+    pub active: bool,
+    pub created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, AsChangeset)]
+#[diesel(table_name = profile)]
+pub struct UpdateProfile<'a> {
+    pub name: &'a str,
+    pub updated_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,49 @@
+use crate::domain::entities::exam_entity::ExamEntity;
+use crate::schema::{question_draw, question_draw_item};
+use chrono::NaiveDateTime;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Queryable, Identifiable, Associations, Selectable,
+)]
+#[diesel(table_name = question_draw)]
+#[diesel(belongs_to(ExamEntity, foreign_key = fk_exam_id))]
+pub struct QuestionDrawEntity {
+    pub id: i32,
+    pub fk_exam_id: i32,
+    pub seed: i64,
+    pub stratified: bool,
+    pub created_at: Option<NaiveDateTime>,
+    pub fk_profile_id: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
+#[diesel(table_name = question_draw)]
+pub struct NewQuestionDraw {
+    pub fk_exam_id: i32,
+    pub seed: i64,
+    pub stratified: bool,
+    pub created_at: Option<NaiveDateTime>,
+    pub fk_profile_id: i32,
+}
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Queryable, Identifiable, Associations, Selectable,
+)]
+#[diesel(table_name = question_draw_item)]
+#[diesel(belongs_to(QuestionDrawEntity, foreign_key = fk_draw_id))]
+pub struct QuestionDrawItemEntity {
+    pub id: i32,
+    pub fk_draw_id: i32,
+    pub fk_question_id: i32,
+    pub position: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
+#[diesel(table_name = question_draw_item)]
+pub struct NewQuestionDrawItem {
+    pub fk_draw_id: i32,
+    pub fk_question_id: i32,
+    pub position: i32,
+}
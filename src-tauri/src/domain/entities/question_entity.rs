@@ -21,6 +21,8 @@ pub struct QuestionEntity {
     pub updated_at: Option<NaiveDateTime>,
     pub fk_exam_id: i32,
     pub fk_category_id: Option<i32>,
+    pub ordering_scoring: Option<String>,
+    pub explanation: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
@@ -33,6 +35,8 @@ pub struct NewQuestion<'a> {
     pub created_at: Option<NaiveDateTime>,
     pub fk_exam_id: i32,
     pub fk_category_id: Option<i32>,
+    pub ordering_scoring: Option<&'a str>,
+    pub explanation: Option<&'a str>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, AsChangeset)]
@@ -44,4 +48,8 @@ pub struct UpdateQuestion<'a> {
     pub question_typ: Option<&'a str>,
     pub fk_category_id: Option<i32>,
     pub updated_at: Option<NaiveDateTime>,
+    /// Always written so that a removed ordering scoring is cleared.
+    pub ordering_scoring: Option<Option<&'a str>>,
+    /// Always written so that a removed explanation is cleared.
+    pub explanation: Option<Option<&'a str>>,
 }
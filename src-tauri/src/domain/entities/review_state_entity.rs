@@ -0,0 +1,50 @@
+use crate::domain::entities::question_entity::QuestionEntity;
+use crate::schema::review_state;
+use chrono::NaiveDateTime;
+use diesel::{AsChangeset, Associations, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Queryable, Identifiable, Associations, Selectable,
+)]
+#[diesel(table_name = review_state)]
+#[diesel(belongs_to(QuestionEntity, foreign_key = fk_question_id))]
+pub struct ReviewStateEntity {
+    pub id: i32,
+    pub fk_question_id: i32,
+    pub ease_factor: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub lapses: i32,
+    pub due_at: NaiveDateTime,
+    pub last_reviewed_at: Option<NaiveDateTime>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub fk_profile_id: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
+#[diesel(table_name = review_state)]
+pub struct NewReviewState {
+    pub fk_question_id: i32,
+    pub ease_factor: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub lapses: i32,
+    pub due_at: NaiveDateTime,
+    pub last_reviewed_at: Option<NaiveDateTime>,
+    pub created_at: Option<NaiveDateTime>,
+    pub fk_profile_id: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, AsChangeset)]
+#[diesel(table_name = review_state)]
+pub struct UpdateReviewState {
+    pub ease_factor: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub lapses: i32,
+    pub due_at: NaiveDateTime,
+    pub last_reviewed_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+}
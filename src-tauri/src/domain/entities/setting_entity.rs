@@ -0,0 +1,13 @@
+use crate::schema::setting;
+use chrono::NaiveDateTime;
+use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = setting)]
+pub struct SettingEntity {
+    pub fk_profile_id: i32,
+    pub key: String,
+    pub value: String,
+    pub updated_at: Option<NaiveDateTime>,
+}
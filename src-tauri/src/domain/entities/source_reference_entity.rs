@@ -0,0 +1,30 @@
+use crate::domain::entities::question_entity::QuestionEntity;
+use crate::schema::source_reference;
+use diesel::{Associations, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Queryable, Identifiable, Associations, Selectable,
+)]
+#[diesel(table_name = source_reference)]
+#[diesel(belongs_to(QuestionEntity, foreign_key = fk_question_id))]
+pub struct SourceReferenceEntity {
+    pub id: i32,
+    pub fk_question_id: i32,
+    pub title: String,
+    pub chapter: Option<String>,
+    pub page: Option<String>,
+    pub url: Option<String>,
+    pub position: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
+#[diesel(table_name = source_reference)]
+pub struct NewSourceReference<'a> {
+    pub fk_question_id: i32,
+    pub title: &'a str,
+    pub chapter: Option<&'a str>,
+    pub page: Option<&'a str>,
+    pub url: Option<&'a str>,
+    pub position: i32,
+}
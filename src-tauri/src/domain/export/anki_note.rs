@@ -0,0 +1,259 @@
+use crate::domain::export::xml_writer::escape_xml;
+use crate::domain::model::answer::Answer;
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::{Question, CLOZE_PLACEHOLDER};
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+
+/// Flashcard of one question. `front` and `back` are the HTML fields shown by Anki, `tags`
+/// the category names of the question and the exam with whitespace replaced by `_`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnkiNote {
+    pub front: String,
+    pub back: String,
+    pub tags: Vec<String>,
+}
+
+impl AnkiNote {
+    /// Front shows the question with its choices, back the correct answers with their
+    /// descriptions and the explanation of the question.
+    pub fn new(exam: &Exam, question: &Question) -> Self {
+        let mut back = Self::answers(question);
+        if let Some(explanation) = &question.explanation {
+            back.push_str(&format!(
+                "<div class=\"explanation\">{}</div>",
+                Self::html(explanation)
+            ));
+        }
+
+        let mut tags: Vec<String> = vec![];
+        let categories = [&question.category, &exam.category];
+        for category in categories.into_iter().flatten() {
+            let tag = category
+                .name
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join("_");
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+
+        Self {
+            front: Self::question(question),
+            back,
+            tags,
+        }
+    }
+
+    /// Shows images on the front: those of the question below its text, those of the answers
+    /// after the choices, captioned with the answer text. `images` pairs every attachment
+    /// with its file name in the media of the package.
+    pub fn add_images(&mut self, question: &Question, images: &[(&Attachment, String)]) {
+        let mut question_images = String::new();
+        for (attachment, file_name) in images {
+            let image = format!("<img src=\"{}\">", escape_xml(file_name));
+            let answer = question
+                .answers
+                .iter()
+                .find(|a| a.id.is_some() && a.id == attachment.answer_id);
+            match answer {
+                Some(answer) => self.front.push_str(&format!(
+                    "<div class=\"media\">{}<br>{image}</div>",
+                    Self::html(&answer.answer_text)
+                )),
+                None => question_images.push_str(&format!("<div class=\"media\">{image}</div>")),
+            }
+        }
+        // The question text is escaped, the first closing tag ends the question.
+        let end = self.front.find("</div>").map_or(0, |i| i + "</div>".len());
+        self.front.insert_str(end, &question_images);
+    }
+
+    /// Escapes `text` for HTML, line breaks become `<br>`.
+    pub fn html(text: &str) -> String {
+        escape_xml(text)
+            .replace("\r\n", "<br>")
+            .replace('\n', "<br>")
+    }
+
+    fn question(question: &Question) -> String {
+        let text = match question.r#type {
+            QuestionType::Cloze => Self::cloze_text(&question.question_text),
+            _ => Self::html(&question.question_text),
+        };
+        let mut front = format!("<div class=\"question\">{text}</div>");
+
+        match question.r#type {
+            QuestionType::SingleChoice | QuestionType::MultipleChoice => {
+                let choices = question.answers.iter().map(|a| Self::html(&a.answer_text));
+                front.push_str(&Self::list("ol type=\"a\"", "ol", choices));
+            }
+            QuestionType::Assignment => {
+                let items = question.answers.iter().map(|a| Self::html(&a.answer_text));
+                front.push_str(&Self::list("ul", "ul", items));
+                let options = question
+                    .options
+                    .iter()
+                    .flatten()
+                    .map(|o| Self::html(&o.text));
+                front.push_str(&Self::list("ol type=\"a\"", "ol", options));
+            }
+            QuestionType::Ordering => {
+                // Sorted so the stored order doesn't give the solution away.
+                let mut items: Vec<String> = question
+                    .answers
+                    .iter()
+                    .map(|a| Self::html(&a.answer_text))
+                    .collect();
+                items.sort_by_key(|item| item.to_lowercase());
+                front.push_str(&Self::list("ul", "ul", items));
+            }
+            QuestionType::Cloze => {
+                for blank in question.cloze_blanks() {
+                    let choices: Vec<String> = question
+                        .answers
+                        .iter()
+                        .filter(|a| a.blank == Some(blank) && a.match_mode.is_none())
+                        .map(|a| Self::html(&a.answer_text))
+                        .collect();
+                    if !choices.is_empty() {
+                        front.push_str(&format!(
+                            "<div class=\"choices\">[{blank}] {}</div>",
+                            choices.join(" / ")
+                        ));
+                    }
+                }
+            }
+            QuestionType::ShortAnswer | QuestionType::Numeric => {}
+        }
+        front
+    }
+
+    fn answers(question: &Question) -> String {
+        match question.r#type {
+            QuestionType::SingleChoice | QuestionType::MultipleChoice => {
+                let correct = question
+                    .answers
+                    .iter()
+                    .filter(|a| a.is_correct == Some(true))
+                    .map(|a| Self::described(a, &Self::html(&a.answer_text), true));
+                // Descriptions of wrong choices explain why they are wrong.
+                let wrong = question
+                    .answers
+                    .iter()
+                    .filter(|a| a.is_correct != Some(true) && Self::description(a).is_some())
+                    .map(|a| Self::described(a, &Self::html(&a.answer_text), false));
+                Self::list("ul", "ul", correct.chain(wrong))
+            }
+            QuestionType::Assignment => {
+                let pairs = question.answers.iter().map(|a| {
+                    let option = question
+                        .options
+                        .iter()
+                        .flatten()
+                        .find(|o| Some(o.id) == a.assigned_option_id)
+                        .map(|o| Self::html(&o.text))
+                        .unwrap_or_default();
+                    let pair = format!("{} &rarr; {option}", Self::html(&a.answer_text));
+                    Self::described(a, &pair, true)
+                });
+                Self::list("ul", "ul", pairs)
+            }
+            QuestionType::ShortAnswer => {
+                let accepted = question
+                    .answers
+                    .iter()
+                    .map(|a| Self::described(a, &Self::html(&a.answer_text), true));
+                Self::list("ul", "ul", accepted)
+            }
+            QuestionType::Numeric => {
+                let values = question.answers.iter().filter_map(|a| {
+                    let numeric = a.numeric.as_ref()?;
+                    let mut value = numeric.expected_value.to_string();
+                    if numeric.tolerance != 0.0 {
+                        value.push_str(&match numeric.tolerance_mode {
+                            ToleranceMode::Absolute => format!(" &plusmn; {}", numeric.tolerance),
+                            ToleranceMode::Relative => {
+                                format!(" &plusmn; {} %", numeric.tolerance * 100.0)
+                            }
+                        });
+                    }
+                    if let Some(unit) = &numeric.unit {
+                        value.push_str(&format!(" {}", Self::html(unit)));
+                    }
+                    Some(Self::described(a, &value, true))
+                });
+                Self::list("ul", "ul", values)
+            }
+            QuestionType::Ordering => {
+                let mut items: Vec<&Answer> = question.answers.iter().collect();
+                items.sort_by_key(|a| a.position.unwrap_or(i32::MAX));
+                let ordered = items
+                    .into_iter()
+                    .map(|a| Self::described(a, &Self::html(&a.answer_text), true));
+                Self::list("ol", "ol", ordered)
+            }
+            QuestionType::Cloze => {
+                let blanks = question.cloze_blanks().into_iter().map(|blank| {
+                    let accepted: Vec<String> = question
+                        .answers
+                        .iter()
+                        .filter(|a| {
+                            a.blank == Some(blank)
+                                && (a.match_mode.is_some() || a.is_correct == Some(true))
+                        })
+                        .map(|a| Self::html(&a.answer_text))
+                        .collect();
+                    format!("[{blank}] {}", accepted.join(" / "))
+                });
+                Self::list("ul", "ul", blanks)
+            }
+        }
+    }
+
+    /// Replaces the `{{n}}` placeholders by a visible `[n]` gap.
+    fn cloze_text(text: &str) -> String {
+        let escaped = Self::html(text);
+        CLOZE_PLACEHOLDER
+            .replace_all(&escaped, "<b>[$1]</b>")
+            .into_owned()
+    }
+
+    fn description(answer: &Answer) -> Option<&str> {
+        answer
+            .description
+            .as_deref()
+            .map(str::trim)
+            .filter(|d| !d.is_empty() && *d != answer.answer_text.trim())
+    }
+
+    fn described(answer: &Answer, text: &str, correct: bool) -> String {
+        let class = if correct { "correct" } else { "wrong" };
+        match Self::description(answer) {
+            Some(description) => format!(
+                "<li class=\"{class}\">{text}<div class=\"description\">{}</div></li>",
+                Self::html(description)
+            ),
+            None => format!("<li class=\"{class}\">{text}</li>"),
+        }
+    }
+
+    fn list(open: &str, close: &str, items: impl IntoIterator<Item = String>) -> String {
+        let items: String = items
+            .into_iter()
+            .map(|item| {
+                if item.starts_with("<li") {
+                    item
+                } else {
+                    format!("<li>{item}</li>")
+                }
+            })
+            .collect();
+        if items.is_empty() {
+            return String::new();
+        }
+        format!("<{open}>{items}</{close}>")
+    }
+}
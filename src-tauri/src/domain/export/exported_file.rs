@@ -0,0 +1,45 @@
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::question::Question;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+
+/// Content written by an exporter, together with the questions the format can't express.
+/// Each skipped entry carries the position of the question as `index`.
+#[derive(Debug, Clone)]
+pub struct ExportedFile {
+    pub data: Vec<u8>,
+    pub skipped: Vec<ExtendedValidationError>,
+}
+
+impl ExportedFile {
+    /// Entry of `skipped` for the question at `index`, left out for `reason`.
+    pub fn skip(index: usize, question: &Question, reason: &str) -> ExtendedValidationError {
+        ExtendedValidationError::new(
+            index as i32,
+            format!(
+                "Question `{}` was not exported, {reason}",
+                question.question_text
+            ),
+            vec![],
+            vec![],
+        )
+    }
+
+    /// Entry of `skipped` for an attachment of the question at `index`, left out for
+    /// `reason`.
+    pub fn skip_attachment(
+        index: usize,
+        question: &Question,
+        attachment: &Attachment,
+        reason: &str,
+    ) -> ExtendedValidationError {
+        ExtendedValidationError::new(
+            index as i32,
+            format!(
+                "Attachment {} of question `{}` was not exported, {reason}",
+                attachment.file_name, question.question_text
+            ),
+            vec![],
+            vec![],
+        )
+    }
+}
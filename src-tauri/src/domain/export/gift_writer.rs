@@ -0,0 +1,206 @@
+use crate::domain::export::exported_file::ExportedFile;
+use crate::domain::import::gift_parser::{GIFT_CATEGORY_PREFIX, GIFT_SPECIAL_CHARACTERS};
+use crate::domain::import::moodle_question::MoodleQuestion;
+use crate::domain::model::answer::Answer;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+
+/// Category path of questions without a category.
+const DEFAULT_CATEGORY_PATH: &str = "$course$/top";
+
+/// Writes an exam in the Moodle GIFT format.
+///
+/// Single and multiple choice questions become multiple choice, assignment questions
+/// matching, short answer and numeric questions their GIFT counterparts. GIFT has no
+/// points, ordering and cloze questions and short answers given as regular expression have
+/// no equivalent, those questions are listed in `ExportedFile::skipped`.
+pub struct GiftWriter();
+
+impl GiftWriter {
+    pub fn write(exam: &Exam) -> ExportedFile {
+        let mut gift = format!("// {}\n", exam.name);
+        for line in exam.description.iter().flat_map(|d| d.lines()) {
+            gift.push_str(&format!("// {line}\n"));
+        }
+
+        let mut skipped: Vec<ExtendedValidationError> = vec![];
+        let mut category: Option<&str> = None;
+        for (index, question) in exam.questions.iter().enumerate() {
+            let answers = match Self::answer_block(question) {
+                Ok(answers) => answers,
+                Err(reason) => {
+                    skipped.push(ExportedFile::skip(index, question, &reason));
+                    continue;
+                }
+            };
+
+            let question_category = question.category.as_ref().map(|c| c.name.as_str());
+            if question_category != category {
+                let path = match question_category {
+                    Some(name) => format!("{DEFAULT_CATEGORY_PATH}/{name}"),
+                    None => DEFAULT_CATEGORY_PATH.to_string(),
+                };
+                gift.push_str(&format!("\n{GIFT_CATEGORY_PREFIX} {path}\n"));
+                category = question_category;
+            }
+
+            gift.push_str(&format!(
+                "\n{} {{\n{answers}",
+                Self::escape(&question.question_text)
+            ));
+            if let Some(explanation) = &question.explanation {
+                gift.push_str(&format!("\t####{}\n", Self::escape(explanation)));
+            }
+            gift.push_str("}\n");
+        }
+
+        ExportedFile {
+            data: gift.into_bytes(),
+            skipped,
+        }
+    }
+
+    /// Escapes the characters GIFT uses for its syntax, line breaks become `\n`.
+    pub fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '\n' => escaped.push_str("\\n"),
+                '\r' => {}
+                c if GIFT_SPECIAL_CHARACTERS.contains(&c) || c == '\\' => {
+                    escaped.push('\\');
+                    escaped.push(c);
+                }
+                c => escaped.push(c),
+            }
+        }
+        escaped
+    }
+
+    /// `#<feedback>` for answers whose description adds something to the answer.
+    fn feedback(answer: &Answer) -> String {
+        match &answer.description {
+            Some(description) if *description != answer.answer_text => {
+                format!("#{}", Self::escape(description))
+            }
+            _ => String::new(),
+        }
+    }
+
+    fn answer_block(question: &Question) -> Result<String, String> {
+        let lines: Vec<String> = match question.r#type {
+            QuestionType::SingleChoice => question
+                .answers
+                .iter()
+                .map(|a| {
+                    let marker = if a.is_correct == Some(true) { '=' } else { '~' };
+                    format!(
+                        "{marker}{}{}",
+                        Self::escape(&a.answer_text),
+                        Self::feedback(a)
+                    )
+                })
+                .collect(),
+            QuestionType::MultipleChoice => {
+                let correct = question
+                    .answers
+                    .iter()
+                    .filter(|a| a.is_correct == Some(true))
+                    .count()
+                    .max(1);
+                let fraction = MoodleQuestion::percent(100.0 / correct as f64);
+                question
+                    .answers
+                    .iter()
+                    .map(|a| {
+                        let sign = if a.is_correct == Some(true) { "" } else { "-" };
+                        format!(
+                            "~%{sign}{fraction}%{}{}",
+                            Self::escape(&a.answer_text),
+                            Self::feedback(a)
+                        )
+                    })
+                    .collect()
+            }
+            QuestionType::Assignment => {
+                let options = question.options.clone().unwrap_or_default();
+                let option_text = |id: Option<i32>| {
+                    options
+                        .iter()
+                        .find(|o| Some(o.id) == id)
+                        .map(|o| Self::escape(&o.text))
+                        .unwrap_or_default()
+                };
+                let mut lines: Vec<String> = question
+                    .answers
+                    .iter()
+                    .map(|a| {
+                        format!(
+                            "={} -> {}",
+                            Self::escape(&a.answer_text),
+                            option_text(a.assigned_option_id)
+                        )
+                    })
+                    .collect();
+                lines.extend(
+                    options
+                        .iter()
+                        .filter(|o| {
+                            !question
+                                .answers
+                                .iter()
+                                .any(|a| a.assigned_option_id == Some(o.id))
+                        })
+                        .map(|o| format!("= -> {}", Self::escape(&o.text))),
+                );
+                lines
+            }
+            QuestionType::ShortAnswer => {
+                if question
+                    .answers
+                    .iter()
+                    .any(|a| a.match_mode == Some(MatchMode::Regex))
+                {
+                    return Err(
+                        "answers given as regular expression have no GIFT equivalent".to_string(),
+                    );
+                }
+                question
+                    .answers
+                    .iter()
+                    .map(|a| format!("={}{}", Self::escape(&a.answer_text), Self::feedback(a)))
+                    .collect()
+            }
+            QuestionType::Numeric => {
+                let mut lines = vec!["#".to_string()];
+                lines.extend(question.answers.iter().filter_map(|a| {
+                    let numeric = a.numeric.as_ref()?;
+                    let tolerance = match numeric.tolerance_mode {
+                        ToleranceMode::Absolute => numeric.tolerance,
+                        ToleranceMode::Relative => {
+                            (numeric.expected_value * numeric.tolerance).abs()
+                        }
+                    };
+                    Some(format!(
+                        "={}:{tolerance}{}",
+                        numeric.expected_value,
+                        Self::feedback(a)
+                    ))
+                }));
+                lines
+            }
+            _ => {
+                return Err(format!(
+                    "{} questions have no GIFT equivalent",
+                    question.r#type
+                ))
+            }
+        };
+
+        Ok(lines.iter().map(|line| format!("\t{line}\n")).collect())
+    }
+}
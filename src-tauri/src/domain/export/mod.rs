@@ -0,0 +1,9 @@
+pub mod anki_note;
+pub mod exported_file;
+pub mod gift_writer;
+pub mod moodle_xml_writer;
+pub mod qti_package_writer;
+pub mod xml_writer;
+
+#[cfg(test)]
+mod tests;
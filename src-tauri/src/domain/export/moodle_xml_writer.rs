@@ -0,0 +1,230 @@
+use crate::domain::export::exported_file::ExportedFile;
+use crate::domain::export::xml_writer::XmlWriter;
+use crate::domain::import::moodle_question::MoodleQuestion;
+use crate::domain::model::answer::Answer;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+
+/// Category path of questions without a category.
+const DEFAULT_CATEGORY_PATH: &str = "$course$/top";
+const PLAIN_TEXT: &str = "plain_text";
+/// Moodle shows the name in the question bank, longer texts are cut.
+const NAME_LENGTH: usize = 60;
+
+/// Writes an exam as Moodle XML quiz file.
+///
+/// Single and multiple choice questions become `multichoice`, assignment questions
+/// `matching`, short answer and numeric questions `shortanswer` and `numerical`, each with
+/// the points of the question as default grade. Ordering and cloze questions and short
+/// answers given as regular expression have no equivalent, those questions are listed in
+/// `ExportedFile::skipped`.
+pub struct MoodleXmlWriter();
+
+impl MoodleXmlWriter {
+    pub fn write(exam: &Exam) -> ExportedFile {
+        let mut xml = XmlWriter::new();
+        xml.open("quiz", &[]);
+
+        let mut skipped: Vec<ExtendedValidationError> = vec![];
+        let mut category: Option<&str> = None;
+        for (index, question) in exam.questions.iter().enumerate() {
+            let kind = match question.r#type {
+                QuestionType::SingleChoice | QuestionType::MultipleChoice => "multichoice",
+                QuestionType::Assignment => "matching",
+                QuestionType::ShortAnswer
+                    if !question
+                        .answers
+                        .iter()
+                        .any(|a| a.match_mode == Some(MatchMode::Regex)) =>
+                {
+                    "shortanswer"
+                }
+                QuestionType::ShortAnswer => {
+                    skipped.push(ExportedFile::skip(
+                        index,
+                        question,
+                        "answers given as regular expression have no Moodle equivalent",
+                    ));
+                    continue;
+                }
+                QuestionType::Numeric => "numerical",
+                _ => {
+                    let reason = format!("{} questions have no Moodle equivalent", question.r#type);
+                    skipped.push(ExportedFile::skip(index, question, &reason));
+                    continue;
+                }
+            };
+
+            let question_category = question.category.as_ref().map(|c| c.name.as_str());
+            if question_category != category {
+                let path = match question_category {
+                    Some(name) => format!("{DEFAULT_CATEGORY_PATH}/{name}"),
+                    None => DEFAULT_CATEGORY_PATH.to_string(),
+                };
+                xml.open("question", &[("type", "category")])
+                    .open("category", &[])
+                    .text("text", &[], &path)
+                    .close()
+                    .close();
+                category = question_category;
+            }
+
+            xml.open("question", &[("type", kind)]);
+            Self::header(&mut xml, question);
+            match question.r#type {
+                QuestionType::SingleChoice | QuestionType::MultipleChoice => {
+                    Self::multichoice(&mut xml, question)
+                }
+                QuestionType::Assignment => Self::matching(&mut xml, question),
+                QuestionType::ShortAnswer => Self::short_answer(&mut xml, question),
+                _ => Self::numerical(&mut xml, question),
+            }
+            xml.close();
+        }
+
+        xml.close();
+        ExportedFile {
+            data: xml.finish().into_bytes(),
+            skipped,
+        }
+    }
+
+    fn name(question: &Question) -> String {
+        let text = question.question_text.trim();
+        match text.char_indices().nth(NAME_LENGTH) {
+            Some((end, _)) => format!("{}…", &text[..end]),
+            None => text.to_string(),
+        }
+    }
+
+    fn formatted_text(xml: &mut XmlWriter, name: &str, format: &str, text: &str) {
+        xml.open(name, &[("format", format)])
+            .text("text", &[], text)
+            .close();
+    }
+
+    fn header(xml: &mut XmlWriter, question: &Question) {
+        xml.open("name", &[])
+            .text("text", &[], &Self::name(question))
+            .close();
+        Self::formatted_text(xml, "questiontext", PLAIN_TEXT, &question.question_text);
+        if let Some(explanation) = &question.explanation {
+            Self::formatted_text(xml, "generalfeedback", "markdown", explanation);
+        }
+        xml.text("defaultgrade", &[], &question.points_total.to_string());
+        xml.text("hidden", &[], "0");
+    }
+
+    fn answer(xml: &mut XmlWriter, fraction: &str, text: &str, answer: &Answer) {
+        xml.open("answer", &[("fraction", fraction), ("format", PLAIN_TEXT)])
+            .text("text", &[], text);
+        match &answer.description {
+            Some(description) if *description != answer.answer_text => {
+                Self::formatted_text(xml, "feedback", PLAIN_TEXT, description)
+            }
+            _ => {}
+        }
+    }
+
+    fn multichoice(xml: &mut XmlWriter, question: &Question) {
+        let single = question.r#type == QuestionType::SingleChoice;
+        xml.text("single", &[], if single { "true" } else { "false" })
+            .text("shuffleanswers", &[], "true")
+            .text("answernumbering", &[], "abc");
+
+        let correct = question
+            .answers
+            .iter()
+            .filter(|a| a.is_correct == Some(true))
+            .count()
+            .max(1);
+        let share = if single {
+            "100".to_string()
+        } else {
+            MoodleQuestion::percent(100.0 / correct as f64)
+        };
+        for answer in &question.answers {
+            let fraction = match (answer.is_correct == Some(true), single) {
+                (true, _) => share.clone(),
+                (false, true) => "0".to_string(),
+                // every wrong choice cancels a correct one
+                (false, false) => format!("-{share}"),
+            };
+            Self::answer(xml, &fraction, &answer.answer_text, answer);
+            xml.close();
+        }
+    }
+
+    fn matching(xml: &mut XmlWriter, question: &Question) {
+        xml.text("shuffleanswers", &[], "true");
+        let options = question.options.clone().unwrap_or_default();
+        for answer in &question.answers {
+            let option = options
+                .iter()
+                .find(|o| Some(o.id) == answer.assigned_option_id)
+                .map(|o| o.text.as_str())
+                .unwrap_or_default();
+            xml.open("subquestion", &[("format", PLAIN_TEXT)])
+                .text("text", &[], &answer.answer_text)
+                .open("answer", &[])
+                .text("text", &[], option)
+                .close()
+                .close();
+        }
+        // options no answer is assigned to remain as distractors
+        for option in options.iter().filter(|o| {
+            !question
+                .answers
+                .iter()
+                .any(|a| a.assigned_option_id == Some(o.id))
+        }) {
+            xml.open("subquestion", &[("format", PLAIN_TEXT)])
+                .text("text", &[], "")
+                .open("answer", &[])
+                .text("text", &[], &option.text)
+                .close()
+                .close();
+        }
+    }
+
+    fn short_answer(xml: &mut XmlWriter, question: &Question) {
+        let case_sensitive = question
+            .answers
+            .iter()
+            .any(|a| a.match_mode == Some(MatchMode::Exact));
+        xml.text("usecase", &[], if case_sensitive { "1" } else { "0" });
+        for answer in &question.answers {
+            Self::answer(xml, "100", &answer.answer_text, answer);
+            xml.close();
+        }
+    }
+
+    fn numerical(xml: &mut XmlWriter, question: &Question) {
+        let mut unit: Option<&str> = None;
+        for answer in &question.answers {
+            let Some(numeric) = &answer.numeric else {
+                continue;
+            };
+            let tolerance = match numeric.tolerance_mode {
+                ToleranceMode::Absolute => numeric.tolerance,
+                ToleranceMode::Relative => (numeric.expected_value * numeric.tolerance).abs(),
+            };
+            unit = unit.or(numeric.unit.as_deref());
+            Self::answer(xml, "100", &numeric.expected_value.to_string(), answer);
+            xml.text("tolerance", &[], &tolerance.to_string());
+            xml.close();
+        }
+        if let Some(unit) = unit {
+            xml.open("units", &[])
+                .open("unit", &[])
+                .text("multiplier", &[], "1")
+                .text("unit_name", &[], unit)
+                .close()
+                .close();
+        }
+    }
+}
@@ -0,0 +1,495 @@
+use crate::domain::export::exported_file::ExportedFile;
+use crate::domain::export::xml_writer::XmlWriter;
+use crate::domain::model::answer::Answer;
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+use std::io::{Cursor, Write};
+use zip::result::ZipResult;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+pub const QTI_MANIFEST: &str = "imsmanifest.xml";
+pub const QTI_ITEM_RESOURCE: &str = "imsqti_item_xmlv2p1";
+pub const QTI_TEST_RESOURCE: &str = "imsqti_test_xmlv2p1";
+
+const QTI_NAMESPACE: &str = "http://www.imsglobal.org/xsd/imsqti_v2p1";
+const QTI_SCHEMA_LOCATION: &str =
+    "http://www.imsglobal.org/xsd/imsqti_v2p1 http://www.imsglobal.org/xsd/qti/qtiv2p1/imsqti_v2p1.xsd";
+const CONTENT_PACKAGE_NAMESPACE: &str = "http://www.imsglobal.org/xsd/imscp_v1p1";
+const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+const MATCH_CORRECT_TEMPLATE: &str =
+    "http://www.imsglobal.org/question/qti_v2p1/rptemplates/match_correct";
+const MAP_RESPONSE_TEMPLATE: &str =
+    "http://www.imsglobal.org/question/qti_v2p1/rptemplates/map_response";
+
+const TEST_FILE: &str = "assessment.xml";
+const MEDIA_DIRECTORY: &str = "media";
+const RESPONSE: &str = "RESPONSE";
+
+/// `assessmentItem` of an exported question.
+struct Item {
+    identifier: String,
+    xml: String,
+    /// Attachments the item references, relative to the package root.
+    media: Vec<String>,
+}
+
+/// Attachment of a question or one of its answers, written to `path` in the package.
+struct Media<'a> {
+    attachment: &'a Attachment,
+    path: String,
+    content: Vec<u8>,
+}
+
+/// Writes an exam as IMS QTI 2.1 content package: `imsmanifest.xml`, an `assessmentTest`
+/// with the name, time limit and description of the exam, and one `assessmentItem` per
+/// question.
+///
+/// Single and multiple choice questions become a `choiceInteraction`, assignment questions
+/// a `matchInteraction`. Questions of the other types are listed in `ExportedFile::skipped`.
+///
+/// Attachments of the exported questions and their answers are written to `media/`. Images
+/// are referenced as `img`, other files as `object`, in front of the interaction for
+/// questions and within the choice for answers.
+pub struct QtiPackageWriter();
+
+impl QtiPackageWriter {
+    pub fn write(exam: &Exam, attachments: &[Attachment]) -> ZipResult<ExportedFile> {
+        let mut items: Vec<Item> = vec![];
+        let mut files: Vec<(String, Vec<u8>)> = vec![];
+        let mut skipped: Vec<ExtendedValidationError> = vec![];
+        for (index, question) in exam.questions.iter().enumerate() {
+            let identifier = format!("item_{}", index + 1);
+            if !matches!(
+                question.r#type,
+                QuestionType::SingleChoice
+                    | QuestionType::MultipleChoice
+                    | QuestionType::Assignment
+            ) {
+                let reason = format!("{} questions have no QTI interaction", question.r#type);
+                skipped.push(ExportedFile::skip(index, question, &reason));
+                continue;
+            }
+
+            let media = Self::media(index, question, attachments, files.len(), &mut skipped);
+            let xml = match question.r#type {
+                QuestionType::Assignment => Self::match_item(&identifier, question, &media),
+                _ => Self::choice_item(&identifier, question, &media),
+            };
+            items.push(Item {
+                identifier,
+                xml,
+                media: media.iter().map(|m| m.path.clone()).collect(),
+            });
+            files.extend(media.into_iter().map(|m| (m.path, m.content)));
+        }
+
+        let options = SimpleFileOptions::default();
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file(QTI_MANIFEST, options)?;
+        zip.write_all(Self::manifest(&items).as_bytes())?;
+        zip.start_file(TEST_FILE, options)?;
+        zip.write_all(Self::test(exam, &items).as_bytes())?;
+        for item in &items {
+            zip.start_file(Self::item_path(&item.identifier), options)?;
+            zip.write_all(item.xml.as_bytes())?;
+        }
+        for (path, content) in &files {
+            zip.start_file(path, options)?;
+            zip.write_all(content)?;
+        }
+
+        Ok(ExportedFile {
+            data: zip.finish()?.into_inner(),
+            skipped,
+        })
+    }
+
+    fn item_path(identifier: &str) -> String {
+        format!("items/{identifier}.xml")
+    }
+
+    /// Attachments of the question and its answers with their content. Attachments without
+    /// content are reported in `skipped`. `written` counts the media files of the previous
+    /// items and keeps the file names unique.
+    fn media<'a>(
+        index: usize,
+        question: &Question,
+        attachments: &'a [Attachment],
+        written: usize,
+        skipped: &mut Vec<ExtendedValidationError>,
+    ) -> Vec<Media<'a>> {
+        let mut media = vec![];
+        for attachment in attachments.iter().filter(|a| a.belongs_to(question)) {
+            match attachment.decode_data() {
+                Ok(Some(content)) => {
+                    let number = written + media.len() + 1;
+                    media.push(Media {
+                        attachment,
+                        path: format!("{MEDIA_DIRECTORY}/{number}_{}", attachment.safe_file_name()),
+                        content,
+                    });
+                }
+                _ => skipped.push(ExportedFile::skip_attachment(
+                    index,
+                    question,
+                    attachment,
+                    "it has no content",
+                )),
+            }
+        }
+        media
+    }
+
+    /// `img` for images, `object` for other files. Items are stored one level below the
+    /// package root, so the reference starts with `../`.
+    fn reference(xml: &mut XmlWriter, media: &Media) {
+        let src = format!("../{}", media.path);
+        if media.attachment.mime_type.starts_with("image/") {
+            xml.empty(
+                "img",
+                &[("src", &src), ("alt", &media.attachment.file_name)],
+            );
+        } else {
+            xml.empty(
+                "object",
+                &[("data", &src), ("type", &media.attachment.mime_type)],
+            );
+        }
+    }
+
+    /// Attachments of the question, one paragraph each.
+    fn question_media(xml: &mut XmlWriter, media: &[Media]) {
+        for media in media.iter().filter(|m| m.attachment.question_id.is_some()) {
+            xml.open("p", &[]);
+            Self::reference(xml, media);
+            xml.close();
+        }
+    }
+
+    /// Choice with the text of the answer followed by the answer's attachments.
+    fn choice(
+        xml: &mut XmlWriter,
+        name: &str,
+        attributes: &[(&str, &str)],
+        answer: &Answer,
+        media: &[Media],
+    ) {
+        let media: Vec<&Media> = media
+            .iter()
+            .filter(|m| answer.id.is_some() && m.attachment.answer_id == answer.id)
+            .collect();
+        if media.is_empty() {
+            xml.text(name, attributes, &answer.answer_text);
+            return;
+        }
+        xml.open(name, attributes).content(&answer.answer_text);
+        for media in media {
+            Self::reference(xml, media);
+        }
+        xml.close();
+    }
+
+    fn manifest(items: &[Item]) -> String {
+        let mut xml = XmlWriter::new();
+        xml.open(
+            "manifest",
+            &[
+                ("xmlns", CONTENT_PACKAGE_NAMESPACE),
+                ("identifier", "MANIFEST_1"),
+            ],
+        );
+        xml.open("metadata", &[])
+            .text("schema", &[], "QTIv2.1 Package")
+            .text("schemaversion", &[], "1.0.0")
+            .close();
+        xml.empty("organizations", &[]);
+        xml.open("resources", &[]);
+        xml.open(
+            "resource",
+            &[
+                ("identifier", "test"),
+                ("type", QTI_TEST_RESOURCE),
+                ("href", TEST_FILE),
+            ],
+        );
+        xml.empty("file", &[("href", TEST_FILE)]);
+        for item in items {
+            xml.empty("dependency", &[("identifierref", &item.identifier)]);
+        }
+        xml.close();
+        for item in items {
+            let path = Self::item_path(&item.identifier);
+            xml.open(
+                "resource",
+                &[
+                    ("identifier", &item.identifier),
+                    ("type", QTI_ITEM_RESOURCE),
+                    ("href", &path),
+                ],
+            )
+            .empty("file", &[("href", &path)]);
+            for media in &item.media {
+                xml.empty("file", &[("href", media)]);
+            }
+            xml.close();
+        }
+        xml.close().close();
+        xml.finish()
+    }
+
+    fn test(exam: &Exam, items: &[Item]) -> String {
+        let mut xml = XmlWriter::new();
+        xml.open(
+            "assessmentTest",
+            &[
+                ("xmlns", QTI_NAMESPACE),
+                ("xmlns:xsi", XSI_NAMESPACE),
+                ("xsi:schemaLocation", QTI_SCHEMA_LOCATION),
+                ("identifier", "test"),
+                ("title", &exam.name),
+            ],
+        );
+        if let Some(duration) = exam.duration {
+            let seconds = (duration * 60).to_string();
+            xml.empty("timeLimits", &[("maxTime", &seconds)]);
+        }
+        xml.open(
+            "testPart",
+            &[
+                ("identifier", "part_1"),
+                ("navigationMode", "nonlinear"),
+                ("submissionMode", "simultaneous"),
+            ],
+        );
+        xml.open(
+            "assessmentSection",
+            &[
+                ("identifier", "section_1"),
+                ("title", &exam.name),
+                ("visible", "true"),
+            ],
+        );
+        if let Some(description) = &exam.description {
+            xml.open("rubricBlock", &[("view", "candidate")])
+                .text("p", &[], description)
+                .close();
+        }
+        for item in items {
+            xml.empty(
+                "assessmentItemRef",
+                &[
+                    ("identifier", &item.identifier),
+                    ("href", &Self::item_path(&item.identifier)),
+                ],
+            );
+        }
+        xml.close().close().close();
+        xml.finish()
+    }
+
+    fn open_item(xml: &mut XmlWriter, identifier: &str, question: &Question) {
+        xml.open(
+            "assessmentItem",
+            &[
+                ("xmlns", QTI_NAMESPACE),
+                ("xmlns:xsi", XSI_NAMESPACE),
+                ("xsi:schemaLocation", QTI_SCHEMA_LOCATION),
+                ("identifier", identifier),
+                ("title", &question.question_text),
+                ("adaptive", "false"),
+                ("timeDependent", "false"),
+            ],
+        );
+    }
+
+    /// `SCORE` and `MAXSCORE`, the latter carries the points of the question.
+    fn outcomes(xml: &mut XmlWriter, question: &Question) {
+        xml.open(
+            "outcomeDeclaration",
+            &[
+                ("identifier", "SCORE"),
+                ("cardinality", "single"),
+                ("baseType", "float"),
+            ],
+        )
+        .open("defaultValue", &[])
+        .text("value", &[], "0")
+        .close()
+        .close();
+        xml.open(
+            "outcomeDeclaration",
+            &[
+                ("identifier", "MAXSCORE"),
+                ("cardinality", "single"),
+                ("baseType", "float"),
+            ],
+        )
+        .open("defaultValue", &[])
+        .text("value", &[], &question.points_total.to_string())
+        .close()
+        .close();
+    }
+
+    /// Maps every correct response to the points of a correct answer.
+    fn mapping(xml: &mut XmlWriter, question: &Question, keys: &[String]) {
+        let upper_bound = question.points_total.to_string();
+        let value = question.points_per_correct_answer.unwrap_or(1).to_string();
+        xml.open(
+            "mapping",
+            &[
+                ("lowerBound", "0"),
+                ("upperBound", &upper_bound),
+                ("defaultValue", "0"),
+            ],
+        );
+        for key in keys {
+            xml.empty("mapEntry", &[("mapKey", key), ("mappedValue", &value)]);
+        }
+        xml.close();
+    }
+
+    fn choice_item(identifier: &str, question: &Question, media: &[Media]) -> String {
+        let multiple = question.r#type == QuestionType::MultipleChoice;
+        let choices: Vec<String> = (1..=question.answers.len())
+            .map(|n| format!("choice_{n}"))
+            .collect();
+        let correct: Vec<String> = question
+            .answers
+            .iter()
+            .zip(&choices)
+            .filter(|(answer, _)| answer.is_correct == Some(true))
+            .map(|(_, choice)| choice.clone())
+            .collect();
+
+        let mut xml = XmlWriter::new();
+        Self::open_item(&mut xml, identifier, question);
+        xml.open(
+            "responseDeclaration",
+            &[
+                ("identifier", RESPONSE),
+                ("cardinality", if multiple { "multiple" } else { "single" }),
+                ("baseType", "identifier"),
+            ],
+        );
+        xml.open("correctResponse", &[]);
+        for choice in &correct {
+            xml.text("value", &[], choice);
+        }
+        xml.close();
+        if multiple {
+            Self::mapping(&mut xml, question, &correct);
+        }
+        xml.close();
+        Self::outcomes(&mut xml, question);
+
+        xml.open("itemBody", &[]);
+        Self::question_media(&mut xml, media);
+        xml.open(
+            "choiceInteraction",
+            &[
+                ("responseIdentifier", RESPONSE),
+                ("shuffle", "false"),
+                ("maxChoices", if multiple { "0" } else { "1" }),
+            ],
+        );
+        xml.text("prompt", &[], &question.question_text);
+        for (answer, choice) in question.answers.iter().zip(&choices) {
+            Self::choice(
+                &mut xml,
+                "simpleChoice",
+                &[("identifier", choice)],
+                answer,
+                media,
+            );
+        }
+        xml.close().close();
+
+        let template = if multiple {
+            MAP_RESPONSE_TEMPLATE
+        } else {
+            MATCH_CORRECT_TEMPLATE
+        };
+        xml.empty("responseProcessing", &[("template", template)]);
+        xml.close();
+        xml.finish()
+    }
+
+    fn match_item(identifier: &str, question: &Question, media: &[Media]) -> String {
+        let options = question.options.clone().unwrap_or_default();
+        let pairs: Vec<String> = question
+            .answers
+            .iter()
+            .enumerate()
+            .filter_map(|(index, answer)| {
+                answer
+                    .assigned_option_id
+                    .map(|option| format!("answer_{} option_{option}", index + 1))
+            })
+            .collect();
+
+        let mut xml = XmlWriter::new();
+        Self::open_item(&mut xml, identifier, question);
+        xml.open(
+            "responseDeclaration",
+            &[
+                ("identifier", RESPONSE),
+                ("cardinality", "multiple"),
+                ("baseType", "directedPair"),
+            ],
+        );
+        xml.open("correctResponse", &[]);
+        for pair in &pairs {
+            xml.text("value", &[], pair);
+        }
+        xml.close();
+        Self::mapping(&mut xml, question, &pairs);
+        xml.close();
+        Self::outcomes(&mut xml, question);
+
+        let answer_count = question.answers.len().to_string();
+        xml.open("itemBody", &[]);
+        Self::question_media(&mut xml, media);
+        xml.open(
+            "matchInteraction",
+            &[
+                ("responseIdentifier", RESPONSE),
+                ("shuffle", "false"),
+                ("maxAssociations", &answer_count),
+            ],
+        );
+        xml.text("prompt", &[], &question.question_text);
+        xml.open("simpleMatchSet", &[]);
+        for (index, answer) in question.answers.iter().enumerate() {
+            Self::choice(
+                &mut xml,
+                "simpleAssociableChoice",
+                &[
+                    ("identifier", &format!("answer_{}", index + 1)),
+                    ("matchMax", "1"),
+                ],
+                answer,
+                media,
+            );
+        }
+        xml.close().open("simpleMatchSet", &[]);
+        for option in &options {
+            xml.text(
+                "simpleAssociableChoice",
+                &[
+                    ("identifier", &format!("option_{}", option.id)),
+                    ("matchMax", &answer_count),
+                ],
+                &option.text,
+            );
+        }
+        xml.close().close().close();
+
+        xml.empty("responseProcessing", &[("template", MAP_RESPONSE_TEMPLATE)]);
+        xml.close();
+        xml.finish()
+    }
+}
@@ -0,0 +1,181 @@
+use crate::domain::export::anki_note::AnkiNote;
+use crate::domain::import::text_exam_parser::TextExamParser;
+use crate::domain::model::answer::Answer;
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::category::Category;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::tests::fixtures::{self, answer};
+
+const TEMPLATE: &str = include_str!("../../../../../templates/import_template_txt.txt");
+
+fn category(name: &str) -> Option<Category> {
+    Some(Category {
+        id: None,
+        name: name.into(),
+        created_at: None,
+        updated_at: None,
+    })
+}
+
+fn exam() -> Exam {
+    TextExamParser::parse(TEMPLATE).unwrap().exam
+}
+
+fn question(text: &str, r#type: QuestionType, answers: Vec<Answer>) -> Question {
+    Question {
+        r#type,
+        answers,
+        ..fixtures::question(text)
+    }
+}
+
+#[test]
+fn it_should_show_choices_on_the_front_and_correct_answers_on_the_back() {
+    let mut exam = exam();
+    exam.questions[0].answers[2].description = Some("Every school defines it differently".into());
+    exam.questions[0].answers[0].description = Some("Would <only> hold for one system".into());
+
+    let note = AnkiNote::new(&exam, &exam.questions[0]);
+
+    assert!(note.front.contains("&quot;Concept A&quot;"));
+    assert!(note
+        .front
+        .contains("<ol type=\"a\"><li>Exactly one for all types of systems.</li>"));
+    assert!(note.back.starts_with(
+        "<ul><li class=\"correct\">A dozen or more different definitions.<div class=\"description\">Every school defines it differently</div></li>"
+    ));
+    assert!(note.back.contains("<li class=\"wrong\">Exactly one for all types of systems.<div class=\"description\">Would &lt;only&gt; hold for one system</div></li>"));
+    assert!(!note.back.contains("One for each type of system"));
+}
+
+#[test]
+fn it_should_write_assignments_numeric_answers_and_explanations() {
+    let exam = exam();
+
+    let assignment = AnkiNote::new(&exam, &exam.questions[2]);
+    assert!(assignment
+        .front
+        .contains("<ol type=\"a\"><li>Suitable</li><li>Not suitable</li></ol>"));
+    assert!(assignment.back.contains(
+        "<li class=\"correct\">The lead role coordinates the creation of the documentation. &rarr; Suitable</li>"
+    ));
+
+    let hosts = AnkiNote::new(&exam, &exam.questions[3]);
+    assert!(hosts.back.contains("<li class=\"correct\">62 hosts</li>"));
+    assert!(hosts
+        .back
+        .contains("<div class=\"explanation\">A /26 leaves **6 host bits**"));
+
+    let throughput = AnkiNote::new(&exam, &exam.questions[4]);
+    assert!(throughput
+        .back
+        .contains("<li class=\"correct\">950 &plusmn; 5 % Mbit/s</li>"));
+}
+
+#[test]
+fn it_should_hide_the_order_and_fill_cloze_blanks() {
+    let exam = exam();
+    let mut first = answer("Design", true);
+    first.position = Some(2);
+    let mut second = answer("Analysis", true);
+    second.position = Some(1);
+    let mut third = answer("Build", true);
+    third.position = Some(3);
+    let ordering = question(
+        "Order the phases",
+        QuestionType::Ordering,
+        vec![third, first, second],
+    );
+
+    let note = AnkiNote::new(&exam, &ordering);
+    assert!(note
+        .front
+        .ends_with("<ul><li>Analysis</li><li>Build</li><li>Design</li></ul>"));
+    assert!(note.back.starts_with("<ol><li class=\"correct\">Analysis</li><li class=\"correct\">Design</li><li class=\"correct\">Build</li></ol>"));
+
+    let blank = |text: &str, blank: i32, is_correct: bool, match_mode: Option<MatchMode>| Answer {
+        blank: Some(blank),
+        match_mode,
+        ..answer(text, is_correct)
+    };
+    let cloze = question(
+        "HTTPS runs on port {{1}} and uses {{2}} as transport.",
+        QuestionType::Cloze,
+        vec![
+            blank("443", 1, true, Some(MatchMode::Exact)),
+            blank("TCP", 2, true, None),
+            blank("UDP", 2, false, None),
+        ],
+    );
+
+    let note = AnkiNote::new(&exam, &cloze);
+    assert!(note
+        .front
+        .contains("HTTPS runs on port <b>[1]</b> and uses <b>[2]</b> as transport."));
+    assert!(note
+        .front
+        .contains("<div class=\"choices\">[2] TCP / UDP</div>"));
+    assert_eq!(note.back, "<ul><li>[1] 443</li><li>[2] TCP</li></ul>");
+}
+
+#[test]
+fn it_should_tag_notes_with_category_names() {
+    let mut exam = exam();
+    exam.category = category("Software Architecture");
+    exam.questions[0].category = category("Quality  Goals");
+    exam.questions[1].category = category("Software Architecture");
+
+    assert_eq!(
+        AnkiNote::new(&exam, &exam.questions[0]).tags,
+        vec!["Quality_Goals", "Software_Architecture"]
+    );
+    assert_eq!(
+        AnkiNote::new(&exam, &exam.questions[1]).tags,
+        vec!["Software_Architecture"]
+    );
+
+    exam.category = None;
+    assert!(AnkiNote::new(&exam, &exam.questions[2]).tags.is_empty());
+}
+
+#[test]
+fn it_should_show_images_of_the_question_and_its_answers() {
+    let mut exam = exam();
+    exam.questions[0].id = Some(1);
+    exam.questions[0].answers[1].id = Some(11);
+    let image = |question_id, answer_id| Attachment {
+        id: None,
+        question_id,
+        answer_id,
+        file_name: "diagram.png".into(),
+        mime_type: "image/png".into(),
+        hash: String::new(),
+        size: 0,
+        created_at: None,
+        data: None,
+    };
+    let question_image = image(Some(1), None);
+    let answer_image = image(None, Some(11));
+
+    let mut note = AnkiNote::new(&exam, &exam.questions[0]);
+    let back = note.back.clone();
+    note.add_images(
+        &exam.questions[0],
+        &[
+            (&answer_image, "answer.png".to_string()),
+            (&question_image, "question <1>.png".to_string()),
+        ],
+    );
+
+    assert!(note.front.contains(
+        "</div><div class=\"media\"><img src=\"question &lt;1&gt;.png\"></div><ol type=\"a\">"
+    ));
+    assert!(note.front.ends_with(&format!(
+        "<div class=\"media\">{}<br><img src=\"answer.png\"></div>",
+        AnkiNote::html(&exam.questions[0].answers[1].answer_text)
+    )));
+    assert_eq!(note.back, back);
+}
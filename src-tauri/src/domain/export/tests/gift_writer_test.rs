@@ -0,0 +1,96 @@
+use crate::domain::export::gift_writer::GiftWriter;
+use crate::domain::import::gift_parser::GiftParser;
+use crate::domain::import::text_exam_parser::TextExamParser;
+use crate::domain::model::category::Category;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+
+const TEMPLATE: &str = include_str!("../../../../../templates/import_template_txt.txt");
+
+fn exam() -> Exam {
+    let mut exam = TextExamParser::parse(TEMPLATE).unwrap().exam;
+    exam.questions[0].category = Some(Category {
+        id: None,
+        name: "Architecture".into(),
+        created_at: None,
+        updated_at: None,
+    });
+    exam
+}
+
+fn answers(question: &Question) -> Vec<(String, Option<bool>, Option<i32>)> {
+    question
+        .answers
+        .iter()
+        .map(|a| (a.answer_text.clone(), a.is_correct, a.assigned_option_id))
+        .collect()
+}
+
+#[test]
+fn it_should_write_questions_that_read_back() {
+    let exam = exam();
+    let exported = GiftWriter::write(&exam);
+    assert!(exported.skipped.is_empty());
+
+    let imported = GiftParser::parse(&String::from_utf8(exported.data).unwrap()).unwrap();
+
+    assert!(imported.skipped.is_empty());
+    assert_eq!(imported.exam.questions.len(), exam.questions.len());
+    for (original, imported) in exam.questions.iter().zip(&imported.exam.questions) {
+        assert_eq!(imported.question_text, original.question_text);
+        assert_eq!(imported.r#type, original.r#type);
+        assert_eq!(imported.explanation, original.explanation);
+        assert_eq!(
+            imported.category.as_ref().map(|c| &c.name),
+            original.category.as_ref().map(|c| &c.name)
+        );
+        if original.r#type != QuestionType::Numeric {
+            assert_eq!(answers(imported), answers(original));
+        }
+    }
+}
+
+#[test]
+fn it_should_write_categories_and_escape_special_characters() {
+    let mut exam = exam();
+    exam.questions.truncate(1);
+    exam.questions[0].question_text = "Is {a} = b: ~c #d?".into();
+
+    let gift = String::from_utf8(GiftWriter::write(&exam).data).unwrap();
+
+    assert!(gift.contains("$CATEGORY: $course$/top/Architecture\n"));
+    assert!(gift.contains("Is \\{a\\} \\= b\\: \\~c \\#d? {\n\t~Exactly one"));
+}
+
+#[test]
+fn it_should_report_questions_without_equivalent() {
+    let mut exam = exam();
+    exam.questions.truncate(1);
+    let mut regex = exam.questions[0].clone();
+    regex.r#type = QuestionType::ShortAnswer;
+    regex.question_text = "Which protocol secures HTTP?".into();
+    regex.answers.truncate(1);
+    regex.answers[0].answer_text = "(?i)tls|ssl".into();
+    regex.answers[0].match_mode = Some(MatchMode::Regex);
+    let mut ordering = exam.questions[0].clone();
+    ordering.r#type = QuestionType::Ordering;
+    exam.questions.extend([regex, ordering]);
+
+    let exported = GiftWriter::write(&exam);
+
+    let skipped: Vec<(i32, &str)> = exported
+        .skipped
+        .iter()
+        .map(|e| (e.index, e.message.as_str()))
+        .collect();
+    assert_eq!(skipped.len(), 2);
+    assert_eq!(skipped[0].0, 1);
+    assert!(skipped[0]
+        .1
+        .ends_with("answers given as regular expression have no GIFT equivalent"));
+    assert!(skipped[1]
+        .1
+        .ends_with("Ordering questions have no GIFT equivalent"));
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod qti_package_writer_test;
+#[cfg(test)]
+mod xml_writer_test;
+#[cfg(test)]
+mod gift_writer_test;
+#[cfg(test)]
+mod moodle_xml_writer_test;
+#[cfg(test)]
+mod anki_note_test;
@@ -0,0 +1,78 @@
+use crate::domain::export::moodle_xml_writer::MoodleXmlWriter;
+use crate::domain::import::moodle_xml_reader::MoodleXmlReader;
+use crate::domain::import::text_exam_parser::TextExamParser;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+
+const TEMPLATE: &str = include_str!("../../../../../templates/import_template_txt.txt");
+
+fn exam() -> Exam {
+    TextExamParser::parse(TEMPLATE).unwrap().exam
+}
+
+fn answers(question: &Question) -> Vec<(String, Option<bool>, Option<i32>)> {
+    question
+        .answers
+        .iter()
+        .map(|a| (a.answer_text.clone(), a.is_correct, a.assigned_option_id))
+        .collect()
+}
+
+#[test]
+fn it_should_write_a_quiz_that_reads_back() {
+    let exam = exam();
+    let exported = MoodleXmlWriter::write(&exam);
+    assert!(exported.skipped.is_empty());
+
+    let imported = MoodleXmlReader::read(&String::from_utf8(exported.data).unwrap()).unwrap();
+
+    assert!(imported.skipped.is_empty());
+    assert_eq!(imported.exam.questions.len(), exam.questions.len());
+    for (original, imported) in exam.questions.iter().zip(&imported.exam.questions) {
+        assert_eq!(imported.question_text, original.question_text);
+        assert_eq!(imported.r#type, original.r#type);
+        // points that don't divide among the correct parts come back as a point per part
+        if !matches!(
+            original.r#type,
+            QuestionType::MultipleChoice | QuestionType::Assignment
+        ) {
+            assert_eq!(imported.points_total, original.points_total);
+        }
+        assert_eq!(imported.explanation, original.explanation);
+        if original.r#type == QuestionType::Numeric {
+            let value = |q: &Question| q.answers[0].numeric.clone().unwrap().expected_value;
+            assert_eq!(value(imported), value(original));
+        } else {
+            assert_eq!(answers(imported), answers(original));
+        }
+    }
+}
+
+#[test]
+fn it_should_write_grades_as_fractions() {
+    let mut exam = exam();
+    exam.questions.truncate(2);
+
+    let xml = String::from_utf8(MoodleXmlWriter::write(&exam).data).unwrap();
+
+    assert!(xml.contains("<single>true</single>"));
+    assert!(xml.contains("<single>false</single>"));
+    assert!(xml.contains("<answer fraction=\"100\" format=\"plain_text\">"));
+    assert!(xml.contains("<answer fraction=\"0\" format=\"plain_text\">"));
+    assert!(xml.contains("<answer fraction=\"-"));
+}
+
+#[test]
+fn it_should_report_questions_without_equivalent() {
+    let mut exam = exam();
+    exam.questions[1].r#type = QuestionType::Ordering;
+
+    let exported = MoodleXmlWriter::write(&exam);
+
+    assert_eq!(exported.skipped.len(), 1);
+    assert_eq!(exported.skipped[0].index, 1);
+    assert!(exported.skipped[0]
+        .message
+        .ends_with("Ordering questions have no Moodle equivalent"));
+}
@@ -0,0 +1,205 @@
+use crate::domain::export::qti_package_writer::QtiPackageWriter;
+use crate::domain::import::qti_package_reader::QtiPackageReader;
+use crate::domain::import::text_exam_parser::TextExamParser;
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+const TEMPLATE: &str = include_str!("../../../../../templates/import_template_txt.txt");
+
+fn exam() -> Exam {
+    let mut exam = TextExamParser::parse(TEMPLATE).unwrap().exam;
+    exam.name = "Architecture <Foundation> & Basics".into();
+    exam.description = Some("Covers the basics of the architecture.".into());
+    exam.duration = Some(90);
+    exam
+}
+
+/// Exam with ids, as loaded from the database.
+fn exam_with_ids() -> Exam {
+    let mut exam = exam();
+    for (index, question) in exam.questions.iter_mut().enumerate() {
+        question.id = Some(index as i32 + 1);
+        for (n, answer) in question.answers.iter_mut().enumerate() {
+            answer.id = Some((index as i32 + 1) * 10 + n as i32);
+        }
+    }
+    exam
+}
+
+fn attachment(
+    question_id: Option<i32>,
+    answer_id: Option<i32>,
+    file_name: &str,
+    mime_type: &str,
+    content: Option<&[u8]>,
+) -> Attachment {
+    Attachment {
+        id: None,
+        question_id,
+        answer_id,
+        file_name: file_name.into(),
+        mime_type: mime_type.into(),
+        hash: String::new(),
+        size: 0,
+        created_at: None,
+        data: content.map(Attachment::encode_data),
+    }
+}
+
+fn read_entry(archive: &mut ZipArchive<Cursor<Vec<u8>>>, name: &str) -> Vec<u8> {
+    let mut content = vec![];
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_end(&mut content)
+        .unwrap();
+    content
+}
+
+fn answers(question: &Question) -> Vec<(String, Option<bool>, Option<i32>)> {
+    question
+        .answers
+        .iter()
+        .map(|a| (a.answer_text.clone(), a.is_correct, a.assigned_option_id))
+        .collect()
+}
+
+#[test]
+fn it_should_write_a_package_that_reads_back() {
+    let exam = exam();
+    let package = QtiPackageWriter::write(&exam, &[]).unwrap();
+
+    let imported = QtiPackageReader::read(&package.data).unwrap();
+
+    assert!(imported.skipped.is_empty());
+    assert_eq!(imported.exam.name, exam.name);
+    assert_eq!(imported.exam.description, exam.description);
+    assert_eq!(imported.exam.duration, Some(90));
+    assert_eq!(imported.exam.questions.len(), 3);
+    for (original, imported) in exam.questions.iter().zip(&imported.exam.questions) {
+        assert_eq!(imported.question_text, original.question_text);
+        assert_eq!(imported.r#type, original.r#type);
+        assert_eq!(imported.points_total, original.points_total);
+        assert_eq!(
+            imported.points_per_correct_answer,
+            original.points_per_correct_answer
+        );
+        assert_eq!(answers(imported), answers(original));
+    }
+    let options: Vec<String> = imported.exam.questions[2]
+        .options
+        .iter()
+        .flatten()
+        .map(|o| o.text.clone())
+        .collect();
+    assert_eq!(options, vec!["Suitable", "Not suitable"]);
+}
+
+#[test]
+fn it_should_report_questions_without_interaction() {
+    let package = QtiPackageWriter::write(&exam(), &[]).unwrap();
+
+    let skipped: Vec<(i32, &str)> = package
+        .skipped
+        .iter()
+        .map(|e| (e.index, e.message.as_str()))
+        .collect();
+    assert_eq!(
+        skipped,
+        vec![
+            (3, "Question `How many usable host addresses does a /26 subnet provide?` was not exported, Numeric questions have no QTI interaction"),
+            (4, "Question `Which effective throughput does a gigabit link reach after protocol overhead?` was not exported, Numeric questions have no QTI interaction"),
+        ]
+    );
+}
+
+#[test]
+fn it_should_list_test_and_items_in_the_manifest() {
+    let mut exam = exam();
+    exam.questions.truncate(2);
+    exam.questions[1].r#type = QuestionType::MultipleChoice;
+    let package = QtiPackageWriter::write(&exam, &[]).unwrap();
+
+    let mut archive = ZipArchive::new(Cursor::new(package.data)).unwrap();
+    let names: Vec<&str> = archive.file_names().collect();
+    assert_eq!(names.len(), 4);
+    let mut manifest = String::new();
+    archive
+        .by_name("imsmanifest.xml")
+        .unwrap()
+        .read_to_string(&mut manifest)
+        .unwrap();
+
+    assert!(manifest.contains(
+        r#"<resource identifier="test" type="imsqti_test_xmlv2p1" href="assessment.xml">"#
+    ));
+    assert!(manifest.contains(
+        r#"<resource identifier="item_2" type="imsqti_item_xmlv2p1" href="items/item_2.xml">"#
+    ));
+}
+
+#[test]
+fn it_should_write_and_reference_attachments() {
+    let exam = exam_with_ids();
+    let attachments = vec![
+        attachment(
+            Some(1),
+            None,
+            "network diagram.png",
+            "image/png",
+            Some(b"diagram"),
+        ),
+        attachment(
+            None,
+            Some(31),
+            "layers.pdf",
+            "application/pdf",
+            Some(b"layers"),
+        ),
+        attachment(Some(4), None, "subnet.png", "image/png", Some(b"subnet")),
+    ];
+    let package = QtiPackageWriter::write(&exam, &attachments).unwrap();
+
+    let mut archive = ZipArchive::new(Cursor::new(package.data.clone())).unwrap();
+    assert_eq!(
+        read_entry(&mut archive, "media/1_network_diagram.png"),
+        b"diagram"
+    );
+    assert_eq!(read_entry(&mut archive, "media/2_layers.pdf"), b"layers");
+    // Question 4 is skipped and so is its attachment.
+    assert_eq!(archive.len(), 7);
+
+    let item = String::from_utf8(read_entry(&mut archive, "items/item_1.xml")).unwrap();
+    assert!(
+        item.contains(r#"<img src="../media/1_network_diagram.png" alt="network diagram.png"/>"#)
+    );
+    let item = String::from_utf8(read_entry(&mut archive, "items/item_3.xml")).unwrap();
+    assert!(item.contains(r#"<object data="../media/2_layers.pdf" type="application/pdf"/>"#));
+    let manifest = String::from_utf8(read_entry(&mut archive, "imsmanifest.xml")).unwrap();
+    assert!(manifest.contains(r#"<file href="media/2_layers.pdf"/>"#));
+
+    // The references don't change the text of questions and answers.
+    let imported = QtiPackageReader::read(&package.data).unwrap();
+    for (original, imported) in exam.questions.iter().zip(&imported.exam.questions) {
+        assert_eq!(imported.question_text, original.question_text);
+        assert_eq!(answers(imported), answers(original));
+    }
+}
+
+#[test]
+fn it_should_report_attachments_without_content() {
+    let attachments = vec![attachment(None, Some(10), "empty.png", "image/png", None)];
+    let package = QtiPackageWriter::write(&exam_with_ids(), &attachments).unwrap();
+
+    assert_eq!(package.skipped.len(), 3);
+    assert_eq!(package.skipped[0].index, 0);
+    assert!(package.skipped[0]
+        .message
+        .starts_with("Attachment empty.png of question"));
+    let archive = ZipArchive::new(Cursor::new(package.data)).unwrap();
+    assert!(archive.file_names().all(|name| !name.starts_with("media/")));
+}
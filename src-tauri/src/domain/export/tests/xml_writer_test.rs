@@ -0,0 +1,45 @@
+use crate::domain::export::xml_writer::{escape_xml, XmlWriter};
+
+#[test]
+fn it_should_escape_markup() {
+    assert_eq!(
+        escape_xml(r#"Vec<T> & "str" 'a"#),
+        "Vec&lt;T&gt; &amp; &quot;str&quot; &apos;a"
+    );
+}
+
+#[test]
+fn it_should_write_nested_elements() {
+    let mut xml = XmlWriter::new();
+    xml.open("item", &[("title", "a < b")])
+        .text("prompt", &[], "Fish & Chips")
+        .empty("choice", &[("identifier", "A")])
+        .close();
+
+    assert_eq!(
+        xml.finish(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <item title=\"a &lt; b\">\n  \
+         <prompt>Fish &amp; Chips</prompt>\n  \
+         <choice identifier=\"A\"/>\n\
+         </item>\n"
+    );
+}
+
+#[test]
+fn it_should_write_mixed_content() {
+    let mut xml = XmlWriter::new();
+    xml.open("simpleChoice", &[])
+        .content("Fish & Chips")
+        .empty("img", &[("src", "media/1_fish.png")])
+        .close();
+
+    assert_eq!(
+        xml.finish(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <simpleChoice>\n  \
+         Fish &amp; Chips\n  \
+         <img src=\"media/1_fish.png\"/>\n\
+         </simpleChoice>\n"
+    );
+}
@@ -0,0 +1,92 @@
+/// Builds an indented XML document element by element, escaping text and attribute values.
+pub struct XmlWriter {
+    buffer: String,
+    open: Vec<String>,
+}
+
+impl Default for XmlWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlWriter {
+    pub fn new() -> Self {
+        Self {
+            buffer: String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"),
+            open: vec![],
+        }
+    }
+
+    /// Starts an element that is closed by the matching `close`.
+    pub fn open(&mut self, name: &str, attributes: &[(&str, &str)]) -> &mut Self {
+        self.start_tag(name, attributes);
+        self.buffer.push_str(">\n");
+        self.open.push(name.to_string());
+        self
+    }
+
+    pub fn close(&mut self) -> &mut Self {
+        let name = self.open.pop().expect("an open element to close");
+        self.indent();
+        self.buffer.push_str(&format!("</{name}>\n"));
+        self
+    }
+
+    /// Element with text content only.
+    pub fn text(&mut self, name: &str, attributes: &[(&str, &str)], text: &str) -> &mut Self {
+        self.start_tag(name, attributes);
+        self.buffer
+            .push_str(&format!(">{}</{name}>\n", escape_xml(text)));
+        self
+    }
+
+    /// Text node inside the open element, for elements with mixed content.
+    pub fn content(&mut self, text: &str) -> &mut Self {
+        self.indent();
+        self.buffer.push_str(&escape_xml(text));
+        self.buffer.push('\n');
+        self
+    }
+
+    pub fn empty(&mut self, name: &str, attributes: &[(&str, &str)]) -> &mut Self {
+        self.start_tag(name, attributes);
+        self.buffer.push_str("/>\n");
+        self
+    }
+
+    pub fn finish(self) -> String {
+        debug_assert!(self.open.is_empty(), "unclosed elements: {:?}", self.open);
+        self.buffer
+    }
+
+    fn start_tag(&mut self, name: &str, attributes: &[(&str, &str)]) {
+        self.indent();
+        self.buffer.push('<');
+        self.buffer.push_str(name);
+        for (key, value) in attributes {
+            self.buffer
+                .push_str(&format!(" {key}=\"{}\"", escape_xml(value)));
+        }
+    }
+
+    fn indent(&mut self) {
+        self.buffer.push_str(&"  ".repeat(self.open.len()));
+    }
+}
+
+/// Replaces the characters with a meaning in XML by their entities.
+pub fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
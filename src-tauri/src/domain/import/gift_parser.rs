@@ -0,0 +1,489 @@
+use crate::domain::import::imported_exam::imported_exam;
+use crate::domain::import::moodle_question::{
+    category_name, html_to_text, MoodleAnswer, MoodleQuestion,
+};
+use crate::domain::model::import_report::ImportReport;
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::question::Question;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+
+pub const IMPORTED_GIFT_EXAM_NAME: &str = "Imported GIFT questions";
+pub const GIFT_CATEGORY_PREFIX: &str = "$CATEGORY:";
+
+/// Characters with a meaning in GIFT, text contains them escaped as `\~`, `\=` and so on.
+pub const GIFT_SPECIAL_CHARACTERS: [char; 6] = ['~', '=', '#', '{', '}', ':'];
+
+/// Replaces the answer block of a question that continues after it, like Moodle shows
+/// missing word questions.
+const BLANK: &str = "_____";
+
+/// Lines of one question, joined by `\n`, and the line number each of them starts at.
+struct Item {
+    text: String,
+    line_starts: Vec<(usize, usize)>,
+}
+
+impl Item {
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let (start, line) = self
+            .line_starts
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= offset)
+            .copied()
+            .unwrap_or((0, 1));
+        (line, self.text[start..offset].chars().count() + 1)
+    }
+}
+
+/// Answer of the answer block before it is mapped.
+struct Token {
+    marker: char,
+    fraction: Option<f64>,
+    text: String,
+    feedback: Option<String>,
+    offset: usize,
+}
+
+/// Parser for the Moodle GIFT format.
+///
+/// Questions are separated by blank lines, `//` lines are comments and a `$CATEGORY:` line
+/// puts the following questions into the innermost category of its path. Multiple choice,
+/// matching, short answer and numerical questions are imported, true/false, essay and
+/// description items have no equivalent and are reported in `ImportReport::skipped`.
+/// Syntax errors fail the whole import with their line and column.
+pub struct GiftParser {
+    diagnostics: Vec<ExtendedValidationError>,
+    skipped: Vec<ExtendedValidationError>,
+    questions: Vec<Question>,
+    category: Option<String>,
+    index: usize,
+}
+
+impl GiftParser {
+    pub fn parse(content: &str) -> Result<ImportReport, ExtendedValidationError> {
+        let mut parser = GiftParser {
+            diagnostics: vec![],
+            skipped: vec![],
+            questions: vec![],
+            category: None,
+            index: 0,
+        };
+        for item in Self::split_items(content) {
+            parser.item(&item);
+        }
+
+        if !parser.diagnostics.is_empty() {
+            return Err(ExtendedValidationError::new(
+                0,
+                "GIFT import errors",
+                vec![],
+                parser.diagnostics,
+            ));
+        }
+        if parser.questions.is_empty() {
+            return Err(ExtendedValidationError::new(
+                0,
+                "The file does not contain any question that can be imported",
+                vec![],
+                parser.skipped,
+            ));
+        }
+
+        Ok(ImportReport {
+            exam: imported_exam(IMPORTED_GIFT_EXAM_NAME, None, None, parser.questions),
+            skipped: parser.skipped,
+        })
+    }
+
+    fn split_items(content: &str) -> Vec<Item> {
+        let mut items: Vec<Item> = vec![];
+        let mut current: Option<Item> = None;
+        for (index, raw) in content.lines().enumerate() {
+            let trimmed = raw.trim();
+            if trimmed.starts_with("//") {
+                continue;
+            }
+            if trimmed.is_empty() {
+                items.extend(current.take());
+                continue;
+            }
+            let item = current.get_or_insert_with(|| Item {
+                text: String::new(),
+                line_starts: vec![],
+            });
+            if !item.text.is_empty() {
+                item.text.push('\n');
+            }
+            item.line_starts.push((item.text.len(), index + 1));
+            item.text.push_str(raw);
+        }
+        items.extend(current);
+        items
+    }
+
+    fn report(&mut self, (line, column): (usize, usize), message: impl Into<String>) {
+        let index = self.diagnostics.len() as i32;
+        self.diagnostics
+            .push(ExtendedValidationError::new(index, message, vec![], vec![]).at(line, column));
+    }
+
+    /// Byte offset of the first `pattern` at or after `from` that is not escaped.
+    fn find_unescaped(text: &str, from: usize, pattern: &str) -> Option<usize> {
+        let mut escaped = false;
+        for (offset, c) in text[from..].char_indices() {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if text[from + offset..].starts_with(pattern) {
+                return Some(from + offset);
+            }
+        }
+        None
+    }
+
+    fn unescape(text: &str) -> String {
+        let mut unescaped = String::with_capacity(text.len());
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                unescaped.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some(next) if GIFT_SPECIAL_CHARACTERS.contains(&next) || next == '\\' => {
+                    unescaped.push(next)
+                }
+                Some(next) => {
+                    unescaped.push(c);
+                    unescaped.push(next);
+                }
+                None => unescaped.push(c),
+            }
+        }
+        unescaped
+    }
+
+    fn clean(text: &str, html: bool) -> String {
+        let text = Self::unescape(text);
+        if html {
+            html_to_text(&text)
+        } else {
+            text.split_whitespace().collect::<Vec<_>>().join(" ")
+        }
+    }
+
+    fn item(&mut self, item: &Item) {
+        let text = item.text.as_str();
+        let mut start = 0;
+        let first_line = text.lines().next().unwrap_or_default().trim_start();
+        if let Some(path) = first_line.strip_prefix(GIFT_CATEGORY_PREFIX) {
+            self.category = category_name(path);
+            match text.find('\n') {
+                Some(end) => start = end + 1,
+                None => return,
+            }
+        }
+        start += text[start..].len() - text[start..].trim_start().len();
+
+        let mut title: Option<String> = None;
+        if text[start..].starts_with("::") {
+            let Some(end) = Self::find_unescaped(text, start + 2, "::") else {
+                self.report(
+                    item.position(start),
+                    "The title is not closed, expected `::` after it",
+                );
+                return;
+            };
+            title = Some(Self::clean(&text[start + 2..end], false));
+            start = end + 2;
+            start += text[start..].len() - text[start..].trim_start().len();
+        }
+
+        let mut html = false;
+        if text[start..].starts_with('[') {
+            if let Some(end) = text[start..].find(']') {
+                let format = &text[start + 1..start + end];
+                if ["html", "moodle", "plain", "markdown"].contains(&format) {
+                    html = format == "html";
+                    start += end + 1;
+                }
+            }
+        }
+
+        let index = self.index;
+        self.index += 1;
+        let Some(open) = Self::find_unescaped(text, start, "{") else {
+            let name = title.unwrap_or_else(|| Self::clean(&text[start..], html));
+            self.skip(
+                item,
+                index,
+                &name,
+                "it has no answers, descriptions have no equivalent question type",
+            );
+            return;
+        };
+        let Some(close) = Self::find_unescaped(text, open + 1, "}") else {
+            self.report(
+                item.position(open),
+                "The answer block is not closed, expected `}`",
+            );
+            return;
+        };
+
+        let mut question_text = Self::clean(&text[start..open], html);
+        let continuation = Self::clean(&text[close + 1..], html);
+        if !continuation.is_empty() {
+            question_text = format!("{question_text} {BLANK} {continuation}")
+                .trim()
+                .to_string();
+        }
+        let name = title.unwrap_or_else(|| question_text.clone());
+        let question = MoodleQuestion {
+            text: question_text,
+            grade: 1.0,
+            general_feedback: None,
+            category: self.category.clone(),
+        };
+
+        match self.answers(item, open + 1, &text[open + 1..close], question) {
+            Some(Ok(question)) => self.questions.push(question),
+            Some(Err(reason)) => self.skip(item, index, &name, &reason),
+            None => {}
+        }
+    }
+
+    fn skip(&mut self, item: &Item, index: usize, name: &str, reason: &str) {
+        let (line, _) = item.position(0);
+        self.skipped.push(
+            ExtendedValidationError::new(
+                index as i32,
+                format!("Question `{name}` was not imported, {reason}"),
+                vec![],
+                vec![],
+            )
+            .at(line, 1),
+        );
+    }
+
+    /// Maps the answer block, `None` if it has syntax errors, which are reported.
+    fn answers(
+        &mut self,
+        item: &Item,
+        offset: usize,
+        block: &str,
+        mut question: MoodleQuestion,
+    ) -> Option<Result<Question, String>> {
+        let block = match Self::find_unescaped(block, 0, "####") {
+            Some(feedback) => {
+                question.general_feedback =
+                    Some(Self::clean(&block[feedback + 4..], false)).filter(|f| !f.is_empty());
+                &block[..feedback]
+            }
+            None => block,
+        };
+
+        let trimmed = block.trim();
+        let lead = block.len() - block.trim_start().len();
+        if trimmed.is_empty() {
+            return Some(Err(
+                "essay questions have no equivalent question type".to_string()
+            ));
+        }
+        if let Some(spec) = trimmed.strip_prefix('#') {
+            return self.numerical(item, offset + lead + 1, spec, question);
+        }
+        let keyword = &trimmed[..Self::find_unescaped(trimmed, 0, "#").unwrap_or(trimmed.len())];
+        if ["T", "TRUE", "F", "FALSE"].contains(&keyword.trim().to_uppercase().as_str()) {
+            return Some(Err(
+                "true/false questions have no equivalent question type".to_string()
+            ));
+        }
+
+        let tokens = self.tokens(item, offset, block)?;
+        if tokens.is_empty() {
+            self.report(
+                item.position(offset + lead),
+                "The answer block has no answers, expected answers starting with `=` or `~`",
+            );
+            return None;
+        }
+
+        if tokens.iter().any(|t| t.text.contains("->")) {
+            let mut pairs: Vec<(String, String)> = vec![];
+            for token in &tokens {
+                match token.text.split_once("->") {
+                    Some((sub_question, answer)) if token.marker == '=' => pairs.push((
+                        Self::clean(sub_question, false),
+                        Self::clean(answer, false),
+                    )),
+                    _ => self.report(
+                        item.position(token.offset),
+                        "Every answer of a matching question needs the form `=<sub question> -> <answer>`",
+                    ),
+                }
+            }
+            return (pairs.len() == tokens.len()).then(|| question.matching(pairs));
+        }
+
+        let has_right_answer = tokens.iter().any(|t| t.marker == '=');
+        let is_short_answer = tokens.iter().all(|t| t.marker == '=');
+        let answers: Vec<MoodleAnswer> = tokens
+            .into_iter()
+            .map(|token| MoodleAnswer {
+                text: Self::clean(&token.text, false),
+                fraction: token
+                    .fraction
+                    .unwrap_or(if token.marker == '=' { 100.0 } else { 0.0 }),
+                feedback: token.feedback,
+            })
+            .collect();
+        Some(if is_short_answer {
+            question.short_answer(answers, false)
+        } else {
+            question.multichoice(answers, has_right_answer)
+        })
+    }
+
+    /// Splits the answer block at the unescaped `=` and `~` markers.
+    fn tokens(&mut self, item: &Item, offset: usize, block: &str) -> Option<Vec<Token>> {
+        let mut markers: Vec<usize> = vec![];
+        let mut from = 0;
+        while let Some(position) = [
+            Self::find_unescaped(block, from, "="),
+            Self::find_unescaped(block, from, "~"),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+        {
+            markers.push(position);
+            from = position + 1;
+        }
+
+        let first = markers.first().copied().unwrap_or(block.len());
+        let before = block[..first].trim();
+        if !before.is_empty() {
+            let lead = block.len() - block.trim_start().len();
+            self.report(
+                item.position(offset + lead),
+                format!(
+                    "Unexpected text `{before}` in the answer block, answers start with `=` or `~`"
+                ),
+            );
+            return None;
+        }
+
+        let mut tokens: Vec<Token> = vec![];
+        let mut valid = true;
+        for (index, position) in markers.iter().enumerate() {
+            let end = markers.get(index + 1).copied().unwrap_or(block.len());
+            let marker = block[*position..].chars().next().unwrap_or('=');
+            match self.token(item, marker, &block[position + 1..end], offset + position) {
+                Some(token) => tokens.push(token),
+                None => valid = false,
+            }
+        }
+        valid.then_some(tokens)
+    }
+
+    /// Answer with its optional `%<percent>%` grade and `#<feedback>`.
+    fn token(&mut self, item: &Item, marker: char, raw: &str, offset: usize) -> Option<Token> {
+        let mut text = raw.trim_start();
+        let mut fraction: Option<f64> = None;
+        if let Some(weighted) = text.strip_prefix('%') {
+            let grade = weighted
+                .split_once('%')
+                .and_then(|(grade, rest)| Some((grade.trim().parse::<f64>().ok()?, rest)));
+            let Some((grade, rest)) = grade else {
+                self.report(
+                    item.position(offset),
+                    "Malformed grade, expected `%<percent>%` in front of the answer",
+                );
+                return None;
+            };
+            fraction = Some(grade);
+            text = rest;
+        }
+
+        let (text, feedback) = match Self::find_unescaped(text, 0, "#") {
+            Some(feedback) => (
+                &text[..feedback],
+                Some(Self::clean(&text[feedback + 1..], false)).filter(|f| !f.is_empty()),
+            ),
+            None => (text, None),
+        };
+        Some(Token {
+            marker,
+            fraction,
+            text: text.to_string(),
+            feedback,
+            offset,
+        })
+    }
+
+    fn numerical(
+        &mut self,
+        item: &Item,
+        offset: usize,
+        spec: &str,
+        question: MoodleQuestion,
+    ) -> Option<Result<Question, String>> {
+        let tokens = if Self::find_unescaped(spec, 0, "=").is_some() {
+            self.tokens(item, offset, spec)?
+        } else {
+            vec![self.token(item, '=', spec, offset)?]
+        };
+
+        let mut answers: Vec<(MoodleAnswer, NumericAnswer)> = vec![];
+        let mut valid = true;
+        for token in tokens {
+            let Some(numeric) = Self::numeric(token.text.trim()) else {
+                self.report(
+                    item.position(token.offset),
+                    format!(
+                        "Malformed number `{}`, expected `<value>`, `<value>:<tolerance>` or `<min>..<max>`",
+                        token.text.trim()
+                    ),
+                );
+                valid = false;
+                continue;
+            };
+            answers.push((
+                MoodleAnswer {
+                    text: numeric.expected_value.to_string(),
+                    fraction: token.fraction.unwrap_or(if token.marker == '=' {
+                        100.0
+                    } else {
+                        0.0
+                    }),
+                    feedback: token.feedback,
+                },
+                numeric,
+            ));
+        }
+        valid.then(|| question.numerical(answers))
+    }
+
+    /// `<value>`, `<value>:<tolerance>` or `<min>..<max>`.
+    fn numeric(spec: &str) -> Option<NumericAnswer> {
+        let number = |value: &str| value.trim().parse::<f64>().ok();
+        let (expected_value, tolerance) = if let Some((min, max)) = spec.split_once("..") {
+            let (min, max) = (number(min)?, number(max)?);
+            ((min + max) / 2.0, (max - min).abs() / 2.0)
+        } else if let Some((value, tolerance)) = spec.split_once(':') {
+            (number(value)?, number(tolerance)?)
+        } else {
+            (number(spec)?, 0.0)
+        };
+        Some(NumericAnswer {
+            expected_value,
+            tolerance,
+            tolerance_mode: ToleranceMode::Absolute,
+            unit: None,
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::status_type::StatusType;
+
+/// Share of the total points needed to pass an imported exam.
+const PASSING_RATIO: f64 = 0.7;
+const DEFAULT_DURATION: i32 = 30;
+const DEFAULT_MAX_QUESTIONS_REAL_EXAM: i32 = 30;
+
+/// Draft exam around questions read from another format. Formats without a pass mark get
+/// 70 % of the total points, formats without a time limit 30 minutes.
+pub fn imported_exam(
+    name: impl Into<String>,
+    description: Option<String>,
+    duration: Option<i32>,
+    questions: Vec<Question>,
+) -> Exam {
+    let points_total: i32 = questions.iter().map(|q| q.points_total).sum();
+    Exam {
+        id: None,
+        name: name.into(),
+        description,
+        points_to_succeeded: Some((f64::from(points_total) * PASSING_RATIO).round() as i32),
+        duration: Some(duration.unwrap_or(DEFAULT_DURATION)),
+        status_type: Some(StatusType::Draft),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: Some(DEFAULT_MAX_QUESTIONS_REAL_EXAM),
+        questions,
+    }
+}
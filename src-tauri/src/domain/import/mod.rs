@@ -0,0 +1,10 @@
+pub mod gift_parser;
+pub mod imported_exam;
+pub mod moodle_question;
+pub mod moodle_xml_reader;
+pub mod qti_package_reader;
+pub mod spreadsheet_parser;
+pub mod text_exam_parser;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,332 @@
+use crate::domain::model::answer::Answer;
+use crate::domain::model::assignment_option::AssignmentOption;
+use crate::domain::model::category::Category;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Grades within this distance count as equal, Moodle stores them with seven decimals.
+const GRADE_EPSILON: f64 = 0.01;
+
+/// Tags that separate words when the HTML is rendered.
+static BLOCK_TAG: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)</?(p|br|div|li|ul|ol|tr|td|th|h[1-6]|table)\b[^>]*>")
+        .expect("valid tag pattern")
+});
+/// Any HTML tag.
+pub(crate) static TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<[^>]*>").expect("valid tag pattern"));
+static NUMERIC_ENTITY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"&#(x[0-9a-fA-F]+|\d+);").expect("valid entity pattern"));
+
+/// Answer of a Moodle question with its grade in percent of the question's mark.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoodleAnswer {
+    pub text: String,
+    pub fraction: f64,
+    pub feedback: Option<String>,
+}
+
+/// The parts GIFT and Moodle XML have in common, mapped to a `Question` by the method
+/// of the Moodle question type. Each method fails with the reason if the question uses
+/// something the matching question type can't express.
+#[derive(Debug, Clone, Default)]
+pub struct MoodleQuestion {
+    pub text: String,
+    /// Mark of the whole question.
+    pub grade: f64,
+    pub general_feedback: Option<String>,
+    /// Name of the innermost category of the Moodle category path.
+    pub category: Option<String>,
+}
+
+impl MoodleQuestion {
+    /// `single` multichoice questions become single choice questions, their one answer
+    /// graded 100 % is the correct one. Other multichoice questions become multiple
+    /// choice questions where every positively graded answer is correct. Those answers
+    /// must share the grade equally, negative grades are dropped since every wrong choice
+    /// already cancels a correct one.
+    pub fn multichoice(self, answers: Vec<MoodleAnswer>, single: bool) -> Result<Question, String> {
+        if single {
+            if let Some(partial) = answers.iter().find(|a| Self::is_partial(a.fraction)) {
+                return Err(format!(
+                    "it gives {}% for `{}`, single choice questions have no partial credit",
+                    Self::percent(partial.fraction),
+                    partial.text
+                ));
+            }
+            let correct = answers.iter().filter(|a| Self::is_full(a.fraction)).count();
+            if correct != 1 {
+                return Err(format!(
+                    "it has {correct} answers graded 100%, a single choice question needs exactly one"
+                ));
+            }
+            let mut question = self.question(QuestionType::SingleChoice, Self::points(self.grade));
+            question.points_per_correct_answer = Some(0);
+            question.answers = answers
+                .into_iter()
+                .map(|a| Self::choice(a.text, Self::is_full(a.fraction), a.feedback))
+                .collect();
+            return Ok(question);
+        }
+
+        let grades: Vec<f64> = answers
+            .iter()
+            .map(|a| a.fraction)
+            .filter(|fraction| *fraction > 0.0)
+            .collect();
+        let Some(first) = grades.first() else {
+            return Err("none of its answers is graded positive".to_string());
+        };
+        if grades.iter().any(|g| (g - first).abs() > GRADE_EPSILON) {
+            let grades: Vec<String> = grades
+                .iter()
+                .map(|g| format!("{}%", Self::percent(*g)))
+                .collect();
+            return Err(format!(
+                "its correct answers are graded differently ({}), but each correct answer is worth the same points",
+                grades.join(", ")
+            ));
+        }
+        let (points_total, per_answer) = self.shares(grades.len());
+        let mut question = self.question(QuestionType::MultipleChoice, points_total);
+        question.points_per_correct_answer = Some(per_answer);
+        question.answers = answers
+            .into_iter()
+            .map(|a| Self::choice(a.text, a.fraction > 0.0, a.feedback))
+            .collect();
+        Ok(question)
+    }
+
+    /// Pairs of sub question and its answer. Sub questions without text only add their
+    /// answer as a distractor option.
+    pub fn matching(self, pairs: Vec<(String, String)>) -> Result<Question, String> {
+        let mut options: Vec<AssignmentOption> = vec![];
+        let mut answers: Vec<Answer> = vec![];
+        for (sub_question, option_text) in pairs {
+            if option_text.is_empty() {
+                return Err(format!("the sub question `{sub_question}` has no answer"));
+            }
+            let option_id = match options.iter().find(|o| o.text == option_text) {
+                Some(option) => option.id,
+                None => {
+                    let id = options.len() as i32 + 1;
+                    options.push(AssignmentOption {
+                        row_id: None,
+                        id,
+                        text: option_text,
+                        question_id: None,
+                    });
+                    id
+                }
+            };
+            if !sub_question.is_empty() {
+                let mut answer = Self::answer(sub_question);
+                answer.assigned_option_id = Some(option_id);
+                answers.push(answer);
+            }
+        }
+        if answers.is_empty() {
+            return Err("it has no sub questions".to_string());
+        }
+
+        let (points_total, per_answer) = self.shares(answers.len());
+        let mut question = self.question(QuestionType::Assignment, points_total);
+        question.points_per_correct_answer = Some(per_answer);
+        question.answers = answers;
+        question.options = Some(options);
+        Ok(question)
+    }
+
+    /// Answers graded 100 % are accepted. A `*` in an answer matches any text, like it does
+    /// in Moodle.
+    pub fn short_answer(
+        self,
+        answers: Vec<MoodleAnswer>,
+        case_sensitive: bool,
+    ) -> Result<Question, String> {
+        let answers = Self::accepted(answers)?;
+        let mut question = self.question(QuestionType::ShortAnswer, Self::points(self.grade));
+        question.points_per_correct_answer = Some(0);
+        question.answers = answers
+            .into_iter()
+            .map(|a| {
+                let (text, match_mode) = if a.text.contains('*') {
+                    let pattern: Vec<String> = a.text.split('*').map(regex::escape).collect();
+                    let flags = if case_sensitive { "" } else { "(?i)" };
+                    (format!("{flags}{}", pattern.join(".*")), MatchMode::Regex)
+                } else if case_sensitive {
+                    (a.text, MatchMode::Exact)
+                } else {
+                    (a.text, MatchMode::CaseInsensitive)
+                };
+                let mut answer = Self::choice(text, true, a.feedback);
+                answer.match_mode = Some(match_mode);
+                answer
+            })
+            .collect();
+        Ok(question)
+    }
+
+    /// Answers graded 100 % are the accepted values, each with its absolute tolerance.
+    pub fn numerical(
+        self,
+        answers: Vec<(MoodleAnswer, NumericAnswer)>,
+    ) -> Result<Question, String> {
+        let (answers, values): (Vec<MoodleAnswer>, Vec<NumericAnswer>) = answers
+            .into_iter()
+            .filter(|(a, _)| a.fraction != 0.0)
+            .unzip();
+        let answers = Self::accepted(answers)?;
+        let mut question = self.question(QuestionType::Numeric, Self::points(self.grade));
+        question.points_per_correct_answer = Some(0);
+        question.answers = answers
+            .into_iter()
+            .zip(values)
+            .map(|(a, numeric)| {
+                let mut answer = Self::choice(a.text, true, a.feedback);
+                answer.numeric = Some(numeric);
+                answer
+            })
+            .collect();
+        Ok(question)
+    }
+
+    fn accepted(answers: Vec<MoodleAnswer>) -> Result<Vec<MoodleAnswer>, String> {
+        if let Some(partial) = answers.iter().find(|a| Self::is_partial(a.fraction)) {
+            return Err(format!(
+                "it gives {}% for `{}`, accepted answers have no partial credit",
+                Self::percent(partial.fraction),
+                partial.text
+            ));
+        }
+        let accepted: Vec<MoodleAnswer> = answers
+            .into_iter()
+            .filter(|a| Self::is_full(a.fraction))
+            .collect();
+        if accepted.is_empty() {
+            return Err("none of its answers is graded 100%".to_string());
+        }
+        Ok(accepted)
+    }
+
+    fn is_full(fraction: f64) -> bool {
+        (fraction - 100.0).abs() < GRADE_EPSILON
+    }
+
+    fn is_partial(fraction: f64) -> bool {
+        fraction > 0.0 && !Self::is_full(fraction)
+    }
+
+    /// Grade without trailing zeros, e.g. `33.33333` or `50`.
+    pub fn percent(fraction: f64) -> String {
+        let formatted = format!("{fraction:.5}");
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    }
+
+    fn points(grade: f64) -> i32 {
+        (grade.round() as i32).max(1)
+    }
+
+    /// Points of the question and of each of its `count` correct parts. Moodle splits the
+    /// mark into fractions, points are whole numbers, so a mark that doesn't divide into
+    /// whole points gives one point per part.
+    fn shares(&self, count: usize) -> (i32, i32) {
+        let per_part = self.grade / count as f64;
+        if per_part >= 1.0 && (per_part - per_part.round()).abs() < GRADE_EPSILON {
+            let per_part = per_part.round() as i32;
+            (per_part * count as i32, per_part)
+        } else {
+            (count as i32, 1)
+        }
+    }
+
+    fn question(&self, r#type: QuestionType, points_total: i32) -> Question {
+        Question {
+            id: None,
+            question_text: self.text.clone(),
+            points_total,
+            r#type,
+            answers: vec![],
+            points_per_correct_answer: None,
+            category: self.category.clone().map(|name| Category {
+                id: None,
+                name,
+                created_at: None,
+                updated_at: None,
+            }),
+            created_at: None,
+            updated_at: None,
+            options: Some(vec![]),
+            ordering_scoring: None,
+            explanation: self.general_feedback.clone(),
+            sources: None,
+            exam_id: None,
+        }
+    }
+
+    fn answer(answer_text: String) -> Answer {
+        Answer {
+            id: None,
+            answer_text,
+            description: None,
+            is_correct: None,
+            assigned_option_id: None,
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
+            created_at: None,
+            updated_at: None,
+            question_id: None,
+        }
+    }
+
+    fn choice(text: String, is_correct: bool, feedback: Option<String>) -> Answer {
+        let mut answer = Self::answer(text);
+        answer.is_correct = Some(is_correct);
+        answer.description = feedback;
+        answer
+    }
+}
+
+/// Plain text of the HTML Moodle stores question texts in: tags are dropped, the common
+/// entities decoded and whitespace collapsed.
+pub fn html_to_text(html: &str) -> String {
+    let text = BLOCK_TAG.replace_all(html, " ");
+    let text = TAG.replace_all(&text, "");
+    let text = NUMERIC_ENTITY.replace_all(&text, |captures: &regex::Captures| {
+        let code = &captures[1];
+        let value = match code.strip_prefix('x') {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => code.parse::<u32>().ok(),
+        };
+        value
+            .and_then(char::from_u32)
+            .map(String::from)
+            .unwrap_or_default()
+    });
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Innermost category of a Moodle category path like `$course$/top/Networking/Routing`,
+/// `None` for the default categories.
+pub fn category_name(path: &str) -> Option<String> {
+    path.split('/')
+        .map(str::trim)
+        .rfind(|segment| !segment.is_empty() && !segment.starts_with('$') && *segment != "top")
+        .map(str::to_string)
+}
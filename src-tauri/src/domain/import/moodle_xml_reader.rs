@@ -0,0 +1,206 @@
+use crate::domain::import::imported_exam::imported_exam;
+use crate::domain::import::moodle_question::{
+    category_name, html_to_text, MoodleAnswer, MoodleQuestion,
+};
+use crate::domain::model::import_report::ImportReport;
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::question::Question;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+use roxmltree::{Document, Node};
+
+pub const IMPORTED_MOODLE_EXAM_NAME: &str = "Imported Moodle questions";
+
+/// Reader for Moodle XML quiz files.
+///
+/// `category` entries put the following questions into the innermost category of their
+/// path. `multichoice`, `matching`, `shortanswer` and `numerical` questions are imported,
+/// every other question type is reported in `ImportReport::skipped` with the line of the
+/// question.
+pub struct MoodleXmlReader();
+
+impl MoodleXmlReader {
+    pub fn read(content: &str) -> Result<ImportReport, ExtendedValidationError> {
+        let document = Document::parse(content).map_err(|e| {
+            let position = e.pos();
+            ExtendedValidationError::new(
+                0,
+                format!("The file is not well-formed XML: {e}"),
+                vec![],
+                vec![],
+            )
+            .at(position.row as usize, position.col as usize)
+        })?;
+        let root = document.root_element();
+        if !root.has_tag_name("quiz") {
+            return Err(ExtendedValidationError::new(
+                0,
+                "The file is not a Moodle XML quiz, expected a `quiz` root element",
+                vec![],
+                vec![],
+            ));
+        }
+
+        let mut category: Option<String> = None;
+        let mut questions: Vec<Question> = vec![];
+        let mut skipped: Vec<ExtendedValidationError> = vec![];
+        let mut index = 0;
+        for node in root.children().filter(|n| n.has_tag_name("question")) {
+            if node.attribute("type") == Some("category") {
+                category = Self::text(node, "category").and_then(|path| category_name(&path));
+                continue;
+            }
+            match Self::question(node, category.clone()) {
+                Ok(question) => questions.push(question),
+                Err(reason) => {
+                    let name = Self::text(node, "name").unwrap_or_default();
+                    let position = document.text_pos_at(node.range().start);
+                    skipped.push(
+                        ExtendedValidationError::new(
+                            index,
+                            format!("Question `{name}` was not imported, {reason}"),
+                            vec![],
+                            vec![],
+                        )
+                        .at(position.row as usize, position.col as usize),
+                    );
+                }
+            }
+            index += 1;
+        }
+
+        if questions.is_empty() {
+            return Err(ExtendedValidationError::new(
+                0,
+                "The file does not contain any question that can be imported",
+                vec![],
+                skipped,
+            ));
+        }
+
+        Ok(ImportReport {
+            exam: imported_exam(IMPORTED_MOODLE_EXAM_NAME, None, None, questions),
+            skipped,
+        })
+    }
+
+    fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+        node.children().find(|n| n.has_tag_name(name))
+    }
+
+    /// Content of the `<text>` of the element, converted according to its `format`.
+    /// Markdown keeps its line breaks, HTML is reduced to plain text.
+    fn formatted(node: Node) -> Option<String> {
+        let text = Self::child(node, "text")?.text().unwrap_or_default();
+        let text = match node.attribute("format") {
+            Some("html") => html_to_text(text),
+            Some("markdown") => text.trim().to_string(),
+            _ => text.split_whitespace().collect::<Vec<_>>().join(" "),
+        };
+        Some(text).filter(|t| !t.is_empty())
+    }
+
+    fn text(node: Node, name: &str) -> Option<String> {
+        Self::child(node, name).and_then(Self::formatted)
+    }
+
+    fn value(node: Node, name: &str) -> Option<String> {
+        Self::child(node, name)
+            .and_then(|n| n.text())
+            .map(|t| t.trim().to_string())
+    }
+
+    fn flag(node: Node, name: &str) -> bool {
+        matches!(Self::value(node, name).as_deref(), Some("true" | "1"))
+    }
+
+    fn answers(node: Node) -> Vec<MoodleAnswer> {
+        node.children()
+            .filter(|n| n.has_tag_name("answer"))
+            .map(|answer| MoodleAnswer {
+                text: Self::formatted(answer).unwrap_or_default(),
+                fraction: answer
+                    .attribute("fraction")
+                    .and_then(|f| f.parse::<f64>().ok())
+                    .unwrap_or(0.0),
+                feedback: Self::text(answer, "feedback"),
+            })
+            .collect()
+    }
+
+    fn question(node: Node, category: Option<String>) -> Result<Question, String> {
+        let kind = node.attribute("type").unwrap_or_default();
+        let question = MoodleQuestion {
+            text: Self::text(node, "questiontext").unwrap_or_default(),
+            grade: Self::value(node, "defaultgrade")
+                .and_then(|g| g.parse::<f64>().ok())
+                .unwrap_or(1.0),
+            general_feedback: Self::text(node, "generalfeedback"),
+            category,
+        };
+
+        match kind {
+            "multichoice" => question.multichoice(Self::answers(node), Self::flag(node, "single")),
+            "matching" => question.matching(
+                node.children()
+                    .filter(|n| n.has_tag_name("subquestion"))
+                    .map(|sub| {
+                        (
+                            Self::formatted(sub).unwrap_or_default(),
+                            Self::text(sub, "answer").unwrap_or_default(),
+                        )
+                    })
+                    .collect(),
+            ),
+            "shortanswer" => {
+                question.short_answer(Self::answers(node), Self::flag(node, "usecase"))
+            }
+            "numerical" => {
+                let unit = Self::child(node, "units")
+                    .into_iter()
+                    .flat_map(|units| units.children().filter(|n| n.has_tag_name("unit")))
+                    .find(|unit| {
+                        Self::value(*unit, "multiplier").and_then(|m| m.parse::<f64>().ok())
+                            == Some(1.0)
+                    })
+                    .and_then(|unit| Self::value(unit, "unit_name"))
+                    .filter(|name| !name.is_empty());
+                let mut answers: Vec<(MoodleAnswer, NumericAnswer)> = vec![];
+                for (answer, node) in Self::answers(node)
+                    .into_iter()
+                    .zip(node.children().filter(|n| n.has_tag_name("answer")))
+                {
+                    // `*` grades any other response
+                    if answer.text == "*" {
+                        continue;
+                    }
+                    let expected_value = answer
+                        .text
+                        .parse::<f64>()
+                        .map_err(|_| format!("its answer `{}` is not a number", answer.text))?;
+                    let tolerance = Self::value(node, "tolerance")
+                        .and_then(|t| t.parse::<f64>().ok())
+                        .unwrap_or(0.0);
+                    let text = match &unit {
+                        Some(unit) => format!("{} {unit}", answer.text),
+                        None => answer.text.clone(),
+                    };
+                    answers.push((
+                        MoodleAnswer { text, ..answer },
+                        NumericAnswer {
+                            expected_value,
+                            tolerance,
+                            tolerance_mode: ToleranceMode::Absolute,
+                            unit: unit.clone(),
+                        },
+                    ));
+                }
+                question.numerical(answers)
+            }
+            "truefalse" => Err("true/false questions have no equivalent question type".to_string()),
+            _ => Err(format!(
+                "`{kind}` questions have no equivalent question type"
+            )),
+        }
+    }
+}
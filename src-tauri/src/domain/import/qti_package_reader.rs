@@ -0,0 +1,479 @@
+use crate::domain::export::qti_package_writer::{
+    QTI_ITEM_RESOURCE, QTI_MANIFEST, QTI_TEST_RESOURCE,
+};
+use crate::domain::import::imported_exam::imported_exam;
+use crate::domain::model::answer::Answer;
+use crate::domain::model::assignment_option::AssignmentOption;
+use crate::domain::model::import_report::ImportReport;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+use roxmltree::{Document, Node};
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+pub const IMPORTED_QTI_EXAM_NAME: &str = "Imported QTI package";
+
+const SUPPORTED_INTERACTIONS: [&str; 3] = [
+    "choiceInteraction",
+    "matchInteraction",
+    "associateInteraction",
+];
+
+/// Reader for IMS QTI 2.1 content packages: a zip with an `imsmanifest.xml` listing the
+/// `assessmentItem` files and optionally an `assessmentTest`.
+///
+/// `choiceInteraction` becomes a single or multiple choice question depending on the
+/// cardinality of its response, `matchInteraction` and `associateInteraction` become
+/// assignment questions. Items with any other interaction, or that can't be read, are
+/// reported in `ImportReport::skipped` with the reason instead of being dropped.
+pub struct QtiPackageReader {
+    archive: ZipArchive<Cursor<Vec<u8>>>,
+}
+
+impl QtiPackageReader {
+    pub fn read(data: &[u8]) -> Result<ImportReport, ExtendedValidationError> {
+        let archive = ZipArchive::new(Cursor::new(data.to_vec())).map_err(|e| {
+            Self::error(format!(
+                "The file is not a QTI package, it can't be opened as zip: {e}"
+            ))
+        })?;
+        let mut reader = QtiPackageReader { archive };
+
+        let manifest = reader
+            .entry(QTI_MANIFEST)
+            .map_err(|e| Self::error(format!("The package can't be imported: {e}")))?;
+        let manifest = Document::parse(&manifest).map_err(|e| {
+            Self::error(format!(
+                "The package can't be imported, `{QTI_MANIFEST}` is not well-formed: {e}"
+            ))
+        })?;
+
+        let test_href = Self::resources(&manifest, QTI_TEST_RESOURCE)
+            .into_iter()
+            .next();
+        let mut name = IMPORTED_QTI_EXAM_NAME.to_string();
+        let mut description: Option<String> = None;
+        let mut duration: Option<i32> = None;
+        let item_hrefs = match test_href {
+            Some(test_href) => {
+                let test = reader
+                    .entry(&test_href)
+                    .map_err(|e| Self::error(format!("The package can't be imported: {e}")))?;
+                let test = Document::parse(&test).map_err(|e| {
+                    Self::error(format!(
+                        "The package can't be imported, `{test_href}` is not well-formed: {e}"
+                    ))
+                })?;
+                let root = test.root_element();
+                if let Some(title) = root.attribute("title").filter(|t| !t.trim().is_empty()) {
+                    name = title.trim().to_string();
+                }
+                description = Self::descendant(root, "rubricBlock")
+                    .map(Self::text)
+                    .filter(|text| !text.is_empty());
+                duration = Self::descendant(root, "timeLimits")
+                    .and_then(|limits| limits.attribute("maxTime"))
+                    .and_then(|seconds| seconds.parse::<f64>().ok())
+                    .map(|seconds| (seconds / 60.0).round() as i32);
+                root.descendants()
+                    .filter(|n| n.has_tag_name("assessmentItemRef"))
+                    .filter_map(|n| n.attribute("href"))
+                    .map(|href| Self::resolve(&test_href, href))
+                    .collect()
+            }
+            None => Self::resources(&manifest, QTI_ITEM_RESOURCE),
+        };
+
+        let mut questions: Vec<Question> = vec![];
+        let mut skipped: Vec<ExtendedValidationError> = vec![];
+        for (index, href) in item_hrefs.iter().enumerate() {
+            match reader.item(href) {
+                Ok(question) => questions.push(question),
+                Err(reason) => skipped.push(ExtendedValidationError::new(
+                    index as i32,
+                    format!("Item `{href}` was not imported, {reason}"),
+                    vec![],
+                    vec![],
+                )),
+            }
+        }
+
+        if questions.is_empty() {
+            return Err(ExtendedValidationError::new(
+                0,
+                "The QTI package does not contain any item that can be imported",
+                vec![],
+                skipped,
+            ));
+        }
+
+        Ok(ImportReport {
+            exam: imported_exam(name, description, duration, questions),
+            skipped,
+        })
+    }
+
+    fn error(message: impl Into<String>) -> ExtendedValidationError {
+        ExtendedValidationError::new(0, message, vec![], vec![])
+    }
+
+    fn entry(&mut self, name: &str) -> Result<String, String> {
+        let mut file = self
+            .archive
+            .by_name(name)
+            .map_err(|_| format!("`{name}` is missing in the package"))?;
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .map_err(|e| format!("`{name}` can't be read: {e}"))?;
+        Ok(content)
+    }
+
+    /// `href` of the manifest resources of the given type, in manifest order.
+    fn resources(manifest: &Document, resource_type: &str) -> Vec<String> {
+        manifest
+            .descendants()
+            .filter(|n| n.has_tag_name("resource"))
+            .filter(|n| n.attribute("type") == Some(resource_type))
+            .filter_map(|n| n.attribute("href"))
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Path within the package of `href`, which is relative to the file `base`.
+    fn resolve(base: &str, href: &str) -> String {
+        let mut segments: Vec<&str> = base.split('/').collect();
+        segments.pop();
+        for segment in href.split('/') {
+            match segment {
+                "." | "" => {}
+                ".." => {
+                    segments.pop();
+                }
+                _ => segments.push(segment),
+            }
+        }
+        segments.join("/")
+    }
+
+    fn descendant<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+        node.descendants().find(|n| n.has_tag_name(name))
+    }
+
+    fn children<'a, 'input>(
+        node: Node<'a, 'input>,
+        name: &'a str,
+    ) -> impl Iterator<Item = Node<'a, 'input>> {
+        node.children().filter(move |n| n.has_tag_name(name))
+    }
+
+    /// Text content with the whitespace of the XML layout collapsed.
+    fn text(node: Node) -> String {
+        Self::collapse(
+            node.descendants()
+                .filter(|n| n.is_text())
+                .filter_map(|n| n.text()),
+        )
+    }
+
+    fn collapse<'a>(parts: impl Iterator<Item = &'a str>) -> String {
+        parts
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn item(&mut self, href: &str) -> Result<Question, String> {
+        let content = self.entry(href)?;
+        let document =
+            Document::parse(&content).map_err(|e| format!("it is not well-formed: {e}"))?;
+        let root = document.root_element();
+        if !root.has_tag_name("assessmentItem") {
+            return Err("it is not an assessmentItem".to_string());
+        }
+        let body = Self::children(root, "itemBody")
+            .next()
+            .ok_or("it has no itemBody")?;
+
+        let interactions: Vec<Node> = body
+            .descendants()
+            .filter(|n| n.is_element() && n.tag_name().name().ends_with("Interaction"))
+            .collect();
+        let interaction = match interactions.as_slice() {
+            [] => return Err("it has no interaction".to_string()),
+            [interaction] => *interaction,
+            _ => {
+                return Err(format!(
+                "it combines {} interactions, only items with a single interaction are supported",
+                interactions.len()
+            ))
+            }
+        };
+        let kind = interaction.tag_name().name();
+        if !SUPPORTED_INTERACTIONS.contains(&kind) {
+            return Err(format!(
+                "`{kind}` is not supported, only {} can be imported",
+                SUPPORTED_INTERACTIONS.join(", ")
+            ));
+        }
+
+        let response = interaction
+            .attribute("responseIdentifier")
+            .unwrap_or_default();
+        let declaration = Self::children(root, "responseDeclaration")
+            .find(|n| n.attribute("identifier") == Some(response))
+            .ok_or_else(|| format!("it does not declare the response `{response}`"))?;
+        let correct: Vec<String> = Self::children(declaration, "correctResponse")
+            .flat_map(|n| Self::children(n, "value"))
+            .map(Self::text)
+            .collect();
+        if correct.is_empty() {
+            return Err("it does not declare a correct response".to_string());
+        }
+        let mapping: Vec<(String, f64)> = Self::children(declaration, "mapping")
+            .flat_map(|n| Self::children(n, "mapEntry"))
+            .filter_map(|entry| {
+                let key = Self::collapse(entry.attribute("mapKey").into_iter());
+                let value = entry.attribute("mappedValue")?.parse::<f64>().ok()?;
+                Some((key, value))
+            })
+            .collect();
+
+        // the prompt and any text around the interaction make up the question
+        let question_text = Self::collapse(
+            body.descendants()
+                .filter(|n| n.is_text())
+                .filter(|n| {
+                    !n.ancestors().any(|a| a == interaction)
+                        || n.ancestors().any(|a| a.has_tag_name("prompt"))
+                })
+                .filter_map(|n| n.text()),
+        );
+        let mut question = Question {
+            id: None,
+            question_text,
+            points_total: Self::points(root, &mapping, &correct),
+            r#type: QuestionType::SingleChoice,
+            answers: vec![],
+            points_per_correct_answer: None,
+            category: None,
+            created_at: None,
+            updated_at: None,
+            options: Some(vec![]),
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+            exam_id: None,
+        };
+        let mapped_value = correct
+            .iter()
+            .find_map(|key| mapping.iter().find(|(k, _)| k == key))
+            .map(|(_, value)| value.round() as i32);
+
+        match kind {
+            "choiceInteraction" => {
+                let multiple = declaration.attribute("cardinality") == Some("multiple");
+                Self::choices(&mut question, interaction, &correct, multiple, mapped_value)?
+            }
+            "matchInteraction" => {
+                Self::matches(&mut question, interaction, &correct, mapped_value)?
+            }
+            _ => Self::associations(&mut question, interaction, &correct, mapped_value)?,
+        }
+        Ok(question)
+    }
+
+    /// `MAXSCORE` of the item, otherwise the sum of the mapped values of the correct
+    /// responses, otherwise one point.
+    fn points(root: Node, mapping: &[(String, f64)], correct: &[String]) -> i32 {
+        let max_score = Self::children(root, "outcomeDeclaration")
+            .find(|n| n.attribute("identifier") == Some("MAXSCORE"))
+            .and_then(|n| Self::descendant(n, "value"))
+            .and_then(|n| Self::text(n).parse::<f64>().ok());
+        let mapped: f64 = mapping
+            .iter()
+            .filter(|(key, value)| correct.contains(key) && *value > 0.0)
+            .map(|(_, value)| value)
+            .sum();
+        let points = max_score.unwrap_or(if mapped > 0.0 { mapped } else { 1.0 });
+        (points.round() as i32).max(1)
+    }
+
+    fn answer(answer_text: String) -> Answer {
+        Answer {
+            id: None,
+            answer_text,
+            description: None,
+            is_correct: None,
+            assigned_option_id: None,
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
+            created_at: None,
+            updated_at: None,
+            question_id: None,
+        }
+    }
+
+    fn choices(
+        question: &mut Question,
+        interaction: Node,
+        correct: &[String],
+        multiple: bool,
+        mapped_value: Option<i32>,
+    ) -> Result<(), String> {
+        for choice in Self::children(interaction, "simpleChoice") {
+            let identifier = choice.attribute("identifier").unwrap_or_default();
+            let mut answer = Self::answer(Self::text(choice));
+            answer.is_correct = Some(correct.iter().any(|c| c == identifier));
+            question.answers.push(answer);
+        }
+
+        if multiple {
+            question.r#type = QuestionType::MultipleChoice;
+            question.points_per_correct_answer = Some(mapped_value.unwrap_or_else(|| {
+                (f64::from(question.points_total) / correct.len() as f64).round() as i32
+            }));
+        } else if correct.len() > 1 {
+            return Err("its single response declares more than one correct choice".to_string());
+        } else {
+            question.points_per_correct_answer = Some(0);
+        }
+        Ok(())
+    }
+
+    /// Choices with their identifier.
+    fn associable_choices(set: Node) -> Vec<(String, String)> {
+        Self::children(set, "simpleAssociableChoice")
+            .map(|choice| {
+                (
+                    choice
+                        .attribute("identifier")
+                        .unwrap_or_default()
+                        .to_string(),
+                    Self::text(choice),
+                )
+            })
+            .collect()
+    }
+
+    fn pairs(correct: &[String]) -> Vec<(&str, &str)> {
+        correct
+            .iter()
+            .filter_map(|value| {
+                let mut parts = value.split_whitespace();
+                Some((parts.next()?, parts.next()?))
+            })
+            .collect()
+    }
+
+    /// Fills an assignment question from the answers and options with their identifiers
+    /// and the `(answer, option)` pairs of the correct response.
+    fn assignment(
+        question: &mut Question,
+        answers: &[(String, String)],
+        options: &[(String, String)],
+        pairs: &[(&str, &str)],
+        mapped_value: Option<i32>,
+    ) -> Result<(), String> {
+        for (identifier, text) in answers {
+            let assigned: Vec<usize> = pairs
+                .iter()
+                .filter(|(answer, _)| answer == identifier)
+                .filter_map(|(_, option)| options.iter().position(|(id, _)| id == option))
+                .collect();
+            let option = match assigned.as_slice() {
+                [option] => option,
+                [] => return Err(format!("`{text}` is not associated with any choice, assignment questions need one per answer")),
+                _ => return Err(format!("`{text}` is associated with more than one choice, assignment questions allow one per answer")),
+            };
+            let mut answer = Self::answer(text.clone());
+            answer.assigned_option_id = Some(*option as i32 + 1);
+            question.answers.push(answer);
+        }
+
+        question.options = Some(
+            options
+                .iter()
+                .enumerate()
+                .map(|(index, (_, text))| AssignmentOption {
+                    row_id: None,
+                    id: index as i32 + 1,
+                    text: text.clone(),
+                    question_id: None,
+                })
+                .collect(),
+        );
+        question.r#type = QuestionType::Assignment;
+        question.points_per_correct_answer = Some(mapped_value.unwrap_or(1));
+        Ok(())
+    }
+
+    /// The first match set holds the answers, the second the options they are assigned to.
+    fn matches(
+        question: &mut Question,
+        interaction: Node,
+        correct: &[String],
+        mapped_value: Option<i32>,
+    ) -> Result<(), String> {
+        let sets: Vec<Node> = Self::children(interaction, "simpleMatchSet").collect();
+        let [answers, options] = sets.as_slice() else {
+            return Err(format!(
+                "its matchInteraction has {} simpleMatchSet, expected two",
+                sets.len()
+            ));
+        };
+        Self::assignment(
+            question,
+            &Self::associable_choices(*answers),
+            &Self::associable_choices(*options),
+            &Self::pairs(correct),
+            mapped_value,
+        )
+    }
+
+    /// Associations are undirected, so of every correct pair the choice listed first in
+    /// the interaction becomes the answer and the other one the option. Choices without a
+    /// pair are kept as additional options.
+    fn associations(
+        question: &mut Question,
+        interaction: Node,
+        correct: &[String],
+        mapped_value: Option<i32>,
+    ) -> Result<(), String> {
+        let choices = Self::associable_choices(interaction);
+        let position = |identifier: &str| choices.iter().position(|(id, _)| id == identifier);
+        let mut pairs: Vec<(&str, &str)> = vec![];
+        for (first, second) in Self::pairs(correct) {
+            match (position(first), position(second)) {
+                (Some(a), Some(b)) if a < b => pairs.push((first, second)),
+                (Some(_), Some(_)) => pairs.push((second, first)),
+                _ => {
+                    return Err(format!(
+                        "its correct response `{first} {second}` refers to unknown choices"
+                    ))
+                }
+            }
+        }
+
+        let answers: Vec<(String, String)> = choices
+            .iter()
+            .filter(|(id, _)| pairs.iter().any(|(answer, _)| answer == id))
+            .cloned()
+            .collect();
+        let options: Vec<(String, String)> = choices
+            .iter()
+            .filter(|(id, _)| !pairs.iter().any(|(answer, _)| answer == id))
+            .cloned()
+            .collect();
+        if let Some((_, text)) = answers
+            .iter()
+            .find(|(id, _)| pairs.iter().any(|(_, option)| option == id))
+        {
+            return Err(format!("`{text}` is associated both as answer and as option, which an assignment question can't express"));
+        }
+        Self::assignment(question, &answers, &options, &pairs, mapped_value)
+    }
+}
@@ -0,0 +1,566 @@
+use crate::domain::import::text_exam_parser::TextExamParser;
+use crate::domain::model::answer::Answer;
+use crate::domain::model::assignment_option::AssignmentOption;
+use crate::domain::model::category::Category;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::spreadsheet_mapping::SpreadsheetMapping;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+use calamine::{open_workbook_from_rs, Reader, Xlsx, XlsxError};
+use std::io::Cursor;
+use std::path::Path;
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+const DEFAULT_POINTS: i32 = 1;
+const TRUE_FLAGS: [&str; 4] = ["x", "1", "true", "yes"];
+const FALSE_FLAGS: [&str; 4] = ["", "0", "false", "no"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpreadsheetFormat {
+    Csv,
+    Xlsx,
+}
+
+impl SpreadsheetFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "csv" => Some(Self::Csv),
+            "xlsx" => Some(Self::Xlsx),
+            _ => None,
+        }
+    }
+}
+
+/// Question read from a data row. `index` counts the data rows below the header, `row` is
+/// the 1-based row number in the sheet.
+#[derive(Debug, Clone)]
+pub struct SpreadsheetRow {
+    pub index: usize,
+    pub row: usize,
+    pub question: Question,
+}
+
+/// Questions of the rows that could be mapped, and one error per row that couldn't. Each
+/// error carries the row as `line` and its problems in `nested_errors`, which point to the
+/// offending cell with `line` and `column`.
+#[derive(Debug, Clone)]
+pub struct ParsedSpreadsheet {
+    pub rows: Vec<SpreadsheetRow>,
+    pub invalid: Vec<ExtendedValidationError>,
+}
+
+impl ParsedSpreadsheet {
+    /// Error for a data row, `index` counts the data rows below the header.
+    pub fn row_error(
+        index: usize,
+        row: usize,
+        nested_errors: Vec<ExtendedValidationError>,
+    ) -> ExtendedValidationError {
+        ExtendedValidationError::new(
+            index as i32,
+            format!("Row {row} was not imported"),
+            vec![],
+            nested_errors,
+        )
+        .at(row, 1)
+    }
+}
+
+/// Position of the mapped columns in the header row.
+struct Columns {
+    question_text: usize,
+    r#type: Option<usize>,
+    points: Option<usize>,
+    answers: usize,
+    correct: Option<usize>,
+    category: Option<usize>,
+    options: Option<usize>,
+    explanation: Option<usize>,
+}
+
+/// One data row with the 1-based row number, for cell access and error positions.
+struct Row<'a> {
+    number: usize,
+    cells: &'a [String],
+    errors: Vec<ExtendedValidationError>,
+}
+
+impl<'a> Row<'a> {
+    fn cell(&self, column: Option<usize>) -> &'a str {
+        column
+            .and_then(|column| self.cells.get(column))
+            .map_or("", |cell| cell.trim())
+    }
+
+    fn report(&mut self, column: Option<usize>, message: impl Into<String>) {
+        let index = self.errors.len() as i32;
+        self.errors.push(
+            ExtendedValidationError::new(index, message, vec![], vec![])
+                .at(self.number, column.map_or(1, |column| column + 1)),
+        );
+    }
+}
+
+/// Importer for questions kept in a spreadsheet, one question per row below a header row.
+///
+/// The columns are found by their header, see `SpreadsheetMapping`. Answers, correct flags
+/// and assignment options are lists within one cell, split at the mapping's separator.
+/// CSV files may be separated by `,` or `;`, of XLSX workbooks the first sheet is read.
+/// Empty rows are ignored, rows that can't be mapped are reported with every problem and
+/// don't prevent the other rows from being imported.
+pub struct SpreadsheetParser<'a> {
+    mapping: &'a SpreadsheetMapping,
+    columns: Columns,
+}
+
+impl<'a> SpreadsheetParser<'a> {
+    pub fn parse(
+        data: &[u8],
+        format: SpreadsheetFormat,
+        mapping: &'a SpreadsheetMapping,
+    ) -> Result<ParsedSpreadsheet, ExtendedValidationError> {
+        let rows = match format {
+            SpreadsheetFormat::Csv => Self::read_csv(data)?,
+            SpreadsheetFormat::Xlsx => Self::read_xlsx(data)?,
+        };
+        let is_empty = |cells: &Vec<String>| cells.iter().all(|cell| cell.trim().is_empty());
+        let Some(header) = rows.iter().position(|cells| !is_empty(cells)) else {
+            return Err(ExtendedValidationError::new(
+                0,
+                "The spreadsheet is empty, expected a header row and a question per row",
+                vec![],
+                vec![],
+            ));
+        };
+
+        let parser = SpreadsheetParser {
+            mapping,
+            columns: Self::columns(&rows[header], header + 1, mapping)?,
+        };
+        let mut parsed = ParsedSpreadsheet {
+            rows: vec![],
+            invalid: vec![],
+        };
+        for (index, (offset, cells)) in rows
+            .iter()
+            .enumerate()
+            .skip(header + 1)
+            .filter(|(_, cells)| !is_empty(cells))
+            .enumerate()
+        {
+            let mut row = Row {
+                number: offset + 1,
+                cells,
+                errors: vec![],
+            };
+            let question = parser.question(&mut row);
+            if row.errors.is_empty() {
+                parsed.rows.push(SpreadsheetRow {
+                    index,
+                    row: row.number,
+                    question,
+                });
+            } else {
+                parsed
+                    .invalid
+                    .push(ParsedSpreadsheet::row_error(index, row.number, row.errors));
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn read_csv(data: &[u8]) -> Result<Vec<Vec<String>>, ExtendedValidationError> {
+        let data = data.strip_prefix(UTF8_BOM).unwrap_or(data);
+        // spreadsheet applications in many locales separate with `;`
+        let first_line = data.split(|b| *b == b'\n').next().unwrap_or_default();
+        let count = |delimiter: u8| first_line.iter().filter(|b| **b == delimiter).count();
+        let delimiter = if count(b';') > count(b',') {
+            b';'
+        } else {
+            b','
+        };
+
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(delimiter)
+            .from_reader(data);
+        let mut rows: Vec<Vec<String>> = vec![];
+        for record in reader.records() {
+            let record = record.map_err(|e| {
+                ExtendedValidationError::new(
+                    0,
+                    format!("The CSV file could not be read: {e}"),
+                    vec![],
+                    vec![],
+                )
+                .at(rows.len() + 1, 1)
+            })?;
+            // the reader drops blank lines, which are empty rows in a spreadsheet
+            let start = record
+                .position()
+                .map_or(0, |position| position.byte() as usize);
+            rows.resize(rows.len() + Self::blank_lines(data, start), vec![]);
+            rows.push(record.iter().map(str::to_string).collect());
+        }
+        Ok(rows)
+    }
+
+    /// Line breaks in front of the record at `start`, `\r\n` counts as one. The reader
+    /// starts the record right after the first byte of the previous line break, the `\n` of
+    /// a `\r\n` still belongs to the previous record.
+    fn blank_lines(data: &[u8], start: usize) -> usize {
+        let mut position = start;
+        if position > 0 && data[position - 1] == b'\r' && data.get(position) == Some(&b'\n') {
+            position += 1;
+        }
+        let mut blank = 0;
+        while let Some(byte) = data.get(position) {
+            match byte {
+                b'\r' if data.get(position + 1) == Some(&b'\n') => position += 2,
+                b'\r' | b'\n' => position += 1,
+                _ => break,
+            }
+            blank += 1;
+        }
+        blank
+    }
+
+    fn read_xlsx(data: &[u8]) -> Result<Vec<Vec<String>>, ExtendedValidationError> {
+        let unreadable = |e: String| {
+            ExtendedValidationError::new(
+                0,
+                format!("The XLSX file could not be read: {e}"),
+                vec![],
+                vec![],
+            )
+        };
+        let mut workbook: Xlsx<_> = open_workbook_from_rs(Cursor::new(data))
+            .map_err(|e: XlsxError| unreadable(e.to_string()))?;
+        let range = workbook
+            .worksheet_range_at(0)
+            .ok_or_else(|| unreadable("the workbook has no sheet".to_string()))?
+            .map_err(|e| unreadable(e.to_string()))?;
+
+        // the range starts at the first used cell, rows and columns before it are empty
+        let (first_row, first_column) = range.start().unwrap_or((0, 0));
+        let mut rows: Vec<Vec<String>> = vec![vec![]; first_row as usize];
+        for cells in range.rows() {
+            let mut row = vec![String::new(); first_column as usize];
+            row.extend(cells.iter().map(|cell| cell.to_string()));
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+
+    fn columns(
+        header: &[String],
+        row: usize,
+        mapping: &SpreadsheetMapping,
+    ) -> Result<Columns, ExtendedValidationError> {
+        let find = |name: &str| {
+            let name = name.trim();
+            if name.is_empty() {
+                return None;
+            }
+            header
+                .iter()
+                .position(|cell| cell.trim().eq_ignore_ascii_case(name))
+        };
+
+        let missing: Vec<ExtendedValidationError> = [&mapping.question_text, &mapping.answers]
+            .into_iter()
+            .filter(|name| find(name).is_none())
+            .enumerate()
+            .map(|(index, name)| {
+                ExtendedValidationError::new(
+                    index as i32,
+                    format!("The required column `{name}` is missing in the header row"),
+                    vec![],
+                    vec![],
+                )
+                .at(row, 1)
+            })
+            .collect();
+        if !missing.is_empty() {
+            return Err(ExtendedValidationError::new(
+                0,
+                "Spreadsheet import errors",
+                vec![],
+                missing,
+            ));
+        }
+
+        Ok(Columns {
+            question_text: find(&mapping.question_text).unwrap_or_default(),
+            r#type: find(&mapping.r#type),
+            points: find(&mapping.points),
+            answers: find(&mapping.answers).unwrap_or_default(),
+            correct: find(&mapping.correct),
+            category: find(&mapping.category),
+            options: find(&mapping.options),
+            explanation: find(&mapping.explanation),
+        })
+    }
+
+    /// Parts of a list cell, empty parts included since an empty flag marks a wrong answer.
+    fn parts<'c>(&self, cell: &'c str) -> Vec<&'c str> {
+        if cell.is_empty() {
+            return vec![];
+        }
+        let separator = match self.mapping.separator.as_str() {
+            "" => "|",
+            separator => separator,
+        };
+        cell.split(separator).map(str::trim).collect()
+    }
+
+    /// Non-empty parts of a list cell.
+    fn list<'c>(&self, cell: &'c str) -> Vec<&'c str> {
+        let mut parts = self.parts(cell);
+        parts.retain(|part| !part.is_empty());
+        parts
+    }
+
+    fn question(&self, row: &mut Row) -> Question {
+        let columns = &self.columns;
+        let question_text = row.cell(Some(columns.question_text)).to_string();
+        if question_text.is_empty() {
+            row.report(Some(columns.question_text), "The question text is missing");
+        }
+
+        let points = match row.cell(columns.points) {
+            "" => DEFAULT_POINTS,
+            points => points.parse::<i32>().unwrap_or_else(|_| {
+                row.report(
+                    columns.points,
+                    format!("Invalid points `{points}`, expected a whole number"),
+                );
+                0
+            }),
+        };
+
+        let mut question = Question {
+            id: None,
+            question_text,
+            points_total: points,
+            r#type: QuestionType::SingleChoice,
+            answers: vec![],
+            points_per_correct_answer: None,
+            category: Some(row.cell(columns.category))
+                .filter(|name| !name.is_empty())
+                .map(|name| Category {
+                    id: None,
+                    name: name.to_string(),
+                    created_at: None,
+                    updated_at: None,
+                }),
+            created_at: None,
+            updated_at: None,
+            options: Some(vec![]),
+            ordering_scoring: None,
+            explanation: Some(row.cell(columns.explanation).to_string())
+                .filter(|explanation| !explanation.is_empty()),
+            sources: None,
+            exam_id: None,
+        };
+
+        let answers: Vec<String> = self
+            .list(row.cell(Some(columns.answers)))
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        if answers.is_empty() {
+            row.report(Some(columns.answers), "The question has no answers");
+            return question;
+        }
+
+        let has_options = !row.cell(columns.options).is_empty();
+        match self.question_type(row, has_options) {
+            Some(QuestionType::Assignment) => self.assignment(row, &mut question, answers),
+            Some(QuestionType::ShortAnswer) => {
+                question.r#type = QuestionType::ShortAnswer;
+                question.points_per_correct_answer = Some(0);
+                question.answers = answers
+                    .into_iter()
+                    .map(|text| {
+                        let mut answer = Self::answer(text);
+                        answer.is_correct = Some(true);
+                        answer.match_mode = Some(MatchMode::CaseInsensitive);
+                        answer
+                    })
+                    .collect();
+            }
+            Some(QuestionType::Numeric) => {
+                question.r#type = QuestionType::Numeric;
+                question.points_per_correct_answer = Some(0);
+                for text in answers {
+                    match TextExamParser::numeric_answer(&text) {
+                        Some(answer) => question.answers.push(answer),
+                        None => row.report(
+                            Some(columns.answers),
+                            format!("Malformed numeric answer `{text}`, expected `<value> [± <tolerance>[%]] [unit]`"),
+                        ),
+                    }
+                }
+            }
+            Some(r#type) => self.choices(row, &mut question, answers, Some(r#type)),
+            None if has_options => self.assignment(row, &mut question, answers),
+            None => self.choices(row, &mut question, answers, None),
+        }
+        question
+    }
+
+    /// The type of the type column, `None` if it is empty and the type follows from the
+    /// answers.
+    fn question_type(&self, row: &mut Row, has_options: bool) -> Option<QuestionType> {
+        let column = self.columns.r#type;
+        let name = row.cell(column).to_lowercase().replace([' ', '-'], "_");
+        match name.as_str() {
+            "" => None,
+            "single_choice" => Some(QuestionType::SingleChoice),
+            "multiple_choice" => Some(QuestionType::MultipleChoice),
+            "assignment" => Some(QuestionType::Assignment),
+            "short_answer" => Some(QuestionType::ShortAnswer),
+            "numeric" => Some(QuestionType::Numeric),
+            _ => {
+                row.report(
+                    column,
+                    format!(
+                        "Unknown type `{}`, expected single_choice, multiple_choice, assignment, short_answer or numeric",
+                        row.cell(column)
+                    ),
+                );
+                has_options.then_some(QuestionType::Assignment)
+            }
+        }
+    }
+
+    fn choices(
+        &self,
+        row: &mut Row,
+        question: &mut Question,
+        answers: Vec<String>,
+        r#type: Option<QuestionType>,
+    ) {
+        let column = self.columns.correct;
+        let flags: Vec<String> = self
+            .parts(row.cell(column))
+            .into_iter()
+            .map(str::to_lowercase)
+            .collect();
+        if flags.len() > answers.len() {
+            row.report(
+                column,
+                format!(
+                    "Found {} correct flags for {} answers, expected one flag per answer",
+                    flags.len(),
+                    answers.len()
+                ),
+            );
+        }
+
+        for (index, text) in answers.into_iter().enumerate() {
+            let flag = flags.get(index).map_or("", String::as_str);
+            let is_correct = if TRUE_FLAGS.contains(&flag) {
+                true
+            } else if FALSE_FLAGS.contains(&flag) {
+                false
+            } else {
+                row.report(
+                    column,
+                    format!("Unknown correct flag `{flag}`, expected `x`, `1`, `true` or `yes` for correct answers and an empty flag, `0`, `false` or `no` otherwise"),
+                );
+                false
+            };
+            let mut answer = Self::answer(text);
+            answer.is_correct = Some(is_correct);
+            question.answers.push(answer);
+        }
+
+        let correct = question
+            .answers
+            .iter()
+            .filter(|a| a.is_correct == Some(true))
+            .count() as i32;
+        question.r#type = r#type.unwrap_or(if correct > 1 {
+            QuestionType::MultipleChoice
+        } else {
+            QuestionType::SingleChoice
+        });
+        question.points_per_correct_answer = match question.r#type {
+            QuestionType::MultipleChoice if correct > 0 => {
+                Some((f64::from(question.points_total) / f64::from(correct)).round() as i32)
+            }
+            _ => Some(0),
+        };
+    }
+
+    /// Each answer is assigned the option named, by text or 1-based number, at the same
+    /// position of the correct column.
+    fn assignment(&self, row: &mut Row, question: &mut Question, answers: Vec<String>) {
+        let options: Vec<AssignmentOption> = self
+            .list(row.cell(self.columns.options))
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| AssignmentOption {
+                row_id: None,
+                id: index as i32 + 1,
+                text: text.to_string(),
+                question_id: None,
+            })
+            .collect();
+        if options.is_empty() {
+            row.report(
+                self.columns.options,
+                "The assignment has no options, expected them in the options column",
+            );
+        }
+
+        let column = self.columns.correct;
+        let assigned: Vec<String> = self
+            .parts(row.cell(column))
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        for (index, text) in answers.into_iter().enumerate() {
+            let reference = assigned.get(index).map_or("", String::as_str);
+            let option = options.iter().find(|o| {
+                o.text.eq_ignore_ascii_case(reference)
+                    || reference.parse::<i32>().is_ok_and(|number| number == o.id)
+            });
+            if option.is_none() {
+                row.report(
+                    column,
+                    format!("The answer `{text}` is assigned to `{reference}`, which is none of the options"),
+                );
+            }
+            let mut answer = Self::answer(text);
+            answer.assigned_option_id = option.map(|o| o.id);
+            question.answers.push(answer);
+        }
+
+        question.r#type = QuestionType::Assignment;
+        question.points_per_correct_answer = Some(1);
+        question.options = Some(options);
+    }
+
+    fn answer(answer_text: String) -> Answer {
+        Answer {
+            id: None,
+            answer_text,
+            description: None,
+            is_correct: None,
+            assigned_option_id: None,
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
+            created_at: None,
+            updated_at: None,
+            question_id: None,
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use crate::domain::import::gift_parser::GiftParser;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::question_type::QuestionType;
+
+const FIXTURE: &str = include_str!("fixtures/gift/rust_basics.gift");
+
+#[test]
+fn it_should_parse_the_questions_with_their_categories() {
+    let report = GiftParser::parse(FIXTURE).unwrap();
+
+    let exam = report.exam;
+    assert_eq!(exam.name, "Imported GIFT questions");
+    let questions: Vec<(QuestionType, Option<&str>)> = exam
+        .questions
+        .iter()
+        .map(|q| {
+            (
+                q.r#type.clone(),
+                q.category.as_ref().map(|c| c.name.as_str()),
+            )
+        })
+        .collect();
+    assert_eq!(
+        questions,
+        vec![
+            (QuestionType::SingleChoice, Some("Ownership")),
+            (QuestionType::MultipleChoice, Some("Ownership")),
+            (QuestionType::Assignment, Some("Tooling")),
+            (QuestionType::ShortAnswer, Some("Tooling")),
+            (QuestionType::Numeric, Some("Tooling")),
+        ]
+    );
+}
+
+#[test]
+fn it_should_report_items_without_equivalent() {
+    let report = GiftParser::parse(FIXTURE).unwrap();
+
+    let skipped: Vec<(i32, Option<i32>, &str)> = report
+        .skipped
+        .iter()
+        .map(|e| (e.index, e.line, e.message.as_str()))
+        .collect();
+    assert_eq!(
+        skipped,
+        vec![
+            (5, Some(33), "Question `Rust reached version 1.0 in 2015.` was not imported, true/false questions have no equivalent question type"),
+            (6, Some(35), "Question `Opinion` was not imported, essay questions have no equivalent question type"),
+        ]
+    );
+}
+
+#[test]
+fn it_should_map_choices_with_feedback_and_grades() {
+    let exam = GiftParser::parse(FIXTURE).unwrap().exam;
+
+    let single = &exam.questions[0];
+    assert_eq!(
+        single.question_text,
+        "Which keyword creates a mutable borrow?"
+    );
+    assert_eq!(
+        single.explanation.as_deref(),
+        Some("Only one mutable borrow may exist at a time.")
+    );
+    assert_eq!(single.answers[0].answer_text, "&mut value");
+    assert_eq!(single.answers[0].is_correct, Some(true));
+    assert_eq!(
+        single.answers[0].description.as_deref(),
+        Some("Mutable references are written with &mut.")
+    );
+    assert_eq!(single.answers[2].answer_text, "*value");
+
+    let multiple = &exam.questions[1];
+    let correct: Vec<Option<bool>> = multiple.answers.iter().map(|a| a.is_correct).collect();
+    assert_eq!(
+        correct,
+        vec![Some(true), Some(true), Some(false), Some(false)]
+    );
+    assert_eq!(multiple.points_total, 2);
+    assert_eq!(multiple.points_per_correct_answer, Some(1));
+}
+
+#[test]
+fn it_should_map_matching_short_answer_and_numerical_questions() {
+    let exam = GiftParser::parse(FIXTURE).unwrap().exam;
+
+    let matching = &exam.questions[2];
+    let options: Vec<&str> = matching
+        .options
+        .iter()
+        .flatten()
+        .map(|o| o.text.as_str())
+        .collect();
+    assert_eq!(
+        options,
+        vec![
+            "compiles the crate",
+            "runs the tests",
+            "generates documentation",
+            "formats the code"
+        ]
+    );
+    assert_eq!(matching.answers.len(), 3);
+    assert_eq!(matching.answers[1].assigned_option_id, Some(2));
+
+    let short_answer = &exam.questions[3];
+    assert_eq!(short_answer.answers.len(), 2);
+    assert_eq!(
+        short_answer.answers[0].match_mode,
+        Some(MatchMode::CaseInsensitive)
+    );
+
+    let numeric = exam.questions[4].answers[0].numeric.clone().unwrap();
+    assert_eq!(numeric.expected_value, 2018.0);
+    assert_eq!(numeric.tolerance, 0.0);
+}
+
+#[test]
+fn it_should_parse_ranges_and_escaped_characters() {
+    let report = GiftParser::parse(
+        "What is 1 \\= 1 \\{in Rust\\}? {\n=%100%true as bool\n~false as bool#no \\#way\n}\n\nPick a number {#1..5}",
+    )
+    .unwrap();
+
+    let questions = report.exam.questions;
+    assert_eq!(questions[0].question_text, "What is 1 = 1 {in Rust}?");
+    assert_eq!(
+        questions[0].answers[1].description.as_deref(),
+        Some("no #way")
+    );
+    let numeric = questions[1].answers[0].numeric.clone().unwrap();
+    assert_eq!((numeric.expected_value, numeric.tolerance), (3.0, 2.0));
+}
+
+#[test]
+fn it_should_fail_with_the_position_of_syntax_errors() {
+    let error =
+        GiftParser::parse("::Title::Question {\n=right\n~wrong\n\nAnother {#abc}").unwrap_err();
+
+    assert_eq!(error.message, "GIFT import errors");
+    let diagnostics: Vec<(Option<i32>, Option<i32>, &str)> = error
+        .nested_errors
+        .iter()
+        .map(|e| (e.line, e.column, e.message.as_str()))
+        .collect();
+    assert_eq!(
+        diagnostics,
+        vec![
+            (Some(1), Some(19), "The answer block is not closed, expected `}`"),
+            (Some(5), Some(11), "Malformed number `abc`, expected `<value>`, `<value>:<tolerance>` or `<min>..<max>`"),
+        ]
+    );
+}
+
+#[test]
+fn it_should_reject_files_without_importable_questions() {
+    let error = GiftParser::parse("// only a comment\n\nTrue or not? {TRUE}").unwrap_err();
+
+    assert_eq!(
+        error.message,
+        "The file does not contain any question that can be imported"
+    );
+    assert_eq!(error.nested_errors.len(), 1);
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod text_exam_parser_test;
+#[cfg(test)]
+mod qti_package_reader_test;
+#[cfg(test)]
+mod gift_parser_test;
+#[cfg(test)]
+mod moodle_xml_reader_test;
+#[cfg(test)]
+mod spreadsheet_parser_test;
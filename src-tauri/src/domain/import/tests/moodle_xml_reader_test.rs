@@ -0,0 +1,127 @@
+use crate::domain::import::moodle_xml_reader::MoodleXmlReader;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::question_type::QuestionType;
+
+const FIXTURE: &str = include_str!("fixtures/moodle/rust_basics.xml");
+
+#[test]
+fn it_should_read_the_questions_with_their_categories() {
+    let report = MoodleXmlReader::read(FIXTURE).unwrap();
+
+    let exam = report.exam;
+    assert_eq!(exam.name, "Imported Moodle questions");
+    let questions: Vec<(QuestionType, i32, Option<&str>)> = exam
+        .questions
+        .iter()
+        .map(|q| {
+            (
+                q.r#type.clone(),
+                q.points_total,
+                q.category.as_ref().map(|c| c.name.as_str()),
+            )
+        })
+        .collect();
+    assert_eq!(
+        questions,
+        vec![
+            (QuestionType::SingleChoice, 2, Some("Ownership")),
+            (QuestionType::MultipleChoice, 2, Some("Ownership")),
+            (QuestionType::Assignment, 2, Some("Tooling")),
+            (QuestionType::ShortAnswer, 1, Some("Tooling")),
+            (QuestionType::Numeric, 1, Some("Tooling")),
+        ]
+    );
+}
+
+#[test]
+fn it_should_report_questions_without_equivalent() {
+    let report = MoodleXmlReader::read(FIXTURE).unwrap();
+
+    let skipped: Vec<(i32, Option<i32>, &str)> = report
+        .skipped
+        .iter()
+        .map(|e| (e.index, e.line, e.message.as_str()))
+        .collect();
+    assert_eq!(
+        skipped,
+        vec![
+            (5, Some(118), "Question `Version` was not imported, true/false questions have no equivalent question type"),
+            (6, Some(132), "Question `Opinion` was not imported, `essay` questions have no equivalent question type"),
+        ]
+    );
+}
+
+#[test]
+fn it_should_convert_html_and_keep_feedback() {
+    let exam = MoodleXmlReader::read(FIXTURE).unwrap().exam;
+
+    let single = &exam.questions[0];
+    assert_eq!(
+        single.question_text,
+        "Which keyword creates a mutable borrow?"
+    );
+    assert_eq!(
+        single.explanation.as_deref(),
+        Some("Only one mutable borrow may exist at a time.")
+    );
+    assert_eq!(single.answers[0].answer_text, "&mut value");
+    assert_eq!(
+        single.answers[0].description.as_deref(),
+        Some("Mutable references are written with &mut.")
+    );
+
+    let multiple = &exam.questions[1];
+    assert_eq!(multiple.points_per_correct_answer, Some(1));
+    assert_eq!(multiple.answers[2].is_correct, Some(false));
+}
+
+#[test]
+fn it_should_map_matching_short_answer_and_numerical_questions() {
+    let exam = MoodleXmlReader::read(FIXTURE).unwrap().exam;
+
+    let matching = &exam.questions[2];
+    assert_eq!(matching.options.as_ref().map(Vec::len), Some(3));
+    assert_eq!(matching.answers.len(), 2);
+    assert_eq!(matching.points_per_correct_answer, Some(1));
+
+    let short_answer = &exam.questions[3];
+    assert_eq!(
+        short_answer.answers[0].match_mode,
+        Some(MatchMode::CaseInsensitive)
+    );
+
+    let numeric = &exam.questions[4];
+    assert_eq!(numeric.answers.len(), 1);
+    assert_eq!(numeric.answers[0].answer_text, "8 MiB");
+    let value = numeric.answers[0].numeric.clone().unwrap();
+    assert_eq!(
+        (value.expected_value, value.tolerance, value.unit),
+        (8.0, 0.5, Some("MiB".to_string()))
+    );
+}
+
+#[test]
+fn it_should_report_grades_a_question_type_cant_express() {
+    let content = FIXTURE.replace("<answer fraction=\"-50\">", "<answer fraction=\"25\">");
+
+    let report = MoodleXmlReader::read(&content).unwrap();
+
+    assert_eq!(report.exam.questions.len(), 4);
+    assert_eq!(
+        report.skipped[0].message,
+        "Question `Copy types` was not imported, its correct answers are graded differently (50%, 50%, 25%), but each correct answer is worth the same points"
+    );
+}
+
+#[test]
+fn it_should_reject_documents_that_are_not_quizzes() {
+    let error = MoodleXmlReader::read("<quiz><question>").unwrap_err();
+    assert!(error.message.starts_with("The file is not well-formed XML"));
+    assert!(error.line.is_some());
+
+    let error = MoodleXmlReader::read("<assessmentTest/>").unwrap_err();
+    assert_eq!(
+        error.message,
+        "The file is not a Moodle XML quiz, expected a `quiz` root element"
+    );
+}
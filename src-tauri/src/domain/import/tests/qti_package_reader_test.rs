@@ -0,0 +1,211 @@
+use crate::domain::import::qti_package_reader::QtiPackageReader;
+use crate::domain::model::question_type::QuestionType;
+use std::io::{Cursor, Write};
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+const MANIFEST: &str = include_str!("fixtures/qti/imsmanifest.xml");
+
+const FIXTURES: [(&str, &str); 7] = [
+    ("imsmanifest.xml", MANIFEST),
+    (
+        "tests/rust_basics.xml",
+        include_str!("fixtures/qti/tests/rust_basics.xml"),
+    ),
+    (
+        "items/single_choice.xml",
+        include_str!("fixtures/qti/items/single_choice.xml"),
+    ),
+    (
+        "items/multiple_choice.xml",
+        include_str!("fixtures/qti/items/multiple_choice.xml"),
+    ),
+    (
+        "items/match.xml",
+        include_str!("fixtures/qti/items/match.xml"),
+    ),
+    (
+        "items/associate.xml",
+        include_str!("fixtures/qti/items/associate.xml"),
+    ),
+    (
+        "items/text_entry.xml",
+        include_str!("fixtures/qti/items/text_entry.xml"),
+    ),
+];
+
+fn package(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, content) in files {
+        zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+#[test]
+fn it_should_read_the_test_of_the_package() {
+    let report = QtiPackageReader::read(&package(&FIXTURES)).unwrap();
+
+    let exam = report.exam;
+    assert_eq!(exam.name, "Rust Basics Certification");
+    assert_eq!(
+        exam.description.as_deref(),
+        Some("Checks the fundamentals of the Rust language.")
+    );
+    assert_eq!(exam.duration, Some(45));
+    let types: Vec<QuestionType> = exam.questions.iter().map(|q| q.r#type.clone()).collect();
+    assert_eq!(
+        types,
+        vec![
+            QuestionType::SingleChoice,
+            QuestionType::MultipleChoice,
+            QuestionType::Assignment,
+            QuestionType::Assignment,
+        ]
+    );
+    assert_eq!(exam.points_to_succeeded, Some(6));
+}
+
+#[test]
+fn it_should_report_unsupported_interactions() {
+    let report = QtiPackageReader::read(&package(&FIXTURES)).unwrap();
+
+    assert_eq!(report.skipped.len(), 1);
+    assert_eq!(report.skipped[0].index, 2);
+    assert!(report.skipped[0].message.starts_with(
+        "Item `items/text_entry.xml` was not imported, `textEntryInteraction` is not supported"
+    ));
+}
+
+#[test]
+fn it_should_map_choice_interactions() {
+    let exam = QtiPackageReader::read(&package(&FIXTURES)).unwrap().exam;
+
+    let single = &exam.questions[0];
+    assert_eq!(
+        single.question_text,
+        "Ownership rules apply to every value. How many owners can a value have at a time?"
+    );
+    assert_eq!(single.points_total, 2);
+    let answers: Vec<(&str, Option<bool>)> = single
+        .answers
+        .iter()
+        .map(|a| (a.answer_text.as_str(), a.is_correct))
+        .collect();
+    assert_eq!(
+        answers,
+        vec![
+            ("No owner at all", Some(false)),
+            ("Exactly one owner", Some(true)),
+            ("As many as needed", Some(false)),
+        ]
+    );
+
+    let multiple = &exam.questions[1];
+    assert_eq!(multiple.points_total, 4);
+    assert_eq!(multiple.points_per_correct_answer, Some(2));
+    assert_eq!(multiple.answers[2].answer_text, "&str slice");
+    assert_eq!(multiple.answers[2].is_correct, Some(false));
+}
+
+#[test]
+fn it_should_map_match_and_associate_interactions_to_assignments() {
+    let exam = QtiPackageReader::read(&package(&FIXTURES)).unwrap().exam;
+
+    let options = |index: usize| -> Vec<&str> {
+        exam.questions[index]
+            .options
+            .iter()
+            .flatten()
+            .map(|o| o.text.as_str())
+            .collect()
+    };
+    let assigned = |index: usize| -> Vec<(&str, Option<i32>)> {
+        exam.questions[index]
+            .answers
+            .iter()
+            .map(|a| (a.answer_text.as_str(), a.assigned_option_id))
+            .collect()
+    };
+    assert_eq!(options(2), vec!["std::fmt", "std::clone"]);
+    assert_eq!(
+        assigned(2),
+        vec![("Clone trait", Some(2)), ("Debug trait", Some(1))]
+    );
+    assert_eq!(
+        options(3),
+        vec!["Growable buffer", "Fixed size", "Linked nodes"]
+    );
+    assert_eq!(
+        assigned(3),
+        vec![("Vec<T> type", Some(1)), ("[T; N] type", Some(2))]
+    );
+}
+
+#[test]
+fn it_should_read_items_of_the_manifest_without_test() {
+    let manifest = MANIFEST.replace("imsqti_test_xmlv2p1", "webcontent");
+    let mut files = FIXTURES.to_vec();
+    files[0] = ("imsmanifest.xml", &manifest);
+
+    let report = QtiPackageReader::read(&package(&files)).unwrap();
+
+    assert_eq!(report.exam.name, "Imported QTI package");
+    assert_eq!(report.exam.questions.len(), 4);
+    assert_eq!(
+        report.exam.questions[2].question_text,
+        "Assign each trait to the module it is defined in."
+    );
+    assert_eq!(report.skipped[0].index, 4);
+}
+
+#[test]
+fn it_should_report_items_that_cant_be_mapped() {
+    let single = FIXTURES[2]
+        .1
+        .replace("<value>B</value>", "<value>A</value><value>B</value>");
+    let associate = FIXTURES[5].1.replace("fixed array", "growable fixed");
+    let mut files = FIXTURES.to_vec();
+    files[2] = ("items/single_choice.xml", &single);
+    files[5] = ("items/associate.xml", &associate);
+    files.remove(4);
+
+    let report = QtiPackageReader::read(&package(&files)).unwrap();
+
+    let messages: Vec<&str> = report.skipped.iter().map(|e| e.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Item `items/single_choice.xml` was not imported, its single response declares more than one correct choice",
+            "Item `items/text_entry.xml` was not imported, `textEntryInteraction` is not supported, only choiceInteraction, matchInteraction, associateInteraction can be imported",
+            "Item `items/match.xml` was not imported, `items/match.xml` is missing in the package",
+            "Item `items/associate.xml` was not imported, `Growable buffer` is associated both as answer and as option, which an assignment question can't express",
+        ]
+    );
+}
+
+#[test]
+fn it_should_reject_files_that_are_not_packages() {
+    let error = QtiPackageReader::read(b"Q: not a zip").unwrap_err();
+    assert!(error.message.starts_with("The file is not a QTI package"));
+
+    let error = QtiPackageReader::read(&package(&FIXTURES[1..])).unwrap_err();
+    assert_eq!(
+        error.message,
+        "The package can't be imported: `imsmanifest.xml` is missing in the package"
+    );
+}
+
+#[test]
+fn it_should_fail_without_importable_items() {
+    let files = [FIXTURES[0], FIXTURES[1], FIXTURES[6]];
+
+    let error = QtiPackageReader::read(&package(&files)).unwrap_err();
+
+    assert_eq!(
+        error.message,
+        "The QTI package does not contain any item that can be imported"
+    );
+    assert_eq!(error.nested_errors.len(), 5);
+}
@@ -0,0 +1,315 @@
+use crate::domain::import::spreadsheet_parser::{SpreadsheetFormat, SpreadsheetParser};
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::spreadsheet_mapping::SpreadsheetMapping;
+use std::io::{Cursor, Write};
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+const TEMPLATE: &str = include_str!("../../../../../templates/import_template_csv.csv");
+
+fn parse_csv(content: &str) -> crate::domain::import::spreadsheet_parser::ParsedSpreadsheet {
+    SpreadsheetParser::parse(
+        content.as_bytes(),
+        SpreadsheetFormat::Csv,
+        &SpreadsheetMapping::default(),
+    )
+    .unwrap()
+}
+
+/// Minimal workbook with the rows as inline strings in the first sheet, starting at `B2`.
+fn workbook(rows: &[&[&str]]) -> Vec<u8> {
+    let mut sheet = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>"#,
+    );
+    for (index, cells) in rows.iter().enumerate() {
+        let row = index + 2;
+        sheet.push_str(&format!(r#"<row r="{row}">"#));
+        for (column, cell) in cells.iter().enumerate() {
+            let reference = format!("{}{row}", (b'B' + column as u8) as char);
+            sheet.push_str(&format!(
+                r#"<c r="{reference}" t="inlineStr"><is><t>{cell}</t></is></c>"#
+            ));
+        }
+        sheet.push_str("</row>");
+    }
+    sheet.push_str("</sheetData></worksheet>");
+
+    let files = [
+        (
+            "[Content_Types].xml",
+            r#"<?xml version="1.0" encoding="UTF-8"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/></Types>"#.to_string(),
+        ),
+        (
+            "_rels/.rels",
+            r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#.to_string(),
+        ),
+        (
+            "xl/workbook.xml",
+            r#"<?xml version="1.0" encoding="UTF-8"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="Questions" sheetId="1" r:id="rId1"/></sheets></workbook>"#.to_string(),
+        ),
+        (
+            "xl/_rels/workbook.xml.rels",
+            r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/></Relationships>"#.to_string(),
+        ),
+        ("xl/worksheets/sheet1.xml", sheet),
+    ];
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, content) in files {
+        zip.start_file(name, SimpleFileOptions::default()).unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+#[test]
+fn it_should_read_the_template() {
+    let parsed = parse_csv(TEMPLATE);
+
+    assert!(parsed.invalid.is_empty(), "{:?}", parsed.invalid);
+    let types: Vec<QuestionType> = parsed
+        .rows
+        .iter()
+        .map(|r| r.question.r#type.clone())
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            QuestionType::SingleChoice,
+            QuestionType::MultipleChoice,
+            QuestionType::Assignment,
+            QuestionType::ShortAnswer,
+            QuestionType::Numeric,
+        ]
+    );
+    let rows: Vec<usize> = parsed.rows.iter().map(|r| r.row).collect();
+    assert_eq!(rows, vec![2, 3, 4, 5, 6]);
+
+    let single = &parsed.rows[0].question;
+    assert_eq!(
+        single.question_text,
+        "How many definitions of the term \"Concept A\" exist?"
+    );
+    let correct: Vec<Option<bool>> = single.answers.iter().map(|a| a.is_correct).collect();
+    assert_eq!(correct, vec![Some(false), Some(false), Some(true)]);
+    assert_eq!(
+        single.category.as_ref().map(|c| c.name.as_str()),
+        Some("Architecture")
+    );
+
+    let multiple = &parsed.rows[1].question;
+    assert_eq!(multiple.points_total, 3);
+    assert_eq!(multiple.points_per_correct_answer, Some(1));
+}
+
+#[test]
+fn it_should_map_assignments_short_answers_and_numeric_answers() {
+    let parsed = parse_csv(TEMPLATE);
+
+    let assignment = &parsed.rows[2].question;
+    let assigned: Vec<Option<i32>> = assignment
+        .answers
+        .iter()
+        .map(|a| a.assigned_option_id)
+        .collect();
+    assert_eq!(assigned, vec![Some(1), Some(1), Some(2)]);
+    assert_eq!(assignment.options.as_ref().map(Vec::len), Some(2));
+
+    let short_answer = &parsed.rows[3].question;
+    assert_eq!(
+        short_answer.answers[0].match_mode,
+        Some(MatchMode::CaseInsensitive)
+    );
+
+    let numeric = &parsed.rows[4].question;
+    assert_eq!(numeric.answers[0].answer_text, "62 hosts");
+    assert!(numeric
+        .explanation
+        .as_deref()
+        .unwrap()
+        .contains("6 host bits"));
+}
+
+#[test]
+fn it_should_infer_the_type_and_accept_semicolons() {
+    let parsed = parse_csv(
+        "question;answers;correct;options\n\
+         Pick the right one;First answer|Second answer;|yes;\n\
+         \n\
+         Pick both;First answer|Second answer;1|1;\n\
+         Assign them;First answer|Second answer;2|1;Left side|Right side\n",
+    );
+
+    assert!(parsed.invalid.is_empty(), "{:?}", parsed.invalid);
+    let rows: Vec<(usize, QuestionType)> = parsed
+        .rows
+        .iter()
+        .map(|r| (r.row, r.question.r#type.clone()))
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            (2, QuestionType::SingleChoice),
+            (4, QuestionType::MultipleChoice),
+            (5, QuestionType::Assignment),
+        ]
+    );
+    assert_eq!(parsed.rows[0].question.points_total, 1);
+    assert_eq!(
+        parsed.rows[2].question.answers[0].assigned_option_id,
+        Some(2)
+    );
+}
+
+#[test]
+fn it_should_report_every_problem_of_a_row() {
+    let parsed = parse_csv(
+        "Question,Type,Points,Answers,Correct,Options\n\
+         ,ordering,two,First answer|Second answer,x|maybe,\n\
+         Assign them,assignment,1,First answer,3,Left side|Right side\n\
+         Valid question,,1,First answer|Second answer,x|,\n",
+    );
+
+    assert_eq!(parsed.rows.len(), 1);
+    assert_eq!(parsed.rows[0].index, 2);
+    let rows: Vec<(i32, Option<i32>, &str)> = parsed
+        .invalid
+        .iter()
+        .map(|e| (e.index, e.line, e.message.as_str()))
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            (0, Some(2), "Row 2 was not imported"),
+            (1, Some(3), "Row 3 was not imported"),
+        ]
+    );
+    let problems: Vec<(Option<i32>, &str)> = parsed.invalid[0]
+        .nested_errors
+        .iter()
+        .map(|e| (e.column, e.message.as_str()))
+        .collect();
+    assert_eq!(
+        problems,
+        vec![
+            (Some(1), "The question text is missing"),
+            (Some(3), "Invalid points `two`, expected a whole number"),
+            (Some(2), "Unknown type `ordering`, expected single_choice, multiple_choice, assignment, short_answer or numeric"),
+            (Some(5), "Unknown correct flag `maybe`, expected `x`, `1`, `true` or `yes` for correct answers and an empty flag, `0`, `false` or `no` otherwise"),
+        ]
+    );
+    assert_eq!(
+        parsed.invalid[1].nested_errors[0].message,
+        "The answer `First answer` is assigned to `3`, which is none of the options"
+    );
+}
+
+#[test]
+fn it_should_count_rows_of_windows_line_endings() {
+    let parsed = parse_csv(
+        "Question,Type,Points,Answers,Correct,Options\r\n\
+         ,single_choice,1,First answer|Second answer,x|,\r\n\
+         \r\n\
+         Valid question,,1,First answer|Second answer,x|,\r\n\
+         Another question,,two,First answer|Second answer,x|,\r\n",
+    );
+
+    assert_eq!(parsed.rows.len(), 1);
+    assert_eq!(parsed.rows[0].row, 4);
+    let rows: Vec<(Option<i32>, &str)> = parsed
+        .invalid
+        .iter()
+        .map(|e| (e.line, e.message.as_str()))
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            (Some(2), "Row 2 was not imported"),
+            (Some(5), "Row 5 was not imported"),
+        ]
+    );
+}
+
+#[test]
+fn it_should_use_the_configured_columns() {
+    let mapping = SpreadsheetMapping {
+        question_text: "Frage".into(),
+        answers: "Antworten".into(),
+        correct: "Richtig".into(),
+        separator: ";".into(),
+        ..SpreadsheetMapping::default()
+    };
+    let content = "Frage,Antworten,Richtig\nWelche Antwort?,Erste Antwort;Zweite Antwort,;x\n";
+
+    let parsed =
+        SpreadsheetParser::parse(content.as_bytes(), SpreadsheetFormat::Csv, &mapping).unwrap();
+
+    assert_eq!(parsed.rows[0].question.answers[1].is_correct, Some(true));
+
+    let error = SpreadsheetParser::parse(
+        content.as_bytes(),
+        SpreadsheetFormat::Csv,
+        &SpreadsheetMapping::default(),
+    )
+    .unwrap_err();
+    let missing: Vec<&str> = error
+        .nested_errors
+        .iter()
+        .map(|e| e.message.as_str())
+        .collect();
+    assert_eq!(
+        missing,
+        vec![
+            "The required column `Question` is missing in the header row",
+            "The required column `Answers` is missing in the header row",
+        ]
+    );
+}
+
+#[test]
+fn it_should_read_the_first_sheet_of_a_workbook() {
+    let data = workbook(&[
+        &["Question", "Answers", "Correct"],
+        &["Pick the right one", "First answer|Second answer", "|x"],
+        &["", "", ""],
+        &["Pick both", "First answer|Second answer", "x|x"],
+    ]);
+
+    let parsed = SpreadsheetParser::parse(
+        &data,
+        SpreadsheetFormat::Xlsx,
+        &SpreadsheetMapping::default(),
+    )
+    .unwrap();
+
+    assert!(parsed.invalid.is_empty(), "{:?}", parsed.invalid);
+    let rows: Vec<(usize, QuestionType)> = parsed
+        .rows
+        .iter()
+        .map(|r| (r.row, r.question.r#type.clone()))
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            (3, QuestionType::SingleChoice),
+            (5, QuestionType::MultipleChoice)
+        ]
+    );
+}
+
+#[test]
+fn it_should_recognize_the_format_by_extension() {
+    assert_eq!(
+        SpreadsheetFormat::from_path(Path::new("/tmp/questions.CSV")),
+        Some(SpreadsheetFormat::Csv)
+    );
+    assert_eq!(
+        SpreadsheetFormat::from_path(Path::new("questions.xlsx")),
+        Some(SpreadsheetFormat::Xlsx)
+    );
+    assert_eq!(
+        SpreadsheetFormat::from_path(Path::new("questions.ods")),
+        None
+    );
+}
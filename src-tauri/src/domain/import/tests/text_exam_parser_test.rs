@@ -0,0 +1,229 @@
+use crate::domain::import::text_exam_parser::{TextExamParser, IMPORTED_EXAM_NAME};
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+use crate::domain::validation::validation_error::ValidationError;
+
+const TEMPLATE: &str = include_str!("../../../../../templates/import_template_txt.txt");
+
+fn diagnostics(content: &str) -> Vec<(i32, i32, String)> {
+    TextExamParser::parse(content)
+        .unwrap_err()
+        .nested_errors
+        .into_iter()
+        .map(|e| (e.line.unwrap(), e.column.unwrap(), e.message))
+        .collect()
+}
+
+#[test]
+fn it_should_parse_the_template() {
+    let exam = TextExamParser::parse(TEMPLATE).unwrap().exam;
+
+    assert_eq!(exam.name, IMPORTED_EXAM_NAME);
+    assert_eq!(exam.points_to_succeeded, Some(5));
+    assert_eq!(exam.duration, Some(30));
+    let types: Vec<QuestionType> = exam.questions.iter().map(|q| q.r#type.clone()).collect();
+    assert_eq!(
+        types,
+        vec![
+            QuestionType::SingleChoice,
+            QuestionType::MultipleChoice,
+            QuestionType::Assignment,
+            QuestionType::Numeric,
+            QuestionType::Numeric,
+        ]
+    );
+
+    let single = &exam.questions[0];
+    assert_eq!(
+        single.question_text,
+        "How many definitions of the term \"Concept A\" exist?"
+    );
+    assert_eq!(
+        single.answers[2].answer_text,
+        "A dozen or more different definitions."
+    );
+    assert_eq!(single.answers[2].is_correct, Some(true));
+
+    let multiple = &exam.questions[1];
+    assert_eq!(multiple.points_per_correct_answer, Some(0));
+    assert_eq!(
+        multiple.answers[2].answer_text,
+        "(internal and external) interfaces"
+    );
+
+    let assignment = &exam.questions[2];
+    let options = assignment.options.as_ref().unwrap();
+    assert_eq!(options[1].text, "Not suitable");
+    let assigned: Vec<Option<i32>> = assignment
+        .answers
+        .iter()
+        .map(|a| a.assigned_option_id)
+        .collect();
+    assert_eq!(assigned, vec![Some(1), Some(1), Some(2)]);
+
+    let hosts = &exam.questions[3];
+    assert_eq!(hosts.answers[0].answer_text, "62 hosts");
+    assert!(hosts
+        .explanation
+        .as_ref()
+        .unwrap()
+        .contains("**6 host bits**"));
+    let sources = hosts.sources.as_ref().unwrap();
+    assert_eq!(sources[0].page.as_deref(), Some("442"));
+    assert_eq!(
+        sources[1].url.as_deref(),
+        Some("https://www.rfc-editor.org/rfc/rfc4632")
+    );
+
+    let numeric = exam.questions[4].answers[0].numeric.as_ref().unwrap();
+    assert_eq!(numeric.expected_value, 950.0);
+    assert_eq!(numeric.tolerance, 0.05);
+    assert_eq!(numeric.tolerance_mode, ToleranceMode::Relative);
+    assert_eq!(numeric.unit.as_deref(), Some("Mbit/s"));
+}
+
+#[test]
+fn it_should_split_points_between_correct_answers() {
+    let exam = TextExamParser::parse(
+        "Q: Pick two P: 4\nWhich are even numbers?\n[X] (a) Two\n[ ] (b) Three\n[x] (c) Four\n",
+    )
+    .unwrap()
+    .exam;
+
+    assert_eq!(exam.questions[0].r#type, QuestionType::MultipleChoice);
+    assert_eq!(exam.questions[0].points_per_correct_answer, Some(2));
+}
+
+#[test]
+fn it_should_report_missing_and_invalid_points() {
+    let content = "Q: Select one\nWhich one?\n[X] First\n[ ] Second\nQ: Select one P: many\nWhich one?\n[X] First\n[ ] Second";
+
+    assert_eq!(
+        diagnostics(content),
+        vec![
+            (
+                1,
+                14,
+                "Missing points, expected `P: <points>` in the question header".to_string()
+            ),
+            (
+                5,
+                18,
+                "Invalid points, expected a whole number after `P:`".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn it_should_report_unknown_markers_with_their_column() {
+    let content = "Q: Select one P: 1\nWhich one?\n  [X] First\n  [?] Second";
+
+    assert_eq!(
+        diagnostics(content),
+        vec![(
+            4,
+            4,
+            "Unknown marker `[?]`, expected `[X]` or `[ ]`".to_string()
+        )]
+    );
+}
+
+#[test]
+fn it_should_report_malformed_assignment_rows() {
+    let content = "Q: Assign P: 2\nWhich fits?\nA: Suitable | | Not suitable\n[X] [ ] [ ] First row\n[X] [X] [ ] Second row\n[ ] [ ] Third row";
+
+    let messages = diagnostics(content);
+    assert_eq!(
+        messages[0],
+        (3, 15, "Option 2 of the assignment has no text".to_string())
+    );
+    assert_eq!(messages[1].0, 5);
+    assert!(messages[1].2.contains("more than one option"));
+    assert_eq!(messages[2].0, 6);
+    assert!(messages[2].2.contains("Expected 3 markers"));
+    assert_eq!(messages[3].0, 6);
+    assert!(messages[3].2.contains("does not assign an option"));
+}
+
+#[test]
+fn it_should_report_malformed_numeric_answers_and_sources() {
+    let content = "Q: Count P: 1\nHow many?\nN: about sixty\nS: | ";
+
+    assert_eq!(
+        diagnostics(content),
+        vec![
+            (
+                3,
+                4,
+                "Malformed numeric answer, expected `N: <value> [± <tolerance>[%]] [unit]`".to_string()
+            ),
+            (1, 1, "The question has no answers, expected `[X]` / `[ ]` lines, an `A:` row or `N:` values".to_string()),
+            (
+                4,
+                4,
+                "The source is empty, expected `S: <title> | <chapter> | <page>`".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn it_should_report_lines_outside_of_questions() {
+    let content =
+        "My exam\n[X] Orphan answer\nQ: Select one P: 1\nWhich one?\n[X] First\n[ ] Second\nN: 42";
+
+    let messages = diagnostics(content);
+    assert_eq!(messages.len(), 2);
+    assert_eq!((messages[0].0, messages[0].1), (2, 1));
+    assert!(messages[0].2.contains("no question was started"));
+    assert_eq!((messages[1].0, messages[1].1), (7, 1));
+    assert!(messages[1].2.contains("Unexpected line after the answers"));
+}
+
+#[test]
+fn it_should_report_text_without_questions() {
+    assert_eq!(
+        diagnostics("Just some notes"),
+        vec![(
+            1,
+            1,
+            "The text does not contain any question, expected a line starting with `Q:`"
+                .to_string()
+        )]
+    );
+}
+
+#[test]
+fn it_should_locate_validation_errors() {
+    let parsed = TextExamParser::parse(
+        "Q: Assign P: 2\n\nWhich fits?\nA: Suitable | Not suitable\n[X] [ ] First row\n[ ] [X] Row",
+    )
+    .unwrap();
+    let answer_error = ExtendedValidationError::new(1, "Answer: Row", vec![], vec![]);
+    let option_error = ExtendedValidationError::new(1, "Option: Not suitable", vec![], vec![]);
+    let question_error = ExtendedValidationError::new(
+        0,
+        "Question: Which fits?",
+        vec![ValidationError::new("points_total", "too low")],
+        vec![answer_error, option_error],
+    );
+    let error =
+        ExtendedValidationError::new(0, "Exam validation errors", vec![], vec![question_error]);
+
+    let located = parsed.locate(error);
+
+    let question_error = &located.nested_errors[0];
+    assert_eq!(
+        (question_error.line, question_error.column),
+        (Some(1), Some(1))
+    );
+    let answer_error = &question_error.nested_errors[0];
+    assert_eq!((answer_error.line, answer_error.column), (Some(6), Some(1)));
+    let option_error = &question_error.nested_errors[1];
+    assert_eq!(
+        (option_error.line, option_error.column),
+        (Some(4), Some(15))
+    );
+}
@@ -0,0 +1,603 @@
+use crate::domain::import::imported_exam::imported_exam;
+use crate::domain::model::answer::Answer;
+use crate::domain::model::assignment_option::AssignmentOption;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::source_reference::SourceReference;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+use regex::Regex;
+use std::sync::LazyLock;
+
+pub const QUESTION_PREFIX: &str = "Q:";
+pub const POINTS_PREFIX: &str = "P:";
+pub const ASSIGNMENT_PREFIX: &str = "A:";
+pub const NUMERIC_PREFIX: &str = "N:";
+pub const EXPLANATION_PREFIX: &str = "E:";
+pub const SOURCE_PREFIX: &str = "S:";
+
+pub const IMPORTED_EXAM_NAME: &str = "Imported Certificate";
+pub const IMPORTED_EXAM_DESCRIPTION: &str = "Automatically parsed from text file";
+
+/// `[X]`, `[x]` or `[ ]` at the start of an answer line; anything else between the
+/// brackets is reported as an unknown marker.
+static MARKER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\[([^\]]?)\]\s*").expect("valid marker pattern"));
+
+/// Optional `(a)` label in front of the answer text.
+static LABEL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\([a-z]\)\s*").expect("valid label pattern"));
+
+/// `<value> [± <tolerance>[%]] [unit]`, the tolerance may also be written as `+/-` or `+-`.
+static NUMERIC: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^([-+]?\d+(?:[.,]\d+)?(?:[eE][-+]?\d+)?)\s*(?:(?:±|\+/?-)\s*(\d+(?:[.,]\d+)?)\s*(%)?)?\s*(.*)$",
+    )
+    .expect("valid numeric pattern")
+});
+
+/// Where a question and its parts were found in the imported text.
+#[derive(Debug, Clone, Default)]
+struct QuestionPositions {
+    header: (usize, usize),
+    answers: Vec<(usize, usize)>,
+    options: Vec<(usize, usize)>,
+}
+
+/// Exam read from the text format together with the position of every question,
+/// answer and assignment option, so validation errors can point back into the file.
+#[derive(Debug, Clone)]
+pub struct ParsedExam {
+    pub exam: Exam,
+    positions: Vec<QuestionPositions>,
+}
+
+impl ParsedExam {
+    /// Attaches line and column to the question, answer and option errors produced by
+    /// `ExamUseCase::validate_exam` for this exam.
+    pub fn locate(&self, mut error: ExtendedValidationError) -> ExtendedValidationError {
+        error.nested_errors = error
+            .nested_errors
+            .into_iter()
+            .map(|question_error| {
+                let Some(positions) = self.positions.get(question_error.index as usize) else {
+                    return question_error;
+                };
+                let (line, column) = positions.header;
+                let mut question_error = question_error.at(line, column);
+                question_error.nested_errors = question_error
+                    .nested_errors
+                    .into_iter()
+                    .map(|nested| {
+                        let candidates = if nested.message.starts_with("Option: ") {
+                            &positions.options
+                        } else {
+                            &positions.answers
+                        };
+                        match candidates.get(nested.index as usize) {
+                            Some((line, column)) => nested.at(*line, *column),
+                            None => nested.at(line, column),
+                        }
+                    })
+                    .collect();
+                question_error
+            })
+            .collect();
+        error
+    }
+}
+
+/// Non-empty line of the imported text, trimmed but remembering where it started.
+#[derive(Debug, Clone, Copy)]
+struct Line<'a> {
+    number: usize,
+    indent: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// 1-based column of the byte offset within the trimmed text.
+    fn column(&self, offset: usize) -> usize {
+        self.indent + self.text[..offset].chars().count() + 1
+    }
+
+    fn position(&self, offset: usize) -> (usize, usize) {
+        (self.number, self.column(offset))
+    }
+
+    fn is_question_header(&self) -> bool {
+        self.text.starts_with(QUESTION_PREFIX)
+    }
+
+    fn is_choice(&self) -> bool {
+        MARKER.is_match(self.text)
+    }
+
+    fn is_detail(&self) -> bool {
+        self.text.starts_with(EXPLANATION_PREFIX) || self.text.starts_with(SOURCE_PREFIX)
+    }
+
+    /// Whether the line ends the question text.
+    fn starts_section(&self) -> bool {
+        self.is_question_header()
+            || self.is_choice()
+            || self.is_detail()
+            || self.text.starts_with(ASSIGNMENT_PREFIX)
+            || self.text.starts_with(NUMERIC_PREFIX)
+    }
+
+    /// Text after `prefix` without leading whitespace, and its byte offset in the line.
+    fn after(&self, prefix: &str) -> (&'a str, usize) {
+        let rest = &self.text[prefix.len()..];
+        let value = rest.trim_start();
+        (value, self.text.len() - value.len())
+    }
+}
+
+/// Parser for the `Q:` / `P:` / `A:` / `N:` / `[X]` / `E:` / `S:` text format of
+/// `templates/import_template_txt.txt`.
+///
+/// Every problem is collected with its 1-based line and column instead of stopping at the
+/// first one, so a malformed file can be fixed in a single pass.
+pub struct TextExamParser<'a> {
+    lines: Vec<Line<'a>>,
+    cursor: usize,
+    diagnostics: Vec<ExtendedValidationError>,
+}
+
+impl<'a> TextExamParser<'a> {
+    pub fn parse(content: &'a str) -> Result<ParsedExam, ExtendedValidationError> {
+        let mut parser = TextExamParser {
+            lines: Self::split_lines(content),
+            cursor: 0,
+            diagnostics: vec![],
+        };
+
+        let mut questions: Vec<Question> = vec![];
+        let mut positions: Vec<QuestionPositions> = vec![];
+        while let Some(line) = parser.peek() {
+            if line.is_question_header() {
+                let (question, question_positions) = parser.question();
+                questions.push(question);
+                positions.push(question_positions);
+                continue;
+            }
+
+            // Free text in front of the first question, like a title, is skipped.
+            if line.starts_section() {
+                parser.report(
+                    line.position(0),
+                    format!("The line belongs to a question, but no question was started, expected a line starting with `{QUESTION_PREFIX}` first"),
+                );
+            }
+            parser.advance();
+        }
+
+        if questions.is_empty() && parser.diagnostics.is_empty() {
+            parser.report(
+                (1, 1),
+                format!("The text does not contain any question, expected a line starting with `{QUESTION_PREFIX}`"),
+            );
+        }
+
+        if !parser.diagnostics.is_empty() {
+            return Err(ExtendedValidationError::new(
+                0,
+                "Text import errors",
+                vec![],
+                parser.diagnostics,
+            ));
+        }
+
+        let exam = imported_exam(
+            IMPORTED_EXAM_NAME,
+            Some(IMPORTED_EXAM_DESCRIPTION.to_string()),
+            None,
+            questions,
+        );
+        Ok(ParsedExam { exam, positions })
+    }
+
+    fn split_lines(content: &str) -> Vec<Line<'_>> {
+        content
+            .lines()
+            .enumerate()
+            .filter_map(|(index, raw)| {
+                let text = raw.trim();
+                if text.is_empty() {
+                    return None;
+                }
+                let indent = raw[..raw.len() - raw.trim_start().len()].chars().count();
+                Some(Line {
+                    number: index + 1,
+                    indent,
+                    text,
+                })
+            })
+            .collect()
+    }
+
+    fn peek(&self) -> Option<Line<'a>> {
+        self.lines.get(self.cursor).copied()
+    }
+
+    fn advance(&mut self) {
+        self.cursor += 1;
+    }
+
+    fn report(&mut self, (line, column): (usize, usize), message: impl Into<String>) {
+        let index = self.diagnostics.len() as i32;
+        self.diagnostics
+            .push(ExtendedValidationError::new(index, message, vec![], vec![]).at(line, column));
+    }
+
+    fn question(&mut self) -> (Question, QuestionPositions) {
+        let header = self.peek().expect("question header");
+        self.advance();
+
+        let mut positions = QuestionPositions {
+            header: header.position(0),
+            ..QuestionPositions::default()
+        };
+
+        let mut text_lines: Vec<&str> = vec![];
+        while let Some(line) = self.peek() {
+            if line.starts_section() {
+                break;
+            }
+            text_lines.push(line.text);
+            self.advance();
+        }
+
+        let mut question = Question {
+            id: None,
+            question_text: text_lines.join(" "),
+            points_total: self.points(header),
+            r#type: QuestionType::SingleChoice,
+            answers: vec![],
+            points_per_correct_answer: None,
+            category: None,
+            created_at: None,
+            updated_at: None,
+            options: Some(vec![]),
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+            exam_id: None,
+        };
+
+        match self.peek() {
+            Some(line) if line.text.starts_with(ASSIGNMENT_PREFIX) => {
+                self.assignment(&mut question, &mut positions)
+            }
+            Some(line) if line.text.starts_with(NUMERIC_PREFIX) => {
+                self.numeric(&mut question, &mut positions)
+            }
+            _ => self.choices(&mut question, &mut positions),
+        }
+
+        if question.answers.is_empty() {
+            self.report(
+                positions.header,
+                format!("The question has no answers, expected `[X]` / `[ ]` lines, an `{ASSIGNMENT_PREFIX}` row or `{NUMERIC_PREFIX}` values"),
+            );
+        }
+
+        self.details(&mut question);
+        (question, positions)
+    }
+
+    fn points(&mut self, header: Line) -> i32 {
+        let Some(offset) = header.text.find(POINTS_PREFIX) else {
+            self.report(
+                header.position(header.text.len()),
+                format!(
+                    "Missing points, expected `{POINTS_PREFIX} <points>` in the question header"
+                ),
+            );
+            return 0;
+        };
+
+        let value = header.text[offset + POINTS_PREFIX.len()..].trim_start();
+        let value_offset = header.text.len() - value.len();
+        let digits = value
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(value, |end| &value[..end]);
+        match digits.parse::<i32>() {
+            Ok(points) => points,
+            Err(_) => {
+                self.report(
+                    header.position(value_offset),
+                    format!("Invalid points, expected a whole number after `{POINTS_PREFIX}`"),
+                );
+                0
+            }
+        }
+    }
+
+    /// Reads the `[X]` / `[ ]` marker at the start of `text`, reporting unknown markers.
+    fn marker(&mut self, line: Line, offset: usize) -> Option<(bool, usize)> {
+        let text = &line.text[offset..];
+        let captures = MARKER.captures(text)?;
+        let checked = match &captures[1] {
+            "x" | "X" => true,
+            " " => false,
+            other => {
+                self.report(
+                    line.position(offset + 1),
+                    format!("Unknown marker `[{other}]`, expected `[X]` or `[ ]`"),
+                );
+                false
+            }
+        };
+        Some((checked, captures[0].len()))
+    }
+
+    /// Answer text after the markers, without the optional `(a)` label.
+    fn answer_text(&mut self, line: Line, offset: usize) -> String {
+        let text = &line.text[offset..];
+        let label = LABEL.find(text).map_or(0, |m| m.end());
+        let answer_text = text[label..].trim();
+        if answer_text.is_empty() {
+            self.report(line.position(line.text.len()), "The answer text is missing");
+        }
+        answer_text.to_string()
+    }
+
+    fn choices(&mut self, question: &mut Question, positions: &mut QuestionPositions) {
+        while let Some(line) = self.peek().filter(Line::is_choice) {
+            self.advance();
+            let Some((is_correct, offset)) = self.marker(line, 0) else {
+                continue;
+            };
+            let answer_text = self.answer_text(line, offset);
+            positions.answers.push(line.position(0));
+            question.answers.push(Answer {
+                id: None,
+                description: Some(answer_text.clone()),
+                answer_text,
+                is_correct: Some(is_correct),
+                assigned_option_id: None,
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
+                created_at: None,
+                updated_at: None,
+                question_id: None,
+            });
+        }
+
+        let correct = question
+            .answers
+            .iter()
+            .filter(|a| a.is_correct == Some(true))
+            .count() as i32;
+        if correct > 1 {
+            question.r#type = QuestionType::MultipleChoice;
+            question.points_per_correct_answer =
+                Some((f64::from(question.points_total) / f64::from(correct)).round() as i32);
+        } else {
+            question.points_per_correct_answer = Some(0);
+        }
+    }
+
+    fn assignment(&mut self, question: &mut Question, positions: &mut QuestionPositions) {
+        let header = self.peek().expect("assignment header");
+        self.advance();
+
+        let (definition, mut offset) = header.after(ASSIGNMENT_PREFIX);
+        let mut options: Vec<AssignmentOption> = vec![];
+        for (index, part) in definition.split('|').enumerate() {
+            let text = part.trim();
+            let text_offset = offset + (part.len() - part.trim_start().len());
+            if text.is_empty() {
+                self.report(
+                    header.position(text_offset),
+                    format!("Option {} of the assignment has no text", index + 1),
+                );
+            }
+            positions.options.push(header.position(text_offset));
+            options.push(AssignmentOption {
+                row_id: None,
+                id: index as i32 + 1,
+                text: text.to_string(),
+                question_id: None,
+            });
+            offset += part.len() + 1;
+        }
+
+        while let Some(line) = self.peek().filter(Line::is_choice) {
+            self.advance();
+
+            let mut offset = 0;
+            let mut marks: Vec<bool> = vec![];
+            while let Some((checked, length)) = self.marker(line, offset) {
+                marks.push(checked);
+                offset += length;
+            }
+
+            if marks.len() != options.len() {
+                self.report(
+                    line.position(0),
+                    format!(
+                        "Expected {} markers, one per option of the `{ASSIGNMENT_PREFIX}` row, but found {}",
+                        options.len(),
+                        marks.len()
+                    ),
+                );
+            }
+            let assigned: Vec<usize> = marks
+                .iter()
+                .enumerate()
+                .filter(|(_, checked)| **checked)
+                .map(|(index, _)| index)
+                .collect();
+            if assigned.len() > 1 {
+                self.report(
+                    line.position(0),
+                    "The row assigns more than one option, mark exactly one column with `[X]`",
+                );
+            } else if assigned.is_empty() {
+                self.report(
+                    line.position(0),
+                    "The row does not assign an option, mark one column with `[X]`",
+                );
+            }
+
+            let answer_text = self.answer_text(line, offset);
+            positions.answers.push(line.position(0));
+            question.answers.push(Answer {
+                id: None,
+                description: Some(answer_text.clone()),
+                answer_text,
+                is_correct: None,
+                assigned_option_id: assigned.first().map(|index| *index as i32 + 1),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
+                created_at: None,
+                updated_at: None,
+                question_id: None,
+            });
+        }
+
+        question.r#type = QuestionType::Assignment;
+        question.points_per_correct_answer = Some(1);
+        question.options = Some(options);
+    }
+
+    fn numeric(&mut self, question: &mut Question, positions: &mut QuestionPositions) {
+        while let Some(line) = self
+            .peek()
+            .filter(|line| line.text.starts_with(NUMERIC_PREFIX))
+        {
+            self.advance();
+
+            let (definition, offset) = line.after(NUMERIC_PREFIX);
+            let Some(answer) = Self::numeric_answer(definition) else {
+                self.report(
+                    line.position(offset),
+                    format!("Malformed numeric answer, expected `{NUMERIC_PREFIX} <value> [± <tolerance>[%]] [unit]`"),
+                );
+                continue;
+            };
+
+            positions.answers.push(line.position(0));
+            question.answers.push(answer);
+        }
+
+        question.r#type = QuestionType::Numeric;
+        question.points_per_correct_answer = Some(0);
+    }
+
+    /// Correct answer of a numeric question from `<value> [± <tolerance>[%]] [unit]`, a
+    /// tolerance ending in `%` is relative to the value.
+    pub fn numeric_answer(definition: &str) -> Option<Answer> {
+        let captures = NUMERIC.captures(definition)?;
+        let to_number = |value: &str| value.replace(',', ".").parse::<f64>().unwrap_or(f64::NAN);
+        let value = &captures[1];
+        let is_relative = captures.get(3).is_some();
+        let tolerance = captures.get(2).map_or(0.0, |m| to_number(m.as_str()));
+        let unit = Some(captures[4].trim())
+            .filter(|unit| !unit.is_empty())
+            .map(str::to_string);
+
+        Some(Answer {
+            id: None,
+            answer_text: match &unit {
+                Some(unit) => format!("{value} {unit}"),
+                None => value.to_string(),
+            },
+            description: None,
+            is_correct: Some(true),
+            assigned_option_id: None,
+            match_mode: None,
+            numeric: Some(NumericAnswer {
+                expected_value: to_number(value),
+                tolerance: if is_relative {
+                    tolerance / 100.0
+                } else {
+                    tolerance
+                },
+                tolerance_mode: if is_relative {
+                    ToleranceMode::Relative
+                } else {
+                    ToleranceMode::Absolute
+                },
+                unit,
+            }),
+            position: None,
+            blank: None,
+            created_at: None,
+            updated_at: None,
+            question_id: None,
+        })
+    }
+
+    /// Explanation and sources follow the answers. Each `E:` line adds a line of Markdown to
+    /// the explanation, each `S: <title> | <chapter> | <page>` line adds a source where chapter
+    /// and page are optional and any part may be a URL instead.
+    fn details(&mut self, question: &mut Question) {
+        let mut explanation_lines: Vec<&str> = vec![];
+        let mut sources: Vec<SourceReference> = vec![];
+
+        while let Some(line) = self.peek() {
+            if line.is_question_header() {
+                break;
+            }
+            self.advance();
+
+            if line.text.starts_with(EXPLANATION_PREFIX) {
+                explanation_lines.push(line.after(EXPLANATION_PREFIX).0);
+            } else if line.text.starts_with(SOURCE_PREFIX) {
+                let (definition, offset) = line.after(SOURCE_PREFIX);
+                match Self::source(definition) {
+                    Some(source) => sources.push(source),
+                    None => self.report(
+                        line.position(offset),
+                        format!("The source is empty, expected `{SOURCE_PREFIX} <title> | <chapter> | <page>`"),
+                    ),
+                }
+            } else {
+                self.report(
+                    line.position(0),
+                    format!("Unexpected line after the answers, expected `{EXPLANATION_PREFIX}`, `{SOURCE_PREFIX}` or the next `{QUESTION_PREFIX}`"),
+                );
+            }
+        }
+
+        if !explanation_lines.is_empty() {
+            question.explanation = Some(explanation_lines.join("\n"));
+        }
+        if !sources.is_empty() {
+            question.sources = Some(sources);
+        }
+    }
+
+    fn source(definition: &str) -> Option<SourceReference> {
+        let parts: Vec<&str> = definition
+            .split('|')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect();
+        let url = parts
+            .iter()
+            .find(|part| part.starts_with("http://") || part.starts_with("https://"))
+            .copied();
+        let mut rest = parts.into_iter().filter(|part| Some(*part) != url);
+        let title = rest.next().or(url)?;
+
+        Some(SourceReference {
+            id: None,
+            question_id: None,
+            title: title.to_string(),
+            chapter: rest.next().map(str::to_string),
+            page: rest.next().map(str::to_string),
+            url: url.map(str::to_string),
+        })
+    }
+}
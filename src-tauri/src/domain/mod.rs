@@ -1,4 +1,11 @@
 pub(crate) mod entities;
+pub(crate) mod export;
+pub(crate) mod import;
 pub(crate) mod model;
+pub(crate) mod rendering;
+pub(crate) mod sampling;
+pub(crate) mod scheduling;
+pub(crate) mod scoring;
+pub(crate) mod statistics;
 pub(crate) mod traits;
 pub(crate) mod validation;
@@ -0,0 +1,15 @@
+use crate::domain::model::category_mastery::CategoryMastery;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type_mastery::QuestionTypeMastery;
+use serde::{Deserialize, Serialize};
+
+/// Practice questions weighted toward weak areas, together with the mastery
+/// estimates they were chosen by.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdaptivePracticeSet {
+    pub seed: i64,
+    pub questions: Vec<Question>,
+    pub category_mastery: Vec<CategoryMastery>,
+    pub question_type_mastery: Vec<QuestionTypeMastery>,
+}
@@ -1,3 +1,5 @@
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::numeric_answer::NumericAnswer;
 use crate::domain::traits::validation::Validation;
 use crate::domain::validation::field_validator::FieldValidator;
 use crate::domain::validation::validation_error::ValidationError;
@@ -5,6 +7,7 @@ use crate::domain::validation::validation_result::ValidationResult;
 use crate::domain::validation::validators::optional;
 use crate::domain::validation::validators::str_rules::{max_len, min_len, required};
 use chrono::{DateTime, Utc};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +26,24 @@ pub struct Answer {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assigned_option_id: Option<i32>,
 
+    /// How a short answer response is compared with `answer_text`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_mode: Option<MatchMode>,
+
+    /// Expected value and tolerance of a numeric question.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub numeric: Option<NumericAnswer>,
+
+    /// Correct position (starting at 1) of the item in an ordering question.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<i32>,
+
+    /// Number of the `{{n}}` placeholder of a cloze question the answer belongs to.
+    /// Answers with a `match_mode` are accepted typed answers, the others are the
+    /// choices of a dropdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blank: Option<i32>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
 
@@ -37,12 +58,33 @@ impl Validation for Answer {
     fn validate(&self) -> ValidationResult {
         let mut validation_errors: Vec<ValidationError> = vec![];
 
-        let answer_text_validator = FieldValidator::new("answer_text")
-            .rule(required())
-            .rule(min_len(5))
-            .rule(max_len(255));
+        // accepted short answers such as commands, numbers or words filling a blank are
+        // often shorter than five characters
+        let allows_short_text =
+            self.match_mode.is_some() || self.numeric.is_some() || self.blank.is_some();
+        let answer_text_validator = if allows_short_text {
+            FieldValidator::new("answer_text")
+                .rule(required())
+                .rule(max_len(255))
+        } else {
+            FieldValidator::new("answer_text")
+                .rule(required())
+                .rule(min_len(5))
+                .rule(max_len(255))
+        };
         validation_errors.extend(answer_text_validator.validate(&self.answer_text));
 
+        if self.match_mode == Some(MatchMode::Regex) && Regex::new(&self.answer_text).is_err() {
+            validation_errors.push(ValidationError::new(
+                "answer_text",
+                "The answer is not a valid regular expression",
+            ));
+        }
+
+        if let Some(Err(errors)) = self.numeric.as_ref().map(|n| n.validate()) {
+            validation_errors.extend(errors);
+        }
+
         let description_validator = FieldValidator::new("description")
             .rule(optional(min_len(5)))
             .rule(optional(max_len(255)));
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnswerScore {
+    pub answer_id: Option<i32>,
+    pub selected: bool,
+    /// Whether the learner handled this answer correctly: picked when it is correct,
+    /// left out when it is wrong, assigned to the expected option, put at the expected
+    /// position, or matched by the typed response of a short answer or numeric question.
+    pub is_correct: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_option_id: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_option_id: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_position: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<i32>,
+}
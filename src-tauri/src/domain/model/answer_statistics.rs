@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// How often an answer was picked by learners who answered its question.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnswerStatistics {
+    pub answer_id: Option<i32>,
+    pub answer_text: String,
+    pub is_correct: bool,
+    pub selection_count: i32,
+    pub selection_rate: f64,
+}
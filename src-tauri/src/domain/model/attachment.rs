@@ -0,0 +1,128 @@
+use crate::domain::model::question::Question;
+use crate::domain::traits::validation::Validation;
+use crate::domain::validation::field_validator::FieldValidator;
+use crate::domain::validation::validation_error::ValidationError;
+use crate::domain::validation::validation_result::ValidationResult;
+use crate::domain::validation::validators::str_rules::{max_len, pattern, required};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Largest file that can be attached to a question or an answer, in bytes.
+pub const MAX_ATTACHMENT_SIZE: usize = 10 * 1024 * 1024;
+
+/// Image or file attached to either a question or an answer. The content is stored once
+/// per distinct SHA-256 hash and shared by all attachments with the same content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub question_id: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer_id: Option<i32>,
+
+    pub file_name: String,
+    pub mime_type: String,
+
+    /// Hex encoded SHA-256 hash of the content, set by the backend.
+    #[serde(default)]
+    pub hash: String,
+
+    /// Size of the content in bytes, set by the backend.
+    #[serde(default)]
+    pub size: i32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// Base64 encoded content. Required on upload and only returned when a single
+    /// attachment is fetched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+}
+
+impl Attachment {
+    /// Hex encoded SHA-256 hash used as the storage key of the content.
+    pub fn content_hash(content: &[u8]) -> String {
+        format!("{:x}", Sha256::digest(content))
+    }
+
+    pub fn encode_data(content: &[u8]) -> String {
+        STANDARD.encode(content)
+    }
+
+    /// Whether the attachment belongs to `question` or one of its answers.
+    pub fn belongs_to(&self, question: &Question) -> bool {
+        (question.id.is_some() && self.question_id == question.id)
+            || question
+                .answers
+                .iter()
+                .any(|a| a.id.is_some() && self.answer_id == a.id)
+    }
+
+    /// File name with every character other than ASCII letters, digits, `.`, `_` and `-`
+    /// replaced by `_`, safe to use inside exported packages.
+    pub fn safe_file_name(&self) -> String {
+        self.file_name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || "._-".contains(c) {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    /// Decoded content of the attachment, `None` if no data was given.
+    pub fn decode_data(&self) -> Result<Option<Vec<u8>>, String> {
+        self.data
+            .as_ref()
+            .map(|data| {
+                STANDARD
+                    .decode(data.trim())
+                    .map_err(|e| format!("Attachment data is not valid base64: {e}"))
+            })
+            .transpose()
+    }
+}
+
+impl Validation for Attachment {
+    fn validate(&self) -> ValidationResult {
+        let mut validation_errors: Vec<ValidationError> = vec![];
+
+        if self.question_id.is_some() == self.answer_id.is_some() {
+            validation_errors.push(ValidationError::new(
+                "owner",
+                "An attachment belongs to either a question or an answer",
+            ));
+        }
+
+        let file_name_validator = FieldValidator::new("file_name")
+            .rule(required())
+            .rule(max_len(255));
+        validation_errors.extend(file_name_validator.validate(&self.file_name));
+
+        let mime_type_validator = FieldValidator::new("mime_type")
+            .rule(required())
+            .rule(max_len(100))
+            .rule(pattern(
+                Regex::new(r"^[\w.+-]+/[\w.+-]+$").expect("valid mime type pattern"),
+            ));
+        validation_errors.extend(mime_type_validator.validate(&self.mime_type));
+
+        if validation_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validation_errors)
+        }
+    }
+}
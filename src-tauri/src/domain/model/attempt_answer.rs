@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttemptAnswer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempt_id: Option<i32>,
+
+    pub question_id: i32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer_id: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_option_id: Option<i32>,
+
+    /// Typed response to a short answer or numeric question.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_response: Option<String>,
+
+    /// Position (starting at 1) the learner put the item at in an ordering question.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<i32>,
+
+    /// Placeholder of a cloze question the typed `text_response` fills.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blank: Option<i32>,
+
+    /// Seconds the learner spent on the question, as reported by the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_spent_seconds: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,26 @@
+use crate::application::crud::enum_converter_trait::EnumConverterTrait;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AttemptMode {
+    Simulation,
+    Certification,
+}
+
+impl EnumConverterTrait for AttemptMode {
+    fn convert_to_string(&self) -> &str {
+        match self {
+            AttemptMode::Simulation => "Simulation",
+            AttemptMode::Certification => "Certification",
+        }
+    }
+
+    fn convert_from_string(value: &str) -> Self {
+        match value {
+            "Simulation" => AttemptMode::Simulation,
+            "Certification" => AttemptMode::Certification,
+            _ => AttemptMode::Simulation,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Copy of the whole database, including attachments and the settings of all profiles.
+/// `schema_version` is the latest migration applied to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Backup {
+    pub path: String,
+    pub size: u64,
+    pub schema_version: String,
+}
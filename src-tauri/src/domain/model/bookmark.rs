@@ -0,0 +1,32 @@
+use crate::domain::model::question::Question;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Question bookmarked by a learner to come back to it later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bookmark {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    pub profile_id: i32,
+    pub question_id: i32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub question: Option<Question>,
+}
+
+impl Bookmark {
+    pub fn new(profile_id: i32, question_id: i32) -> Bookmark {
+        Bookmark {
+            id: None,
+            profile_id,
+            question_id,
+            created_at: None,
+            question: None,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::domain::model::category::Category;
+use serde::{Deserialize, Serialize};
+
+/// Estimated mastery of the questions of one category.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryMastery {
+    /// Questions without a category are grouped under none.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<Category>,
+
+    /// Graded responses to questions of the category.
+    pub responses: i32,
+    /// Smoothed share of the points earned, between 0 and 1.
+    pub mastery: f64,
+    pub mastered: bool,
+}
@@ -9,6 +9,7 @@ use crate::domain::validation::validators::optional;
 use crate::domain::validation::validators::str_rules::{max_len, min_len, required};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -44,6 +45,38 @@ pub struct Exam {
     pub questions: Vec<Question>,
 }
 
+impl Exam {
+    /// Latest time the exam, one of its questions or one of their answers was created
+    /// or changed.
+    pub fn last_modified_at(&self) -> Option<DateTime<Utc>> {
+        let questions = self.questions.iter().flat_map(|q| {
+            let answers = q.answers.iter().flat_map(|a| [a.created_at, a.updated_at]);
+            [q.created_at, q.updated_at].into_iter().chain(answers)
+        });
+        [self.created_at, self.updated_at]
+            .into_iter()
+            .chain(questions)
+            .flatten()
+            .max()
+    }
+
+    /// Hash of the exam with its questions, answers, assignment options and sources.
+    /// Every edit, added or deleted entry changes it, the order the entries were loaded
+    /// in does not.
+    pub fn content_revision(&self) -> String {
+        let mut exam = self.clone();
+        exam.questions.sort_by_key(|q| q.id);
+        for question in exam.questions.iter_mut() {
+            question.answers.sort_by_key(|a| a.id);
+            if let Some(options) = question.options.as_mut() {
+                options.sort_by_key(|o| o.id);
+            }
+        }
+        let content = serde_json::to_vec(&exam).unwrap_or_default();
+        format!("{:x}", Sha256::digest(content))
+    }
+}
+
 impl Validation for Exam {
     fn validate(&self) -> ValidationResult {
         let mut validation_errors: Vec<ValidationError> = vec![];
@@ -0,0 +1,78 @@
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::attempt_mode::AttemptMode;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExamAttempt {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    pub exam_id: i32,
+    pub profile_id: i32,
+    pub mode: AttemptMode,
+    pub started_at: DateTime<Utc>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub expired: bool,
+
+    /// Seed of the question and answer order; attempts without one keep the exam order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shuffle_seed: Option<i64>,
+
+    /// Question draw the attempt covers; attempts without one cover all questions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub draw_id: Option<i32>,
+
+    /// Revision of the exam content when the attempt started, see `Exam::content_revision`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_revision: Option<String>,
+
+    /// Ids of the attempt's questions in the order they were presented at the start.
+    #[serde(default)]
+    pub question_ids: Vec<i32>,
+
+    #[serde(default)]
+    pub answers: Vec<AttemptAnswer>,
+}
+
+impl ExamAttempt {
+    pub fn is_finished(&self) -> bool {
+        self.finished_at.is_some()
+    }
+
+    /// Whether `now` lies after the deadline plus the grace period. Attempts
+    /// without a deadline never run out of time.
+    pub fn is_past_deadline(&self, now: DateTime<Utc>, grace_period: Duration) -> bool {
+        self.deadline_at
+            .is_some_and(|deadline| now > deadline + grace_period)
+    }
+
+    /// Answers given until the deadline plus the grace period.
+    pub fn answers_in_time(&self, grace_period: Duration) -> Vec<AttemptAnswer> {
+        self.answers
+            .iter()
+            .filter(|a| match (a.created_at, self.deadline_at) {
+                (Some(created), Some(deadline)) => created <= deadline + grace_period,
+                _ => true,
+            })
+            .cloned()
+            .collect()
+    }
+}
@@ -0,0 +1,181 @@
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::category::Category;
+use crate::domain::model::exam::Exam;
+use crate::domain::traits::validation::Validation;
+use crate::domain::validation::validation_error::ValidationError;
+use crate::domain::validation::validation_result::ValidationResult;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Identifies a JSON document as an exported exam.
+pub const EXAM_BUNDLE_FORMAT: &str = "open-xam/exam";
+
+/// Version of the bundle layout, raised whenever the exported structure changes in a way
+/// older versions can't read.
+pub const EXAM_BUNDLE_SCHEMA_VERSION: i32 = 1;
+
+/// Self-contained export of an exam with its questions, answers, assignment options,
+/// sources, categories and attachments.
+///
+/// Database ids, foreign keys and timestamps are removed. Categories are referenced by
+/// name and attachments by the position of their question and answer, so the bundle can
+/// be imported into any installation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExamBundle {
+    pub format: String,
+    pub schema_version: i32,
+    pub exported_at: DateTime<Utc>,
+    pub exam: Exam,
+
+    #[serde(default)]
+    pub attachments: Vec<BundledAttachment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BundledAttachment {
+    /// Position of the owning question in `exam.questions`.
+    pub question: usize,
+
+    /// Position of the owning answer within the answers of that question, if the
+    /// attachment belongs to an answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<usize>,
+
+    pub file_name: String,
+    pub mime_type: String,
+
+    /// Base64 encoded content.
+    pub data: String,
+}
+
+impl ExamBundle {
+    /// Bundles the exam as loaded with its relations. `attachments` must carry their data;
+    /// attachments of other questions are ignored.
+    pub fn new(exam: &Exam, attachments: &[Attachment], exported_at: DateTime<Utc>) -> Self {
+        let bundled = attachments
+            .iter()
+            .filter_map(|attachment| {
+                let (question, answer) = Self::owner_position(exam, attachment)?;
+                Some(BundledAttachment {
+                    question,
+                    answer,
+                    file_name: attachment.file_name.clone(),
+                    mime_type: attachment.mime_type.clone(),
+                    data: attachment.data.clone().unwrap_or_default(),
+                })
+            })
+            .collect();
+
+        Self {
+            format: EXAM_BUNDLE_FORMAT.to_string(),
+            schema_version: EXAM_BUNDLE_SCHEMA_VERSION,
+            exported_at,
+            exam: Self::portable(exam.clone()),
+            attachments: bundled,
+        }
+    }
+
+    fn owner_position(exam: &Exam, attachment: &Attachment) -> Option<(usize, Option<usize>)> {
+        if let Some(answer_id) = attachment.answer_id {
+            return exam.questions.iter().enumerate().find_map(|(index, q)| {
+                q.answers
+                    .iter()
+                    .position(|a| a.id == Some(answer_id))
+                    .map(|answer| (index, Some(answer)))
+            });
+        }
+        exam.questions
+            .iter()
+            .position(|q| q.id.is_some() && q.id == attachment.question_id)
+            .map(|index| (index, None))
+    }
+
+    fn portable_category(category: Option<Category>) -> Option<Category> {
+        category.map(|c| Category {
+            id: None,
+            name: c.name,
+            created_at: None,
+            updated_at: None,
+        })
+    }
+
+    /// Removes everything that only has a meaning in the database the exam came from.
+    fn portable(mut exam: Exam) -> Exam {
+        exam.id = None;
+        exam.created_at = None;
+        exam.updated_at = None;
+        exam.category = Self::portable_category(exam.category);
+        for question in &mut exam.questions {
+            question.id = None;
+            question.exam_id = None;
+            question.created_at = None;
+            question.updated_at = None;
+            question.category = Self::portable_category(question.category.take());
+            for answer in &mut question.answers {
+                answer.id = None;
+                answer.question_id = None;
+                answer.created_at = None;
+                answer.updated_at = None;
+            }
+            for option in question.options.iter_mut().flatten() {
+                option.row_id = None;
+                option.question_id = None;
+            }
+            for source in question.sources.iter_mut().flatten() {
+                source.id = None;
+                source.question_id = None;
+            }
+        }
+        exam
+    }
+}
+
+impl Validation for ExamBundle {
+    fn validate(&self) -> ValidationResult {
+        let mut validation_errors: Vec<ValidationError> = vec![];
+
+        if self.format != EXAM_BUNDLE_FORMAT {
+            validation_errors.push(ValidationError::new(
+                "format",
+                format!("The file is not an exam bundle, expected format `{EXAM_BUNDLE_FORMAT}`"),
+            ));
+        }
+
+        if !(1..=EXAM_BUNDLE_SCHEMA_VERSION).contains(&self.schema_version) {
+            validation_errors.push(ValidationError::new(
+                "schema_version",
+                format!(
+                    "Schema version {} is not supported, this version reads up to {EXAM_BUNDLE_SCHEMA_VERSION}",
+                    self.schema_version
+                ),
+            ));
+        }
+
+        for (index, attachment) in self.attachments.iter().enumerate() {
+            let answers = self
+                .exam
+                .questions
+                .get(attachment.question)
+                .map(|q| q.answers.len());
+            let owner_exists = match (answers, attachment.answer) {
+                (Some(_), None) => true,
+                (Some(answers), Some(answer)) => answer < answers,
+                (None, _) => false,
+            };
+            if !owner_exists {
+                validation_errors.push(ValidationError::new(
+                    "attachments",
+                    format!("Attachment {index} refers to a question or answer that is not part of the exam"),
+                ));
+            }
+        }
+
+        if validation_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validation_errors)
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::domain::model::question_score::QuestionScore;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExamScore {
+    pub exam_id: Option<i32>,
+    pub points_earned: i32,
+    pub points_total: i32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub points_to_succeeded: Option<i32>,
+
+    /// `None` for exams without `points_to_succeeded`, they can't be passed or failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passed: Option<bool>,
+    pub questions: Vec<QuestionScore>,
+}
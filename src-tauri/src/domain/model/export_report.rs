@@ -0,0 +1,11 @@
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+use serde::{Deserialize, Serialize};
+
+/// File an exam was exported to, together with the questions the target format can't
+/// express. Each skipped entry carries the position of the question as `index`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportReport {
+    pub path: String,
+    pub skipped: Vec<ExtendedValidationError>,
+}
@@ -0,0 +1,13 @@
+use crate::domain::model::exam::Exam;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+use serde::{Deserialize, Serialize};
+
+/// Exam read from a foreign format, together with the items that had no equivalent and
+/// were left out. Each skipped entry carries the position of the item in the source as
+/// `index` and the reason as `message`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReport {
+    pub exam: Exam,
+    pub skipped: Vec<ExtendedValidationError>,
+}
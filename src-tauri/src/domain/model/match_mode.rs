@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How the typed response to a short answer question is compared with an accepted
+/// answer.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MatchMode {
+    /// The response must equal the answer character by character.
+    Exact,
+    CaseInsensitive,
+    /// Leading, trailing and repeated whitespace is ignored.
+    WhitespaceNormalized,
+    /// The answer is a regular expression the whole response must match.
+    Regex,
+}
+
+impl fmt::Display for MatchMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
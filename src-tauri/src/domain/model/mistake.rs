@@ -0,0 +1,60 @@
+use crate::domain::model::question::Question;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Entry of the mistake notebook: a question the learner answered incorrectly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Mistake {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    pub profile_id: i32,
+    pub question_id: i32,
+    pub miss_count: i32,
+    /// Correct answers given since the question was last missed.
+    pub correct_streak: i32,
+    pub last_missed_at: DateTime<Utc>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub question: Option<Question>,
+}
+
+impl Mistake {
+    /// Entry of a question that was just missed for the first time.
+    pub fn new(profile_id: i32, question_id: i32, now: DateTime<Utc>) -> Mistake {
+        Mistake {
+            id: None,
+            profile_id,
+            question_id,
+            miss_count: 1,
+            correct_streak: 0,
+            last_missed_at: now,
+            created_at: None,
+            updated_at: None,
+            question: None,
+        }
+    }
+
+    pub fn record_miss(&mut self, now: DateTime<Utc>) {
+        self.miss_count += 1;
+        self.correct_streak = 0;
+        self.last_missed_at = now;
+    }
+
+    pub fn record_correct(&mut self) {
+        self.correct_streak += 1;
+    }
+
+    /// Whether the question was answered correctly often enough in a row to leave
+    /// the notebook.
+    pub fn is_cleared(&self, clear_streak: i32) -> bool {
+        self.correct_streak >= clear_streak
+    }
+}
@@ -0,0 +1,16 @@
+use crate::domain::model::category::Category;
+use crate::domain::model::mistake::Mistake;
+use serde::{Deserialize, Serialize};
+
+/// Mistakes of one exam that share the same question category.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MistakeGroup {
+    pub exam_id: i32,
+    pub exam_name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<Category>,
+
+    pub mistakes: Vec<Mistake>,
+}
@@ -1,16 +1,49 @@
+pub(crate) mod adaptive_practice_set;
 pub(crate) mod answer;
+pub(crate) mod answer_score;
+pub(crate) mod answer_statistics;
 pub(crate) mod assignment_option;
+pub(crate) mod attachment;
+pub(crate) mod attempt_answer;
+pub mod attempt_mode;
+pub(crate) mod backup;
+pub(crate) mod bookmark;
 pub(crate) mod category;
+pub(crate) mod category_mastery;
 pub mod conjunction_type;
 pub(crate) mod exam;
+pub(crate) mod exam_attempt;
+pub(crate) mod exam_bundle;
+pub(crate) mod exam_score;
+pub(crate) mod export_report;
+pub(crate) mod match_mode;
 pub mod exam_overall_statistics;
 pub mod filter_option;
+pub(crate) mod import_report;
+pub(crate) mod mistake;
+pub(crate) mod mistake_group;
+pub(crate) mod numeric_answer;
 pub mod operator;
+pub(crate) mod ordering_scoring;
 pub(crate) mod page_options;
 pub(crate) mod paged_result;
+pub(crate) mod print_format;
+pub(crate) mod print_options;
+pub(crate) mod profile;
 pub(crate) mod question;
+pub(crate) mod question_draw;
+pub(crate) mod question_score;
+pub(crate) mod question_statistics;
 pub(crate) mod question_type;
+pub(crate) mod question_type_mastery;
+pub(crate) mod resumed_attempt;
+pub(crate) mod review_state;
+pub(crate) mod setting;
+pub(crate) mod source_reference;
+pub(crate) mod spreadsheet_import_report;
+pub(crate) mod spreadsheet_mapping;
 pub mod status_type;
+pub(crate) mod tolerance_mode;
 
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
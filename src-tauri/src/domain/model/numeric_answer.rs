@@ -0,0 +1,65 @@
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::traits::validation::Validation;
+use crate::domain::validation::field_validator::FieldValidator;
+use crate::domain::validation::validation_error::ValidationError;
+use crate::domain::validation::validation_result::ValidationResult;
+use crate::domain::validation::validators::num_rules::{max, min};
+use crate::domain::validation::validators::optional;
+use crate::domain::validation::validators::str_rules::{max_len, required};
+use serde::{Deserialize, Serialize};
+
+/// Expected value of a numeric question together with the accepted deviation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NumericAnswer {
+    pub expected_value: f64,
+
+    #[serde(default)]
+    pub tolerance: f64,
+
+    #[serde(default)]
+    pub tolerance_mode: ToleranceMode,
+
+    /// Unit shown next to the input, e.g. `Mbit/s`. A response may repeat it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+impl Validation for NumericAnswer {
+    fn validate(&self) -> ValidationResult {
+        let mut validation_errors: Vec<ValidationError> = vec![];
+
+        if !self.expected_value.is_finite() {
+            validation_errors.push(ValidationError::new(
+                "expected_value",
+                "The expected value must be a finite number",
+            ));
+        }
+
+        if self.tolerance.is_finite() {
+            let tolerance_validator = match self.tolerance_mode {
+                ToleranceMode::Absolute => FieldValidator::new("tolerance").rule(min(0.0)),
+                ToleranceMode::Relative => FieldValidator::new("tolerance")
+                    .rule(min(0.0))
+                    .rule(max(1.0)),
+            };
+            validation_errors.extend(tolerance_validator.validate(&self.tolerance));
+        } else {
+            validation_errors.push(ValidationError::new(
+                "tolerance",
+                "The tolerance must be a finite number",
+            ));
+        }
+
+        let unit_validator = FieldValidator::new("unit")
+            .rule(optional(required()))
+            .rule(optional(max_len(20)));
+        validation_errors.extend(unit_validator.validate(&self.unit));
+
+        if validation_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validation_errors)
+        }
+    }
+}
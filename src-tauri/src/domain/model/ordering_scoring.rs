@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How the submitted sequence of an ordering question is graded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderingScoring {
+    /// All points for the exact sequence, none otherwise.
+    #[default]
+    AllOrNothing,
+    /// Points in proportion to the items placed at their correct position.
+    CorrectlyPlaced,
+    /// Points in proportion to the item pairs kept in the correct relative order, i.e.
+    /// one minus the normalized Kendall tau distance.
+    KendallTau,
+}
+
+impl fmt::Display for OrderingScoring {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
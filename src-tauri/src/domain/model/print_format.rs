@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Document format a printable exam is rendered to.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PrintFormat {
+    #[default]
+    Markdown,
+    /// Standalone HTML page with inline styles, ready for the browser's print dialog.
+    Html,
+    Pdf,
+}
+
+impl fmt::Display for PrintFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
@@ -0,0 +1,33 @@
+use crate::domain::model::print_format::PrintFormat;
+use serde::{Deserialize, Serialize};
+
+/// What a printable exam contains. Omitted fields keep their default: the questions in
+/// their stored order with points and a cover page, without solutions.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PrintOptions {
+    pub format: PrintFormat,
+    /// Number of the shuffled variant, starting at 1. Each variant puts the questions,
+    /// answers and assignment options into its own order, the same number always gives
+    /// the same order. Unset keeps the stored order.
+    pub variant: Option<u32>,
+    /// Renders the solutions of the variant instead of the blank answer sheet.
+    pub answer_key: bool,
+    /// Shows the points of each question next to its number.
+    pub show_points: bool,
+    /// Starts the document with a page showing the number of questions, total points,
+    /// duration and points needed to pass.
+    pub cover_page: bool,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            format: PrintFormat::Markdown,
+            variant: None,
+            answer_key: false,
+            show_points: true,
+            cover_page: true,
+        }
+    }
+}
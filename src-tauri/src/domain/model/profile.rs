@@ -0,0 +1,55 @@
+use crate::domain::traits::validation::Validation;
+use crate::domain::validation::field_validator::FieldValidator;
+use crate::domain::validation::validation_result::ValidationResult;
+use crate::domain::validation::validators::str_rules::{max_len, required};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Local learner. Attempts, review states and settings belong to a profile while
+/// exams and categories are shared by all profiles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    pub name: String,
+
+    #[serde(default)]
+    pub active: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Profile {
+    pub fn new(name: &str) -> Profile {
+        Profile {
+            id: None,
+            name: name.trim().to_string(),
+            active: false,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+impl Validation for Profile {
+    fn validate(&self) -> ValidationResult {
+        let mut validation_errors = vec![];
+
+        let name_validator = FieldValidator::new("name")
+            .rule(required())
+            .rule(max_len(255));
+        validation_errors.extend(name_validator.validate(&self.name));
+
+        if validation_errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(validation_errors)
+    }
+}
@@ -1,7 +1,9 @@
 use crate::domain::model::answer::Answer;
 use crate::domain::model::assignment_option::AssignmentOption;
 use crate::domain::model::category::Category;
+use crate::domain::model::ordering_scoring::OrderingScoring;
 use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::source_reference::SourceReference;
 use crate::domain::traits::validation::Validation;
 use crate::domain::validation::field_validator::FieldValidator;
 use crate::domain::validation::validation_error::ValidationError;
@@ -10,7 +12,19 @@ use crate::domain::validation::validators::num_rules::{max, min};
 use crate::domain::validation::validators::optional;
 use crate::domain::validation::validators::str_rules::{max_len, min_len, required};
 use chrono::{DateTime, Utc};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+/// Longest Markdown explanation a question may have, in characters.
+pub const MAX_EXPLANATION_LENGTH: usize = 10_000;
+
+/// Most source references a question may have.
+pub const MAX_SOURCES: usize = 20;
+
+/// `{{n}}` placeholder of a blank in the question text of a cloze question, capturing `n`.
+pub(crate) static CLOZE_PLACEHOLDER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{\s*(\d+)\s*\}\}").expect("valid placeholder pattern"));
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -37,6 +51,19 @@ pub struct Question {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<Vec<AssignmentOption>>,
+
+    /// How a partially correct ordering question is graded, all-or-nothing if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ordering_scoring: Option<OrderingScoring>,
+
+    /// Markdown explaining why the correct answers are correct, shown when reviewing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+
+    /// Sources to look the question up in. `None` leaves stored sources untouched on update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sources: Option<Vec<SourceReference>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exam_id: Option<i32>,
 }
@@ -53,6 +80,20 @@ impl Question {
         question
     }
 
+    /// Numbers of the `{{n}}` placeholders in the question text of a cloze question, in
+    /// order of their first occurrence.
+    pub fn cloze_blanks(&self) -> Vec<i32> {
+        let mut blanks: Vec<i32> = vec![];
+        for capture in CLOZE_PLACEHOLDER.captures_iter(&self.question_text) {
+            if let Ok(blank) = capture[1].parse::<i32>() {
+                if !blanks.contains(&blank) {
+                    blanks.push(blank);
+                }
+            }
+        }
+        blanks
+    }
+
     pub fn validate_options(&self, constraint_check: bool) -> ValidationResult {
         let mut validation_errors: Vec<ValidationError> = vec![];
 
@@ -80,9 +121,47 @@ impl Question {
         Err(validation_errors)
     }
 
+    pub fn validate_sources(&self) -> ValidationResult {
+        let mut validation_errors: Vec<ValidationError> = vec![];
+
+        if let Some(sources) = &self.sources {
+            if sources.len() > MAX_SOURCES {
+                validation_errors.push(ValidationError::new(
+                    "sources",
+                    format!("A question may not have more than {MAX_SOURCES} sources"),
+                ));
+            }
+            for source in sources {
+                if let Err(e) = source.validate() {
+                    validation_errors.extend(e);
+                }
+            }
+        }
+
+        if validation_errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(validation_errors)
+    }
+
     pub fn validate_answers(&self) -> ValidationResult {
         let mut validation_errors: Vec<ValidationError> = vec![];
-        if self.answers.len() < 2 {
+        if self.r#type == QuestionType::ShortAnswer {
+            if self.answers.is_empty() {
+                validation_errors.push(ValidationError::new(
+                    "answers",
+                    "Question with type SHORT_ANSWER needs at least one accepted answer!",
+                ));
+            }
+        } else if self.r#type == QuestionType::Numeric {
+            if self.answers.is_empty() {
+                validation_errors.push(ValidationError::new(
+                    "answers",
+                    "Question with type NUMERIC needs at least one expected value!",
+                ));
+            }
+        } else if self.r#type != QuestionType::Cloze && self.answers.len() < 2 {
             validation_errors.push(ValidationError::new(
                 "answers",
                 "A Question needs always answers, but none was provided!",
@@ -130,6 +209,43 @@ impl Question {
                     ));
                 }
             }
+            QuestionType::ShortAnswer => {
+                if self.answers.iter().any(|a| a.match_mode.is_none()) {
+                    validation_errors.push(ValidationError::new(
+                        "answers",
+                        "Answers for Question with type SHORT_ANSWER need a match_mode!",
+                    ));
+                }
+            }
+            QuestionType::Numeric => {
+                if self.answers.iter().any(|a| a.numeric.is_none()) {
+                    validation_errors.push(ValidationError::new(
+                        "answers",
+                        "Answers for Question with type NUMERIC need an expected value!",
+                    ));
+                }
+            }
+            QuestionType::Cloze => validation_errors.extend(self.validate_cloze_answers()),
+            QuestionType::Ordering => {
+                let mut positions: Vec<i32> =
+                    self.answers.iter().filter_map(|a| a.position).collect();
+                positions.sort_unstable();
+                if positions.len() != self.answers.len() {
+                    validation_errors.push(ValidationError::new(
+                        "answers",
+                        "Answers for Question with type ORDERING need a position!",
+                    ));
+                } else if positions
+                    .iter()
+                    .zip(1..)
+                    .any(|(position, expected)| *position != expected)
+                {
+                    validation_errors.push(ValidationError::new(
+                        "answers",
+                        "Positions of Question with type ORDERING must be unique and run from 1 to the number of answers!",
+                    ));
+                }
+            }
         }
 
         if validation_errors.is_empty() {
@@ -137,6 +253,60 @@ impl Question {
         }
         Err(validation_errors)
     }
+
+    fn validate_cloze_answers(&self) -> Vec<ValidationError> {
+        let mut validation_errors: Vec<ValidationError> = vec![];
+        let blanks = self.cloze_blanks();
+        if blanks.is_empty() {
+            validation_errors.push(ValidationError::new(
+                "question_text",
+                "Question with type CLOZE needs at least one placeholder like {{1}}!",
+            ));
+        }
+
+        for blank in &blanks {
+            let answers: Vec<&Answer> = self
+                .answers
+                .iter()
+                .filter(|a| a.blank == Some(*blank))
+                .collect();
+            let typed = answers.iter().filter(|a| a.match_mode.is_some()).count();
+            let correct_choices = answers
+                .iter()
+                .filter(|a| a.match_mode.is_none() && a.is_correct == Some(true))
+                .count();
+
+            if answers.is_empty() {
+                validation_errors.push(ValidationError::new(
+                    "answers",
+                    format!("Blank {{{{{blank}}}}} of Question with type CLOZE needs answers!"),
+                ));
+            } else if typed > 0 && typed < answers.len() {
+                validation_errors.push(ValidationError::new(
+                    "answers",
+                    format!("Blank {{{{{blank}}}}} mixes typed answers with dropdown choices!"),
+                ));
+            } else if typed == 0 && correct_choices != 1 {
+                validation_errors.push(ValidationError::new(
+                    "answers",
+                    format!("Dropdown of blank {{{{{blank}}}}} must contain exactly one correct choice!"),
+                ));
+            }
+        }
+
+        if self
+            .answers
+            .iter()
+            .any(|a| !a.blank.is_some_and(|blank| blanks.contains(&blank)))
+        {
+            validation_errors.push(ValidationError::new(
+                "answers",
+                "Answers for Question with type CLOZE must belong to a placeholder of the question text!",
+            ));
+        }
+
+        validation_errors
+    }
 }
 
 impl Validation for Question {
@@ -159,6 +329,10 @@ impl Validation for Question {
         validation_errors
             .extend(points_per_correct_answer_validator.validate(&self.points_per_correct_answer));
 
+        let explanation_validator =
+            FieldValidator::new("explanation").rule(optional(max_len(MAX_EXPLANATION_LENGTH)));
+        validation_errors.extend(explanation_validator.validate(&self.explanation));
+
         if validation_errors.is_empty() {
             Ok(())
         } else {
@@ -0,0 +1,24 @@
+use crate::domain::model::question::Question;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionDraw {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    pub exam_id: i32,
+    pub profile_id: i32,
+    pub seed: i64,
+    pub stratified: bool,
+
+    #[serde(default)]
+    pub question_ids: Vec<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub questions: Vec<Question>,
+}
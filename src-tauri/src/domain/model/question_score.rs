@@ -0,0 +1,17 @@
+use crate::domain::model::answer_score::AnswerScore;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionScore {
+    pub question_id: Option<i32>,
+    pub points_earned: i32,
+    pub points_total: i32,
+    pub answers: Vec<AnswerScore>,
+}
+
+impl QuestionScore {
+    pub fn is_fully_correct(&self) -> bool {
+        self.points_earned >= self.points_total
+    }
+}
@@ -0,0 +1,25 @@
+use crate::domain::model::answer_statistics::AnswerStatistics;
+use serde::{Deserialize, Serialize};
+
+/// Item analysis of a question over the finished attempts of its exam.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionStatistics {
+    pub question_id: Option<i32>,
+    pub question_text: String,
+    /// Number of finished attempts that presented the question, skipped or not.
+    pub responses: i32,
+
+    /// Average share of the points earned (p-value); high values mean easy questions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub difficulty_index: Option<f64>,
+
+    /// Difficulty in the upper 27% of attempts minus the difficulty in the lower 27%.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discrimination_index: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_time_seconds: Option<f64>,
+
+    pub answers: Vec<AnswerStatistics>,
+}
@@ -8,6 +8,10 @@ pub enum QuestionType {
     Assignment,
     MultipleChoice,
     SingleChoice,
+    ShortAnswer,
+    Numeric,
+    Ordering,
+    Cloze,
 }
 
 impl fmt::Display for QuestionType {
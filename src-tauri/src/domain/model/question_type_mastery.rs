@@ -0,0 +1,15 @@
+use crate::domain::model::question_type::QuestionType;
+use serde::{Deserialize, Serialize};
+
+/// Estimated mastery of the questions of one question type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionTypeMastery {
+    pub question_type: QuestionType,
+
+    /// Graded responses to questions of the type.
+    pub responses: i32,
+    /// Smoothed share of the points earned, between 0 and 1.
+    pub mastery: f64,
+    pub mastered: bool,
+}
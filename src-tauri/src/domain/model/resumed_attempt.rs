@@ -0,0 +1,16 @@
+use crate::domain::model::exam::Exam;
+use crate::domain::model::exam_attempt::ExamAttempt;
+use serde::{Deserialize, Serialize};
+
+/// Everything needed to continue an open attempt: the attempt with the answers given
+/// so far and its exam with the questions in the attempt's order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumedAttempt {
+    pub attempt: ExamAttempt,
+    pub exam: Exam,
+
+    /// Seconds left until the deadline; untimed attempts have none.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_seconds: Option<i64>,
+}
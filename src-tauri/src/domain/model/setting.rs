@@ -0,0 +1,73 @@
+use crate::domain::traits::validation::Validation;
+use crate::domain::validation::field_validator::FieldValidator;
+use crate::domain::validation::validation_error::ValidationError;
+use crate::domain::validation::validation_result::ValidationResult;
+use crate::domain::validation::validators::str_rules::{max_len, required};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Seconds an answer may arrive after the deadline of a timed attempt. Learners can change
+/// the setting, so it is limited to `MAX_ATTEMPT_GRACE_PERIOD_SECONDS`.
+pub const ATTEMPT_GRACE_PERIOD_SECONDS: &str = "attempt_grace_period_seconds";
+pub const DEFAULT_ATTEMPT_GRACE_PERIOD_SECONDS: i64 = 30;
+pub const MAX_ATTEMPT_GRACE_PERIOD_SECONDS: i64 = 300;
+
+/// Consecutive correct answers after which a question leaves the mistake notebook.
+pub const MISTAKE_CLEAR_STREAK: &str = "mistake_clear_streak";
+pub const DEFAULT_MISTAKE_CLEAR_STREAK: i32 = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Setting {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_id: Option<i32>,
+
+    pub key: String,
+    pub value: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Validation for Setting {
+    fn validate(&self) -> ValidationResult {
+        let mut validation_errors = vec![];
+
+        let key_validator = FieldValidator::new("key")
+            .rule(required())
+            .rule(max_len(255));
+        validation_errors.extend(key_validator.validate(&self.key));
+
+        let value_validator = FieldValidator::new("value").rule(max_len(4000));
+        validation_errors.extend(value_validator.validate(&self.value));
+
+        if self.key == ATTEMPT_GRACE_PERIOD_SECONDS
+            && !self
+                .value
+                .parse::<i64>()
+                .is_ok_and(|seconds| (0..=MAX_ATTEMPT_GRACE_PERIOD_SECONDS).contains(&seconds))
+        {
+            validation_errors.push(ValidationError::new(
+                "value",
+                format!(
+                    "The grace period must be between 0 and {MAX_ATTEMPT_GRACE_PERIOD_SECONDS} seconds"
+                ),
+            ));
+        }
+
+        if self.key == MISTAKE_CLEAR_STREAK
+            && !self.value.parse::<i32>().is_ok_and(|streak| streak >= 1)
+        {
+            validation_errors.push(ValidationError::new(
+                "value",
+                "The mistake clear streak must be a positive number",
+            ));
+        }
+
+        if validation_errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(validation_errors)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::domain::traits::validation::Validation;
+use crate::domain::validation::field_validator::FieldValidator;
+use crate::domain::validation::validation_error::ValidationError;
+use crate::domain::validation::validation_result::ValidationResult;
+use crate::domain::validation::validators::optional;
+use crate::domain::validation::validators::str_rules::{max_len, pattern, required};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Where the content of a question can be looked up, e.g. a book with chapter and page
+/// or a web page.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceReference {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub question_id: Option<i32>,
+
+    /// Title of the book, standard or web page.
+    pub title: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chapter: Option<String>,
+
+    /// Page or page range, e.g. `42` or `42-45`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl Validation for SourceReference {
+    fn validate(&self) -> ValidationResult {
+        let mut validation_errors: Vec<ValidationError> = vec![];
+
+        let title_validator = FieldValidator::new("title")
+            .rule(required())
+            .rule(max_len(255));
+        validation_errors.extend(title_validator.validate(&self.title));
+
+        let chapter_validator = FieldValidator::new("chapter")
+            .rule(optional(required()))
+            .rule(optional(max_len(255)));
+        validation_errors.extend(chapter_validator.validate(&self.chapter));
+
+        let page_validator = FieldValidator::new("page")
+            .rule(optional(required()))
+            .rule(optional(max_len(50)));
+        validation_errors.extend(page_validator.validate(&self.page));
+
+        let url_validator = FieldValidator::new("url")
+            .rule(optional(max_len(2048)))
+            .rule(optional(pattern(
+                Regex::new(r"^https?://\S+$").expect("valid url pattern"),
+            )));
+        validation_errors.extend(url_validator.validate(&self.url));
+
+        if validation_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validation_errors)
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::domain::model::question::Question;
+use crate::domain::validation::extended_validation_error::ExtendedValidationError;
+use serde::{Deserialize, Serialize};
+
+/// Outcome of a spreadsheet import. `skipped` and `invalid` hold one entry per row that
+/// was not imported, with the row as `line` and the reason as `message`; invalid rows list
+/// every problem of the row in `nested_errors`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadsheetImportReport {
+    pub created: Vec<Question>,
+    pub skipped: Vec<ExtendedValidationError>,
+    pub invalid: Vec<ExtendedValidationError>,
+}
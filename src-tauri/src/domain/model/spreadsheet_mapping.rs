@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// Header names of the columns a spreadsheet import reads, matched case-insensitively
+/// against the header row. Only the question text and answers columns are required; the
+/// order of the columns doesn't matter and unknown columns are ignored.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SpreadsheetMapping {
+    pub question_text: String,
+    /// `single_choice`, `multiple_choice`, `assignment`, `short_answer` or `numeric`.
+    /// Without a type, rows with options are assignments and the others single or multiple
+    /// choice questions depending on the number of correct answers.
+    pub r#type: String,
+    pub points: String,
+    pub answers: String,
+    /// A flag per answer, or for assignments the option of each answer by text or number.
+    pub correct: String,
+    pub category: String,
+    pub options: String,
+    pub explanation: String,
+    /// Separates the answers, correct flags and options within one cell.
+    pub separator: String,
+}
+
+impl Default for SpreadsheetMapping {
+    fn default() -> Self {
+        Self {
+            question_text: "Question".to_string(),
+            r#type: "Type".to_string(),
+            points: "Points".to_string(),
+            answers: "Answers".to_string(),
+            correct: "Correct".to_string(),
+            category: "Category".to_string(),
+            options: "Options".to_string(),
+            explanation: "Explanation".to_string(),
+            separator: "|".to_string(),
+        }
+    }
+}
@@ -1,4 +1,7 @@
 use crate::domain::model::answer::Answer;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::tolerance_mode::ToleranceMode;
 use crate::domain::traits::validation::Validation;
 
 #[test]
@@ -12,6 +15,10 @@ fn test_answer_validation_valid() {
         created_at: None,
         updated_at: None,
         question_id: Some(1),
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
     };
     assert!(a.validate().is_ok());
 }
@@ -27,6 +34,10 @@ fn test_answer_validation_invalid_text() {
         created_at: None,
         updated_at: None,
         question_id: None,
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
     };
     assert!(a.validate().is_err());
     
@@ -45,6 +56,10 @@ fn test_answer_validation_invalid_description() {
         created_at: None,
         updated_at: None,
         question_id: None,
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
     };
     assert!(a.validate().is_err());
     
@@ -63,8 +78,67 @@ fn test_answer_clone_and_debug() {
         created_at: None,
         updated_at: None,
         question_id: Some(1),
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
     };
     let a2 = a.clone();
     assert_eq!(a.answer_text, a2.answer_text);
     assert!(format!("{:?}", a).contains("Answer"));
 }
+
+#[test]
+fn test_answer_validation_short_answer_pattern() {
+    let mut a = Answer {
+        id: None,
+        answer_text: "ls".into(),
+        description: None,
+        is_correct: Some(true),
+        assigned_option_id: None,
+        created_at: None,
+        updated_at: None,
+        question_id: None,
+        match_mode: Some(MatchMode::Exact),
+        numeric: None,
+        position: None,
+        blank: None,
+    };
+    assert!(a.validate().is_ok());
+
+    a.answer_text = "ls (-l|-a".into();
+    assert!(a.validate().is_ok());
+
+    a.match_mode = Some(MatchMode::Regex);
+    assert!(a.validate().is_err());
+
+    a.answer_text = "ls (-l|-a)".into();
+    assert!(a.validate().is_ok());
+}
+
+#[test]
+fn test_answer_validation_numeric_definition() {
+    let mut a = Answer {
+        id: None,
+        answer_text: "62".into(),
+        description: None,
+        is_correct: Some(true),
+        assigned_option_id: None,
+        created_at: None,
+        updated_at: None,
+        question_id: None,
+        match_mode: None,
+        numeric: Some(NumericAnswer {
+            expected_value: 62.0,
+            tolerance: 0.5,
+            tolerance_mode: ToleranceMode::Relative,
+            unit: Some("hosts".into()),
+        }),
+        position: None,
+        blank: None,
+    };
+    assert!(a.validate().is_ok());
+
+    a.numeric.as_mut().unwrap().tolerance = 1.5;
+    assert!(a.validate().is_err());
+}
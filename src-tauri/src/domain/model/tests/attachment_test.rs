@@ -0,0 +1,81 @@
+use crate::domain::model::attachment::Attachment;
+use crate::domain::traits::validation::Validation;
+
+fn attachment() -> Attachment {
+    Attachment {
+        id: None,
+        question_id: Some(1),
+        answer_id: None,
+        file_name: "diagram.png".into(),
+        mime_type: "image/png".into(),
+        hash: String::new(),
+        size: 0,
+        created_at: None,
+        data: Some(Attachment::encode_data(b"png")),
+    }
+}
+
+#[test]
+fn test_attachment_content_hash_is_sha256_hex() {
+    assert_eq!(
+        Attachment::content_hash(b"abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn test_attachment_decode_data() {
+    assert_eq!(attachment().decode_data().unwrap(), Some(b"png".to_vec()));
+
+    let without_data = Attachment {
+        data: None,
+        ..attachment()
+    };
+    assert_eq!(without_data.decode_data().unwrap(), None);
+
+    let invalid = Attachment {
+        data: Some("not base64!".into()),
+        ..attachment()
+    };
+    assert!(invalid.decode_data().is_err());
+}
+
+#[test]
+fn test_attachment_validation_valid() {
+    assert!(attachment().validate().is_ok());
+    let on_answer = Attachment {
+        question_id: None,
+        answer_id: Some(2),
+        mime_type: "application/vnd.ms-excel".into(),
+        ..attachment()
+    };
+    assert!(on_answer.validate().is_ok());
+}
+
+#[test]
+fn test_attachment_validation_requires_exactly_one_owner() {
+    let without_owner = Attachment {
+        question_id: None,
+        ..attachment()
+    };
+    let errors = without_owner.validate().unwrap_err();
+    assert!(errors.iter().any(|e| e.field == "owner"));
+
+    let with_both = Attachment {
+        answer_id: Some(2),
+        ..attachment()
+    };
+    assert!(with_both.validate().is_err());
+}
+
+#[test]
+fn test_attachment_validation_invalid_file_name_and_mime_type() {
+    let invalid = Attachment {
+        file_name: " ".into(),
+        mime_type: "png".into(),
+        ..attachment()
+    };
+    let errors = invalid.validate().unwrap_err();
+    assert!(errors.iter().any(|e| e.field == "file_name"));
+    assert!(errors.iter().any(|e| e.field == "mime_type"));
+}
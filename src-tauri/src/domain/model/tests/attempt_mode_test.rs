@@ -0,0 +1,39 @@
+use crate::application::crud::enum_converter_trait::EnumConverterTrait;
+use crate::domain::model::attempt_mode::AttemptMode;
+
+#[test]
+fn test_convert_to_string() {
+    assert_eq!(AttemptMode::Simulation.convert_to_string(), "Simulation");
+    assert_eq!(
+        AttemptMode::Certification.convert_to_string(),
+        "Certification"
+    );
+}
+
+#[test]
+fn test_convert_from_string() {
+    assert_eq!(
+        AttemptMode::convert_from_string("Simulation"),
+        AttemptMode::Simulation
+    );
+    assert_eq!(
+        AttemptMode::convert_from_string("Certification"),
+        AttemptMode::Certification
+    );
+}
+
+#[test]
+fn test_convert_from_string_invalid_defaults_to_simulation() {
+    assert_eq!(
+        AttemptMode::convert_from_string("Unknown"),
+        AttemptMode::Simulation
+    );
+}
+
+#[test]
+fn test_attempt_mode_serde() {
+    let json = serde_json::to_string(&AttemptMode::Certification).unwrap();
+    assert_eq!(json, "\"CERTIFICATION\"");
+    let mode: AttemptMode = serde_json::from_str("\"SIMULATION\"").unwrap();
+    assert_eq!(mode, AttemptMode::Simulation);
+}
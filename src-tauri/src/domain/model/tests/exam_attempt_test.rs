@@ -0,0 +1,78 @@
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::exam_attempt::ExamAttempt;
+use chrono::{DateTime, Duration, Utc};
+
+fn at(seconds: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(1_700_000_000 + seconds, 0).unwrap()
+}
+
+fn answer(question_id: i32, created_at: Option<DateTime<Utc>>) -> AttemptAnswer {
+    AttemptAnswer {
+        id: None,
+        attempt_id: Some(1),
+        question_id,
+        answer_id: Some(question_id),
+        assigned_option_id: None,
+        time_spent_seconds: None,
+        created_at,
+        text_response: None,
+        position: None,
+        blank: None,
+    }
+}
+
+fn attempt(deadline_at: Option<DateTime<Utc>>) -> ExamAttempt {
+    ExamAttempt {
+        id: Some(1),
+        exam_id: 1,
+        profile_id: 1,
+        mode: AttemptMode::Certification,
+        started_at: at(0),
+        finished_at: None,
+        score: None,
+        created_at: None,
+        updated_at: None,
+        deadline_at,
+        expired: false,
+        shuffle_seed: None,
+        draw_id: None,
+        content_revision: None,
+        question_ids: vec![],
+        answers: vec![
+            answer(1, Some(at(100))),
+            answer(2, Some(at(610))),
+            answer(3, Some(at(700))),
+            answer(4, None),
+        ],
+    }
+}
+
+#[test]
+fn test_attempt_without_deadline_never_expires() {
+    let a = attempt(None);
+    assert!(!a.is_past_deadline(at(1_000_000), Duration::zero()));
+    assert_eq!(a.answers_in_time(Duration::zero()).len(), 4);
+}
+
+#[test]
+fn test_attempt_is_past_deadline_after_grace_period() {
+    let a = attempt(Some(at(600)));
+    assert!(!a.is_past_deadline(at(600), Duration::seconds(30)));
+    assert!(!a.is_past_deadline(at(630), Duration::seconds(30)));
+    assert!(a.is_past_deadline(at(631), Duration::seconds(30)));
+}
+
+#[test]
+fn test_answers_in_time_respect_grace_period() {
+    let a = attempt(Some(at(600)));
+    let in_time: Vec<i32> = a
+        .answers_in_time(Duration::seconds(30))
+        .iter()
+        .map(|a| a.question_id)
+        .collect();
+    assert_eq!(in_time, vec![1, 2, 4]);
+
+    let in_time = a.answers_in_time(Duration::zero());
+    assert_eq!(in_time.len(), 2);
+}
@@ -0,0 +1,123 @@
+use crate::domain::import::text_exam_parser::TextExamParser;
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::category::Category;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::exam_bundle::{
+    BundledAttachment, ExamBundle, EXAM_BUNDLE_FORMAT, EXAM_BUNDLE_SCHEMA_VERSION,
+};
+use crate::domain::traits::validation::Validation;
+use chrono::Utc;
+
+/// Exam as loaded from the database, with ids on every level.
+fn stored_exam() -> Exam {
+    let content = "Q: Select one P: 1\nWhich topology is shown?\n[X] (a) Star topology\n[ ] (b) Ring topology\nS: Computer Networks | 1 | 12";
+    let mut exam = TextExamParser::parse(content).unwrap().exam;
+    exam.id = Some(7);
+    exam.created_at = Some(Utc::now());
+    exam.category = Some(Category {
+        id: Some(3),
+        name: "Networking".into(),
+        created_at: Some(Utc::now()),
+        updated_at: None,
+    });
+    let question = &mut exam.questions[0];
+    question.id = Some(11);
+    question.exam_id = Some(7);
+    for (index, answer) in question.answers.iter_mut().enumerate() {
+        answer.id = Some(20 + index as i32);
+        answer.question_id = Some(11);
+    }
+    for source in question.sources.iter_mut().flatten() {
+        source.id = Some(5);
+        source.question_id = Some(11);
+    }
+    exam
+}
+
+fn attachment(question_id: Option<i32>, answer_id: Option<i32>) -> Attachment {
+    Attachment {
+        id: Some(1),
+        question_id,
+        answer_id,
+        file_name: "diagram.png".into(),
+        mime_type: "image/png".into(),
+        hash: Attachment::content_hash(b"png"),
+        size: 3,
+        created_at: None,
+        data: Some(Attachment::encode_data(b"png")),
+    }
+}
+
+#[test]
+fn test_exam_bundle_strips_database_ids() {
+    let bundle = ExamBundle::new(&stored_exam(), &[], Utc::now());
+
+    assert_eq!(bundle.format, EXAM_BUNDLE_FORMAT);
+    assert_eq!(bundle.schema_version, EXAM_BUNDLE_SCHEMA_VERSION);
+    assert!(bundle.exam.id.is_none());
+    assert!(bundle.exam.created_at.is_none());
+    let category = bundle.exam.category.as_ref().unwrap();
+    assert!(category.id.is_none());
+    assert_eq!(category.name, "Networking");
+    let question = &bundle.exam.questions[0];
+    assert!(question.id.is_none() && question.exam_id.is_none());
+    assert!(question
+        .answers
+        .iter()
+        .all(|a| a.id.is_none() && a.question_id.is_none()));
+    let source = &question.sources.as_ref().unwrap()[0];
+    assert!(source.id.is_none() && source.question_id.is_none());
+}
+
+#[test]
+fn test_exam_bundle_refers_to_attachment_owners_by_position() {
+    let attachments = [
+        attachment(Some(11), None),
+        attachment(None, Some(21)),
+        attachment(Some(99), None),
+    ];
+    let bundle = ExamBundle::new(&stored_exam(), &attachments, Utc::now());
+
+    let positions: Vec<(usize, Option<usize>)> = bundle
+        .attachments
+        .iter()
+        .map(|a| (a.question, a.answer))
+        .collect();
+    assert_eq!(positions, vec![(0, None), (0, Some(1))]);
+    assert_eq!(bundle.attachments[0].data, Attachment::encode_data(b"png"));
+}
+
+#[test]
+fn test_exam_bundle_validation() {
+    let mut bundle = ExamBundle::new(&stored_exam(), &[], Utc::now());
+    assert!(bundle.validate().is_ok());
+
+    bundle.format = "something-else".into();
+    bundle.schema_version = EXAM_BUNDLE_SCHEMA_VERSION + 1;
+    bundle.attachments.push(BundledAttachment {
+        question: 0,
+        answer: Some(5),
+        file_name: "diagram.png".into(),
+        mime_type: "image/png".into(),
+        data: String::new(),
+    });
+    let fields: Vec<String> = bundle
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|e| e.field)
+        .collect();
+    assert_eq!(fields, vec!["format", "schema_version", "attachments"]);
+}
+
+#[test]
+fn test_exam_bundle_json_round_trip() {
+    let bundle = ExamBundle::new(&stored_exam(), &[attachment(Some(11), None)], Utc::now());
+    let json = serde_json::to_string(&bundle).unwrap();
+    assert!(json.contains("\"schemaVersion\":1"));
+    assert!(json.contains("\"format\":\"open-xam/exam\""));
+
+    let parsed: ExamBundle = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.exam.questions.len(), 1);
+    assert_eq!(parsed.attachments, bundle.attachments);
+}
@@ -78,3 +78,119 @@ fn test_exam_clone_and_debug() {
     assert_eq!(e.name, e2.name);
     assert!(format!("{:?}", e).contains("Exam"));
 }
+
+#[test]
+fn test_exam_last_modified_at_covers_questions_and_answers() {
+    use crate::domain::model::answer::Answer;
+    use crate::domain::model::question::Question;
+    use crate::domain::model::question_type::QuestionType;
+    use chrono::DateTime;
+
+    let at = |seconds: i64| DateTime::from_timestamp(seconds, 0);
+    let mut e = Exam {
+        id: Some(1),
+        name: "Exam".into(),
+        description: None,
+        points_to_succeeded: None,
+        duration: None,
+        status_type: None,
+        created_at: at(100),
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![Question {
+            id: Some(1),
+            question_text: "Question?".into(),
+            points_total: 1,
+            r#type: QuestionType::SingleChoice,
+            answers: vec![Answer {
+                id: Some(1),
+                answer_text: "Answer".into(),
+                description: None,
+                is_correct: Some(true),
+                assigned_option_id: None,
+                created_at: at(100),
+                updated_at: None,
+                question_id: Some(1),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
+            }],
+            points_per_correct_answer: None,
+            category: None,
+            created_at: at(100),
+            updated_at: at(200),
+            options: None,
+            exam_id: Some(1),
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+        }],
+    };
+    assert_eq!(e.last_modified_at(), at(200));
+
+    e.questions[0].answers[0].updated_at = at(300);
+    assert_eq!(e.last_modified_at(), at(300));
+
+    e.questions.clear();
+    assert_eq!(e.last_modified_at(), at(100));
+}
+
+#[test]
+fn test_exam_content_revision_ignores_load_order() {
+    use crate::domain::model::answer::Answer;
+    use crate::domain::model::question::Question;
+    use crate::domain::model::question_type::QuestionType;
+
+    let answer = |id: i32| Answer {
+        id: Some(id),
+        answer_text: format!("Answer {id}"),
+        description: None,
+        is_correct: Some(id == 1),
+        assigned_option_id: None,
+        created_at: None,
+        updated_at: None,
+        question_id: Some(1),
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
+    };
+    let mut e = Exam {
+        id: Some(1),
+        name: "Exam".into(),
+        description: None,
+        points_to_succeeded: None,
+        duration: None,
+        status_type: None,
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![Question {
+            id: Some(1),
+            question_text: "Question?".into(),
+            points_total: 1,
+            r#type: QuestionType::SingleChoice,
+            answers: vec![answer(1), answer(2)],
+            points_per_correct_answer: None,
+            category: None,
+            created_at: None,
+            updated_at: None,
+            options: None,
+            exam_id: Some(1),
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+        }],
+    };
+    let revision = e.content_revision();
+    assert_eq!(revision.len(), 64);
+
+    e.questions[0].answers.reverse();
+    assert_eq!(e.content_revision(), revision);
+
+    e.questions[0].answers.pop();
+    assert_ne!(e.content_revision(), revision);
+}
@@ -0,0 +1,45 @@
+//! Questions and answers shared by the tests of several layers.
+
+use crate::domain::model::answer::Answer;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+
+pub(crate) fn answer(text: &str, is_correct: bool) -> Answer {
+    Answer {
+        id: None,
+        answer_text: text.into(),
+        description: None,
+        is_correct: Some(is_correct),
+        assigned_option_id: None,
+        created_at: None,
+        updated_at: None,
+        question_id: None,
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
+    }
+}
+
+/// Single choice question worth one point with one correct and one wrong answer.
+pub(crate) fn question(text: &str) -> Question {
+    Question {
+        id: None,
+        question_text: text.into(),
+        points_total: 1,
+        r#type: QuestionType::SingleChoice,
+        answers: vec![
+            answer("Correct answer", true),
+            answer("Wrong answer", false),
+        ],
+        points_per_correct_answer: None,
+        category: None,
+        created_at: None,
+        updated_at: None,
+        options: None,
+        exam_id: None,
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
+    }
+}
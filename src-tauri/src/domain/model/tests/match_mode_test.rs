@@ -0,0 +1,26 @@
+use crate::domain::model::match_mode::MatchMode;
+
+#[test]
+fn test_match_mode_display() {
+    assert_eq!(format!("{}", MatchMode::Exact), "Exact");
+    assert_eq!(
+        format!("{}", MatchMode::WhitespaceNormalized),
+        "WhitespaceNormalized"
+    );
+}
+
+#[test]
+fn test_match_mode_serialization() {
+    assert_eq!(
+        serde_json::to_string(&MatchMode::CaseInsensitive).unwrap(),
+        "\"CASE_INSENSITIVE\""
+    );
+    assert_eq!(
+        serde_json::to_string(&MatchMode::Regex).unwrap(),
+        "\"REGEX\""
+    );
+    assert_eq!(
+        serde_json::from_str::<MatchMode>("\"WHITESPACE_NORMALIZED\"").unwrap(),
+        MatchMode::WhitespaceNormalized
+    );
+}
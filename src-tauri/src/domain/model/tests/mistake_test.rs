@@ -0,0 +1,37 @@
+use crate::domain::model::mistake::Mistake;
+use chrono::{Duration, Utc};
+
+#[test]
+fn test_mistake_new_counts_first_miss() {
+    let now = Utc::now();
+    let mistake = Mistake::new(1, 7, now);
+    assert_eq!(mistake.miss_count, 1);
+    assert_eq!(mistake.correct_streak, 0);
+    assert_eq!(mistake.last_missed_at, now);
+    assert!(mistake.id.is_none());
+}
+
+#[test]
+fn test_mistake_record_miss_resets_streak() {
+    let now = Utc::now();
+    let mut mistake = Mistake::new(1, 7, now);
+    mistake.record_correct();
+    mistake.record_correct();
+
+    let later = now + Duration::hours(1);
+    mistake.record_miss(later);
+    assert_eq!(mistake.miss_count, 2);
+    assert_eq!(mistake.correct_streak, 0);
+    assert_eq!(mistake.last_missed_at, later);
+}
+
+#[test]
+fn test_mistake_is_cleared_after_streak() {
+    let mut mistake = Mistake::new(1, 7, Utc::now());
+    mistake.record_correct();
+    mistake.record_correct();
+    assert!(!mistake.is_cleared(3));
+
+    mistake.record_correct();
+    assert!(mistake.is_cleared(3));
+}
@@ -24,3 +24,29 @@ mod question_type_test;
 mod operator_test;
 #[cfg(test)]
 mod conjunction_type_test;
+#[cfg(test)]
+mod attempt_mode_test;
+#[cfg(test)]
+mod exam_attempt_test;
+#[cfg(test)]
+mod setting_test;
+#[cfg(test)]
+mod profile_test;
+#[cfg(test)]
+mod mistake_test;
+#[cfg(test)]
+mod attachment_test;
+#[cfg(test)]
+mod source_reference_test;
+#[cfg(test)]
+mod match_mode_test;
+#[cfg(test)]
+mod numeric_answer_test;
+#[cfg(test)]
+mod tolerance_mode_test;
+#[cfg(test)]
+mod ordering_scoring_test;
+#[cfg(test)]
+mod exam_bundle_test;
+#[cfg(test)]
+pub(crate) mod fixtures;
@@ -0,0 +1,58 @@
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::traits::validation::Validation;
+
+fn numeric() -> NumericAnswer {
+    NumericAnswer {
+        expected_value: 950.0,
+        tolerance: 25.0,
+        tolerance_mode: ToleranceMode::Absolute,
+        unit: Some("Mbit/s".into()),
+    }
+}
+
+#[test]
+fn test_numeric_answer_validation_valid() {
+    assert!(numeric().validate().is_ok());
+}
+
+#[test]
+fn test_numeric_answer_validation_rejects_malformed_values() {
+    let mut n = numeric();
+    n.expected_value = f64::NAN;
+    assert!(n.validate().is_err());
+
+    let mut n = numeric();
+    n.tolerance = -1.0;
+    assert!(n.validate().is_err());
+
+    let mut n = numeric();
+    n.tolerance = f64::INFINITY;
+    assert!(n.validate().is_err());
+
+    // relative tolerances are fractions of the expected value
+    let mut n = numeric();
+    n.tolerance_mode = ToleranceMode::Relative;
+    assert!(n.validate().is_err());
+    n.tolerance = 0.05;
+    assert!(n.validate().is_ok());
+
+    let mut n = numeric();
+    n.unit = Some(" ".into());
+    assert!(n.validate().is_err());
+    n.unit = Some("u".repeat(21));
+    assert!(n.validate().is_err());
+}
+
+#[test]
+fn test_numeric_answer_deserialization_defaults() {
+    let n: NumericAnswer = serde_json::from_str(r#"{"expectedValue": 62}"#).unwrap();
+    assert_eq!(n.expected_value, 62.0);
+    assert_eq!(n.tolerance, 0.0);
+    assert_eq!(n.tolerance_mode, ToleranceMode::Absolute);
+    assert_eq!(n.unit, None);
+
+    let json = serde_json::to_string(&numeric()).unwrap();
+    assert!(json.contains("\"toleranceMode\":\"ABSOLUTE\""));
+    assert!(json.contains("\"unit\":\"Mbit/s\""));
+}
@@ -0,0 +1,20 @@
+use crate::domain::model::ordering_scoring::OrderingScoring;
+
+#[test]
+fn test_ordering_scoring_display() {
+    assert_eq!(format!("{}", OrderingScoring::AllOrNothing), "AllOrNothing");
+    assert_eq!(format!("{}", OrderingScoring::KendallTau), "KendallTau");
+}
+
+#[test]
+fn test_ordering_scoring_serialization() {
+    assert_eq!(
+        serde_json::to_string(&OrderingScoring::CorrectlyPlaced).unwrap(),
+        "\"CORRECTLY_PLACED\""
+    );
+    assert_eq!(
+        serde_json::from_str::<OrderingScoring>("\"KENDALL_TAU\"").unwrap(),
+        OrderingScoring::KendallTau
+    );
+    assert_eq!(OrderingScoring::default(), OrderingScoring::AllOrNothing);
+}
@@ -0,0 +1,21 @@
+use crate::domain::model::profile::Profile;
+use crate::domain::traits::validation::Validation;
+
+#[test]
+fn test_profile_new_trims_name() {
+    let profile = Profile::new("  Alice  ");
+    assert_eq!(profile.name, "Alice");
+    assert!(!profile.active);
+    assert!(profile.id.is_none());
+}
+
+#[test]
+fn test_profile_validation_valid() {
+    assert!(Profile::new("Alice").validate().is_ok());
+}
+
+#[test]
+fn test_profile_validation_invalid_name() {
+    assert!(Profile::new("   ").validate().is_err());
+    assert!(Profile::new(&"a".repeat(256)).validate().is_err());
+}
@@ -1,7 +1,11 @@
-use crate::domain::model::question::Question;
+use crate::domain::model::question::{Question, MAX_EXPLANATION_LENGTH, MAX_SOURCES};
 use crate::domain::model::answer::Answer;
 use crate::domain::model::assignment_option::AssignmentOption;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::numeric_answer::NumericAnswer;
 use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::source_reference::SourceReference;
+use crate::domain::model::tolerance_mode::ToleranceMode;
 use crate::domain::traits::validation::Validation;
 
 fn base_question() -> Question {
@@ -20,6 +24,10 @@ fn base_question() -> Question {
                 created_at: None,
                 updated_at: None,
                 question_id: Some(0),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             },
             Answer {
                 id: None,
@@ -30,6 +38,10 @@ fn base_question() -> Question {
                 created_at: None,
                 updated_at: None,
                 question_id: Some(0),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             },
         ],
         points_per_correct_answer: Some(5),
@@ -38,6 +50,9 @@ fn base_question() -> Question {
         updated_at: None,
         options: None,
         exam_id: Some(1),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
     }
 }
 
@@ -55,6 +70,9 @@ fn test_question_new() {
         updated_at: None,
         options: None,
         exam_id: None,
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
     };
     let answers = vec![Answer {
         id: None,
@@ -65,6 +83,10 @@ fn test_question_new() {
         created_at: None,
         updated_at: None,
         question_id: None,
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
     }];
     let options = vec![AssignmentOption {
         row_id: None,
@@ -93,6 +115,44 @@ fn test_question_validation_invalid_text() {
     assert!(q.validate().is_err());
 }
 
+#[test]
+fn test_question_validation_explanation_length() {
+    let mut q = base_question();
+    q.explanation = Some("a".repeat(MAX_EXPLANATION_LENGTH));
+    assert!(q.validate().is_ok());
+
+    q.explanation = Some("a".repeat(MAX_EXPLANATION_LENGTH + 1));
+    let errors = q.validate().unwrap_err();
+    assert!(errors.iter().any(|e| e.field == "explanation"));
+}
+
+#[test]
+fn test_validate_sources() {
+    let source = SourceReference {
+        id: None,
+        question_id: None,
+        title: "Computer Networks".into(),
+        chapter: None,
+        page: Some("42".into()),
+        url: None,
+    };
+    let mut q = base_question();
+    assert!(q.validate_sources().is_ok());
+
+    q.sources = Some(vec![source.clone(); MAX_SOURCES]);
+    assert!(q.validate_sources().is_ok());
+
+    q.sources = Some(vec![source.clone(); MAX_SOURCES + 1]);
+    assert!(q.validate_sources().is_err());
+
+    q.sources = Some(vec![SourceReference {
+        title: "".into(),
+        ..source
+    }]);
+    let errors = q.validate_sources().unwrap_err();
+    assert!(errors.iter().any(|e| e.field == "title"));
+}
+
 #[test]
 fn test_question_validation_invalid_points() {
     let mut q = base_question();
@@ -166,6 +226,197 @@ fn test_validate_answers_assignment() {
     assert!(q.validate_answers().is_err());
 }
 
+#[test]
+fn test_validate_answers_short_answer() {
+    let mut q = base_question();
+    q.r#type = QuestionType::ShortAnswer;
+    q.answers = vec![q.answers[0].clone()];
+
+    // Missing match mode
+    assert!(q.validate_answers().is_err());
+
+    q.answers[0].match_mode = Some(MatchMode::CaseInsensitive);
+    assert!(q.validate_answers().is_ok());
+
+    // No accepted answer
+    q.answers = vec![];
+    assert!(q.validate_answers().is_err());
+}
+
+#[test]
+fn test_validate_answers_numeric() {
+    let mut q = base_question();
+    q.r#type = QuestionType::Numeric;
+    q.answers = vec![q.answers[0].clone()];
+
+    // Missing expected value
+    assert!(q.validate_answers().is_err());
+
+    q.answers[0].numeric = Some(NumericAnswer {
+        expected_value: 1024.0,
+        tolerance: 0.0,
+        tolerance_mode: ToleranceMode::Absolute,
+        unit: None,
+    });
+    assert!(q.validate_answers().is_ok());
+
+    // No expected value at all
+    q.answers = vec![];
+    assert!(q.validate_answers().is_err());
+}
+
+#[test]
+fn test_validate_answers_ordering() {
+    let mut q = base_question();
+    q.r#type = QuestionType::Ordering;
+    q.answers.push(q.answers[0].clone());
+
+    // Missing positions
+    assert!(q.validate_answers().is_err());
+
+    q.answers[0].position = Some(2);
+    q.answers[1].position = Some(3);
+    q.answers[2].position = Some(1);
+    assert!(q.validate_answers().is_ok());
+
+    // Duplicate position
+    q.answers[2].position = Some(2);
+    assert!(q.validate_answers().is_err());
+
+    // Gap in the sequence
+    q.answers[2].position = Some(4);
+    assert!(q.validate_answers().is_err());
+}
+
+fn cloze() -> Question {
+    let blank = |text: &str, blank: i32, is_correct: bool, match_mode: Option<MatchMode>| Answer {
+        id: None,
+        answer_text: text.into(),
+        description: None,
+        is_correct: Some(is_correct),
+        assigned_option_id: None,
+        created_at: None,
+        updated_at: None,
+        question_id: Some(0),
+        match_mode,
+        numeric: None,
+        position: None,
+        blank: Some(blank),
+    };
+    Question {
+        question_text: "HTTPS runs on port {{1}} and uses {{2}} as transport.".into(),
+        r#type: QuestionType::Cloze,
+        answers: vec![
+            blank("443", 1, true, Some(MatchMode::Exact)),
+            blank("TCP", 2, true, None),
+            blank("UDP", 2, false, None),
+        ],
+        ..base_question()
+    }
+}
+
+#[test]
+fn test_cloze_blanks() {
+    let mut q = cloze();
+    assert_eq!(q.cloze_blanks(), vec![1, 2]);
+
+    q.question_text = "{{ 2 }} before {{1}}, then {{2}} again and {{x}}".into();
+    assert_eq!(q.cloze_blanks(), vec![2, 1]);
+}
+
+#[test]
+fn test_validate_answers_cloze() {
+    let mut q = cloze();
+    assert!(q.validate_answers().is_ok());
+
+    // Blank without answers
+    q.question_text.push_str(" It was defined in {{3}}.");
+    assert!(q.validate_answers().is_err());
+
+    // Answer for an unknown blank
+    let mut q = cloze();
+    q.answers[0].blank = Some(5);
+    assert!(q.validate_answers().is_err());
+    q.answers[0].blank = None;
+    assert!(q.validate_answers().is_err());
+
+    // Dropdown without exactly one correct choice
+    let mut q = cloze();
+    q.answers[2].is_correct = Some(true);
+    assert!(q.validate_answers().is_err());
+
+    // Typed answers and choices in the same blank
+    let mut q = cloze();
+    q.answers[2].match_mode = Some(MatchMode::Exact);
+    assert!(q.validate_answers().is_err());
+
+    // No placeholder at all
+    let mut q = cloze();
+    q.question_text = "Which port does HTTPS use?".into();
+    assert!(q.validate_answers().is_err());
+}
+
+#[test]
+fn test_cloze_json_round_trip() {
+    let json = r#"{
+        "questionText": "HTTPS runs on port {{1}} and uses {{2}} as transport.",
+        "pointsTotal": 2,
+        "type": "CLOZE",
+        "pointsPerCorrectAnswer": 1,
+        "answers": [
+            {"answerText": "443", "isCorrect": true, "matchMode": "EXACT", "blank": 1},
+            {"answerText": "TCP", "isCorrect": true, "blank": 2},
+            {"answerText": "UDP", "isCorrect": false, "blank": 2}
+        ]
+    }"#;
+    let q: Question = serde_json::from_str(json).unwrap();
+    assert_eq!(q.r#type, QuestionType::Cloze);
+    assert_eq!(q.answers[0].blank, Some(1));
+    assert!(q.validate_answers().is_ok());
+
+    let again: Question = serde_json::from_str(&serde_json::to_string(&q).unwrap()).unwrap();
+    assert_eq!(
+        again.answers.iter().map(|a| a.blank).collect::<Vec<_>>(),
+        vec![Some(1), Some(2), Some(2)]
+    );
+    assert_eq!(again.answers[0].match_mode, Some(MatchMode::Exact));
+}
+
+#[test]
+fn test_explanation_and_sources_json_round_trip() {
+    let json = r#"{
+        "questionText": "Which layer routes packets?",
+        "pointsTotal": 1,
+        "type": "SINGLE_CHOICE",
+        "answers": [],
+        "explanation": "The **network layer** forwards packets between networks.",
+        "sources": [
+            {"title": "Computer Networks", "chapter": "5", "page": "355"},
+            {"title": "RFC 791", "url": "https://www.rfc-editor.org/rfc/rfc791"}
+        ]
+    }"#;
+    let q: Question = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        q.explanation.as_deref(),
+        Some("The **network layer** forwards packets between networks.")
+    );
+    let sources = q.sources.as_ref().unwrap();
+    assert_eq!(sources.len(), 2);
+    assert_eq!(sources[0].page.as_deref(), Some("355"));
+    assert!(q.validate_sources().is_ok());
+
+    let again: Question = serde_json::from_str(&serde_json::to_string(&q).unwrap()).unwrap();
+    assert_eq!(again.explanation, q.explanation);
+    assert_eq!(again.sources, q.sources);
+
+    let without: Question = serde_json::from_str(
+        r#"{"questionText": "Which layer?", "pointsTotal": 1, "type": "SINGLE_CHOICE", "answers": []}"#,
+    )
+    .unwrap();
+    assert!(without.explanation.is_none());
+    assert!(without.sources.is_none());
+}
+
 #[test]
 fn test_validate_options() {
     let mut q = base_question();
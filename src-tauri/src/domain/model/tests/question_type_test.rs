@@ -5,6 +5,9 @@ fn test_question_type_display() {
     assert_eq!(format!("{}", QuestionType::Assignment), "Assignment");
     assert_eq!(format!("{}", QuestionType::MultipleChoice), "MultipleChoice");
     assert_eq!(format!("{}", QuestionType::SingleChoice), "SingleChoice");
+    assert_eq!(format!("{}", QuestionType::ShortAnswer), "ShortAnswer");
+    assert_eq!(format!("{}", QuestionType::Numeric), "Numeric");
+    assert_eq!(format!("{}", QuestionType::Cloze), "Cloze");
 }
 
 #[test]
@@ -12,6 +15,9 @@ fn test_question_type_serialization() {
     assert_eq!(serde_json::to_string(&QuestionType::Assignment).unwrap(), "\"ASSIGNMENT\"");
     assert_eq!(serde_json::to_string(&QuestionType::MultipleChoice).unwrap(), "\"MULTIPLE_CHOICE\"");
     assert_eq!(serde_json::to_string(&QuestionType::SingleChoice).unwrap(), "\"SINGLE_CHOICE\"");
+    assert_eq!(serde_json::to_string(&QuestionType::ShortAnswer).unwrap(), "\"SHORT_ANSWER\"");
+    assert_eq!(serde_json::to_string(&QuestionType::Numeric).unwrap(), "\"NUMERIC\"");
+    assert_eq!(serde_json::to_string(&QuestionType::Cloze).unwrap(), "\"CLOZE\"");
 }
 
 #[test]
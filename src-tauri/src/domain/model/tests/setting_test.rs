@@ -0,0 +1,67 @@
+use crate::domain::model::setting::{Setting, ATTEMPT_GRACE_PERIOD_SECONDS, MISTAKE_CLEAR_STREAK};
+use crate::domain::traits::validation::Validation;
+
+#[test]
+fn test_setting_validation_valid() {
+    let s = Setting {
+        profile_id: None,
+        key: "attempt_grace_period_seconds".into(),
+        value: "30".into(),
+        updated_at: None,
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn test_setting_validation_invalid_key() {
+    let mut s = Setting {
+        profile_id: None,
+        key: "".into(),
+        value: "30".into(),
+        updated_at: None,
+    };
+    assert!(s.validate().is_err());
+
+    s.key = "k".repeat(256);
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn test_setting_validation_grace_period_must_be_seconds() {
+    let mut s = Setting {
+        profile_id: None,
+        key: ATTEMPT_GRACE_PERIOD_SECONDS.into(),
+        value: "-1".into(),
+        updated_at: None,
+    };
+    assert!(s.validate().is_err());
+
+    s.value = "soon".into();
+    assert!(s.validate().is_err());
+
+    s.value = "0".into();
+    assert!(s.validate().is_ok());
+
+    s.value = "300".into();
+    assert!(s.validate().is_ok());
+
+    s.value = "301".into();
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn test_setting_validation_mistake_clear_streak_must_be_positive() {
+    let mut s = Setting {
+        profile_id: None,
+        key: MISTAKE_CLEAR_STREAK.into(),
+        value: "0".into(),
+        updated_at: None,
+    };
+    assert!(s.validate().is_err());
+
+    s.value = "often".into();
+    assert!(s.validate().is_err());
+
+    s.value = "2".into();
+    assert!(s.validate().is_ok());
+}
@@ -0,0 +1,51 @@
+use crate::domain::model::source_reference::SourceReference;
+use crate::domain::traits::validation::Validation;
+
+fn source() -> SourceReference {
+    SourceReference {
+        id: None,
+        question_id: None,
+        title: "Computer Networks".into(),
+        chapter: Some("5".into()),
+        page: Some("42".into()),
+        url: Some("https://example.org/networks".into()),
+    }
+}
+
+#[test]
+fn test_source_reference_validation_valid() {
+    assert!(source().validate().is_ok());
+    let title_only = SourceReference {
+        chapter: None,
+        page: None,
+        url: None,
+        ..source()
+    };
+    assert!(title_only.validate().is_ok());
+}
+
+#[test]
+fn test_source_reference_validation_invalid_fields() {
+    let invalid = SourceReference {
+        title: " ".into(),
+        chapter: Some("".into()),
+        page: Some("1".repeat(51)),
+        url: Some("example.org".into()),
+        ..source()
+    };
+    let errors = invalid.validate().unwrap_err();
+    for field in ["title", "chapter", "page", "url"] {
+        assert!(errors.iter().any(|e| e.field == field), "{field}");
+    }
+}
+
+#[test]
+fn test_source_reference_json_round_trip() {
+    let json = serde_json::to_string(&source()).unwrap();
+    assert!(json.contains("\"title\":\"Computer Networks\""));
+    assert!(!json.contains("\"id\""));
+
+    let parsed: SourceReference = serde_json::from_str(r#"{"title": "RFC 791"}"#).unwrap();
+    assert_eq!(parsed.title, "RFC 791");
+    assert!(parsed.url.is_none());
+}
@@ -0,0 +1,20 @@
+use crate::domain::model::tolerance_mode::ToleranceMode;
+
+#[test]
+fn test_tolerance_mode_display() {
+    assert_eq!(format!("{}", ToleranceMode::Absolute), "Absolute");
+    assert_eq!(format!("{}", ToleranceMode::Relative), "Relative");
+}
+
+#[test]
+fn test_tolerance_mode_serialization() {
+    assert_eq!(
+        serde_json::to_string(&ToleranceMode::Relative).unwrap(),
+        "\"RELATIVE\""
+    );
+    assert_eq!(
+        serde_json::from_str::<ToleranceMode>("\"ABSOLUTE\"").unwrap(),
+        ToleranceMode::Absolute
+    );
+    assert_eq!(ToleranceMode::default(), ToleranceMode::Absolute);
+}
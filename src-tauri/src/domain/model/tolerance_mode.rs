@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How the tolerance of a numeric answer is applied to the expected value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ToleranceMode {
+    /// The response may deviate from the expected value by the tolerance itself.
+    #[default]
+    Absolute,
+    /// The tolerance is a fraction of the expected value, e.g. `0.05` for 5 %.
+    Relative,
+}
+
+impl fmt::Display for ToleranceMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
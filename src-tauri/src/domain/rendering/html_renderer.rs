@@ -0,0 +1,200 @@
+use crate::domain::export::xml_writer::escape_xml;
+use crate::domain::rendering::printable_exam::{
+    CoverPage, PrintableBody, PrintableChoice, PrintableExam, PrintableQuestion,
+};
+
+const STYLE: &str = "body { font-family: Helvetica, Arial, sans-serif; font-size: 11pt; line-height: 1.4; max-width: 180mm; margin: 0 auto; }
+h1 { font-size: 20pt; margin-bottom: 0; }
+.subtitle { font-style: italic; margin-top: 4pt; }
+.cover { page-break-after: always; }
+.cover table { border-collapse: collapse; margin: 16pt 0; }
+.cover td { padding: 4pt 12pt 4pt 0; }
+.question { page-break-inside: avoid; margin-top: 18pt; }
+.question h2 { font-size: 13pt; margin-bottom: 4pt; }
+.text { white-space: pre-wrap; }
+.hint { font-style: italic; color: #444; }
+.box { display: inline-block; width: 12pt; height: 12pt; border: 1px solid #000; text-align: center; line-height: 12pt; margin-right: 6pt; font-weight: bold; }
+ul.choices { list-style: none; padding-left: 0; }
+ul.choices li { margin: 4pt 0; }
+table.grid { border-collapse: collapse; margin-top: 6pt; }
+table.grid th, table.grid td { border: 1px solid #999; padding: 4pt 8pt; }
+table.grid td.mark { text-align: center; }
+.write-in { display: inline-block; min-width: 80mm; border-bottom: 1px solid #000; }
+.explanation { white-space: pre-wrap; border-left: 3px solid #999; padding-left: 8pt; color: #333; }";
+
+/// Renders a printable exam as standalone HTML page with inline styles.
+///
+/// Choices and ordering items get a box, assignment questions a table with a box per row
+/// and option, arranged like the `A:` rows of the text import format. The cover page ends
+/// with a page break when printed.
+pub struct HtmlRenderer();
+
+impl HtmlRenderer {
+    pub fn render(exam: &PrintableExam) -> String {
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n",
+            escape_xml(&exam.title)
+        );
+
+        let mut header = format!("<h1>{}</h1>\n", escape_xml(&exam.title));
+        if let Some(subtitle) = &exam.subtitle {
+            header.push_str(&format!(
+                "<p class=\"subtitle\">{}</p>\n",
+                escape_xml(subtitle)
+            ));
+        }
+        match &exam.cover {
+            Some(cover) => {
+                html.push_str("<section class=\"cover\">\n");
+                html.push_str(&header);
+                html.push_str(&Self::cover(cover, exam.answer_key));
+                html.push_str("</section>\n");
+            }
+            None => html.push_str(&header),
+        }
+
+        for question in &exam.questions {
+            html.push_str(&Self::question(question));
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    fn cover(cover: &CoverPage, answer_key: bool) -> String {
+        let mut html = String::new();
+        if let Some(description) = &cover.description {
+            html.push_str(&format!(
+                "<p class=\"text\">{}</p>\n",
+                escape_xml(description.trim())
+            ));
+        }
+        let mut rows = vec![
+            ("Questions", cover.question_count.to_string()),
+            (
+                "Total points",
+                PrintableExam::points_text(cover.points_total),
+            ),
+        ];
+        if let Some(duration) = cover.duration {
+            rows.push(("Duration", format!("{duration} minutes")));
+        }
+        if let Some(points) = cover.points_to_succeeded {
+            rows.push(("Required to pass", PrintableExam::points_text(points)));
+        }
+        html.push_str("<table>\n");
+        for (label, value) in rows {
+            html.push_str(&format!("<tr><td>{label}</td><td>{value}</td></tr>\n"));
+        }
+        html.push_str("</table>\n");
+        if !answer_key {
+            html.push_str("<p>Name: <span class=\"write-in\"></span></p>\n");
+        }
+        html
+    }
+
+    fn question(question: &PrintableQuestion) -> String {
+        let mut html = format!(
+            "<section class=\"question\">\n<h2>Question {}",
+            question.number
+        );
+        if let Some(points) = question.points {
+            html.push_str(&format!(" ({})", PrintableExam::points_text(points)));
+        }
+        html.push_str(&format!(
+            "</h2>\n<p class=\"text\">{}</p>\n",
+            escape_xml(&question.text)
+        ));
+        if let Some(hint) = &question.hint {
+            html.push_str(&format!("<p class=\"hint\">{}</p>\n", escape_xml(hint)));
+        }
+
+        match &question.body {
+            PrintableBody::Choices(choices) | PrintableBody::Ordering(choices) => {
+                html.push_str("<ul class=\"choices\">\n");
+                for choice in choices {
+                    html.push_str(&Self::choice(choice));
+                }
+                html.push_str("</ul>\n");
+            }
+            PrintableBody::Grid { columns, rows } => {
+                html.push_str("<table class=\"grid\">\n<tr><th></th>");
+                for column in columns {
+                    html.push_str(&format!("<th>{}</th>", escape_xml(column)));
+                }
+                html.push_str("</tr>\n");
+                for row in rows {
+                    html.push_str(&format!(
+                        "<tr><td>({}) {}</td>",
+                        row.label,
+                        escape_xml(&row.text)
+                    ));
+                    for column in 0..columns.len() {
+                        let mark = if row.marked == Some(column) { "X" } else { "" };
+                        html.push_str(&format!(
+                            "<td class=\"mark\"><span class=\"box\">{mark}</span></td>"
+                        ));
+                    }
+                    html.push_str("</tr>\n");
+                }
+                html.push_str("</table>\n");
+            }
+            PrintableBody::WriteIn { unit, solutions } => {
+                let answer = if solutions.is_empty() {
+                    let unit = unit
+                        .as_deref()
+                        .map(|u| format!(" {}", escape_xml(u)))
+                        .unwrap_or_default();
+                    format!("<span class=\"write-in\"></span>{unit}")
+                } else {
+                    let solutions: Vec<String> = solutions.iter().map(|s| escape_xml(s)).collect();
+                    format!("<strong>{}</strong>", solutions.join(" / "))
+                };
+                html.push_str(&format!("<p>Answer: {answer}</p>\n"));
+            }
+            PrintableBody::Cloze(blanks) => {
+                let items: Vec<String> = blanks
+                    .iter()
+                    .filter_map(|blank| {
+                        let (content, class) = if !blank.solutions.is_empty() {
+                            (&blank.solutions, " class=\"solution\"")
+                        } else if !blank.choices.is_empty() {
+                            (&blank.choices, "")
+                        } else {
+                            return None;
+                        };
+                        let content: Vec<String> = content.iter().map(|c| escape_xml(c)).collect();
+                        Some(format!(
+                            "<li{class}>({}) {}</li>\n",
+                            blank.number,
+                            content.join(" / ")
+                        ))
+                    })
+                    .collect();
+                if !items.is_empty() {
+                    html.push_str(&format!(
+                        "<ul class=\"choices\">\n{}</ul>\n",
+                        items.concat()
+                    ));
+                }
+            }
+        }
+
+        if let Some(explanation) = &question.explanation {
+            html.push_str(&format!(
+                "<p class=\"explanation\">{}</p>\n",
+                escape_xml(explanation.trim())
+            ));
+        }
+        html.push_str("</section>\n");
+        html
+    }
+
+    fn choice(choice: &PrintableChoice) -> String {
+        format!(
+            "<li><span class=\"box\">{}</span>({}) {}</li>\n",
+            choice.mark.as_deref().map(escape_xml).unwrap_or_default(),
+            choice.label,
+            escape_xml(&choice.text)
+        )
+    }
+}
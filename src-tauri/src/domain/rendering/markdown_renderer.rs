@@ -0,0 +1,168 @@
+use crate::domain::rendering::printable_exam::{
+    CoverPage, PrintableBody, PrintableChoice, PrintableExam, PrintableQuestion,
+};
+
+/// Line to write a short or numeric answer on.
+const WRITE_IN_LINE: &str = "______________________________";
+
+/// Renders a printable exam as Markdown.
+///
+/// Choices and ordering items become task list entries with the `(a)` labels of the text
+/// import format, assignment questions a table with a `[ ]` box per option. The cover page
+/// is separated from the questions by a horizontal rule.
+pub struct MarkdownRenderer();
+
+impl MarkdownRenderer {
+    pub fn render(exam: &PrintableExam) -> String {
+        let mut markdown = format!("# {}\n", Self::escape(&exam.title));
+        if let Some(subtitle) = &exam.subtitle {
+            markdown.push_str(&format!("\n*{subtitle}*\n"));
+        }
+        if let Some(cover) = &exam.cover {
+            markdown.push_str(&Self::cover(cover, exam.answer_key));
+            markdown.push_str("\n---\n");
+        }
+        for question in &exam.questions {
+            markdown.push_str(&Self::question(question));
+        }
+        markdown
+    }
+
+    fn cover(cover: &CoverPage, answer_key: bool) -> String {
+        let mut markdown = String::new();
+        if let Some(description) = &cover.description {
+            markdown.push_str(&format!("\n{}\n", description.trim()));
+        }
+        markdown.push_str("\n| | |\n|---|---|\n");
+        markdown.push_str(&format!("| Questions | {} |\n", cover.question_count));
+        markdown.push_str(&format!(
+            "| Total points | {} |\n",
+            PrintableExam::points_text(cover.points_total)
+        ));
+        if let Some(duration) = cover.duration {
+            markdown.push_str(&format!("| Duration | {duration} minutes |\n"));
+        }
+        if let Some(points) = cover.points_to_succeeded {
+            markdown.push_str(&format!(
+                "| Required to pass | {} |\n",
+                PrintableExam::points_text(points)
+            ));
+        }
+        if !answer_key {
+            markdown.push_str(&format!("\nName: {WRITE_IN_LINE}\n"));
+        }
+        markdown
+    }
+
+    fn question(question: &PrintableQuestion) -> String {
+        let mut markdown = format!("\n## Question {}", question.number);
+        if let Some(points) = question.points {
+            markdown.push_str(&format!(" ({})", PrintableExam::points_text(points)));
+        }
+        markdown.push_str(&format!("\n\n{}\n", Self::escape(&question.text)));
+        if let Some(hint) = &question.hint {
+            markdown.push_str(&format!("\n*{hint}*\n"));
+        }
+
+        match &question.body {
+            PrintableBody::Choices(choices) | PrintableBody::Ordering(choices) => {
+                markdown.push('\n');
+                for choice in choices {
+                    markdown.push_str(&Self::choice(choice));
+                }
+            }
+            PrintableBody::Grid { columns, rows } => {
+                markdown.push_str("\n| |");
+                for column in columns {
+                    markdown.push_str(&format!(" {} |", Self::cell(column)));
+                }
+                markdown.push_str(&format!("\n|---|{}\n", ":---:|".repeat(columns.len())));
+                for row in rows {
+                    markdown.push_str(&format!("| ({}) {} |", row.label, Self::cell(&row.text)));
+                    for column in 0..columns.len() {
+                        let mark = if row.marked == Some(column) { "X" } else { " " };
+                        markdown.push_str(&format!(" [{mark}] |"));
+                    }
+                    markdown.push('\n');
+                }
+            }
+            PrintableBody::WriteIn { unit, solutions } => {
+                let answer = if solutions.is_empty() {
+                    match unit {
+                        Some(unit) => format!("{WRITE_IN_LINE} {}", Self::escape(unit)),
+                        None => WRITE_IN_LINE.to_string(),
+                    }
+                } else {
+                    solutions
+                        .iter()
+                        .map(|s| Self::escape(s))
+                        .collect::<Vec<_>>()
+                        .join(" / ")
+                };
+                markdown.push_str(&format!("\nAnswer: {answer}\n"));
+            }
+            PrintableBody::Cloze(blanks) => {
+                let lines: Vec<String> = blanks
+                    .iter()
+                    .filter_map(|blank| {
+                        let content = if !blank.solutions.is_empty() {
+                            &blank.solutions
+                        } else if !blank.choices.is_empty() {
+                            &blank.choices
+                        } else {
+                            return None;
+                        };
+                        let content: Vec<String> =
+                            content.iter().map(|c| Self::escape(c)).collect();
+                        Some(format!("- ({}) {}\n", blank.number, content.join(" / ")))
+                    })
+                    .collect();
+                if !lines.is_empty() {
+                    markdown.push('\n');
+                    markdown.push_str(&lines.concat());
+                }
+            }
+        }
+
+        if let Some(explanation) = &question.explanation {
+            markdown.push('\n');
+            // Explanations are Markdown already.
+            for line in explanation.trim().lines() {
+                if line.trim().is_empty() {
+                    markdown.push_str(">\n");
+                } else {
+                    markdown.push_str(&format!("> {line}\n"));
+                }
+            }
+        }
+        markdown
+    }
+
+    fn choice(choice: &PrintableChoice) -> String {
+        format!(
+            "- [{}] ({}) {}\n",
+            choice.mark.as_deref().unwrap_or(" "),
+            choice.label,
+            Self::escape(&choice.text)
+        )
+    }
+
+    /// Escapes the characters that would start Markdown formatting inside plain text.
+    fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            if matches!(
+                c,
+                '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|'
+            ) {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    fn cell(text: &str) -> String {
+        Self::escape(text).replace('\n', " ")
+    }
+}
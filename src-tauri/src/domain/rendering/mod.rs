@@ -0,0 +1,7 @@
+pub mod html_renderer;
+pub mod markdown_renderer;
+pub mod pdf_renderer;
+pub mod printable_exam;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,496 @@
+use crate::domain::rendering::printable_exam::{
+    CoverPage, GridRow, PrintableBody, PrintableChoice, PrintableExam, PrintableQuestion,
+};
+use printpdf::path::PaintMode;
+use printpdf::{
+    BuiltinFont, IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference,
+    Point, Rect,
+};
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 20.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+const LAYER: &str = "Content";
+
+/// Millimeters per typographic point.
+const PT: f32 = 0.3528;
+const LINE_SPACING: f32 = 1.35;
+
+const TITLE_SIZE: f32 = 20.0;
+const HEADING_SIZE: f32 = 12.0;
+const TEXT_SIZE: f32 = 11.0;
+const SMALL_SIZE: f32 = 9.0;
+
+const BOX_SIZE: f32 = 3.6;
+/// Indent of the text next to a box.
+const BOX_INDENT: f32 = 9.0;
+
+/// Advance widths of the Helvetica glyphs from ` ` to `~` in 1/1000 of the font size.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// Characters of Windows-1252 above Latin-1, the only other characters the built-in PDF
+/// fonts can show.
+const WINDOWS_1252_EXTRAS: &str = "€‚ƒ„…†‡ˆ‰Š‹ŒŽ‘’“”•–—˜™š›œžŸ";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Style {
+    Regular,
+    Bold,
+    Italic,
+}
+
+/// Renders a printable exam as A4 PDF with the built-in Helvetica fonts, so no font files
+/// have to be shipped.
+///
+/// Text is wrapped using the Helvetica glyph widths. The built-in fonts only cover
+/// Windows-1252, arrows and comparison signs are replaced by ASCII and any other character
+/// outside that range by `?`.
+pub struct PdfRenderer();
+
+impl PdfRenderer {
+    pub fn render(exam: &PrintableExam) -> Result<Vec<u8>, String> {
+        let mut pdf = PdfLayout::new(&exam.title)?;
+
+        pdf.paragraph(&exam.title, Style::Bold, TITLE_SIZE, MARGIN, CONTENT_WIDTH);
+        if let Some(subtitle) = &exam.subtitle {
+            pdf.space(1.0);
+            pdf.paragraph(subtitle, Style::Italic, TEXT_SIZE, MARGIN, CONTENT_WIDTH);
+        }
+        match &exam.cover {
+            Some(cover) => {
+                Self::cover(&mut pdf, cover, exam.answer_key);
+                pdf.new_page();
+            }
+            None => pdf.space(4.0),
+        }
+
+        for question in &exam.questions {
+            Self::question(&mut pdf, question);
+        }
+        pdf.finish()
+    }
+
+    fn cover(pdf: &mut PdfLayout, cover: &CoverPage, answer_key: bool) {
+        if let Some(description) = &cover.description {
+            pdf.space(6.0);
+            pdf.paragraph(
+                description.trim(),
+                Style::Regular,
+                TEXT_SIZE,
+                MARGIN,
+                CONTENT_WIDTH,
+            );
+        }
+
+        let mut rows = vec![
+            ("Questions", cover.question_count.to_string()),
+            (
+                "Total points",
+                PrintableExam::points_text(cover.points_total),
+            ),
+        ];
+        if let Some(duration) = cover.duration {
+            rows.push(("Duration", format!("{duration} minutes")));
+        }
+        if let Some(points) = cover.points_to_succeeded {
+            rows.push(("Required to pass", PrintableExam::points_text(points)));
+        }
+        pdf.space(8.0);
+        for (label, value) in rows {
+            let baseline = pdf.next_line(TEXT_SIZE);
+            pdf.text(label, Style::Bold, TEXT_SIZE, MARGIN, baseline);
+            pdf.text(&value, Style::Regular, TEXT_SIZE, MARGIN + 45.0, baseline);
+        }
+
+        if !answer_key {
+            pdf.space(12.0);
+            let baseline = pdf.next_line(TEXT_SIZE);
+            pdf.text("Name:", Style::Regular, TEXT_SIZE, MARGIN, baseline);
+            pdf.rule(MARGIN + 15.0, MARGIN + 110.0, baseline - 1.0);
+        }
+    }
+
+    fn question(pdf: &mut PdfLayout, question: &PrintableQuestion) {
+        // Keeps the heading together with the start of the question.
+        pdf.ensure(30.0);
+        pdf.space(6.0);
+
+        let mut heading = format!("Question {}", question.number);
+        if let Some(points) = question.points {
+            heading.push_str(&format!(" ({})", PrintableExam::points_text(points)));
+        }
+        pdf.paragraph(&heading, Style::Bold, HEADING_SIZE, MARGIN, CONTENT_WIDTH);
+        pdf.space(1.0);
+        pdf.paragraph(
+            &question.text,
+            Style::Regular,
+            TEXT_SIZE,
+            MARGIN,
+            CONTENT_WIDTH,
+        );
+        if let Some(hint) = &question.hint {
+            pdf.paragraph(hint, Style::Italic, SMALL_SIZE, MARGIN, CONTENT_WIDTH);
+        }
+        pdf.space(2.0);
+
+        match &question.body {
+            PrintableBody::Choices(choices) | PrintableBody::Ordering(choices) => {
+                for choice in choices {
+                    Self::choice(pdf, choice);
+                }
+            }
+            PrintableBody::Grid { columns, rows } => {
+                Self::grid(pdf, columns, rows);
+            }
+            PrintableBody::WriteIn { unit, solutions } => {
+                pdf.space(3.0);
+                let baseline = pdf.next_line(TEXT_SIZE);
+                pdf.text("Answer:", Style::Regular, TEXT_SIZE, MARGIN, baseline);
+                if solutions.is_empty() {
+                    pdf.rule(MARGIN + 18.0, MARGIN + 100.0, baseline - 1.0);
+                    if let Some(unit) = unit {
+                        pdf.text(unit, Style::Regular, TEXT_SIZE, MARGIN + 102.0, baseline);
+                    }
+                } else {
+                    pdf.paragraph_from(
+                        baseline,
+                        &solutions.join(" / "),
+                        Style::Bold,
+                        TEXT_SIZE,
+                        MARGIN + 18.0,
+                        CONTENT_WIDTH - 18.0,
+                    );
+                }
+            }
+            PrintableBody::Cloze(blanks) => {
+                for blank in blanks {
+                    let content = if !blank.solutions.is_empty() {
+                        (&blank.solutions, Style::Bold)
+                    } else if !blank.choices.is_empty() {
+                        (&blank.choices, Style::Regular)
+                    } else {
+                        continue;
+                    };
+                    pdf.paragraph(
+                        &format!("({}) {}", blank.number, content.0.join(" / ")),
+                        content.1,
+                        TEXT_SIZE,
+                        MARGIN + 4.0,
+                        CONTENT_WIDTH - 4.0,
+                    );
+                }
+            }
+        }
+
+        if let Some(explanation) = &question.explanation {
+            pdf.space(2.0);
+            pdf.paragraph(
+                explanation.trim(),
+                Style::Italic,
+                SMALL_SIZE,
+                MARGIN + 4.0,
+                CONTENT_WIDTH - 4.0,
+            );
+        }
+    }
+
+    fn choice(pdf: &mut PdfLayout, choice: &PrintableChoice) {
+        let text = format!("({}) {}", choice.label, choice.text);
+        let lines = PdfLayout::wrap(&text, Style::Regular, TEXT_SIZE, CONTENT_WIDTH - BOX_INDENT);
+        pdf.ensure(PdfLayout::line_height(TEXT_SIZE) * lines.len() as f32 + 1.5);
+        pdf.space(1.5);
+        let baseline = pdf.next_line(TEXT_SIZE);
+        pdf.check_box(MARGIN + 1.0, baseline, choice.mark.as_deref());
+        pdf.lines_from(
+            baseline,
+            &lines,
+            Style::Regular,
+            TEXT_SIZE,
+            MARGIN + BOX_INDENT,
+        );
+    }
+
+    fn grid(pdf: &mut PdfLayout, columns: &[String], rows: &[GridRow]) {
+        let widest = columns
+            .iter()
+            .map(|c| PdfLayout::text_width(c, Style::Bold, SMALL_SIZE))
+            .fold(0.0, f32::max);
+        // Columns share at most half of the width, longer headers wrap.
+        let column_width = (widest + 4.0)
+            .max(16.0)
+            .min(CONTENT_WIDTH / 2.0 / columns.len().max(1) as f32);
+        let label_width = CONTENT_WIDTH - column_width * columns.len() as f32;
+        let column_x = |column: usize| MARGIN + label_width + column_width * column as f32;
+
+        let headers: Vec<Vec<String>> = columns
+            .iter()
+            .map(|c| PdfLayout::wrap(c, Style::Bold, SMALL_SIZE, column_width - 2.0))
+            .collect();
+        let header_lines = headers.iter().map(Vec::len).max().unwrap_or(1);
+        pdf.ensure(PdfLayout::line_height(SMALL_SIZE) * header_lines as f32 + 12.0);
+        pdf.space(2.0);
+        let top = pdf.y;
+        for (column, lines) in headers.iter().enumerate() {
+            pdf.y = top;
+            for line in lines {
+                let baseline = pdf.next_line(SMALL_SIZE);
+                let width = PdfLayout::text_width(line, Style::Bold, SMALL_SIZE);
+                let x = column_x(column) + (column_width - width) / 2.0;
+                pdf.text(line, Style::Bold, SMALL_SIZE, x, baseline);
+            }
+        }
+        pdf.y = top - PdfLayout::line_height(SMALL_SIZE) * header_lines as f32 - 1.0;
+        pdf.rule(MARGIN, MARGIN + CONTENT_WIDTH, pdf.y);
+
+        for row in rows {
+            let text = format!("({}) {}", row.label, row.text);
+            let lines = PdfLayout::wrap(&text, Style::Regular, TEXT_SIZE, label_width - 2.0);
+            pdf.ensure(PdfLayout::line_height(TEXT_SIZE) * lines.len() as f32 + 2.0);
+            pdf.space(1.5);
+            let baseline = pdf.next_line(TEXT_SIZE);
+            for column in 0..columns.len() {
+                let x = column_x(column) + (column_width - BOX_SIZE) / 2.0;
+                let mark = (row.marked == Some(column)).then_some("X");
+                pdf.check_box(x, baseline, mark);
+            }
+            pdf.lines_from(baseline, &lines, Style::Regular, TEXT_SIZE, MARGIN);
+        }
+    }
+}
+
+/// Page state while writing the document top to bottom. `y` is the distance of the
+/// writing position from the bottom of the page in millimeters, as PDF counts.
+struct PdfLayout {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    italic: IndirectFontRef,
+    y: f32,
+    page: usize,
+}
+
+impl PdfLayout {
+    fn new(title: &str) -> Result<Self, String> {
+        let (doc, page, layer) = PdfDocument::new(
+            Self::encodable(title),
+            Mm(PAGE_WIDTH),
+            Mm(PAGE_HEIGHT),
+            LAYER,
+        );
+        let font = |font: BuiltinFont| doc.add_builtin_font(font).map_err(|e| e.to_string());
+        let regular = font(BuiltinFont::Helvetica)?;
+        let bold = font(BuiltinFont::HelveticaBold)?;
+        let italic = font(BuiltinFont::HelveticaOblique)?;
+        let layer = doc.get_page(page).get_layer(layer);
+        let mut layout = Self {
+            doc,
+            layer,
+            regular,
+            bold,
+            italic,
+            y: PAGE_HEIGHT - MARGIN,
+            page: 1,
+        };
+        layout.footer();
+        Ok(layout)
+    }
+
+    fn finish(self) -> Result<Vec<u8>, String> {
+        self.doc.save_to_bytes().map_err(|e| e.to_string())
+    }
+
+    fn new_page(&mut self) {
+        let (page, layer) = self.doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), LAYER);
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT - MARGIN;
+        self.page += 1;
+        self.footer();
+    }
+
+    fn footer(&mut self) {
+        let text = format!("Page {}", self.page);
+        let width = Self::text_width(&text, Style::Regular, SMALL_SIZE);
+        self.text(
+            &text,
+            Style::Regular,
+            SMALL_SIZE,
+            (PAGE_WIDTH - width) / 2.0,
+            MARGIN / 2.0,
+        );
+    }
+
+    /// Starts a new page unless `height` fits on the current one.
+    fn ensure(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.new_page();
+        }
+    }
+
+    fn space(&mut self, height: f32) {
+        self.y -= height;
+    }
+
+    fn line_height(size: f32) -> f32 {
+        size * PT * LINE_SPACING
+    }
+
+    /// Moves down by one line and returns its baseline, on a new page if necessary.
+    fn next_line(&mut self, size: f32) -> f32 {
+        self.ensure(Self::line_height(size));
+        self.y -= Self::line_height(size);
+        self.y + Self::line_height(size) - size * PT
+    }
+
+    fn paragraph(&mut self, text: &str, style: Style, size: f32, x: f32, width: f32) {
+        for line in Self::wrap(text, style, size, width) {
+            let baseline = self.next_line(size);
+            self.text(&line, style, size, x, baseline);
+        }
+    }
+
+    /// Like `paragraph`, with the first line on the already taken line at `baseline`.
+    fn paragraph_from(
+        &mut self,
+        baseline: f32,
+        text: &str,
+        style: Style,
+        size: f32,
+        x: f32,
+        width: f32,
+    ) {
+        let lines = Self::wrap(text, style, size, width);
+        self.lines_from(baseline, &lines, style, size, x);
+    }
+
+    fn lines_from(&mut self, baseline: f32, lines: &[String], style: Style, size: f32, x: f32) {
+        for (index, line) in lines.iter().enumerate() {
+            let baseline = if index == 0 {
+                baseline
+            } else {
+                self.next_line(size)
+            };
+            self.text(line, style, size, x, baseline);
+        }
+    }
+
+    fn text(&self, text: &str, style: Style, size: f32, x: f32, baseline: f32) {
+        let font = match style {
+            Style::Regular => &self.regular,
+            Style::Bold => &self.bold,
+            Style::Italic => &self.italic,
+        };
+        self.layer
+            .use_text(Self::encodable(text), size, Mm(x), Mm(baseline), font);
+    }
+
+    fn check_box(&self, x: f32, baseline: f32, mark: Option<&str>) {
+        let bottom = baseline - 0.8;
+        self.layer.set_outline_thickness(0.6);
+        self.layer.add_rect(
+            Rect::new(Mm(x), Mm(bottom), Mm(x + BOX_SIZE), Mm(bottom + BOX_SIZE))
+                .with_mode(PaintMode::Stroke),
+        );
+        if let Some(mark) = mark {
+            let width = Self::text_width(mark, Style::Bold, SMALL_SIZE);
+            self.text(
+                mark,
+                Style::Bold,
+                SMALL_SIZE,
+                x + (BOX_SIZE - width) / 2.0,
+                baseline,
+            );
+        }
+    }
+
+    fn rule(&self, from: f32, to: f32, y: f32) {
+        self.layer.set_outline_thickness(0.4);
+        self.layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(from), Mm(y)), false),
+                (Point::new(Mm(to), Mm(y)), false),
+            ],
+            is_closed: false,
+        });
+    }
+
+    /// Breaks `text` into lines of at most `width` millimeters, at spaces where possible.
+    fn wrap(text: &str, style: Style, size: f32, width: f32) -> Vec<String> {
+        let mut lines: Vec<String> = vec![];
+        for paragraph in text.lines() {
+            let mut line = String::new();
+            for word in paragraph.split_whitespace() {
+                let candidate = if line.is_empty() {
+                    word.to_string()
+                } else {
+                    format!("{line} {word}")
+                };
+                if Self::text_width(&candidate, style, size) <= width {
+                    line = candidate;
+                    continue;
+                }
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                // Words wider than the line are split wherever they reach the edge.
+                for c in word.chars() {
+                    line.push(c);
+                    if Self::text_width(&line, style, size) > width && line.chars().count() > 1 {
+                        line.pop();
+                        lines.push(std::mem::replace(&mut line, c.to_string()));
+                    }
+                }
+            }
+            lines.push(line);
+        }
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines
+    }
+
+    fn text_width(text: &str, style: Style, size: f32) -> f32 {
+        let units: u32 = Self::encodable(text)
+            .chars()
+            .map(|c| match c as u32 {
+                code @ 32..=126 => HELVETICA_WIDTHS[(code - 32) as usize] as u32,
+                _ => 556,
+            })
+            .sum();
+        // Bold glyphs are slightly wider, the estimate keeps lines inside the margin.
+        let factor = if style == Style::Bold { 1.07 } else { 1.0 };
+        units as f32 / 1000.0 * size * PT * factor
+    }
+
+    /// Replaces what Windows-1252 can't encode.
+    fn encodable(text: &str) -> String {
+        let mut encoded = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '\t' => encoded.push(' '),
+                '→' | '⇒' => encoded.push_str("->"),
+                '←' | '⇐' => encoded.push_str("<-"),
+                '↔' | '⇔' => encoded.push_str("<->"),
+                '≤' => encoded.push_str("<="),
+                '≥' => encoded.push_str(">="),
+                '≠' => encoded.push_str("!="),
+                '≈' => encoded.push('~'),
+                c if (' '..='~').contains(&c)
+                    || ('\u{a0}'..='\u{ff}').contains(&c)
+                    || WINDOWS_1252_EXTRAS.contains(c) =>
+                {
+                    encoded.push(c)
+                }
+                _ => encoded.push('?'),
+            }
+        }
+        encoded
+    }
+}
@@ -0,0 +1,292 @@
+use crate::domain::model::answer::Answer;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::print_options::PrintOptions;
+use crate::domain::model::question::{Question, CLOZE_PLACEHOLDER};
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::sampling::attempt_shuffler::AttemptShuffler;
+use crate::domain::sampling::seeded_random::SeededRandom;
+
+/// Layout independent content of a printed exam, rendered by the Markdown, HTML and PDF
+/// renderers. All texts are plain text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintableExam {
+    pub title: String,
+    /// `Variant 2`, `Answer key` or both.
+    pub subtitle: Option<String>,
+    pub cover: Option<CoverPage>,
+    pub answer_key: bool,
+    pub questions: Vec<PrintableQuestion>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverPage {
+    pub description: Option<String>,
+    pub question_count: usize,
+    pub points_total: i32,
+    /// Minutes.
+    pub duration: Option<i32>,
+    pub points_to_succeeded: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintableQuestion {
+    pub number: usize,
+    pub text: String,
+    pub points: Option<i32>,
+    /// How to answer, e.g. `Select all correct answers.`
+    pub hint: Option<String>,
+    pub body: PrintableBody,
+    /// Explanation of the question, only on the answer key.
+    pub explanation: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrintableBody {
+    /// A box per choice, on the answer key the correct ones are marked with `X`.
+    Choices(Vec<PrintableChoice>),
+    /// One row per answer and one column per option, like the `A:` rows of the text
+    /// import format. On the answer key `marked` is the column of the assigned option.
+    Grid {
+        columns: Vec<String>,
+        rows: Vec<GridRow>,
+    },
+    /// Line to write the answer on, the answer key shows the accepted answers instead.
+    WriteIn {
+        unit: Option<String>,
+        solutions: Vec<String>,
+    },
+    /// A box per item for its position, filled in on the answer key.
+    Ordering(Vec<PrintableChoice>),
+    /// The gaps are part of the question text as `_____ (n)`.
+    Cloze(Vec<ClozeBlank>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintableChoice {
+    pub label: String,
+    pub text: String,
+    /// Content of the box, empty on the answer sheet.
+    pub mark: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridRow {
+    pub label: String,
+    pub text: String,
+    pub marked: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClozeBlank {
+    pub number: i32,
+    /// Choices of a dropdown gap, empty for typed gaps.
+    pub choices: Vec<String>,
+    /// Correct answers, only on the answer key.
+    pub solutions: Vec<String>,
+}
+
+/// Gap of a cloze question on paper.
+pub const CLOZE_GAP: &str = "_____";
+
+impl PrintableExam {
+    /// Prepares `exam`, as loaded with its relations, for printing with `options`. The
+    /// format of `options` is left to the renderer.
+    pub fn new(exam: &Exam, options: &PrintOptions) -> Self {
+        let questions = match options.variant {
+            Some(variant) => AttemptShuffler::shuffle(
+                &exam.questions,
+                Self::variant_seed(exam.id.unwrap_or_default(), variant),
+            ),
+            None => exam.questions.clone(),
+        };
+
+        let subtitle = match (options.variant, options.answer_key) {
+            (Some(variant), true) => Some(format!("Variant {variant} - Answer key")),
+            (Some(variant), false) => Some(format!("Variant {variant}")),
+            (None, true) => Some("Answer key".to_string()),
+            (None, false) => None,
+        };
+
+        let cover = options.cover_page.then(|| CoverPage {
+            description: exam.description.clone().filter(|d| !d.trim().is_empty()),
+            question_count: questions.len(),
+            points_total: questions.iter().map(|q| q.points_total).sum(),
+            duration: exam.duration,
+            points_to_succeeded: exam.points_to_succeeded,
+        });
+
+        let questions = questions
+            .iter()
+            .enumerate()
+            .map(|(index, question)| PrintableQuestion {
+                number: index + 1,
+                text: match question.r#type {
+                    QuestionType::Cloze => Self::cloze_text(&question.question_text),
+                    _ => question.question_text.clone(),
+                },
+                points: options.show_points.then_some(question.points_total),
+                hint: Self::hint(&question.r#type).map(str::to_string),
+                body: Self::body(question, options),
+                explanation: question.explanation.clone().filter(|_| options.answer_key),
+            })
+            .collect();
+
+        Self {
+            title: exam.name.clone(),
+            subtitle,
+            cover,
+            answer_key: options.answer_key,
+            questions,
+        }
+    }
+
+    /// `a` to `z`, then numbers.
+    pub fn label(index: usize) -> String {
+        match u8::try_from(index) {
+            Ok(offset) if offset < 26 => ((b'a' + offset) as char).to_string(),
+            _ => (index + 1).to_string(),
+        }
+    }
+
+    /// Text of a question with points, e.g. `1 point` or `3 points`.
+    pub fn points_text(points: i32) -> String {
+        if points == 1 {
+            "1 point".to_string()
+        } else {
+            format!("{points} points")
+        }
+    }
+
+    fn variant_seed(exam_id: i32, variant: u32) -> i64 {
+        SeededRandom::new(((exam_id as i64) << 32) | variant as i64).next_u64() as i64
+    }
+
+    fn hint(r#type: &QuestionType) -> Option<&'static str> {
+        match r#type {
+            QuestionType::SingleChoice => Some("Select one answer."),
+            QuestionType::MultipleChoice => Some("Select all correct answers."),
+            QuestionType::Assignment => Some("Mark one column per row."),
+            QuestionType::Ordering => Some("Number the items in the correct order."),
+            QuestionType::Cloze => Some("Fill in the gaps."),
+            QuestionType::ShortAnswer | QuestionType::Numeric => None,
+        }
+    }
+
+    fn body(question: &Question, options: &PrintOptions) -> PrintableBody {
+        let key = options.answer_key;
+        match question.r#type {
+            QuestionType::SingleChoice | QuestionType::MultipleChoice => PrintableBody::Choices(
+                question
+                    .answers
+                    .iter()
+                    .enumerate()
+                    .map(|(index, answer)| PrintableChoice {
+                        label: Self::label(index),
+                        text: answer.answer_text.clone(),
+                        mark: (key && answer.is_correct == Some(true)).then(|| "X".to_string()),
+                    })
+                    .collect(),
+            ),
+            QuestionType::Assignment => {
+                let columns = question.options.iter().flatten().collect::<Vec<_>>();
+                PrintableBody::Grid {
+                    columns: columns.iter().map(|o| o.text.clone()).collect(),
+                    rows: question
+                        .answers
+                        .iter()
+                        .enumerate()
+                        .map(|(index, answer)| GridRow {
+                            label: Self::label(index),
+                            text: answer.answer_text.clone(),
+                            marked: columns
+                                .iter()
+                                .position(|o| Some(o.id) == answer.assigned_option_id)
+                                .filter(|_| key),
+                        })
+                        .collect(),
+                }
+            }
+            QuestionType::ShortAnswer | QuestionType::Numeric => PrintableBody::WriteIn {
+                unit: question
+                    .answers
+                    .iter()
+                    .find_map(|a| a.numeric.as_ref().and_then(|n| n.unit.clone())),
+                solutions: if key {
+                    question.answers.iter().map(Self::solution).collect()
+                } else {
+                    vec![]
+                },
+            },
+            QuestionType::Ordering => {
+                let mut items: Vec<&Answer> = question.answers.iter().collect();
+                if options.variant.is_none() {
+                    // The stored order may be the solution.
+                    items.sort_by_key(|a| a.answer_text.to_lowercase());
+                }
+                PrintableBody::Ordering(
+                    items
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, answer)| PrintableChoice {
+                            label: Self::label(index),
+                            text: answer.answer_text.clone(),
+                            mark: answer.position.filter(|_| key).map(|p| p.to_string()),
+                        })
+                        .collect(),
+                )
+            }
+            QuestionType::Cloze => PrintableBody::Cloze(
+                question
+                    .cloze_blanks()
+                    .into_iter()
+                    .map(|blank| {
+                        let answers = question.answers.iter().filter(|a| a.blank == Some(blank));
+                        ClozeBlank {
+                            number: blank,
+                            choices: answers
+                                .clone()
+                                .filter(|a| a.match_mode.is_none())
+                                .map(|a| a.answer_text.clone())
+                                .collect(),
+                            solutions: answers
+                                .filter(|a| {
+                                    key && (a.match_mode.is_some() || a.is_correct == Some(true))
+                                })
+                                .map(|a| a.answer_text.clone())
+                                .collect(),
+                        }
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn solution(answer: &Answer) -> String {
+        match &answer.numeric {
+            Some(numeric) => Self::numeric_text(numeric),
+            None => answer.answer_text.clone(),
+        }
+    }
+
+    fn numeric_text(numeric: &NumericAnswer) -> String {
+        let mut text = numeric.expected_value.to_string();
+        if numeric.tolerance != 0.0 {
+            text.push_str(&match numeric.tolerance_mode {
+                ToleranceMode::Absolute => format!(" ± {}", numeric.tolerance),
+                ToleranceMode::Relative => format!(" ± {} %", numeric.tolerance * 100.0),
+            });
+        }
+        if let Some(unit) = &numeric.unit {
+            text.push_str(&format!(" {unit}"));
+        }
+        text
+    }
+
+    fn cloze_text(text: &str) -> String {
+        CLOZE_PLACEHOLDER
+            .replace_all(text, format!("{CLOZE_GAP} ($1)"))
+            .into_owned()
+    }
+}
@@ -0,0 +1,38 @@
+use crate::domain::model::print_options::PrintOptions;
+use crate::domain::rendering::html_renderer::HtmlRenderer;
+use crate::domain::rendering::printable_exam::PrintableExam;
+use crate::domain::rendering::tests::printable_exam_test::exam;
+
+#[test]
+fn it_should_render_a_standalone_page() {
+    let html = HtmlRenderer::render(&PrintableExam::new(&exam(), &PrintOptions::default()));
+
+    assert!(html.starts_with("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">"));
+    assert!(html.ends_with("</body>\n</html>\n"));
+    assert!(html.contains("<section class=\"cover\">"));
+    assert!(html.contains("<tr><td>Duration</td><td>90 minutes</td></tr>"));
+    assert!(html.contains("<tr><td>Required to pass</td><td>5 points</td></tr>"));
+    assert!(html.contains("How many definitions of the term &quot;Concept A&quot; exist?"));
+    assert!(html
+        .contains("<li><span class=\"box\"></span>(a) Exactly one for all types of systems.</li>"));
+    assert!(html.contains(
+        "<table class=\"grid\">\n<tr><th></th><th>Suitable</th><th>Not suitable</th></tr>"
+    ));
+    assert!(html.contains("<p>Answer: <span class=\"write-in\"></span> Mbit/s</p>"));
+}
+
+#[test]
+fn it_should_mark_the_solutions_on_the_answer_key() {
+    let options = PrintOptions {
+        answer_key: true,
+        variant: Some(3),
+        ..PrintOptions::default()
+    };
+    let html = HtmlRenderer::render(&PrintableExam::new(&exam(), &options));
+
+    assert!(html.contains("<p class=\"subtitle\">Variant 3 - Answer key</p>"));
+    assert!(!html.contains("Name:"));
+    assert!(html.contains("<span class=\"box\">X</span>"));
+    assert!(html.contains("<p>Answer: <strong>62 hosts</strong></p>"));
+    assert!(html.contains("<p class=\"explanation\">A /26 leaves **6 host bits**"));
+}
@@ -0,0 +1,40 @@
+use crate::domain::model::print_options::PrintOptions;
+use crate::domain::rendering::markdown_renderer::MarkdownRenderer;
+use crate::domain::rendering::printable_exam::PrintableExam;
+use crate::domain::rendering::tests::printable_exam_test::exam;
+
+#[test]
+fn it_should_render_the_answer_sheet() {
+    let markdown = MarkdownRenderer::render(&PrintableExam::new(&exam(), &PrintOptions::default()));
+
+    assert!(markdown.starts_with(&format!("# {}\n", exam().name)));
+    assert!(markdown.contains("\nClosed book.\n"));
+    assert!(markdown.contains(
+        "| Total points | 9 points |\n| Duration | 90 minutes |\n| Required to pass | 5 points |\n"
+    ));
+    assert!(markdown.contains("\nName: ____"));
+    assert!(markdown.contains("\n## Question 1 (1 point)\n\nHow many definitions of the term \"Concept A\" exist?\n\n*Select one answer.*\n\n- [ ] (a) Exactly one for all types of systems.\n"));
+    assert!(markdown.contains("\n| | Suitable | Not suitable |\n|---|:---:|:---:|\n| (a) The lead role coordinates the creation of the documentation. | [ ] | [ ] |\n"));
+    assert!(markdown.contains("\nAnswer: ______________________________ Mbit/s\n"));
+    assert!(markdown.contains("port \\_\\_\\_\\_\\_ (1) and"));
+    assert!(markdown.contains("\n- (2) TCP / UDP\n"));
+}
+
+#[test]
+fn it_should_render_the_answer_key() {
+    let options = PrintOptions {
+        answer_key: true,
+        cover_page: false,
+        ..PrintOptions::default()
+    };
+    let markdown = MarkdownRenderer::render(&PrintableExam::new(&exam(), &options));
+
+    assert!(markdown.contains("\n*Answer key*\n"));
+    assert!(!markdown.contains("Name:"));
+    assert!(markdown.contains("- [X] (c) A dozen or more different definitions.\n"));
+    assert!(markdown.contains("| (c) All parts of the documentation are automatically extracted from the source system. | [ ] | [X] |\n"));
+    assert!(markdown.contains("\nAnswer: 950 ± 5 % Mbit/s\n"));
+    assert!(markdown.contains("\n> A /26 leaves **6 host bits**"));
+    assert!(markdown.contains("- [3] (b) Build\n"));
+    assert!(markdown.contains("\n- (1) 443\n- (2) TCP\n"));
+}
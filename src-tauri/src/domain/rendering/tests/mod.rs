@@ -0,0 +1,8 @@
+#[cfg(test)]
+mod html_renderer_test;
+#[cfg(test)]
+mod markdown_renderer_test;
+#[cfg(test)]
+mod pdf_renderer_test;
+#[cfg(test)]
+pub(crate) mod printable_exam_test;
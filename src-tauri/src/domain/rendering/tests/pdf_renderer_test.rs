@@ -0,0 +1,55 @@
+use crate::domain::model::print_options::PrintOptions;
+use crate::domain::rendering::pdf_renderer::PdfRenderer;
+use crate::domain::rendering::printable_exam::PrintableExam;
+use crate::domain::rendering::tests::printable_exam_test::exam;
+use printpdf::lopdf::Document;
+
+fn pages(options: &PrintOptions, exam: &crate::domain::model::exam::Exam) -> Document {
+    let data = PdfRenderer::render(&PrintableExam::new(exam, options)).unwrap();
+    assert!(data.starts_with(b"%PDF-"));
+    Document::load_mem(&data).unwrap()
+}
+
+#[test]
+fn it_should_put_the_cover_on_its_own_page() {
+    let document = pages(&PrintOptions::default(), &exam());
+    let page_count = document.get_pages().len();
+
+    let cover = document.extract_text(&[1]).unwrap();
+    assert!(cover.contains("Duration"));
+    assert!(cover.contains("90 minutes"));
+    assert!(!cover.contains("Question 1"));
+    let questions = document.extract_text(&[2]).unwrap();
+    assert!(questions.contains("Question 1 (1 point)"));
+    assert!(questions.contains("(a) Exactly one for all types of systems."));
+
+    let without_cover = PrintOptions {
+        cover_page: false,
+        ..PrintOptions::default()
+    };
+    assert_eq!(
+        pages(&without_cover, &exam()).get_pages().len(),
+        page_count - 1
+    );
+}
+
+#[test]
+fn it_should_wrap_text_and_continue_on_new_pages() {
+    let mut exam = exam();
+    exam.questions[0].question_text =
+        "Which of the following statements about the architecture → design mapping holds? "
+            .repeat(12);
+    for _ in 0..4 {
+        exam.questions.extend(exam.questions.clone());
+    }
+    let options = PrintOptions {
+        cover_page: false,
+        ..PrintOptions::default()
+    };
+
+    let document = pages(&options, &exam);
+    assert!(document.get_pages().len() > 5);
+    let first = document.extract_text(&[1]).unwrap();
+    assert!(first.contains("architecture -> design"));
+    assert!(first.lines().all(|line| line.chars().count() < 120));
+}
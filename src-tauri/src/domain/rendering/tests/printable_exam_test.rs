@@ -0,0 +1,249 @@
+use crate::domain::import::text_exam_parser::TextExamParser;
+use crate::domain::model::answer::Answer;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::print_options::PrintOptions;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::tests::fixtures::{self, answer};
+use crate::domain::rendering::printable_exam::{
+    ClozeBlank, GridRow, PrintableBody, PrintableChoice, PrintableExam,
+};
+
+const TEMPLATE: &str = include_str!("../../../../../templates/import_template_txt.txt");
+
+fn question(text: &str, r#type: QuestionType, answers: Vec<Answer>) -> Question {
+    Question {
+        r#type,
+        answers,
+        ..fixtures::question(text)
+    }
+}
+
+/// The text import template with an ordering and a cloze question, ids as if stored.
+pub(crate) fn exam() -> Exam {
+    let mut exam = TextExamParser::parse(TEMPLATE).unwrap().exam;
+    exam.id = Some(7);
+    exam.duration = Some(90);
+    exam.points_to_succeeded = Some(5);
+    exam.description = Some("Closed book.".into());
+
+    let ordered = ["Analysis", "Design", "Build"]
+        .iter()
+        .enumerate()
+        .map(|(index, text)| Answer {
+            position: Some(index as i32 + 1),
+            ..answer(text, true)
+        })
+        .collect();
+    exam.questions.push(question(
+        "Order the phases",
+        QuestionType::Ordering,
+        ordered,
+    ));
+
+    let blank = |text: &str, blank: i32, is_correct: bool, match_mode: Option<MatchMode>| Answer {
+        blank: Some(blank),
+        match_mode,
+        ..answer(text, is_correct)
+    };
+    exam.questions.push(question(
+        "HTTPS runs on port {{1}} and uses {{2}} as transport.",
+        QuestionType::Cloze,
+        vec![
+            blank("443", 1, true, Some(MatchMode::Exact)),
+            blank("TCP", 2, true, None),
+            blank("UDP", 2, false, None),
+        ],
+    ));
+
+    let mut answer_id = 0;
+    for (index, question) in exam.questions.iter_mut().enumerate() {
+        question.id = Some(index as i32 + 1);
+        for answer in question.answers.iter_mut() {
+            answer_id += 1;
+            answer.id = Some(answer_id);
+        }
+    }
+    exam
+}
+
+fn key() -> PrintOptions {
+    PrintOptions {
+        answer_key: true,
+        ..PrintOptions::default()
+    }
+}
+
+fn texts(exam: &PrintableExam) -> Vec<String> {
+    exam.questions.iter().map(|q| q.text.clone()).collect()
+}
+
+#[test]
+fn it_should_prepare_a_blank_answer_sheet() {
+    let printable = PrintableExam::new(&exam(), &PrintOptions::default());
+
+    assert_eq!(printable.subtitle, None);
+    let cover = printable.cover.as_ref().unwrap();
+    assert_eq!(cover.question_count, 7);
+    assert_eq!(cover.points_total, 9);
+    assert_eq!(cover.duration, Some(90));
+    assert_eq!(cover.points_to_succeeded, Some(5));
+
+    let first = &printable.questions[0];
+    assert_eq!(first.number, 1);
+    assert_eq!(first.points, Some(1));
+    assert_eq!(first.hint.as_deref(), Some("Select one answer."));
+    let PrintableBody::Choices(choices) = &first.body else {
+        panic!("expected choices");
+    };
+    assert_eq!(choices[2].label, "c");
+    assert!(choices.iter().all(|c| c.mark.is_none()));
+
+    let PrintableBody::Grid { columns, rows } = &printable.questions[2].body else {
+        panic!("expected a grid");
+    };
+    assert_eq!(
+        columns,
+        &vec!["Suitable".to_string(), "Not suitable".to_string()]
+    );
+    assert!(rows.iter().all(|r| r.marked.is_none()));
+
+    assert_eq!(
+        printable.questions[4].body,
+        PrintableBody::WriteIn {
+            unit: Some("Mbit/s".into()),
+            solutions: vec![],
+        }
+    );
+    assert!(printable.questions[3].explanation.is_none());
+
+    // The stored order of ordering items is the solution, the sheet lists them sorted.
+    let PrintableBody::Ordering(items) = &printable.questions[5].body else {
+        panic!("expected ordering items");
+    };
+    let items: Vec<&str> = items.iter().map(|i| i.text.as_str()).collect();
+    assert_eq!(items, vec!["Analysis", "Build", "Design"]);
+
+    assert_eq!(
+        printable.questions[6].text,
+        "HTTPS runs on port _____ (1) and uses _____ (2) as transport."
+    );
+}
+
+#[test]
+fn it_should_fill_in_the_answer_key() {
+    let printable = PrintableExam::new(&exam(), &key());
+
+    assert_eq!(printable.subtitle.as_deref(), Some("Answer key"));
+    let PrintableBody::Choices(choices) = &printable.questions[1].body else {
+        panic!("expected choices");
+    };
+    let marks: Vec<Option<&str>> = choices.iter().map(|c| c.mark.as_deref()).collect();
+    assert_eq!(marks, vec![Some("X"), Some("X"), Some("X"), None, None]);
+
+    let PrintableBody::Grid { rows, .. } = &printable.questions[2].body else {
+        panic!("expected a grid");
+    };
+    assert_eq!(
+        rows[2],
+        GridRow {
+            label: "c".into(),
+            text:
+                "All parts of the documentation are automatically extracted from the source system."
+                    .into(),
+            marked: Some(1),
+        }
+    );
+
+    assert_eq!(
+        printable.questions[4].body,
+        PrintableBody::WriteIn {
+            unit: Some("Mbit/s".into()),
+            solutions: vec!["950 ± 5 % Mbit/s".into()],
+        }
+    );
+    assert!(printable.questions[3]
+        .explanation
+        .as_deref()
+        .unwrap()
+        .starts_with("A /26 leaves"));
+
+    let PrintableBody::Ordering(items) = &printable.questions[5].body else {
+        panic!("expected ordering items");
+    };
+    assert_eq!(
+        items[1],
+        PrintableChoice {
+            label: "b".into(),
+            text: "Build".into(),
+            mark: Some("3".into()),
+        }
+    );
+
+    assert_eq!(
+        printable.questions[6].body,
+        PrintableBody::Cloze(vec![
+            ClozeBlank {
+                number: 1,
+                choices: vec![],
+                solutions: vec!["443".into()],
+            },
+            ClozeBlank {
+                number: 2,
+                choices: vec!["TCP".into(), "UDP".into()],
+                solutions: vec!["TCP".into()],
+            },
+        ])
+    );
+}
+
+#[test]
+fn it_should_shuffle_variants_reproducibly() {
+    let exam = exam();
+    let variant = |variant: u32, answer_key: bool| {
+        PrintableExam::new(
+            &exam,
+            &PrintOptions {
+                variant: Some(variant),
+                answer_key,
+                ..PrintOptions::default()
+            },
+        )
+    };
+
+    let first = variant(1, false);
+    assert_eq!(first.subtitle.as_deref(), Some("Variant 1"));
+    assert_eq!(first, variant(1, false));
+    assert_ne!(texts(&first), texts(&variant(2, false)));
+
+    let first_key = variant(1, true);
+    assert_eq!(
+        first_key.subtitle.as_deref(),
+        Some("Variant 1 - Answer key")
+    );
+    assert_eq!(texts(&first), texts(&first_key));
+    let numbers: Vec<usize> = first.questions.iter().map(|q| q.number).collect();
+    assert_eq!(numbers, (1..=7).collect::<Vec<_>>());
+
+    let mut sorted = texts(&first);
+    sorted.sort();
+    let mut original = texts(&PrintableExam::new(&exam, &PrintOptions::default()));
+    original.sort();
+    assert_eq!(sorted, original);
+}
+
+#[test]
+fn it_should_leave_out_points_and_cover_page() {
+    let printable = PrintableExam::new(
+        &exam(),
+        &PrintOptions {
+            show_points: false,
+            cover_page: false,
+            ..PrintOptions::default()
+        },
+    );
+
+    assert!(printable.cover.is_none());
+    assert!(printable.questions.iter().all(|q| q.points.is_none()));
+}
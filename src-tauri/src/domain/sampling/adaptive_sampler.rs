@@ -0,0 +1,68 @@
+use crate::domain::model::category_mastery::CategoryMastery;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type_mastery::QuestionTypeMastery;
+use crate::domain::sampling::seeded_random::SeededRandom;
+use crate::domain::statistics::mastery_estimator::PRIOR_MASTERY;
+
+/// Lowest selection weight, so mastered material still comes up now and then.
+pub const MASTERED_WEIGHT: f64 = 0.1;
+
+/// Draws practice questions weighted toward weak categories and question types.
+///
+/// A question's weight is its weakness, one minus the mean mastery of its category
+/// and its type, but never less than [`MASTERED_WEIGHT`]. Questions are drawn without
+/// replacement in the order they are picked; the pool is ordered by id first, so the
+/// same pool, estimates and seed always produce the same set.
+pub struct AdaptiveSampler();
+
+impl AdaptiveSampler {
+    pub fn sample(
+        pool: &[Question],
+        count: usize,
+        seed: i64,
+        categories: &[CategoryMastery],
+        question_types: &[QuestionTypeMastery],
+    ) -> Vec<Question> {
+        let mut random = SeededRandom::new(seed);
+        let mut pool: Vec<&Question> = pool.iter().collect();
+        pool.sort_by_key(|q| q.id);
+        let mut weights: Vec<f64> = pool
+            .iter()
+            .map(|q| AdaptiveSampler::weight(q, categories, question_types))
+            .collect();
+
+        let mut drawn: Vec<Question> = Vec::with_capacity(count.min(pool.len()));
+        while drawn.len() < count && !pool.is_empty() {
+            let mut target = random.next_f64() * weights.iter().sum::<f64>();
+            let mut index = pool.len() - 1;
+            for (i, weight) in weights.iter().enumerate() {
+                if target < *weight {
+                    index = i;
+                    break;
+                }
+                target -= weight;
+            }
+            weights.remove(index);
+            drawn.push(pool.remove(index).clone());
+        }
+        drawn
+    }
+
+    pub fn weight(
+        question: &Question,
+        categories: &[CategoryMastery],
+        question_types: &[QuestionTypeMastery],
+    ) -> f64 {
+        let category_id = question.category.as_ref().and_then(|c| c.id);
+        let category = categories
+            .iter()
+            .find(|m| m.category.as_ref().and_then(|c| c.id) == category_id)
+            .map_or(PRIOR_MASTERY, |m| m.mastery);
+        let question_type = question_types
+            .iter()
+            .find(|m| m.question_type == question.r#type)
+            .map_or(PRIOR_MASTERY, |m| m.mastery);
+
+        (1.0 - (category + question_type) / 2.0).max(MASTERED_WEIGHT)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::sampling::seeded_random::SeededRandom;
+
+/// Puts the questions of an attempt, their answers and the columns of assignment
+/// questions into an order that only depends on the attempt's seed.
+///
+/// Each question shuffles its answers with a generator derived from the seed and its
+/// own id, so the order inside a question stays the same when other questions of the
+/// exam are added or removed.
+pub struct AttemptShuffler();
+
+impl AttemptShuffler {
+    pub fn shuffle(questions: &[Question], seed: i64) -> Vec<Question> {
+        let mut shuffled = questions.to_vec();
+        shuffled.sort_by_key(|q| q.id);
+        SeededRandom::new(seed).shuffle(&mut shuffled);
+
+        for question in shuffled.iter_mut() {
+            let mut random = SeededRandom::new(AttemptShuffler::question_seed(seed, question.id));
+            question.answers.sort_by_key(|a| a.id);
+            random.shuffle(&mut question.answers);
+
+            if question.r#type == QuestionType::Assignment {
+                if let Some(options) = question.options.as_mut() {
+                    options.sort_by_key(|o| o.id);
+                    random.shuffle(options);
+                }
+            }
+        }
+        shuffled
+    }
+
+    fn question_seed(seed: i64, question_id: Option<i32>) -> i64 {
+        SeededRandom::new(seed ^ question_id.unwrap_or_default() as i64).next_u64() as i64
+    }
+}
@@ -0,0 +1,7 @@
+pub mod adaptive_sampler;
+pub mod attempt_shuffler;
+pub mod question_sampler;
+pub mod seeded_random;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,99 @@
+use crate::domain::model::question::Question;
+use crate::domain::sampling::seeded_random::SeededRandom;
+use std::collections::{BTreeMap, HashSet};
+
+/// Draws a subset of an exam's questions for a real exam run.
+///
+/// The pool is ordered by id before sampling, so the same pool, seed and set of
+/// recently drawn ids always produce the same questions in the same order. Recently
+/// drawn questions are only used when there are not enough fresh ones left.
+pub struct QuestionSampler();
+
+impl QuestionSampler {
+    pub fn sample(
+        pool: &[Question],
+        count: usize,
+        seed: i64,
+        stratify: bool,
+        recently_drawn: &HashSet<i32>,
+    ) -> Vec<Question> {
+        let mut random = SeededRandom::new(seed);
+        let mut pool: Vec<&Question> = pool.iter().collect();
+        pool.sort_by_key(|q| q.id);
+        let count = count.min(pool.len());
+
+        let mut drawn: Vec<&Question> = if stratify {
+            let mut groups: BTreeMap<Option<i32>, Vec<&Question>> = BTreeMap::new();
+            for question in pool.iter() {
+                let category_id = question.category.as_ref().and_then(|c| c.id);
+                groups.entry(category_id).or_default().push(question);
+            }
+            let groups: Vec<Vec<&Question>> = groups.into_values().collect();
+            let sizes: Vec<usize> = groups.iter().map(|g| g.len()).collect();
+            let quotas = QuestionSampler::quotas(&sizes, count);
+
+            groups
+                .into_iter()
+                .zip(quotas)
+                .flat_map(|(group, quota)| {
+                    QuestionSampler::pick(group, quota, &mut random, recently_drawn)
+                })
+                .collect()
+        } else {
+            QuestionSampler::pick(pool, count, &mut random, recently_drawn)
+        };
+
+        random.shuffle(&mut drawn);
+        drawn.into_iter().cloned().collect()
+    }
+
+    /// Splits `count` across groups proportionally to their size using the largest
+    /// remainder method, so the quotas always add up to `count`. Ties go to the
+    /// larger group.
+    pub fn quotas(sizes: &[usize], count: usize) -> Vec<usize> {
+        let total: usize = sizes.iter().sum();
+        if total == 0 {
+            return vec![0; sizes.len()];
+        }
+        let count = count.min(total);
+
+        let mut quotas: Vec<usize> = sizes.iter().map(|s| s * count / total).collect();
+        let mut remainders: Vec<(usize, usize)> = sizes
+            .iter()
+            .enumerate()
+            .map(|(index, s)| (s * count % total, index))
+            .collect();
+        remainders.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then(sizes[b.1].cmp(&sizes[a.1]))
+                .then(a.1.cmp(&b.1))
+        });
+
+        let mut missing = count - quotas.iter().sum::<usize>();
+        for (_, index) in remainders {
+            if missing == 0 {
+                break;
+            }
+            if quotas[index] < sizes[index] {
+                quotas[index] += 1;
+                missing -= 1;
+            }
+        }
+        quotas
+    }
+
+    fn pick<'q>(
+        questions: Vec<&'q Question>,
+        count: usize,
+        random: &mut SeededRandom,
+        recently_drawn: &HashSet<i32>,
+    ) -> Vec<&'q Question> {
+        let (mut fresh, mut recent): (Vec<&Question>, Vec<&Question>) = questions
+            .into_iter()
+            .partition(|q| !q.id.is_some_and(|id| recently_drawn.contains(&id)));
+        random.shuffle(&mut fresh);
+        random.shuffle(&mut recent);
+
+        fresh.into_iter().chain(recent).take(count).collect()
+    }
+}
@@ -0,0 +1,50 @@
+use chrono::Utc;
+
+/// Largest seed handed out by [`SeededRandom::generate_seed`]. Seeds stay within the
+/// integer range a JavaScript number can represent so the frontend can replay them.
+pub const MAX_SEED: i64 = (1 << 53) - 1;
+
+/// Small SplitMix64 generator.
+///
+/// The sequence only depends on the seed, so a draw can be reproduced on any
+/// platform and with any dependency versions.
+pub struct SeededRandom {
+    state: u64,
+}
+
+impl SeededRandom {
+    pub fn new(seed: i64) -> Self {
+        Self { state: seed as u64 }
+    }
+
+    pub fn generate_seed() -> i64 {
+        let nanos = Utc::now().timestamp_nanos_opt().unwrap_or_default();
+        (SeededRandom::new(nanos).next_u64() as i64) & MAX_SEED
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `0..bound`. `bound` must not be zero.
+    pub fn next_below(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// Returns a value in `0.0..1.0`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Fisher-Yates shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.next_below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::domain::model::category::Category;
+use crate::domain::model::category_mastery::CategoryMastery;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::question_type_mastery::QuestionTypeMastery;
+use crate::domain::sampling::adaptive_sampler::{AdaptiveSampler, MASTERED_WEIGHT};
+
+fn category(id: i32) -> Category {
+    Category {
+        id: Some(id),
+        name: format!("Category {id}"),
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn question(id: i32, category_id: i32) -> Question {
+    Question {
+        id: Some(id),
+        question_text: format!("Question {id}?"),
+        points_total: 1,
+        r#type: QuestionType::SingleChoice,
+        answers: vec![],
+        points_per_correct_answer: None,
+        category: Some(category(category_id)),
+        created_at: None,
+        updated_at: None,
+        options: None,
+        exam_id: Some(1),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
+    }
+}
+
+fn category_mastery(id: i32, mastery: f64) -> CategoryMastery {
+    CategoryMastery {
+        category: Some(category(id)),
+        responses: 10,
+        mastery,
+        mastered: mastery >= 0.8,
+    }
+}
+
+fn question_types() -> Vec<QuestionTypeMastery> {
+    vec![QuestionTypeMastery {
+        question_type: QuestionType::SingleChoice,
+        responses: 0,
+        mastery: 0.5,
+        mastered: false,
+    }]
+}
+
+/// Ten questions of a mastered category (1) and ten of a weak one (2).
+fn pool() -> Vec<Question> {
+    (1..=20)
+        .map(|id| question(id, if id <= 10 { 1 } else { 2 }))
+        .collect()
+}
+
+fn masteries() -> Vec<CategoryMastery> {
+    vec![category_mastery(1, 1.0), category_mastery(2, 0.0)]
+}
+
+#[test]
+fn weight_favours_weak_areas_but_keeps_mastered_ones() {
+    let types = question_types();
+    let masteries = masteries();
+    let weak = AdaptiveSampler::weight(&question(1, 2), &masteries, &types);
+    let mastered = AdaptiveSampler::weight(&question(1, 1), &masteries, &types);
+    assert!((weak - 0.75).abs() < 1e-9);
+    assert!((mastered - 0.25).abs() < 1e-9);
+
+    let types = vec![QuestionTypeMastery {
+        mastery: 1.0,
+        ..question_types()[0].clone()
+    }];
+    assert_eq!(
+        AdaptiveSampler::weight(&question(1, 1), &masteries, &types),
+        MASTERED_WEIGHT
+    );
+}
+
+#[test]
+fn same_seed_draws_same_questions() {
+    let pool = pool();
+    let first = AdaptiveSampler::sample(&pool, 8, 5, &masteries(), &question_types());
+    let mut reversed = pool.clone();
+    reversed.reverse();
+    let second = AdaptiveSampler::sample(&reversed, 8, 5, &masteries(), &question_types());
+
+    let ids = |questions: &[Question]| questions.iter().map(|q| q.id).collect::<Vec<_>>();
+    assert_eq!(first.len(), 8);
+    assert_eq!(ids(&first), ids(&second));
+}
+
+#[test]
+fn draws_mostly_from_weak_categories() {
+    let pool = pool();
+    let mut weak = 0;
+    let mut mastered = 0;
+    for seed in 0..50 {
+        for question in AdaptiveSampler::sample(&pool, 4, seed, &masteries(), &question_types()) {
+            match question.category.and_then(|c| c.id) {
+                Some(1) => mastered += 1,
+                _ => weak += 1,
+            }
+        }
+    }
+    assert!(weak > mastered * 2);
+    assert!(mastered > 0);
+}
+
+#[test]
+fn draws_each_question_at_most_once() {
+    let pool = pool();
+    let mut drawn: Vec<Option<i32>> =
+        AdaptiveSampler::sample(&pool, 50, 1, &masteries(), &question_types())
+            .iter()
+            .map(|q| q.id)
+            .collect();
+    assert_eq!(drawn.len(), 20);
+    drawn.sort();
+    drawn.dedup();
+    assert_eq!(drawn.len(), 20);
+}
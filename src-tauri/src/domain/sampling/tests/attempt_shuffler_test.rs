@@ -0,0 +1,143 @@
+use crate::domain::model::answer::Answer;
+use crate::domain::model::assignment_option::AssignmentOption;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::sampling::attempt_shuffler::AttemptShuffler;
+
+fn answer(id: i32) -> Answer {
+    Answer {
+        id: Some(id),
+        answer_text: format!("Answer {id}"),
+        description: None,
+        is_correct: Some(id % 2 == 0),
+        assigned_option_id: None,
+        created_at: None,
+        updated_at: None,
+        question_id: None,
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
+    }
+}
+
+fn option(id: i32) -> AssignmentOption {
+    AssignmentOption {
+        row_id: Some(id),
+        id,
+        text: format!("Option {id}"),
+        question_id: None,
+    }
+}
+
+fn question(id: i32, r#type: QuestionType) -> Question {
+    Question {
+        id: Some(id),
+        question_text: format!("Question {id}?"),
+        points_total: 1,
+        r#type,
+        answers: (1..=6).map(|a| answer(id * 10 + a)).collect(),
+        points_per_correct_answer: None,
+        category: None,
+        created_at: None,
+        updated_at: None,
+        options: Some((1..=6).map(option).collect()),
+        exam_id: Some(1),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
+    }
+}
+
+fn questions() -> Vec<Question> {
+    let mut questions: Vec<Question> = (1..=8)
+        .map(|id| question(id, QuestionType::MultipleChoice))
+        .collect();
+    questions.push(question(9, QuestionType::Assignment));
+    questions
+}
+
+fn question_ids(questions: &[Question]) -> Vec<i32> {
+    questions.iter().map(|q| q.id.unwrap()).collect()
+}
+
+fn answer_ids(question: &Question) -> Vec<i32> {
+    question.answers.iter().map(|a| a.id.unwrap()).collect()
+}
+
+fn option_ids(question: &Question) -> Vec<i32> {
+    question.options.iter().flatten().map(|o| o.id).collect()
+}
+
+fn find(questions: &[Question], id: i32) -> &Question {
+    questions.iter().find(|q| q.id == Some(id)).unwrap()
+}
+
+#[test]
+fn same_seed_gives_same_order() {
+    let first = AttemptShuffler::shuffle(&questions(), 7);
+    let second = AttemptShuffler::shuffle(&questions(), 7);
+    assert_eq!(question_ids(&first), question_ids(&second));
+    for (a, b) in first.iter().zip(second.iter()) {
+        assert_eq!(answer_ids(a), answer_ids(b));
+        assert_eq!(option_ids(a), option_ids(b));
+    }
+}
+
+#[test]
+fn order_does_not_depend_on_input_order() {
+    let mut reversed = questions();
+    reversed.reverse();
+    reversed.iter_mut().for_each(|q| q.answers.reverse());
+    let first = AttemptShuffler::shuffle(&questions(), 11);
+    let second = AttemptShuffler::shuffle(&reversed, 11);
+    assert_eq!(question_ids(&first), question_ids(&second));
+    assert_eq!(answer_ids(&first[0]), answer_ids(&second[0]));
+}
+
+#[test]
+fn different_seeds_give_different_orders() {
+    let first = AttemptShuffler::shuffle(&questions(), 1);
+    let second = AttemptShuffler::shuffle(&questions(), 2);
+    assert_ne!(question_ids(&first), question_ids(&second));
+}
+
+#[test]
+fn shuffling_keeps_ids_and_content() {
+    let shuffled = AttemptShuffler::shuffle(&questions(), 3);
+    let mut ids = question_ids(&shuffled);
+    ids.sort();
+    assert_eq!(ids, (1..=9).collect::<Vec<i32>>());
+
+    for question in &shuffled {
+        let mut answers = answer_ids(question);
+        answers.sort();
+        let id = question.id.unwrap();
+        assert_eq!(answers, (1..=6).map(|a| id * 10 + a).collect::<Vec<i32>>());
+        for answer in &question.answers {
+            assert_eq!(answer.is_correct, Some(answer.id.unwrap() % 2 == 0));
+        }
+    }
+}
+
+#[test]
+fn only_assignment_questions_shuffle_their_options() {
+    let shuffled = AttemptShuffler::shuffle(&questions(), 5);
+    assert_eq!(option_ids(find(&shuffled, 1)), vec![1, 2, 3, 4, 5, 6]);
+
+    let mut options = option_ids(find(&shuffled, 9));
+    assert_ne!(options, vec![1, 2, 3, 4, 5, 6]);
+    options.sort();
+    assert_eq!(options, vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn answer_order_is_stable_when_other_questions_change() {
+    let all = AttemptShuffler::shuffle(&questions(), 13);
+    let fewer: Vec<Question> = questions()
+        .into_iter()
+        .filter(|q| q.id != Some(2))
+        .collect();
+    let fewer = AttemptShuffler::shuffle(&fewer, 13);
+    assert_eq!(answer_ids(find(&all, 5)), answer_ids(find(&fewer, 5)));
+}
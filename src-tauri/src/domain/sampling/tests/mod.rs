@@ -0,0 +1,8 @@
+#[cfg(test)]
+mod adaptive_sampler_test;
+#[cfg(test)]
+mod attempt_shuffler_test;
+#[cfg(test)]
+mod question_sampler_test;
+#[cfg(test)]
+mod seeded_random_test;
@@ -0,0 +1,129 @@
+use crate::domain::model::category::Category;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::sampling::question_sampler::QuestionSampler;
+use std::collections::HashSet;
+
+fn question(id: i32, category_id: Option<i32>) -> Question {
+    Question {
+        id: Some(id),
+        question_text: format!("Question {id}?"),
+        points_total: 1,
+        r#type: QuestionType::SingleChoice,
+        answers: vec![],
+        points_per_correct_answer: None,
+        category: category_id.map(|category_id| Category {
+            id: Some(category_id),
+            name: format!("Category {category_id}"),
+            created_at: None,
+            updated_at: None,
+        }),
+        created_at: None,
+        updated_at: None,
+        options: None,
+        exam_id: Some(1),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
+    }
+}
+
+fn ids(questions: &[Question]) -> Vec<i32> {
+    questions.iter().map(|q| q.id.unwrap()).collect()
+}
+
+fn pool() -> Vec<Question> {
+    let mut pool: Vec<Question> = (1..=6).map(|id| question(id, Some(1))).collect();
+    pool.extend((7..=9).map(|id| question(id, Some(2))));
+    pool.push(question(10, None));
+    pool
+}
+
+#[test]
+fn same_seed_draws_same_questions() {
+    let pool = pool();
+    let first = QuestionSampler::sample(&pool, 5, 99, false, &HashSet::new());
+    let second = QuestionSampler::sample(&pool, 5, 99, false, &HashSet::new());
+    assert_eq!(ids(&first), ids(&second));
+    assert_eq!(first.len(), 5);
+}
+
+#[test]
+fn draw_does_not_depend_on_pool_order() {
+    let pool = pool();
+    let mut reversed = pool.clone();
+    reversed.reverse();
+    assert_eq!(
+        ids(&QuestionSampler::sample(
+            &pool,
+            4,
+            12,
+            true,
+            &HashSet::new()
+        )),
+        ids(&QuestionSampler::sample(
+            &reversed,
+            4,
+            12,
+            true,
+            &HashSet::new()
+        ))
+    );
+}
+
+#[test]
+fn count_larger_than_pool_draws_everything() {
+    let pool = pool();
+    let mut drawn = ids(&QuestionSampler::sample(
+        &pool,
+        50,
+        1,
+        false,
+        &HashSet::new(),
+    ));
+    drawn.sort();
+    assert_eq!(drawn, (1..=10).collect::<Vec<i32>>());
+}
+
+#[test]
+fn stratified_draw_keeps_category_shares() {
+    let pool = pool();
+    for seed in 0..20 {
+        let drawn = QuestionSampler::sample(&pool, 5, seed, true, &HashSet::new());
+        let first_category = drawn
+            .iter()
+            .filter(|q| q.category.as_ref().and_then(|c| c.id) == Some(1))
+            .count();
+        let second_category = drawn
+            .iter()
+            .filter(|q| q.category.as_ref().and_then(|c| c.id) == Some(2))
+            .count();
+        assert_eq!(first_category, 3);
+        assert_eq!(second_category, 2);
+    }
+}
+
+#[test]
+fn recently_drawn_questions_are_avoided() {
+    let pool = pool();
+    let recent: HashSet<i32> = (1..=5).collect();
+    let drawn = QuestionSampler::sample(&pool, 5, 8, false, &recent);
+    assert!(ids(&drawn).iter().all(|id| !recent.contains(id)));
+}
+
+#[test]
+fn recently_drawn_questions_fill_up_missing_slots() {
+    let pool = pool();
+    let recent: HashSet<i32> = (1..=8).collect();
+    let drawn = ids(&QuestionSampler::sample(&pool, 4, 8, false, &recent));
+    assert_eq!(drawn.len(), 4);
+    assert!(drawn.contains(&9) && drawn.contains(&10));
+}
+
+#[test]
+fn quotas_use_largest_remainder() {
+    assert_eq!(QuestionSampler::quotas(&[6, 3, 1], 5), vec![3, 2, 0]);
+    assert_eq!(QuestionSampler::quotas(&[1, 1, 1], 2), vec![1, 1, 0]);
+    assert_eq!(QuestionSampler::quotas(&[], 3), Vec::<usize>::new());
+    assert_eq!(QuestionSampler::quotas(&[4], 10), vec![4]);
+}
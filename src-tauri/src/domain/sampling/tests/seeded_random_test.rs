@@ -0,0 +1,50 @@
+use crate::domain::sampling::seeded_random::{SeededRandom, MAX_SEED};
+
+#[test]
+fn same_seed_produces_same_sequence() {
+    let mut first = SeededRandom::new(42);
+    let mut second = SeededRandom::new(42);
+    for _ in 0..10 {
+        assert_eq!(first.next_u64(), second.next_u64());
+    }
+}
+
+#[test]
+fn sequence_is_stable_across_releases() {
+    let mut random = SeededRandom::new(0);
+    assert_eq!(random.next_u64(), 0xE220_A839_7B1D_CDAF);
+    assert_eq!(random.next_u64(), 0x6E78_9E6A_A1B9_65F4);
+}
+
+#[test]
+fn next_below_stays_in_bounds() {
+    let mut random = SeededRandom::new(7);
+    for bound in 1..50 {
+        assert!(random.next_below(bound) < bound);
+    }
+}
+
+#[test]
+fn shuffle_keeps_all_items() {
+    let mut items: Vec<i32> = (0..20).collect();
+    SeededRandom::new(3).shuffle(&mut items);
+    let mut sorted = items.clone();
+    sorted.sort();
+    assert_eq!(sorted, (0..20).collect::<Vec<i32>>());
+    assert_ne!(items, sorted);
+}
+
+#[test]
+fn generated_seed_is_safe_for_javascript() {
+    let seed = SeededRandom::generate_seed();
+    assert!((0..=MAX_SEED).contains(&seed));
+}
+
+#[test]
+fn next_f64_stays_in_unit_interval() {
+    let mut random = SeededRandom::new(11);
+    for _ in 0..100 {
+        let value = random.next_f64();
+        assert!((0.0..1.0).contains(&value));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod spaced_repetition;
+
+#[cfg(test)]
+mod tests;
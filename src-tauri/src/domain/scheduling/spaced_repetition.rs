@@ -0,0 +1,57 @@
+use crate::domain::model::review_state::ReviewState;
+use crate::domain::validation::field_validator::FieldValidator;
+use crate::domain::validation::validation_result::ValidationResult;
+use crate::domain::validation::validators::num_rules::{max, min};
+use chrono::{DateTime, Duration, Utc};
+
+pub const MIN_GRADE: i32 = 0;
+pub const MAX_GRADE: i32 = 5;
+/// Lowest grade that still counts as a successful recall.
+pub const PASSING_GRADE: i32 = 3;
+pub const MIN_EASE_FACTOR: f64 = 1.3;
+
+/// SM-2 scheduler.
+///
+/// Grades go from 0 (blackout) to 5 (perfect recall). A failed review resets the
+/// repetitions, counts a lapse and brings the question back the next day; a
+/// successful one grows the interval from 1 to 6 days and then by the ease factor.
+pub struct SpacedRepetition();
+
+impl SpacedRepetition {
+    pub fn validate_grade(grade: i32) -> ValidationResult {
+        let errors = FieldValidator::new("grade")
+            .rule(min(MIN_GRADE))
+            .rule(max(MAX_GRADE))
+            .validate(&grade);
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+        Err(errors)
+    }
+
+    pub fn review(state: &ReviewState, grade: i32, now: DateTime<Utc>) -> ReviewState {
+        let grade = grade.clamp(MIN_GRADE, MAX_GRADE);
+        let mut next = state.clone();
+
+        if grade < PASSING_GRADE {
+            next.repetitions = 0;
+            next.interval_days = 1;
+            next.lapses += 1;
+        } else {
+            next.interval_days = match state.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (state.interval_days as f64 * state.ease_factor).round() as i32,
+            };
+            next.repetitions += 1;
+        }
+
+        let miss = (MAX_GRADE - grade) as f64;
+        next.ease_factor =
+            (state.ease_factor + 0.1 - miss * (0.08 + miss * 0.02)).max(MIN_EASE_FACTOR);
+        next.due_at = now + Duration::days(next.interval_days as i64);
+        next.last_reviewed_at = Some(now);
+        next
+    }
+}
@@ -0,0 +1,72 @@
+use crate::domain::model::review_state::ReviewState;
+use crate::domain::scheduling::spaced_repetition::{SpacedRepetition, MIN_EASE_FACTOR};
+use chrono::{DateTime, Duration, Utc};
+
+fn now() -> DateTime<Utc> {
+    DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+}
+
+#[test]
+fn new_state_is_due_immediately() {
+    let state = ReviewState::new(1, 1, now());
+    assert!(state.is_due(now()));
+    assert_eq!(state.ease_factor, 2.5);
+    assert_eq!(state.repetitions, 0);
+}
+
+#[test]
+fn successful_reviews_grow_the_interval() {
+    let first = SpacedRepetition::review(&ReviewState::new(1, 1, now()), 4, now());
+    assert_eq!(first.interval_days, 1);
+    assert_eq!(first.repetitions, 1);
+    assert_eq!(first.due_at, now() + Duration::days(1));
+    assert_eq!(first.last_reviewed_at, Some(now()));
+
+    let second = SpacedRepetition::review(&first, 4, now());
+    assert_eq!(second.interval_days, 6);
+
+    let third = SpacedRepetition::review(&second, 4, now());
+    assert_eq!(third.interval_days, 15);
+    assert_eq!(third.repetitions, 3);
+    assert!(!third.is_due(now() + Duration::days(14)));
+    assert!(third.is_due(now() + Duration::days(15)));
+}
+
+#[test]
+fn grade_changes_ease_factor() {
+    let state = ReviewState::new(1, 1, now());
+    assert!((SpacedRepetition::review(&state, 5, now()).ease_factor - 2.6).abs() < 1e-9);
+    assert!((SpacedRepetition::review(&state, 4, now()).ease_factor - 2.5).abs() < 1e-9);
+    assert!((SpacedRepetition::review(&state, 3, now()).ease_factor - 2.36).abs() < 1e-9);
+}
+
+#[test]
+fn failed_review_counts_a_lapse_and_resets() {
+    let mut state = ReviewState::new(1, 1, now());
+    for _ in 0..3 {
+        state = SpacedRepetition::review(&state, 5, now());
+    }
+    let failed = SpacedRepetition::review(&state, 1, now());
+    assert_eq!(failed.repetitions, 0);
+    assert_eq!(failed.interval_days, 1);
+    assert_eq!(failed.lapses, 1);
+    assert!(failed.ease_factor < state.ease_factor);
+}
+
+#[test]
+fn ease_factor_never_drops_below_minimum() {
+    let mut state = ReviewState::new(1, 1, now());
+    for _ in 0..10 {
+        state = SpacedRepetition::review(&state, 0, now());
+    }
+    assert_eq!(state.ease_factor, MIN_EASE_FACTOR);
+    assert_eq!(state.lapses, 10);
+}
+
+#[test]
+fn grade_is_validated() {
+    assert!(SpacedRepetition::validate_grade(0).is_ok());
+    assert!(SpacedRepetition::validate_grade(5).is_ok());
+    assert!(SpacedRepetition::validate_grade(-1).is_err());
+    assert!(SpacedRepetition::validate_grade(6).is_err());
+}
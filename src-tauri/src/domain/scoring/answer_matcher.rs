@@ -0,0 +1,80 @@
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use regex::Regex;
+
+/// Compares the typed response to a short answer or numeric question with an accepted
+/// answer.
+pub struct AnswerMatcher();
+
+impl AnswerMatcher {
+    pub fn matches(match_mode: &MatchMode, pattern: &str, response: &str) -> bool {
+        match match_mode {
+            MatchMode::Exact => pattern == response,
+            MatchMode::CaseInsensitive => pattern.to_lowercase() == response.to_lowercase(),
+            MatchMode::WhitespaceNormalized => {
+                AnswerMatcher::normalize_whitespace(pattern)
+                    == AnswerMatcher::normalize_whitespace(response)
+            }
+            // anchored, so the pattern has to match the whole response
+            MatchMode::Regex => {
+                Regex::new(&format!("^(?:{pattern})$")).is_ok_and(|regex| regex.is_match(response))
+            }
+        }
+    }
+
+    /// Accepts a response within the tolerance of the expected value. The unit may be
+    /// repeated after the number in any case and a decimal comma is read as a decimal
+    /// point. Responses such as "1,000" that may hold a thousands separator are rejected.
+    pub fn matches_numeric(numeric: &NumericAnswer, response: &str) -> bool {
+        let mut response = response.trim();
+        if let Some(unit) = numeric.unit.as_deref() {
+            response = AnswerMatcher::strip_unit(response, unit).trim_end();
+        }
+
+        let Some(value) = AnswerMatcher::parse_number(response) else {
+            return false;
+        };
+        if !value.is_finite() {
+            return false;
+        }
+
+        let allowed = match numeric.tolerance_mode {
+            ToleranceMode::Absolute => numeric.tolerance,
+            ToleranceMode::Relative => numeric.tolerance * numeric.expected_value.abs(),
+        };
+        // absorbs rounding errors such as 0.05 * 950 = 47.500000000000004
+        let epsilon = f64::EPSILON * numeric.expected_value.abs().max(1.0);
+        (value - numeric.expected_value).abs() <= allowed + epsilon
+    }
+
+    /// Reads a single comma as a decimal comma unless the number also holds a point or
+    /// exactly three digits follow the comma, which reads like a thousands separator.
+    fn parse_number(response: &str) -> Option<f64> {
+        let number = match response.split_once(',') {
+            None => response.to_string(),
+            Some((integer, fraction)) => {
+                let thousands = fraction.len() == 3 && fraction.bytes().all(|b| b.is_ascii_digit());
+                if fraction.contains(',') || response.contains('.') || thousands {
+                    return None;
+                }
+                format!("{integer}.{fraction}")
+            }
+        };
+        number.parse::<f64>().ok()
+    }
+
+    fn strip_unit<'a>(response: &'a str, unit: &str) -> &'a str {
+        let Some(split) = response.len().checked_sub(unit.len()) else {
+            return response;
+        };
+        match response.get(split..) {
+            Some(suffix) if suffix.to_lowercase() == unit.to_lowercase() => &response[..split],
+            _ => response,
+        }
+    }
+
+    fn normalize_whitespace(text: &str) -> String {
+        text.split_whitespace().collect::<Vec<&str>>().join(" ")
+    }
+}
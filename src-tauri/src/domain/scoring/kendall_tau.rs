@@ -0,0 +1,23 @@
+/// Measures how far a submitted sequence is from the expected one by comparing the
+/// relative order of every pair of items.
+pub struct KendallTau();
+
+impl KendallTau {
+    /// Counts the item pairs whose submitted positions keep the expected relative order
+    /// and returns them together with the number of all pairs. Items without a submitted
+    /// position and items sharing a position break every pair they are part of.
+    pub fn concordant_pairs(expected: &[i32], submitted: &[Option<i32>]) -> (usize, usize) {
+        let n = expected.len().min(submitted.len());
+        let mut concordant = 0;
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if let (Some(a), Some(b)) = (submitted[i], submitted[j]) {
+                    if a != b && (a < b) == (expected[i] < expected[j]) {
+                        concordant += 1;
+                    }
+                }
+            }
+        }
+        (concordant, n * n.saturating_sub(1) / 2)
+    }
+}
@@ -0,0 +1,6 @@
+pub mod answer_matcher;
+pub mod kendall_tau;
+pub mod scoring_engine;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,291 @@
+use crate::domain::model::answer::Answer;
+use crate::domain::model::answer_score::AnswerScore;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::exam_score::ExamScore;
+use crate::domain::model::ordering_scoring::OrderingScoring;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_score::QuestionScore;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::scoring::answer_matcher::AnswerMatcher;
+use crate::domain::scoring::kendall_tau::KendallTau;
+
+/// Grades learner responses against the stored correct answers.
+///
+/// Single choice questions are all-or-nothing. Multiple choice and assignment
+/// questions award `points_per_correct_answer` for every correct pick (each wrong
+/// pick cancels one correct pick), capped at `points_total`. Without
+/// `points_per_correct_answer` they fall back to all-or-nothing as well. Short answer
+/// and numeric questions earn all points when the typed response matches any accepted
+/// answer. Ordering questions are graded according to their `ordering_scoring`, with
+/// partial credit rounded down. Cloze questions award `points_per_correct_answer` for
+/// every correctly filled blank, capped at `points_total`, and are all-or-nothing
+/// without it.
+pub struct ScoringEngine();
+
+impl ScoringEngine {
+    pub fn score_question(question: &Question, response: &[AttemptAnswer]) -> QuestionScore {
+        let answers: Vec<AnswerScore> = question
+            .answers
+            .iter()
+            .map(|a| ScoringEngine::score_answer(&question.r#type, a, response))
+            .collect();
+
+        let points_earned = match question.r#type {
+            QuestionType::SingleChoice => ScoringEngine::all_or_nothing(question, &answers),
+            QuestionType::ShortAnswer | QuestionType::Numeric => {
+                if answers.iter().any(|a| a.is_correct) {
+                    question.points_total
+                } else {
+                    0
+                }
+            }
+            QuestionType::Ordering => ScoringEngine::ordering_credit(question, &answers),
+            QuestionType::Cloze => ScoringEngine::cloze_credit(question, &answers),
+            QuestionType::MultipleChoice | QuestionType::Assignment => {
+                match question.points_per_correct_answer {
+                    Some(points) if points > 0 => {
+                        ScoringEngine::partial_credit(question, &answers, points)
+                    }
+                    _ => ScoringEngine::all_or_nothing(question, &answers),
+                }
+            }
+        };
+
+        QuestionScore {
+            question_id: question.id,
+            points_earned,
+            points_total: question.points_total,
+            answers,
+        }
+    }
+
+    pub fn score_exam(exam: &Exam, responses: &[AttemptAnswer]) -> ExamScore {
+        let questions: Vec<QuestionScore> = exam
+            .questions
+            .iter()
+            .map(|q| {
+                let response: Vec<AttemptAnswer> = responses
+                    .iter()
+                    .filter(|r| Some(r.question_id) == q.id)
+                    .cloned()
+                    .collect();
+                ScoringEngine::score_question(q, &response)
+            })
+            .collect();
+
+        let points_earned = questions.iter().map(|q| q.points_earned).sum();
+        let points_total = questions.iter().map(|q| q.points_total).sum();
+
+        ExamScore {
+            exam_id: exam.id,
+            points_earned,
+            points_total,
+            points_to_succeeded: exam.points_to_succeeded,
+            passed: exam
+                .points_to_succeeded
+                .map(|required| points_earned >= required),
+            questions,
+        }
+    }
+
+    fn score_answer(
+        question_type: &QuestionType,
+        answer: &Answer,
+        response: &[AttemptAnswer],
+    ) -> AnswerScore {
+        let picked = response
+            .iter()
+            .find(|r| r.answer_id.is_some() && r.answer_id == answer.id);
+
+        match question_type {
+            QuestionType::Assignment => {
+                let assigned_option_id = picked.and_then(|r| r.assigned_option_id);
+                AnswerScore {
+                    answer_id: answer.id,
+                    selected: assigned_option_id.is_some(),
+                    is_correct: assigned_option_id.is_some()
+                        && assigned_option_id == answer.assigned_option_id,
+                    expected_option_id: answer.assigned_option_id,
+                    assigned_option_id,
+                    expected_position: None,
+                    position: None,
+                }
+            }
+            QuestionType::ShortAnswer => {
+                let matched = answer.match_mode.as_ref().is_some_and(|match_mode| {
+                    response
+                        .iter()
+                        .filter_map(|r| r.text_response.as_deref())
+                        .any(|text| AnswerMatcher::matches(match_mode, &answer.answer_text, text))
+                });
+                AnswerScore {
+                    answer_id: answer.id,
+                    selected: matched,
+                    is_correct: matched,
+                    expected_option_id: None,
+                    assigned_option_id: None,
+                    expected_position: None,
+                    position: None,
+                }
+            }
+            QuestionType::Numeric => {
+                let matched = answer.numeric.as_ref().is_some_and(|numeric| {
+                    response
+                        .iter()
+                        .filter_map(|r| r.text_response.as_deref())
+                        .any(|text| AnswerMatcher::matches_numeric(numeric, text))
+                });
+                AnswerScore {
+                    answer_id: answer.id,
+                    selected: matched,
+                    is_correct: matched,
+                    expected_option_id: None,
+                    assigned_option_id: None,
+                    expected_position: None,
+                    position: None,
+                }
+            }
+            QuestionType::Ordering => {
+                let position = picked.and_then(|r| r.position);
+                AnswerScore {
+                    answer_id: answer.id,
+                    selected: position.is_some(),
+                    is_correct: position.is_some() && position == answer.position,
+                    expected_option_id: None,
+                    assigned_option_id: None,
+                    expected_position: answer.position,
+                    position,
+                }
+            }
+            QuestionType::Cloze => match answer.match_mode.as_ref() {
+                Some(match_mode) => {
+                    let matched = response
+                        .iter()
+                        .filter(|r| r.blank.is_some() && r.blank == answer.blank)
+                        .filter_map(|r| r.text_response.as_deref())
+                        .any(|text| AnswerMatcher::matches(match_mode, &answer.answer_text, text));
+                    AnswerScore {
+                        answer_id: answer.id,
+                        selected: matched,
+                        is_correct: matched,
+                        expected_option_id: None,
+                        assigned_option_id: None,
+                        expected_position: None,
+                        position: None,
+                    }
+                }
+                None => {
+                    let selected = picked.is_some();
+                    AnswerScore {
+                        answer_id: answer.id,
+                        selected,
+                        is_correct: selected == answer.is_correct.unwrap_or(false),
+                        expected_option_id: None,
+                        assigned_option_id: None,
+                        expected_position: None,
+                        position: None,
+                    }
+                }
+            },
+            QuestionType::MultipleChoice | QuestionType::SingleChoice => {
+                let selected = picked.is_some();
+                AnswerScore {
+                    answer_id: answer.id,
+                    selected,
+                    is_correct: selected == answer.is_correct.unwrap_or(false),
+                    expected_option_id: None,
+                    assigned_option_id: None,
+                    expected_position: None,
+                    position: None,
+                }
+            }
+        }
+    }
+
+    fn all_or_nothing(question: &Question, answers: &[AnswerScore]) -> i32 {
+        if !answers.is_empty() && answers.iter().all(|a| a.is_correct) {
+            question.points_total
+        } else {
+            0
+        }
+    }
+
+    fn ordering_credit(question: &Question, answers: &[AnswerScore]) -> i32 {
+        let (credited, total) = match question.ordering_scoring.clone().unwrap_or_default() {
+            OrderingScoring::AllOrNothing => {
+                return ScoringEngine::all_or_nothing(question, answers)
+            }
+            OrderingScoring::CorrectlyPlaced => (
+                answers.iter().filter(|a| a.is_correct).count(),
+                answers.len(),
+            ),
+            OrderingScoring::KendallTau => {
+                let expected: Vec<i32> = answers
+                    .iter()
+                    .map(|a| a.expected_position.unwrap_or(0))
+                    .collect();
+                let submitted: Vec<Option<i32>> = answers.iter().map(|a| a.position).collect();
+                KendallTau::concordant_pairs(&expected, &submitted)
+            }
+        };
+
+        if total == 0 {
+            return ScoringEngine::all_or_nothing(question, answers);
+        }
+        (question.points_total as usize * credited / total) as i32
+    }
+
+    /// A typed blank is filled correctly when any accepted answer matched, a dropdown
+    /// blank when its correct choice and nothing else was picked.
+    fn cloze_credit(question: &Question, answers: &[AnswerScore]) -> i32 {
+        let blanks = question.cloze_blanks();
+        let filled = blanks
+            .iter()
+            .filter(|blank| {
+                let scores: Vec<(&Answer, &AnswerScore)> = question
+                    .answers
+                    .iter()
+                    .zip(answers)
+                    .filter(|(a, _)| a.blank == Some(**blank))
+                    .collect();
+                if scores.iter().any(|(a, _)| a.match_mode.is_some()) {
+                    scores.iter().any(|(_, s)| s.is_correct)
+                } else {
+                    !scores.is_empty() && scores.iter().all(|(_, s)| s.is_correct)
+                }
+            })
+            .count() as i32;
+
+        match question.points_per_correct_answer {
+            Some(points) if points > 0 => (filled * points).clamp(0, question.points_total),
+            _ if !blanks.is_empty() && filled == blanks.len() as i32 => question.points_total,
+            _ => 0,
+        }
+    }
+
+    fn partial_credit(question: &Question, answers: &[AnswerScore], points: i32) -> i32 {
+        let (hits, misses) = match question.r#type {
+            QuestionType::Assignment => (
+                answers.iter().filter(|a| a.is_correct).count(),
+                answers
+                    .iter()
+                    .filter(|a| a.selected && !a.is_correct)
+                    .count(),
+            ),
+            _ => (
+                answers
+                    .iter()
+                    .filter(|a| a.selected && a.is_correct)
+                    .count(),
+                answers
+                    .iter()
+                    .filter(|a| a.selected && !a.is_correct)
+                    .count(),
+            ),
+        };
+
+        let credited = hits.saturating_sub(misses) as i32;
+        (credited * points).clamp(0, question.points_total)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::scoring::answer_matcher::AnswerMatcher;
+
+#[test]
+fn exact_requires_identical_text() {
+    assert!(AnswerMatcher::matches(
+        &MatchMode::Exact,
+        "ls -la",
+        "ls -la"
+    ));
+    assert!(!AnswerMatcher::matches(
+        &MatchMode::Exact,
+        "ls -la",
+        "LS -la"
+    ));
+    assert!(!AnswerMatcher::matches(
+        &MatchMode::Exact,
+        "ls -la",
+        "ls -la "
+    ));
+}
+
+#[test]
+fn case_insensitive_ignores_case_only() {
+    assert!(AnswerMatcher::matches(
+        &MatchMode::CaseInsensitive,
+        "Kernel",
+        "kERNEL"
+    ));
+    assert!(!AnswerMatcher::matches(
+        &MatchMode::CaseInsensitive,
+        "Kernel",
+        "Kernel "
+    ));
+}
+
+#[test]
+fn whitespace_normalized_ignores_surrounding_and_repeated_whitespace() {
+    assert!(AnswerMatcher::matches(
+        &MatchMode::WhitespaceNormalized,
+        "git commit -m",
+        "  git   commit\t-m "
+    ));
+    assert!(!AnswerMatcher::matches(
+        &MatchMode::WhitespaceNormalized,
+        "git commit -m",
+        "Git commit -m"
+    ));
+}
+
+#[test]
+fn regex_must_match_the_whole_response() {
+    assert!(AnswerMatcher::matches(
+        &MatchMode::Regex,
+        "chmod (755|u\\+x)",
+        "chmod 755"
+    ));
+    assert!(AnswerMatcher::matches(&MatchMode::Regex, "a|b", "b"));
+    assert!(!AnswerMatcher::matches(
+        &MatchMode::Regex,
+        "chmod 755",
+        "sudo chmod 755 file"
+    ));
+    assert!(!AnswerMatcher::matches(&MatchMode::Regex, "(", "("));
+}
+
+fn numeric(
+    expected_value: f64,
+    tolerance: f64,
+    tolerance_mode: ToleranceMode,
+    unit: Option<&str>,
+) -> NumericAnswer {
+    NumericAnswer {
+        expected_value,
+        tolerance,
+        tolerance_mode,
+        unit: unit.map(String::from),
+    }
+}
+
+#[test]
+fn numeric_accepts_values_within_absolute_tolerance() {
+    let subnet = numeric(62.0, 2.0, ToleranceMode::Absolute, None);
+    assert!(AnswerMatcher::matches_numeric(&subnet, "62"));
+    assert!(AnswerMatcher::matches_numeric(&subnet, " 64 "));
+    assert!(AnswerMatcher::matches_numeric(&subnet, "60.0"));
+    assert!(!AnswerMatcher::matches_numeric(&subnet, "64.5"));
+    assert!(!AnswerMatcher::matches_numeric(&subnet, "59"));
+}
+
+#[test]
+fn numeric_accepts_values_within_relative_tolerance() {
+    let throughput = numeric(950.0, 0.05, ToleranceMode::Relative, Some("Mbit/s"));
+    assert!(AnswerMatcher::matches_numeric(&throughput, "902.5"));
+    assert!(AnswerMatcher::matches_numeric(&throughput, "997.5 Mbit/s"));
+    assert!(AnswerMatcher::matches_numeric(&throughput, "990Mbit/s"));
+    assert!(AnswerMatcher::matches_numeric(&throughput, "990 mbit/s"));
+    assert!(AnswerMatcher::matches_numeric(&throughput, "990 MBIT/S"));
+    assert!(!AnswerMatcher::matches_numeric(&throughput, "1000 Gbit/s"));
+    assert!(!AnswerMatcher::matches_numeric(&throughput, "900"));
+}
+
+#[test]
+fn numeric_reads_decimal_comma_and_rejects_non_numbers() {
+    let ratio = numeric(0.3, 0.0, ToleranceMode::Absolute, None);
+    assert!(AnswerMatcher::matches_numeric(&ratio, "0,3"));
+    assert!(AnswerMatcher::matches_numeric(&ratio, "3e-1"));
+    assert!(!AnswerMatcher::matches_numeric(&ratio, ""));
+    assert!(!AnswerMatcher::matches_numeric(&ratio, "NaN"));
+    assert!(!AnswerMatcher::matches_numeric(&ratio, "about 0.3"));
+}
+
+#[test]
+fn numeric_rejects_comma_that_may_separate_thousands() {
+    let hosts = numeric(1000.0, 0.0, ToleranceMode::Absolute, None);
+    assert!(!AnswerMatcher::matches_numeric(&hosts, "1,000"));
+    assert!(!AnswerMatcher::matches_numeric(&hosts, "1,000.0"));
+    assert!(AnswerMatcher::matches_numeric(&hosts, "1000,0"));
+
+    let small = numeric(1.0, 0.0, ToleranceMode::Absolute, None);
+    assert!(!AnswerMatcher::matches_numeric(&small, "1,000"));
+    assert!(!AnswerMatcher::matches_numeric(&small, "1,0,0"));
+    assert!(AnswerMatcher::matches_numeric(&small, "1,00"));
+}
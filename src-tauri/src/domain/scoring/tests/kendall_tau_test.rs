@@ -0,0 +1,38 @@
+use crate::domain::scoring::kendall_tau::KendallTau;
+
+#[test]
+fn identical_sequence_keeps_every_pair() {
+    assert_eq!(
+        KendallTau::concordant_pairs(&[1, 2, 3, 4], &[Some(1), Some(2), Some(3), Some(4)]),
+        (6, 6)
+    );
+}
+
+#[test]
+fn reversed_sequence_keeps_no_pair() {
+    assert_eq!(
+        KendallTau::concordant_pairs(&[1, 2, 3], &[Some(3), Some(2), Some(1)]),
+        (0, 3)
+    );
+}
+
+#[test]
+fn adjacent_swap_breaks_one_pair() {
+    assert_eq!(
+        KendallTau::concordant_pairs(&[1, 2, 3, 4], &[Some(2), Some(1), Some(3), Some(4)]),
+        (5, 6)
+    );
+}
+
+#[test]
+fn unplaced_and_shared_positions_break_their_pairs() {
+    assert_eq!(
+        KendallTau::concordant_pairs(&[1, 2, 3], &[Some(1), None, Some(3)]),
+        (1, 3)
+    );
+    assert_eq!(
+        KendallTau::concordant_pairs(&[1, 2, 3], &[Some(1), Some(1), Some(3)]),
+        (2, 3)
+    );
+    assert_eq!(KendallTau::concordant_pairs(&[], &[]), (0, 0));
+}
@@ -0,0 +1,6 @@
+#[cfg(test)]
+mod answer_matcher_test;
+#[cfg(test)]
+mod kendall_tau_test;
+#[cfg(test)]
+mod scoring_engine_test;
@@ -0,0 +1,474 @@
+use crate::domain::model::answer::Answer;
+use crate::domain::model::assignment_option::AssignmentOption;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::ordering_scoring::OrderingScoring;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::tolerance_mode::ToleranceMode;
+use crate::domain::scoring::scoring_engine::ScoringEngine;
+
+fn answer(id: i32, is_correct: bool, assigned_option_id: Option<i32>) -> Answer {
+    Answer {
+        id: Some(id),
+        answer_text: format!("Answer {id}"),
+        description: None,
+        is_correct: Some(is_correct),
+        assigned_option_id,
+        created_at: None,
+        updated_at: None,
+        question_id: Some(1),
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
+    }
+}
+
+fn question(
+    r#type: QuestionType,
+    answers: Vec<Answer>,
+    points_total: i32,
+    points_per_correct_answer: Option<i32>,
+) -> Question {
+    Question {
+        id: Some(1),
+        question_text: "Scoring question?".into(),
+        points_total,
+        r#type,
+        answers,
+        points_per_correct_answer,
+        category: None,
+        created_at: None,
+        updated_at: None,
+        options: None,
+        exam_id: Some(1),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
+    }
+}
+
+fn pick(question_id: i32, answer_id: i32) -> AttemptAnswer {
+    AttemptAnswer {
+        id: None,
+        attempt_id: None,
+        question_id,
+        answer_id: Some(answer_id),
+        assigned_option_id: None,
+        time_spent_seconds: None,
+        created_at: None,
+        text_response: None,
+        position: None,
+        blank: None,
+    }
+}
+
+fn assign(answer_id: i32, option_id: i32) -> AttemptAnswer {
+    AttemptAnswer {
+        assigned_option_id: Some(option_id),
+        ..pick(1, answer_id)
+    }
+}
+
+fn multiple_choice(points_per_correct_answer: Option<i32>) -> Question {
+    question(
+        QuestionType::MultipleChoice,
+        vec![
+            answer(1, true, None),
+            answer(2, true, None),
+            answer(3, true, None),
+            answer(4, false, None),
+        ],
+        3,
+        points_per_correct_answer,
+    )
+}
+
+fn assignment(points_per_correct_answer: Option<i32>) -> Question {
+    let mut q = question(
+        QuestionType::Assignment,
+        vec![
+            answer(1, false, Some(1)),
+            answer(2, false, Some(2)),
+            answer(3, false, Some(1)),
+        ],
+        3,
+        points_per_correct_answer,
+    );
+    q.options = Some(vec![
+        AssignmentOption {
+            row_id: Some(1),
+            id: 1,
+            text: "Option 1".into(),
+            question_id: Some(1),
+        },
+        AssignmentOption {
+            row_id: Some(2),
+            id: 2,
+            text: "Option 2".into(),
+            question_id: Some(1),
+        },
+    ]);
+    q
+}
+
+#[test]
+fn single_choice_correct_pick_earns_all_points() {
+    let q = question(
+        QuestionType::SingleChoice,
+        vec![answer(1, true, None), answer(2, false, None)],
+        2,
+        None,
+    );
+    let score = ScoringEngine::score_question(&q, &[pick(1, 1)]);
+    assert_eq!(score.points_earned, 2);
+    assert_eq!(score.points_total, 2);
+    assert!(score.is_fully_correct());
+    assert!(score.answers.iter().all(|a| a.is_correct));
+}
+
+#[test]
+fn single_choice_wrong_or_extra_pick_earns_nothing() {
+    let q = question(
+        QuestionType::SingleChoice,
+        vec![answer(1, true, None), answer(2, false, None)],
+        2,
+        Some(1),
+    );
+    assert_eq!(
+        ScoringEngine::score_question(&q, &[pick(1, 2)]).points_earned,
+        0
+    );
+    assert_eq!(
+        ScoringEngine::score_question(&q, &[pick(1, 1), pick(1, 2)]).points_earned,
+        0
+    );
+    assert_eq!(ScoringEngine::score_question(&q, &[]).points_earned, 0);
+}
+
+#[test]
+fn multiple_choice_awards_partial_credit() {
+    let q = multiple_choice(Some(1));
+    let score = ScoringEngine::score_question(&q, &[pick(1, 1), pick(1, 2)]);
+    assert_eq!(score.points_earned, 2);
+    assert!(!score.is_fully_correct());
+
+    let breakdown = &score.answers;
+    assert!(breakdown[0].selected && breakdown[0].is_correct);
+    assert!(!breakdown[2].selected && !breakdown[2].is_correct);
+    assert!(!breakdown[3].selected && breakdown[3].is_correct);
+}
+
+#[test]
+fn multiple_choice_wrong_pick_cancels_a_correct_pick() {
+    let q = multiple_choice(Some(1));
+    let score = ScoringEngine::score_question(&q, &[pick(1, 1), pick(1, 2), pick(1, 4)]);
+    assert_eq!(score.points_earned, 1);
+
+    let score = ScoringEngine::score_question(&q, &[pick(1, 4)]);
+    assert_eq!(score.points_earned, 0);
+}
+
+#[test]
+fn multiple_choice_partial_credit_is_capped_at_points_total() {
+    let q = multiple_choice(Some(5));
+    let score = ScoringEngine::score_question(&q, &[pick(1, 1), pick(1, 2), pick(1, 3)]);
+    assert_eq!(score.points_earned, 3);
+}
+
+#[test]
+fn multiple_choice_without_points_per_answer_is_all_or_nothing() {
+    let q = multiple_choice(None);
+    assert_eq!(
+        ScoringEngine::score_question(&q, &[pick(1, 1), pick(1, 2)]).points_earned,
+        0
+    );
+    assert_eq!(
+        ScoringEngine::score_question(&q, &[pick(1, 1), pick(1, 2), pick(1, 3)]).points_earned,
+        3
+    );
+}
+
+#[test]
+fn assignment_awards_points_per_matching_option() {
+    let q = assignment(Some(1));
+    let score = ScoringEngine::score_question(&q, &[assign(1, 1), assign(2, 1), assign(3, 1)]);
+    assert_eq!(score.points_earned, 1);
+    assert_eq!(score.answers[0].expected_option_id, Some(1));
+    assert_eq!(score.answers[1].assigned_option_id, Some(1));
+    assert!(!score.answers[1].is_correct);
+}
+
+#[test]
+fn assignment_fully_matched_earns_all_points() {
+    let q = assignment(None);
+    let score = ScoringEngine::score_question(&q, &[assign(1, 1), assign(2, 2), assign(3, 1)]);
+    assert_eq!(score.points_earned, 3);
+
+    let score = ScoringEngine::score_question(&q, &[assign(1, 1), assign(2, 2)]);
+    assert_eq!(score.points_earned, 0);
+}
+
+fn short_answer() -> Question {
+    let accepted = |id: i32, text: &str, match_mode: MatchMode| Answer {
+        answer_text: text.into(),
+        match_mode: Some(match_mode),
+        ..answer(id, true, None)
+    };
+    question(
+        QuestionType::ShortAnswer,
+        vec![
+            accepted(1, "ls -la", MatchMode::WhitespaceNormalized),
+            accepted(2, "ls -a(l)?", MatchMode::Regex),
+        ],
+        2,
+        None,
+    )
+}
+
+fn typed(text: &str) -> AttemptAnswer {
+    AttemptAnswer {
+        answer_id: None,
+        text_response: Some(text.into()),
+        ..pick(1, 0)
+    }
+}
+
+#[test]
+fn short_answer_matching_any_accepted_answer_earns_all_points() {
+    let q = short_answer();
+    let score = ScoringEngine::score_question(&q, &[typed("  ls   -la ")]);
+    assert_eq!(score.points_earned, 2);
+    assert!(score.is_fully_correct());
+    assert!(score.answers[0].selected && score.answers[0].is_correct);
+    assert!(!score.answers[1].selected);
+
+    let score = ScoringEngine::score_question(&q, &[typed("ls -a")]);
+    assert_eq!(score.points_earned, 2);
+    assert!(score.answers[1].is_correct);
+}
+
+#[test]
+fn short_answer_without_match_earns_nothing() {
+    let q = short_answer();
+    assert_eq!(
+        ScoringEngine::score_question(&q, &[typed("ls -l")]).points_earned,
+        0
+    );
+    assert_eq!(ScoringEngine::score_question(&q, &[]).points_earned, 0);
+}
+
+#[test]
+fn numeric_answer_within_tolerance_earns_all_points() {
+    let expected = Answer {
+        answer_text: "62".into(),
+        numeric: Some(NumericAnswer {
+            expected_value: 62.0,
+            tolerance: 0.0,
+            tolerance_mode: ToleranceMode::Absolute,
+            unit: Some("hosts".into()),
+        }),
+        ..answer(1, true, None)
+    };
+    let q = question(QuestionType::Numeric, vec![expected], 2, None);
+
+    let score = ScoringEngine::score_question(&q, &[typed("62 hosts")]);
+    assert_eq!(score.points_earned, 2);
+    assert!(score.answers[0].selected && score.answers[0].is_correct);
+
+    assert_eq!(
+        ScoringEngine::score_question(&q, &[typed("64")]).points_earned,
+        0
+    );
+    assert_eq!(
+        ScoringEngine::score_question(&q, &[typed("sixty-two")]).points_earned,
+        0
+    );
+}
+
+fn ordering(ordering_scoring: Option<OrderingScoring>) -> Question {
+    let step = |id: i32| Answer {
+        position: Some(id),
+        ..answer(id, true, None)
+    };
+    Question {
+        ordering_scoring,
+        ..question(
+            QuestionType::Ordering,
+            vec![step(1), step(2), step(3), step(4)],
+            6,
+            None,
+        )
+    }
+}
+
+fn place(answer_id: i32, position: i32) -> AttemptAnswer {
+    AttemptAnswer {
+        position: Some(position),
+        ..pick(1, answer_id)
+    }
+}
+
+#[test]
+fn ordering_in_correct_sequence_earns_all_points() {
+    let submission = [place(3, 3), place(1, 1), place(4, 4), place(2, 2)];
+    for ordering_scoring in [
+        None,
+        Some(OrderingScoring::CorrectlyPlaced),
+        Some(OrderingScoring::KendallTau),
+    ] {
+        let score = ScoringEngine::score_question(&ordering(ordering_scoring), &submission);
+        assert_eq!(score.points_earned, 6);
+        assert!(score.is_fully_correct());
+    }
+}
+
+#[test]
+fn ordering_partial_credit_depends_on_scoring_mode() {
+    // the first two steps are swapped
+    let submission = [place(1, 2), place(2, 1), place(3, 3), place(4, 4)];
+
+    let score = ScoringEngine::score_question(&ordering(None), &submission);
+    assert_eq!(score.points_earned, 0);
+    assert_eq!(score.answers[0].expected_position, Some(1));
+    assert_eq!(score.answers[0].position, Some(2));
+    assert!(!score.answers[0].is_correct);
+
+    // 2 of 4 items at their position
+    let score = ScoringEngine::score_question(
+        &ordering(Some(OrderingScoring::CorrectlyPlaced)),
+        &submission,
+    );
+    assert_eq!(score.points_earned, 3);
+
+    // 5 of 6 pairs in the correct relative order
+    let score =
+        ScoringEngine::score_question(&ordering(Some(OrderingScoring::KendallTau)), &submission);
+    assert_eq!(score.points_earned, 5);
+
+    // shifting every step by one keeps the relative order of 3 of 6 pairs only
+    let shifted = [place(1, 2), place(2, 3), place(3, 4), place(4, 1)];
+    let score =
+        ScoringEngine::score_question(&ordering(Some(OrderingScoring::KendallTau)), &shifted);
+    assert_eq!(score.points_earned, 3);
+    let score =
+        ScoringEngine::score_question(&ordering(Some(OrderingScoring::CorrectlyPlaced)), &shifted);
+    assert_eq!(score.points_earned, 0);
+}
+
+fn cloze(points_per_correct_answer: Option<i32>) -> Question {
+    let typed_answer = |id: i32, text: &str| Answer {
+        answer_text: text.into(),
+        match_mode: Some(MatchMode::CaseInsensitive),
+        blank: Some(1),
+        ..answer(id, true, None)
+    };
+    let choice = |id: i32, text: &str, is_correct: bool| Answer {
+        answer_text: text.into(),
+        blank: Some(2),
+        ..answer(id, is_correct, None)
+    };
+    Question {
+        question_text: "HTTPS runs on port {{1}} and uses {{2}} as transport.".into(),
+        ..question(
+            QuestionType::Cloze,
+            vec![
+                typed_answer(1, "443"),
+                typed_answer(2, "https"),
+                choice(3, "TCP", true),
+                choice(4, "UDP", false),
+            ],
+            2,
+            points_per_correct_answer,
+        )
+    }
+}
+
+fn fill(blank: i32, text: &str) -> AttemptAnswer {
+    AttemptAnswer {
+        blank: Some(blank),
+        ..typed(text)
+    }
+}
+
+#[test]
+fn cloze_scores_each_blank_independently() {
+    let q = cloze(Some(1));
+    let score = ScoringEngine::score_question(&q, &[fill(1, "HTTPS"), pick(1, 3)]);
+    assert_eq!(score.points_earned, 2);
+    assert!(score.answers[1].is_correct && !score.answers[0].is_correct);
+
+    // only the dropdown is right
+    let score = ScoringEngine::score_question(&q, &[fill(1, "80"), pick(1, 3)]);
+    assert_eq!(score.points_earned, 1);
+
+    // a typed answer only counts for its own blank
+    let score = ScoringEngine::score_question(&q, &[fill(2, "443"), pick(1, 4)]);
+    assert_eq!(score.points_earned, 0);
+
+    // picking both choices does not fill the dropdown
+    let score = ScoringEngine::score_question(&q, &[fill(1, "443"), pick(1, 3), pick(1, 4)]);
+    assert_eq!(score.points_earned, 1);
+}
+
+#[test]
+fn cloze_without_points_per_blank_is_all_or_nothing() {
+    let q = cloze(None);
+    assert_eq!(
+        ScoringEngine::score_question(&q, &[fill(1, "443"), pick(1, 3)]).points_earned,
+        2
+    );
+    assert_eq!(
+        ScoringEngine::score_question(&q, &[fill(1, "443")]).points_earned,
+        0
+    );
+}
+
+#[test]
+fn exam_score_aggregates_questions_and_compares_threshold() {
+    let mut first = multiple_choice(Some(1));
+    first.id = Some(1);
+    let mut second = question(
+        QuestionType::SingleChoice,
+        vec![answer(5, true, None), answer(6, false, None)],
+        2,
+        None,
+    );
+    second.id = Some(2);
+
+    let exam = Exam {
+        id: Some(1),
+        name: "Scoring exam".into(),
+        description: None,
+        points_to_succeeded: Some(4),
+        duration: None,
+        status_type: None,
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![first, second],
+    };
+
+    let responses = vec![pick(1, 1), pick(1, 2), pick(2, 5)];
+    let score = ScoringEngine::score_exam(&exam, &responses);
+    assert_eq!(score.points_earned, 4);
+    assert_eq!(score.points_total, 5);
+    assert_eq!(score.questions.len(), 2);
+    assert_eq!(score.passed, Some(true));
+
+    let score = ScoringEngine::score_exam(&exam, &[pick(2, 5)]);
+    assert_eq!(score.points_earned, 2);
+    assert_eq!(score.passed, Some(false));
+
+    let without_pass_mark = Exam {
+        points_to_succeeded: None,
+        ..exam
+    };
+    let score = ScoringEngine::score_exam(&without_pass_mark, &[]);
+    assert_eq!(score.points_earned, 0);
+    assert_eq!(score.passed, None);
+}
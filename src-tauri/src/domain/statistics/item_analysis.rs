@@ -0,0 +1,196 @@
+use crate::domain::model::answer_statistics::AnswerStatistics;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::exam_attempt::ExamAttempt;
+use crate::domain::model::question_draw::QuestionDraw;
+use crate::domain::model::question_score::QuestionScore;
+use crate::domain::model::question_statistics::QuestionStatistics;
+use crate::domain::scoring::scoring_engine::ScoringEngine;
+use std::cmp::Ordering;
+
+/// Share of the best and worst attempts compared by the discrimination index.
+pub const DISCRIMINATION_GROUP_SHARE: f64 = 0.27;
+
+/// Classical item analysis over the finished attempts of an exam.
+///
+/// A question counts as presented in an attempt when it belongs to the attempt's question
+/// draw, attempts without a draw present every question. Presented questions without a
+/// response score 0, so skipped questions lower the difficulty index instead of being left
+/// out. Questions that were not drawn into an attempt do not skew its statistics.
+pub struct ItemAnalysis();
+
+impl ItemAnalysis {
+    /// `draws` holds the question draws of the attempts, an attempt whose draw is missing
+    /// is treated as presenting every question.
+    pub fn analyze(
+        exam: &Exam,
+        attempts: &[ExamAttempt],
+        draws: &[QuestionDraw],
+    ) -> Vec<QuestionStatistics> {
+        let finished: Vec<&ExamAttempt> = attempts.iter().filter(|a| a.is_finished()).collect();
+        let scores: Vec<Vec<Option<QuestionScore>>> = finished
+            .iter()
+            .map(|attempt| ItemAnalysis::presented_scores(exam, attempt, draws))
+            .collect();
+        let (upper, lower) = ItemAnalysis::groups(&scores);
+
+        exam.questions
+            .iter()
+            .enumerate()
+            .map(|(index, question)| {
+                let ratios: Vec<(usize, f64)> = scores
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, attempt)| {
+                        attempt[index].as_ref().map(|s| (i, ItemAnalysis::ratio(s)))
+                    })
+                    .collect();
+                let presented: Vec<usize> = ratios.iter().map(|(i, _)| *i).collect();
+                let difficulty = |group: &[usize]| {
+                    ItemAnalysis::mean(
+                        ratios
+                            .iter()
+                            .filter(|(i, _)| group.contains(i))
+                            .map(|(_, ratio)| *ratio)
+                            .collect(),
+                    )
+                };
+
+                let difficulty_index =
+                    ItemAnalysis::mean(ratios.iter().map(|(_, ratio)| *ratio).collect());
+                let discrimination_index = match (difficulty(&upper), difficulty(&lower)) {
+                    (Some(upper), Some(lower)) => Some(upper - lower),
+                    _ => None,
+                };
+                let average_time_seconds = ItemAnalysis::mean(
+                    presented
+                        .iter()
+                        .filter_map(|i| {
+                            finished[*i]
+                                .answers
+                                .iter()
+                                .filter(|a| Some(a.question_id) == question.id)
+                                .filter_map(|a| a.time_spent_seconds)
+                                .max()
+                        })
+                        .map(|seconds| seconds as f64)
+                        .collect(),
+                );
+
+                let answers = question
+                    .answers
+                    .iter()
+                    .map(|answer| {
+                        let selection_count = presented
+                            .iter()
+                            .filter(|i| {
+                                finished[**i]
+                                    .answers
+                                    .iter()
+                                    .any(|a| a.answer_id.is_some() && a.answer_id == answer.id)
+                            })
+                            .count();
+                        AnswerStatistics {
+                            answer_id: answer.id,
+                            answer_text: answer.answer_text.clone(),
+                            is_correct: answer.is_correct.unwrap_or(false),
+                            selection_count: selection_count as i32,
+                            selection_rate: if presented.is_empty() {
+                                0.0
+                            } else {
+                                selection_count as f64 / presented.len() as f64
+                            },
+                        }
+                    })
+                    .collect();
+
+                QuestionStatistics {
+                    question_id: question.id,
+                    question_text: question.question_text.clone(),
+                    responses: presented.len() as i32,
+                    difficulty_index,
+                    discrimination_index,
+                    average_time_seconds,
+                    answers,
+                }
+            })
+            .collect()
+    }
+
+    /// Score of every exam question in the attempt, `None` for questions the attempt did
+    /// not present.
+    fn presented_scores(
+        exam: &Exam,
+        attempt: &ExamAttempt,
+        draws: &[QuestionDraw],
+    ) -> Vec<Option<QuestionScore>> {
+        let draw = attempt
+            .draw_id
+            .and_then(|id| draws.iter().find(|d| d.id == Some(id)));
+        exam.questions
+            .iter()
+            .map(|question| {
+                let presented = match draw {
+                    Some(draw) => question
+                        .id
+                        .is_some_and(|id| draw.question_ids.contains(&id)),
+                    None => true,
+                };
+                presented.then(|| {
+                    let response: Vec<AttemptAnswer> = attempt
+                        .answers
+                        .iter()
+                        .filter(|a| Some(a.question_id) == question.id)
+                        .cloned()
+                        .collect();
+                    ScoringEngine::score_question(question, &response)
+                })
+            })
+            .collect()
+    }
+
+    /// Indices of the upper and lower scoring groups, ranked by the score over the questions
+    /// each attempt presented. Both are empty with fewer than two attempts.
+    fn groups(scores: &[Vec<Option<QuestionScore>>]) -> (Vec<usize>, Vec<usize>) {
+        let count = scores.len();
+        if count < 2 {
+            return (vec![], vec![]);
+        }
+
+        let total = |s: &Vec<Option<QuestionScore>>| {
+            let earned: i32 = s.iter().flatten().map(|q| q.points_earned).sum();
+            let total: i32 = s.iter().flatten().map(|q| q.points_total).sum();
+            if total > 0 {
+                earned as f64 / total as f64
+            } else {
+                0.0
+            }
+        };
+        let mut ranked: Vec<usize> = (0..count).collect();
+        ranked.sort_by(|a, b| {
+            total(&scores[*b])
+                .partial_cmp(&total(&scores[*a]))
+                .unwrap_or(Ordering::Equal)
+                .then(a.cmp(b))
+        });
+
+        let size =
+            ((count as f64 * DISCRIMINATION_GROUP_SHARE).round() as usize).clamp(1, count / 2);
+        (ranked[..size].to_vec(), ranked[count - size..].to_vec())
+    }
+
+    fn ratio(score: &QuestionScore) -> f64 {
+        if score.points_total > 0 {
+            score.points_earned as f64 / score.points_total as f64
+        } else {
+            1.0
+        }
+    }
+
+    fn mean(values: Vec<f64>) -> Option<f64> {
+        if values.is_empty() {
+            return None;
+        }
+        Some(values.iter().sum::<f64>() / values.len() as f64)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::domain::model::category::Category;
+use crate::domain::model::category_mastery::CategoryMastery;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::question_type_mastery::QuestionTypeMastery;
+use std::collections::BTreeMap;
+
+/// Mastery from which a category or question type counts as mastered.
+pub const MASTERED_THRESHOLD: f64 = 0.8;
+
+/// Mastery assumed before any response was graded.
+pub const PRIOR_MASTERY: f64 = 0.5;
+
+/// Pseudo-responses at [`PRIOR_MASTERY`] added to every estimate, so a single lucky
+/// answer does not mark a whole category as mastered.
+pub const PRIOR_RESPONSES: f64 = 2.0;
+
+/// Estimates how well the learner masters the categories and question types of a
+/// question pool from graded responses, given as the question and the share of its
+/// points that was earned.
+pub struct MasteryEstimator();
+
+impl MasteryEstimator {
+    /// One estimate per category of the pool, ordered by category id.
+    pub fn by_category(pool: &[Question], responses: &[(&Question, f64)]) -> Vec<CategoryMastery> {
+        let mut categories: BTreeMap<Option<i32>, Option<Category>> = BTreeMap::new();
+        for question in pool {
+            categories
+                .entry(MasteryEstimator::category_id(question))
+                .or_insert_with(|| question.category.clone());
+        }
+
+        categories
+            .into_iter()
+            .map(|(category_id, category)| {
+                let (responses, mastery) = MasteryEstimator::estimate(
+                    responses
+                        .iter()
+                        .filter(|(q, _)| MasteryEstimator::category_id(q) == category_id)
+                        .map(|(_, ratio)| *ratio),
+                );
+                CategoryMastery {
+                    category,
+                    responses,
+                    mastery,
+                    mastered: mastery >= MASTERED_THRESHOLD,
+                }
+            })
+            .collect()
+    }
+
+    /// One estimate per question type of the pool, in order of first appearance.
+    pub fn by_question_type(
+        pool: &[Question],
+        responses: &[(&Question, f64)],
+    ) -> Vec<QuestionTypeMastery> {
+        let mut question_types: Vec<QuestionType> = vec![];
+        for question in pool {
+            if !question_types.contains(&question.r#type) {
+                question_types.push(question.r#type.clone());
+            }
+        }
+
+        question_types
+            .into_iter()
+            .map(|question_type| {
+                let (responses, mastery) = MasteryEstimator::estimate(
+                    responses
+                        .iter()
+                        .filter(|(q, _)| q.r#type == question_type)
+                        .map(|(_, ratio)| *ratio),
+                );
+                QuestionTypeMastery {
+                    question_type,
+                    responses,
+                    mastery,
+                    mastered: mastery >= MASTERED_THRESHOLD,
+                }
+            })
+            .collect()
+    }
+
+    fn estimate(ratios: impl Iterator<Item = f64>) -> (i32, f64) {
+        let (count, sum) = ratios.fold((0, 0.0), |(count, sum), ratio| (count + 1, sum + ratio));
+        let mastery = (sum + PRIOR_MASTERY * PRIOR_RESPONSES) / (count as f64 + PRIOR_RESPONSES);
+        (count, mastery)
+    }
+
+    fn category_id(question: &Question) -> Option<i32> {
+        question.category.as_ref().and_then(|c| c.id)
+    }
+}
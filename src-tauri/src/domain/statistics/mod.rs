@@ -0,0 +1,5 @@
+pub mod item_analysis;
+pub mod mastery_estimator;
+
+#[cfg(test)]
+mod tests;
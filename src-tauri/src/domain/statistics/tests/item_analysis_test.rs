@@ -0,0 +1,207 @@
+use crate::domain::model::answer::Answer;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::exam_attempt::ExamAttempt;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_draw::QuestionDraw;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::statistics::item_analysis::ItemAnalysis;
+use chrono::Utc;
+
+fn answer(id: i32, question_id: i32, is_correct: bool) -> Answer {
+    Answer {
+        id: Some(id),
+        answer_text: format!("Answer {id}"),
+        description: None,
+        is_correct: Some(is_correct),
+        assigned_option_id: None,
+        created_at: None,
+        updated_at: None,
+        question_id: Some(question_id),
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
+    }
+}
+
+fn question(id: i32, answers: Vec<Answer>) -> Question {
+    Question {
+        id: Some(id),
+        question_text: format!("Question {id}?"),
+        points_total: 1,
+        r#type: QuestionType::SingleChoice,
+        answers,
+        points_per_correct_answer: None,
+        category: None,
+        created_at: None,
+        updated_at: None,
+        options: None,
+        exam_id: Some(1),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
+    }
+}
+
+/// Question 1 is answered by everybody, question 2 is only presented to some.
+fn exam() -> Exam {
+    Exam {
+        id: Some(1),
+        name: "Analysis exam".into(),
+        description: None,
+        points_to_succeeded: Some(1),
+        duration: None,
+        status_type: None,
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![
+            question(
+                1,
+                vec![answer(1, 1, true), answer(2, 1, false), answer(3, 1, false)],
+            ),
+            question(2, vec![answer(4, 2, true), answer(5, 2, false)]),
+        ],
+    }
+}
+
+fn pick(question_id: i32, answer_id: i32, time_spent_seconds: Option<i32>) -> AttemptAnswer {
+    AttemptAnswer {
+        id: None,
+        attempt_id: None,
+        question_id,
+        answer_id: Some(answer_id),
+        assigned_option_id: None,
+        time_spent_seconds,
+        created_at: None,
+        text_response: None,
+        position: None,
+        blank: None,
+    }
+}
+
+fn attempt(answers: Vec<AttemptAnswer>, finished: bool, draw_id: Option<i32>) -> ExamAttempt {
+    ExamAttempt {
+        id: None,
+        exam_id: 1,
+        profile_id: 1,
+        mode: AttemptMode::Simulation,
+        started_at: Utc::now(),
+        finished_at: finished.then(Utc::now),
+        score: None,
+        created_at: None,
+        updated_at: None,
+        deadline_at: None,
+        expired: false,
+        shuffle_seed: None,
+        draw_id,
+        content_revision: None,
+        question_ids: vec![],
+        answers,
+    }
+}
+
+/// Draw 1 holds question 1 only.
+fn draws() -> Vec<QuestionDraw> {
+    vec![QuestionDraw {
+        id: Some(1),
+        exam_id: 1,
+        profile_id: 1,
+        seed: 7,
+        stratified: false,
+        question_ids: vec![1],
+        created_at: None,
+        questions: vec![],
+    }]
+}
+
+fn attempts() -> Vec<ExamAttempt> {
+    vec![
+        attempt(vec![pick(1, 1, Some(10)), pick(2, 4, Some(20))], true, None),
+        attempt(vec![pick(1, 1, Some(30)), pick(2, 5, None)], true, None),
+        attempt(vec![pick(1, 2, Some(20))], true, Some(1)),
+        attempt(vec![pick(1, 3, None)], true, Some(1)),
+        // open attempts are ignored
+        attempt(vec![pick(1, 2, Some(100))], false, None),
+    ]
+}
+
+#[test]
+fn difficulty_index_is_share_of_correct_responses() {
+    let statistics = ItemAnalysis::analyze(&exam(), &attempts(), &draws());
+    assert_eq!(statistics.len(), 2);
+
+    assert_eq!(statistics[0].responses, 4);
+    assert_eq!(statistics[0].difficulty_index, Some(0.5));
+    assert_eq!(statistics[1].responses, 2);
+    assert_eq!(statistics[1].difficulty_index, Some(0.5));
+}
+
+#[test]
+fn discrimination_compares_upper_and_lower_group() {
+    let statistics = ItemAnalysis::analyze(&exam(), &attempts(), &draws());
+    // the best attempt got question 1 right, the worst got it wrong
+    assert_eq!(statistics[0].discrimination_index, Some(1.0));
+    // the worst attempt never saw question 2
+    assert_eq!(statistics[1].discrimination_index, None);
+}
+
+#[test]
+fn distractor_histogram_counts_selections() {
+    let statistics = ItemAnalysis::analyze(&exam(), &attempts(), &draws());
+    let answers = &statistics[0].answers;
+    assert_eq!(answers.len(), 3);
+    assert_eq!(answers[0].selection_count, 2);
+    assert!(answers[0].is_correct);
+    assert_eq!(answers[1].selection_count, 1);
+    assert_eq!(answers[2].selection_rate, 0.25);
+}
+
+#[test]
+fn average_time_uses_reported_times_only() {
+    let statistics = ItemAnalysis::analyze(&exam(), &attempts(), &draws());
+    assert_eq!(statistics[0].average_time_seconds, Some(20.0));
+    assert_eq!(statistics[1].average_time_seconds, Some(20.0));
+}
+
+#[test]
+fn no_attempts_yield_empty_statistics() {
+    let statistics = ItemAnalysis::analyze(&exam(), &[], &[]);
+    assert_eq!(statistics[0].responses, 0);
+    assert_eq!(statistics[0].difficulty_index, None);
+    assert_eq!(statistics[0].discrimination_index, None);
+    assert_eq!(statistics[0].answers[0].selection_rate, 0.0);
+}
+
+#[test]
+fn skipped_questions_count_as_wrong() {
+    // without a draw every question is presented, the last two attempts skip question 2
+    let mut attempts = attempts();
+    attempts[2].draw_id = None;
+    attempts[3].draw_id = None;
+    let statistics = ItemAnalysis::analyze(&exam(), &attempts, &draws());
+
+    assert_eq!(statistics[1].responses, 4);
+    assert_eq!(statistics[1].difficulty_index, Some(0.25));
+    assert_eq!(statistics[1].discrimination_index, Some(1.0));
+    assert_eq!(statistics[1].answers[0].selection_rate, 0.25);
+}
+
+#[test]
+fn groups_rank_by_the_presented_questions() {
+    // both draw attempts score 1 of 1 and rank above the attempt with 1 of 2
+    let attempts = vec![
+        attempt(vec![pick(1, 1, None), pick(2, 5, None)], true, None),
+        attempt(vec![pick(1, 1, None)], true, Some(1)),
+        attempt(vec![pick(1, 1, None)], true, Some(1)),
+        attempt(vec![pick(1, 2, None), pick(2, 4, None)], true, None),
+    ];
+    let statistics = ItemAnalysis::analyze(&exam(), &attempts, &draws());
+
+    // upper group: the draw attempt ranked first, lower group: the last attempt
+    assert_eq!(statistics[0].discrimination_index, Some(1.0));
+    assert_eq!(statistics[1].discrimination_index, None);
+}
@@ -0,0 +1,90 @@
+use crate::domain::model::category::Category;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::statistics::mastery_estimator::{MasteryEstimator, PRIOR_MASTERY};
+
+fn question(id: i32, category_id: Option<i32>, r#type: QuestionType) -> Question {
+    Question {
+        id: Some(id),
+        question_text: format!("Question {id}?"),
+        points_total: 1,
+        r#type,
+        answers: vec![],
+        points_per_correct_answer: None,
+        category: category_id.map(|category_id| Category {
+            id: Some(category_id),
+            name: format!("Category {category_id}"),
+            created_at: None,
+            updated_at: None,
+        }),
+        created_at: None,
+        updated_at: None,
+        options: None,
+        exam_id: Some(1),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
+    }
+}
+
+fn pool() -> Vec<Question> {
+    vec![
+        question(1, Some(2), QuestionType::SingleChoice),
+        question(2, Some(1), QuestionType::MultipleChoice),
+        question(3, None, QuestionType::SingleChoice),
+    ]
+}
+
+#[test]
+fn categories_without_responses_keep_prior_mastery() {
+    let pool = pool();
+    let mastery = MasteryEstimator::by_category(&pool, &[]);
+    assert_eq!(mastery.len(), 3);
+    assert!(mastery[0].category.is_none());
+    assert_eq!(mastery[1].category.as_ref().unwrap().id, Some(1));
+    assert!(mastery
+        .iter()
+        .all(|m| m.responses == 0 && m.mastery == PRIOR_MASTERY && !m.mastered));
+}
+
+#[test]
+fn category_mastery_follows_the_points_earned() {
+    let pool = pool();
+    let responses = vec![
+        (&pool[0], 1.0),
+        (&pool[0], 1.0),
+        (&pool[0], 1.0),
+        (&pool[0], 1.0),
+        (&pool[0], 1.0),
+        (&pool[0], 1.0),
+        (&pool[0], 1.0),
+        (&pool[0], 1.0),
+        (&pool[1], 0.0),
+        (&pool[1], 0.5),
+    ];
+    let mastery = MasteryEstimator::by_category(&pool, &responses);
+
+    let strong = &mastery[2];
+    assert_eq!(strong.responses, 8);
+    assert!((strong.mastery - 0.9).abs() < 1e-9);
+    assert!(strong.mastered);
+
+    let weak = &mastery[1];
+    assert_eq!(weak.responses, 2);
+    assert!((weak.mastery - 0.375).abs() < 1e-9);
+    assert!(!weak.mastered);
+}
+
+#[test]
+fn question_type_mastery_covers_types_of_the_pool() {
+    let pool = pool();
+    let responses = vec![(&pool[0], 0.0), (&pool[2], 0.0)];
+    let mastery = MasteryEstimator::by_question_type(&pool, &responses);
+
+    assert_eq!(mastery.len(), 2);
+    assert_eq!(mastery[0].question_type, QuestionType::SingleChoice);
+    assert_eq!(mastery[0].responses, 2);
+    assert!((mastery[0].mastery - 0.25).abs() < 1e-9);
+    assert_eq!(mastery[1].question_type, QuestionType::MultipleChoice);
+    assert_eq!(mastery[1].mastery, PRIOR_MASTERY);
+}
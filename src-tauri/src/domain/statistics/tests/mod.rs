@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod item_analysis_test;
+#[cfg(test)]
+mod mastery_estimator_test;
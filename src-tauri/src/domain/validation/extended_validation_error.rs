@@ -8,6 +8,12 @@ pub struct ExtendedValidationError {
     pub message: String,
     pub errors: Vec<ValidationError>,
     pub nested_errors: Vec<ExtendedValidationError>,
+    /// 1-based line of the imported source the error refers to, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<i32>,
+    /// 1-based column within `line`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<i32>,
 }
 
 impl ExtendedValidationError {
@@ -16,7 +22,15 @@ impl ExtendedValidationError {
             index,
             message: message.into(),
             errors,
-            nested_errors
+            nested_errors,
+            line: None,
+            column: None,
         }
     }
-}
\ No newline at end of file
+
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line as i32);
+        self.column = Some(column as i32);
+        self
+    }
+}
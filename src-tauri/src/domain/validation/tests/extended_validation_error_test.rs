@@ -30,3 +30,17 @@ fn test_extended_validation_error_debug_and_clone() {
     assert_eq!(ext_err, ext_err2);
     assert!(format!("{:?}", ext_err).contains("ExtendedValidationError"));
 }
+
+#[test]
+fn test_extended_validation_error_at() {
+    let ext_err = ExtendedValidationError::new(0, "msg", vec![], vec![]);
+    let json = serde_json::to_string(&ext_err).unwrap();
+    assert!(!json.contains("line"));
+
+    let located = ext_err.at(3, 7);
+    assert_eq!(located.line, Some(3));
+    assert_eq!(located.column, Some(7));
+    let json = serde_json::to_string(&located).unwrap();
+    assert!(json.contains("\"line\":3"));
+    assert!(json.contains("\"column\":7"));
+}
@@ -0,0 +1,453 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::domain::export::anki_note::AnkiNote;
+use crate::domain::export::exported_file::ExportedFile;
+use crate::domain::import::moodle_question::TAG;
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::exam::Exam;
+use crate::infrastructure::anki::anki_schema::{cards, col, notes, COLLECTION_SCHEMA};
+use chrono::{DateTime, Utc};
+use diesel::connection::SimpleConnection;
+use diesel::prelude::*;
+use serde_json::json;
+use sha1::{Digest, Sha1};
+use std::io::{Cursor, Write};
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+pub const ANKI_COLLECTION: &str = "collection.anki2";
+pub const ANKI_MEDIA: &str = "media";
+
+/// Id of the note type used for all exported notes. It stays the same across exports, so
+/// Anki reuses the note type instead of adding a copy per imported deck.
+pub const ANKI_MODEL_ID: i64 = 1_700_000_000_000;
+
+const SCHEMA_VERSION: i32 = 11;
+const DEFAULT_DECK_ID: i64 = 1;
+const DEFAULT_CONF_ID: i64 = 1;
+const FIELD_SEPARATOR: &str = "\x1f";
+const CARD_CSS: &str = ".card { font-family: arial; font-size: 20px; text-align: left; color: black; background-color: white; }
+.choices { margin-top: 0.5em; }
+.correct { color: #1b7f3b; }
+.wrong { color: #b3261e; }
+.description { font-size: 16px; color: #555; }
+.explanation { margin-top: 1em; font-size: 16px; }
+.media { margin-top: 0.5em; }
+.media img { max-width: 100%; }";
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = col)]
+pub struct AnkiCollectionRow {
+    pub id: i64,
+    pub crt: i64,
+    pub modified: i64,
+    pub scm: i64,
+    pub ver: i32,
+    pub dty: i32,
+    pub usn: i32,
+    pub ls: i64,
+    pub conf: String,
+    pub models: String,
+    pub decks: String,
+    pub dconf: String,
+    pub tags: String,
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = notes)]
+pub struct AnkiNoteRow {
+    pub id: i64,
+    pub guid: String,
+    pub mid: i64,
+    pub modified: i64,
+    pub usn: i32,
+    pub tags: String,
+    pub flds: String,
+    pub sfld: String,
+    pub csum: i64,
+    pub flags: i32,
+    pub data: String,
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = cards)]
+pub struct AnkiCardRow {
+    pub id: i64,
+    pub nid: i64,
+    pub did: i64,
+    pub ord: i32,
+    pub modified: i64,
+    pub usn: i32,
+    pub card_type: i32,
+    pub queue: i32,
+    pub due: i64,
+    pub ivl: i32,
+    pub factor: i32,
+    pub reps: i32,
+    pub lapses: i32,
+    pub left: i32,
+    pub odue: i64,
+    pub odid: i64,
+    pub flags: i32,
+    pub data: String,
+}
+
+/// Writes an exam as Anki package (`.apkg`): a zip with an SQLite collection holding one
+/// deck named after the exam and one note per question, see `AnkiNote`.
+///
+/// The collection is built in an in-memory database and serialized into the package.
+/// Image attachments become Anki media, stored as numbered zip entries listed in `media`,
+/// other attachments are reported in `ExportedFile::skipped`. Notes get a guid derived from
+/// the exam name and question text, so importing a later export of the same exam updates
+/// the notes instead of duplicating them.
+pub struct AnkiPackageWriter();
+
+impl AnkiPackageWriter {
+    pub fn write(
+        exam: &Exam,
+        attachments: &[Attachment],
+        exported_at: DateTime<Utc>,
+    ) -> CRUDResult<ExportedFile> {
+        let mut skipped = vec![];
+        let mut media: Vec<(String, Vec<u8>)> = vec![];
+        let mut images: Vec<Vec<(&Attachment, String)>> = vec![];
+        for (index, question) in exam.questions.iter().enumerate() {
+            let mut question_images = vec![];
+            for attachment in attachments.iter().filter(|a| a.belongs_to(question)) {
+                if !attachment.mime_type.starts_with("image/") {
+                    skipped.push(ExportedFile::skip_attachment(
+                        index,
+                        question,
+                        attachment,
+                        "Anki cards only show images",
+                    ));
+                    continue;
+                }
+                let Ok(Some(content)) = attachment.decode_data() else {
+                    skipped.push(ExportedFile::skip_attachment(
+                        index,
+                        question,
+                        attachment,
+                        "it has no content",
+                    ));
+                    continue;
+                };
+                let file_name = Self::media_file_name(attachment, &content);
+                if !media.iter().any(|(name, _)| *name == file_name) {
+                    media.push((file_name.clone(), content));
+                }
+                question_images.push((attachment, file_name));
+            }
+            images.push(question_images);
+        }
+
+        let collection = Self::collection(exam, &images, exported_at)
+            .map_err(|e| CRUDError::new(format!("Unable to build Anki collection: {e}"), None))?;
+
+        let package = Self::package(&collection, &media)
+            .map_err(|e| CRUDError::new(format!("Unable to write Anki package: {e}"), None))?;
+
+        Ok(ExportedFile {
+            data: package,
+            skipped,
+        })
+    }
+
+    /// Anki keeps the media of all decks in one folder. The start of the content hash keeps
+    /// the names of different files apart, equal files share one name.
+    fn media_file_name(attachment: &Attachment, content: &[u8]) -> String {
+        format!(
+            "{}_{}",
+            &Attachment::content_hash(content)[..16],
+            attachment.safe_file_name()
+        )
+    }
+
+    /// The media files are stored as `0`, `1`, ..., `media` maps these entries to the file
+    /// names referenced by the notes.
+    fn package(collection: &[u8], media: &[(String, Vec<u8>)]) -> zip::result::ZipResult<Vec<u8>> {
+        let options = SimpleFileOptions::default();
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file(ANKI_COLLECTION, options)?;
+        zip.write_all(collection)?;
+        let mut names = serde_json::Map::new();
+        for (number, (file_name, content)) in media.iter().enumerate() {
+            zip.start_file(number.to_string(), options)?;
+            zip.write_all(content)?;
+            names.insert(number.to_string(), json!(file_name));
+        }
+        zip.start_file(ANKI_MEDIA, options)?;
+        zip.write_all(serde_json::Value::Object(names).to_string().as_bytes())?;
+        Ok(zip.finish()?.into_inner())
+    }
+
+    /// `images` holds the images of every question, in the order of the questions.
+    fn collection(
+        exam: &Exam,
+        images: &[Vec<(&Attachment, String)>],
+        exported_at: DateTime<Utc>,
+    ) -> Result<Vec<u8>, String> {
+        let mut conn = SqliteConnection::establish(":memory:").map_err(|e| e.to_string())?;
+        conn.batch_execute(COLLECTION_SCHEMA)
+            .map_err(|e| e.to_string())?;
+
+        let millis = exported_at.timestamp_millis();
+        let seconds = exported_at.timestamp();
+        let deck_id = millis;
+
+        let mut guids: Vec<String> = vec![];
+        let mut note_rows: Vec<AnkiNoteRow> = vec![];
+        let mut card_rows: Vec<AnkiCardRow> = vec![];
+        let mut all_tags: Vec<String> = vec![];
+        for (index, question) in exam.questions.iter().enumerate() {
+            let mut note = AnkiNote::new(exam, question);
+            note.add_images(question, &images[index]);
+            let id = millis + index as i64;
+
+            let mut guid = Self::guid(&format!(
+                "{}{FIELD_SEPARATOR}{}",
+                exam.name, question.question_text
+            ));
+            if guids.contains(&guid) {
+                guid = Self::guid(&format!("{guid}{FIELD_SEPARATOR}{index}"));
+            }
+            guids.push(guid.clone());
+
+            for tag in &note.tags {
+                if !all_tags.contains(tag) {
+                    all_tags.push(tag.clone());
+                }
+            }
+
+            let sort_field = Self::strip_html(&note.front);
+            note_rows.push(AnkiNoteRow {
+                id,
+                guid,
+                mid: ANKI_MODEL_ID,
+                modified: seconds,
+                usn: -1,
+                tags: if note.tags.is_empty() {
+                    String::new()
+                } else {
+                    format!(" {} ", note.tags.join(" "))
+                },
+                flds: [note.front.as_str(), note.back.as_str()].join(FIELD_SEPARATOR),
+                csum: Self::checksum(&sort_field),
+                sfld: sort_field,
+                flags: 0,
+                data: String::new(),
+            });
+            card_rows.push(AnkiCardRow {
+                id,
+                nid: id,
+                did: deck_id,
+                ord: 0,
+                modified: seconds,
+                usn: -1,
+                card_type: 0,
+                queue: 0,
+                due: index as i64 + 1,
+                ivl: 0,
+                factor: 0,
+                reps: 0,
+                lapses: 0,
+                left: 0,
+                odue: 0,
+                odid: 0,
+                flags: 0,
+                data: String::new(),
+            });
+        }
+
+        let collection_row = AnkiCollectionRow {
+            id: 1,
+            crt: seconds - seconds.rem_euclid(86_400),
+            modified: millis,
+            scm: millis,
+            ver: SCHEMA_VERSION,
+            dty: 0,
+            usn: 0,
+            ls: 0,
+            conf: Self::conf(deck_id, exam.questions.len()).to_string(),
+            models: Self::models(deck_id, seconds).to_string(),
+            decks: Self::decks(exam, deck_id, seconds).to_string(),
+            dconf: Self::dconf().to_string(),
+            tags: serde_json::Value::Object(
+                all_tags.into_iter().map(|tag| (tag, json!(0))).collect(),
+            )
+            .to_string(),
+        };
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            diesel::insert_into(col::table)
+                .values(&collection_row)
+                .execute(conn)?;
+            diesel::insert_into(notes::table)
+                .values(&note_rows)
+                .execute(conn)?;
+            diesel::insert_into(cards::table)
+                .values(&card_rows)
+                .execute(conn)?;
+            Ok(())
+        })
+        .map_err(|e| e.to_string())?;
+
+        Ok(conn.serialize_database_to_buffer().to_vec())
+    }
+
+    /// Hex encoded start of the SHA-1 of `key`.
+    fn guid(key: &str) -> String {
+        Sha1::digest(key.as_bytes())[..8]
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    /// First 32 bits of the SHA-1 of the sort field, which Anki uses to find duplicates.
+    fn checksum(sort_field: &str) -> i64 {
+        let digest = Sha1::digest(sort_field.as_bytes());
+        u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]) as i64
+    }
+
+    fn strip_html(html: &str) -> String {
+        TAG.replace_all(html, " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&")
+    }
+
+    fn conf(deck_id: i64, note_count: usize) -> serde_json::Value {
+        json!({
+            "activeDecks": [deck_id],
+            "curDeck": deck_id,
+            "newSpread": 0,
+            "collapseTime": 1200,
+            "timeLim": 0,
+            "estTimes": true,
+            "dueCounts": true,
+            "curModel": ANKI_MODEL_ID,
+            "nextPos": note_count + 1,
+            "sortType": "noteFld",
+            "sortBackwards": false,
+            "addToCur": true
+        })
+    }
+
+    fn models(deck_id: i64, modified: i64) -> serde_json::Value {
+        let field = |name: &str, ord: i32| {
+            json!({
+                "name": name,
+                "ord": ord,
+                "sticky": false,
+                "rtl": false,
+                "font": "Arial",
+                "size": 20,
+                "media": []
+            })
+        };
+        json!({
+            ANKI_MODEL_ID.to_string(): {
+                "id": ANKI_MODEL_ID,
+                "name": "Open XAM Question",
+                "type": 0,
+                "mod": modified,
+                "usn": -1,
+                "sortf": 0,
+                "did": deck_id,
+                "tmpls": [{
+                    "name": "Card 1",
+                    "ord": 0,
+                    "qfmt": "{{Front}}",
+                    "afmt": "{{FrontSide}}<hr id=answer>{{Back}}",
+                    "bqfmt": "",
+                    "bafmt": "",
+                    "did": null,
+                    "bfont": "",
+                    "bsize": 0
+                }],
+                "flds": [field("Front", 0), field("Back", 1)],
+                "css": CARD_CSS,
+                "latexPre": "\\documentclass[12pt]{article}\n\\special{papersize=3in,5in}\n\\usepackage[utf8]{inputenc}\n\\usepackage{amssymb,amsmath}\n\\pagestyle{empty}\n\\setlength{\\parindent}{0in}\n\\begin{document}\n",
+                "latexPost": "\\end{document}",
+                "latexsvg": false,
+                "req": [[0, "any", [0]]],
+                "tags": [],
+                "vers": []
+            }
+        })
+    }
+
+    fn decks(exam: &Exam, deck_id: i64, modified: i64) -> serde_json::Value {
+        let deck = |id: i64, name: &str, description: &str| {
+            json!({
+                "id": id,
+                "name": name,
+                "desc": description,
+                "mod": modified,
+                "usn": -1,
+                "conf": DEFAULT_CONF_ID,
+                "dyn": 0,
+                "collapsed": false,
+                "browserCollapsed": false,
+                "extendNew": 0,
+                "extendRev": 0,
+                "newToday": [0, 0],
+                "revToday": [0, 0],
+                "lrnToday": [0, 0],
+                "timeToday": [0, 0]
+            })
+        };
+        let description = exam
+            .description
+            .as_deref()
+            .map(AnkiNote::html)
+            .unwrap_or_default();
+        json!({
+            DEFAULT_DECK_ID.to_string(): deck(DEFAULT_DECK_ID, "Default", ""),
+            deck_id.to_string(): deck(deck_id, &exam.name, &description)
+        })
+    }
+
+    fn dconf() -> serde_json::Value {
+        json!({
+            DEFAULT_CONF_ID.to_string(): {
+                "id": DEFAULT_CONF_ID,
+                "name": "Default",
+                "mod": 0,
+                "usn": 0,
+                "maxTaken": 60,
+                "autoplay": true,
+                "timer": 0,
+                "replayq": true,
+                "dyn": false,
+                "new": {
+                    "bury": false,
+                    "delays": [1.0, 10.0],
+                    "initialFactor": 2500,
+                    "ints": [1, 4, 0],
+                    "order": 1,
+                    "perDay": 20
+                },
+                "lapse": {
+                    "delays": [10.0],
+                    "leechAction": 1,
+                    "leechFails": 8,
+                    "minInt": 1,
+                    "mult": 0.0
+                },
+                "rev": {
+                    "bury": false,
+                    "ease4": 1.3,
+                    "ivlFct": 1.0,
+                    "maxIvl": 36500,
+                    "perDay": 200,
+                    "hardFactor": 1.2
+                }
+            }
+        })
+    }
+}
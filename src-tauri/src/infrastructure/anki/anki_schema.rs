@@ -0,0 +1,139 @@
+//! Tables of an Anki collection (legacy schema 11) written by the package writer.
+
+diesel::table! {
+    col (id) {
+        id -> BigInt,
+        crt -> BigInt,
+        #[sql_name = "mod"]
+        modified -> BigInt,
+        scm -> BigInt,
+        ver -> Integer,
+        dty -> Integer,
+        usn -> Integer,
+        ls -> BigInt,
+        conf -> Text,
+        models -> Text,
+        decks -> Text,
+        dconf -> Text,
+        tags -> Text,
+    }
+}
+
+diesel::table! {
+    notes (id) {
+        id -> BigInt,
+        guid -> Text,
+        mid -> BigInt,
+        #[sql_name = "mod"]
+        modified -> BigInt,
+        usn -> Integer,
+        tags -> Text,
+        flds -> Text,
+        sfld -> Text,
+        csum -> BigInt,
+        flags -> Integer,
+        data -> Text,
+    }
+}
+
+diesel::table! {
+    cards (id) {
+        id -> BigInt,
+        nid -> BigInt,
+        did -> BigInt,
+        ord -> Integer,
+        #[sql_name = "mod"]
+        modified -> BigInt,
+        usn -> Integer,
+        #[sql_name = "type"]
+        card_type -> Integer,
+        queue -> Integer,
+        due -> BigInt,
+        ivl -> Integer,
+        factor -> Integer,
+        reps -> Integer,
+        lapses -> Integer,
+        left -> Integer,
+        odue -> BigInt,
+        odid -> BigInt,
+        flags -> Integer,
+        data -> Text,
+    }
+}
+
+diesel::allow_tables_to_appear_in_same_query!(col, notes, cards);
+
+/// Creates the tables and indexes Anki expects in a collection of schema version 11.
+pub const COLLECTION_SCHEMA: &str = "
+CREATE TABLE col (
+    id integer primary key,
+    crt integer not null,
+    mod integer not null,
+    scm integer not null,
+    ver integer not null,
+    dty integer not null,
+    usn integer not null,
+    ls integer not null,
+    conf text not null,
+    models text not null,
+    decks text not null,
+    dconf text not null,
+    tags text not null
+);
+CREATE TABLE notes (
+    id integer primary key,
+    guid text not null,
+    mid integer not null,
+    mod integer not null,
+    usn integer not null,
+    tags text not null,
+    flds text not null,
+    sfld integer not null,
+    csum integer not null,
+    flags integer not null,
+    data text not null
+);
+CREATE TABLE cards (
+    id integer primary key,
+    nid integer not null,
+    did integer not null,
+    ord integer not null,
+    mod integer not null,
+    usn integer not null,
+    type integer not null,
+    queue integer not null,
+    due integer not null,
+    ivl integer not null,
+    factor integer not null,
+    reps integer not null,
+    lapses integer not null,
+    left integer not null,
+    odue integer not null,
+    odid integer not null,
+    flags integer not null,
+    data text not null
+);
+CREATE TABLE revlog (
+    id integer primary key,
+    cid integer not null,
+    usn integer not null,
+    ease integer not null,
+    ivl integer not null,
+    lastIvl integer not null,
+    factor integer not null,
+    time integer not null,
+    type integer not null
+);
+CREATE TABLE graves (
+    usn integer not null,
+    oid integer not null,
+    type integer not null
+);
+CREATE INDEX ix_notes_usn ON notes (usn);
+CREATE INDEX ix_cards_usn ON cards (usn);
+CREATE INDEX ix_revlog_usn ON revlog (usn);
+CREATE INDEX ix_cards_nid ON cards (nid);
+CREATE INDEX ix_cards_sched ON cards (did, queue, due);
+CREATE INDEX ix_revlog_cid ON revlog (cid);
+CREATE INDEX ix_notes_csum ON notes (csum);
+";
@@ -0,0 +1,5 @@
+pub(crate) mod anki_package_writer;
+pub(crate) mod anki_schema;
+
+#[cfg(test)]
+mod tests;
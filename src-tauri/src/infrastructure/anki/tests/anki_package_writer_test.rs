@@ -0,0 +1,212 @@
+use crate::domain::import::text_exam_parser::TextExamParser;
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::category::Category;
+use crate::domain::model::exam::Exam;
+use crate::infrastructure::anki::anki_package_writer::{
+    AnkiCardRow, AnkiCollectionRow, AnkiNoteRow, AnkiPackageWriter, ANKI_COLLECTION, ANKI_MEDIA,
+    ANKI_MODEL_ID,
+};
+use crate::infrastructure::anki::anki_schema::{cards, col, notes};
+use chrono::{TimeZone, Utc};
+use diesel::prelude::*;
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+const TEMPLATE: &str = include_str!("../../../../../templates/import_template_txt.txt");
+
+fn exam() -> Exam {
+    let mut exam = TextExamParser::parse(TEMPLATE).unwrap().exam;
+    exam.questions[3].category = Some(Category {
+        id: None,
+        name: "Computer Networks".into(),
+        created_at: None,
+        updated_at: None,
+    });
+    exam
+}
+
+fn attachment(
+    question_id: Option<i32>,
+    answer_id: Option<i32>,
+    file_name: &str,
+    mime_type: &str,
+    content: Option<&[u8]>,
+) -> Attachment {
+    Attachment {
+        id: None,
+        question_id,
+        answer_id,
+        file_name: file_name.into(),
+        mime_type: mime_type.into(),
+        hash: String::new(),
+        size: 0,
+        created_at: None,
+        data: content.map(Attachment::encode_data),
+    }
+}
+
+fn entry(data: &[u8], name: &str) -> Vec<u8> {
+    let mut archive = ZipArchive::new(Cursor::new(data)).unwrap();
+    let mut content = vec![];
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_end(&mut content)
+        .unwrap();
+    content
+}
+
+/// Opens the collection read-only. SQLite reads `collection` in place, it must outlive the
+/// connection.
+fn open_collection(collection: &[u8]) -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    conn.deserialize_readonly_database_from_buffer(collection)
+        .unwrap();
+    conn
+}
+
+#[test]
+fn it_should_write_one_note_and_card_per_question() {
+    let exam = exam();
+    let exported_at = Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap();
+    let exported = AnkiPackageWriter::write(&exam, &[], exported_at).unwrap();
+    assert!(exported.skipped.is_empty());
+    assert_eq!(entry(&exported.data, ANKI_MEDIA), b"{}");
+
+    let collection = entry(&exported.data, ANKI_COLLECTION);
+    let mut conn = open_collection(&collection);
+    let collection_row: AnkiCollectionRow = col::table
+        .select(AnkiCollectionRow::as_select())
+        .first(&mut conn)
+        .unwrap();
+    let rows: Vec<AnkiNoteRow> = notes::table
+        .order(notes::id)
+        .select(AnkiNoteRow::as_select())
+        .load(&mut conn)
+        .unwrap();
+    let card_rows: Vec<AnkiCardRow> = cards::table
+        .order(cards::id)
+        .select(AnkiCardRow::as_select())
+        .load(&mut conn)
+        .unwrap();
+
+    assert_eq!(collection_row.ver, 11);
+    let decks: serde_json::Value = serde_json::from_str(&collection_row.decks).unwrap();
+    let deck_id = exported_at.timestamp_millis();
+    assert_eq!(decks[deck_id.to_string()]["name"], exam.name.as_str());
+    let models: serde_json::Value = serde_json::from_str(&collection_row.models).unwrap();
+    assert_eq!(models[ANKI_MODEL_ID.to_string()]["flds"][1]["name"], "Back");
+    let tags: serde_json::Value = serde_json::from_str(&collection_row.tags).unwrap();
+    assert!(tags.get("Computer_Networks").is_some());
+
+    assert_eq!(rows.len(), exam.questions.len());
+    assert_eq!(card_rows.len(), exam.questions.len());
+    for (index, (note, card)) in rows.iter().zip(&card_rows).enumerate() {
+        assert_eq!(note.mid, ANKI_MODEL_ID);
+        assert_eq!(note.flds.split('\x1f').count(), 2);
+        assert_eq!(card.nid, note.id);
+        assert_eq!(card.did, deck_id);
+        assert_eq!(card.due, index as i64 + 1);
+    }
+    assert!(rows[0]
+        .sfld
+        .starts_with("How many definitions of the term \"Concept A\" exist?"));
+    assert!(rows[0]
+        .flds
+        .contains("\x1f<ul><li class=\"correct\">A dozen or more"));
+    assert_eq!(rows[3].tags, " Computer_Networks ");
+    assert_eq!(rows[0].tags, "");
+}
+
+#[test]
+fn it_should_keep_note_guids_stable_across_exports() {
+    let exam = exam();
+    let first = AnkiPackageWriter::write(&exam, &[], Utc::now()).unwrap();
+    let second = AnkiPackageWriter::write(&exam, &[], Utc::now()).unwrap();
+
+    let guids = |data: &[u8]| -> Vec<String> {
+        let collection = entry(data, ANKI_COLLECTION);
+        notes::table
+            .order(notes::id)
+            .select(notes::guid)
+            .load(&mut open_collection(&collection))
+            .unwrap()
+    };
+    let first_guids = guids(&first.data);
+    assert_eq!(first_guids, guids(&second.data));
+
+    let mut unique = first_guids.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), exam.questions.len());
+}
+
+#[test]
+fn it_should_add_images_as_media() {
+    let mut exam = exam();
+    exam.questions[0].id = Some(1);
+    exam.questions[0].answers[1].id = Some(11);
+    exam.questions[1].id = Some(2);
+    let attachments = vec![
+        attachment(
+            Some(1),
+            None,
+            "network diagram.png",
+            "image/png",
+            Some(b"diagram"),
+        ),
+        attachment(None, Some(11), "layers.png", "image/png", Some(b"layers")),
+        attachment(Some(2), None, "copy.png", "image/png", Some(b"diagram")),
+        attachment(
+            Some(2),
+            None,
+            "slides.pdf",
+            "application/pdf",
+            Some(b"slides"),
+        ),
+        attachment(Some(2), None, "empty.png", "image/png", None),
+    ];
+    let exported = AnkiPackageWriter::write(&exam, &attachments, Utc::now()).unwrap();
+
+    let skipped: Vec<(i32, &str)> = exported
+        .skipped
+        .iter()
+        .map(|e| (e.index, e.message.as_str()))
+        .collect();
+    assert_eq!(skipped.len(), 2);
+    assert_eq!(skipped[0].0, 1);
+    assert!(skipped[0]
+        .1
+        .starts_with("Attachment slides.pdf of question"));
+    assert!(skipped[0]
+        .1
+        .ends_with("was not exported, Anki cards only show images"));
+    assert!(skipped[1]
+        .1
+        .ends_with("was not exported, it has no content"));
+
+    let diagram = format!(
+        "{}_network_diagram.png",
+        &Attachment::content_hash(b"diagram")[..16]
+    );
+    let layers = format!("{}_layers.png", &Attachment::content_hash(b"layers")[..16]);
+    let copy = format!("{}_copy.png", &Attachment::content_hash(b"diagram")[..16]);
+    let media: serde_json::Value =
+        serde_json::from_slice(&entry(&exported.data, ANKI_MEDIA)).unwrap();
+    assert_eq!(
+        media,
+        serde_json::json!({"0": diagram, "1": layers, "2": copy})
+    );
+    assert_eq!(entry(&exported.data, "0"), b"diagram");
+    assert_eq!(entry(&exported.data, "1"), b"layers");
+
+    let collection = entry(&exported.data, ANKI_COLLECTION);
+    let fields: Vec<String> = notes::table
+        .order(notes::id)
+        .select(notes::flds)
+        .load(&mut open_collection(&collection))
+        .unwrap();
+    assert!(fields[0].contains(&format!("<img src=\"{diagram}\">")));
+    assert!(fields[0].contains(&format!("<img src=\"{layers}\">")));
+    assert!(fields[1].contains(&format!("<img src=\"{copy}\">")));
+}
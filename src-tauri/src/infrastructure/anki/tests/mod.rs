@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod anki_package_writer_test;
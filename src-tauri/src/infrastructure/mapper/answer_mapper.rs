@@ -1,7 +1,42 @@
 use crate::domain::entities::answer_entity::{AnswerEntity, NewAnswer, UpdateAnswer};
 use crate::domain::model::answer::Answer;
+use crate::domain::model::match_mode::MatchMode;
+use crate::domain::model::numeric_answer::NumericAnswer;
+use crate::domain::model::tolerance_mode::ToleranceMode;
 use chrono::Utc;
 
+pub fn match_mode_to_string(match_mode: &MatchMode) -> &str {
+    match match_mode {
+        MatchMode::Exact => "Exact",
+        MatchMode::CaseInsensitive => "CaseInsensitive",
+        MatchMode::WhitespaceNormalized => "WhitespaceNormalized",
+        MatchMode::Regex => "Regex",
+    }
+}
+
+pub fn match_mode_to_enum(match_mode: &str) -> MatchMode {
+    match match_mode {
+        "CaseInsensitive" => MatchMode::CaseInsensitive,
+        "WhitespaceNormalized" => MatchMode::WhitespaceNormalized,
+        "Regex" => MatchMode::Regex,
+        &_ => MatchMode::Exact,
+    }
+}
+
+pub fn tolerance_mode_to_string(tolerance_mode: &ToleranceMode) -> &str {
+    match tolerance_mode {
+        ToleranceMode::Absolute => "Absolute",
+        ToleranceMode::Relative => "Relative",
+    }
+}
+
+pub fn tolerance_mode_to_enum(tolerance_mode: &str) -> ToleranceMode {
+    match tolerance_mode {
+        "Relative" => ToleranceMode::Relative,
+        &_ => ToleranceMode::Absolute,
+    }
+}
+
 impl<'a> From<&'a Answer> for NewAnswer<'a> {
     fn from(value: &'a Answer) -> Self {
         NewAnswer {
@@ -11,6 +46,16 @@ impl<'a> From<&'a Answer> for NewAnswer<'a> {
             assigned_option_id: value.assigned_option_id,
             fk_question_id: value.question_id.expect("question_id is required for NewAnswer"),
             created_at: Some(Utc::now().naive_utc()),
+            match_mode: value.match_mode.as_ref().map(match_mode_to_string),
+            numeric_value: value.numeric.as_ref().map(|n| n.expected_value),
+            tolerance: value.numeric.as_ref().map(|n| n.tolerance),
+            tolerance_mode: value
+                .numeric
+                .as_ref()
+                .map(|n| tolerance_mode_to_string(&n.tolerance_mode)),
+            unit: value.numeric.as_ref().and_then(|n| n.unit.as_deref()),
+            position: value.position,
+            blank: value.blank,
         }
     }
 }
@@ -24,6 +69,16 @@ impl<'a> From<&'a Answer> for UpdateAnswer<'a> {
             assigned_option_id: value.assigned_option_id,
             fk_question_id: value.question_id,
             updated_at: Some(Utc::now().naive_utc()),
+            match_mode: value.match_mode.as_ref().map(match_mode_to_string),
+            numeric_value: value.numeric.as_ref().map(|n| n.expected_value),
+            tolerance: value.numeric.as_ref().map(|n| n.tolerance),
+            tolerance_mode: value
+                .numeric
+                .as_ref()
+                .map(|n| tolerance_mode_to_string(&n.tolerance_mode)),
+            unit: Some(value.numeric.as_ref().and_then(|n| n.unit.as_deref())),
+            position: value.position,
+            blank: value.blank,
         }
     }
 }
@@ -39,6 +94,19 @@ impl<'a> From<&'a AnswerEntity> for Answer {
             created_at: value.created_at.map(|created| created.and_utc()),
             updated_at: value.updated_at.map(|updated_at| updated_at.and_utc()),
             question_id: Some(value.fk_question_id),
+            match_mode: value.match_mode.as_deref().map(match_mode_to_enum),
+            numeric: value.numeric_value.map(|expected_value| NumericAnswer {
+                expected_value,
+                tolerance: value.tolerance.unwrap_or(0.0),
+                tolerance_mode: value
+                    .tolerance_mode
+                    .as_deref()
+                    .map(tolerance_mode_to_enum)
+                    .unwrap_or_default(),
+                unit: value.unit.clone(),
+            }),
+            position: value.position,
+            blank: value.blank,
         }
     }
 }
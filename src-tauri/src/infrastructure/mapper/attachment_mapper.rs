@@ -0,0 +1,33 @@
+use crate::domain::entities::attachment_entity::{AttachmentEntity, NewAttachment};
+use crate::domain::model::attachment::Attachment;
+use chrono::Utc;
+
+impl<'a> From<&'a Attachment> for NewAttachment {
+    fn from(value: &'a Attachment) -> Self {
+        NewAttachment {
+            fk_question_id: value.question_id,
+            fk_answer_id: value.answer_id,
+            file_name: value.file_name.trim().to_string(),
+            mime_type: value.mime_type.trim().to_lowercase(),
+            hash: value.hash.clone(),
+            size: value.size,
+            created_at: Some(Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl<'a> From<&'a AttachmentEntity> for Attachment {
+    fn from(value: &'a AttachmentEntity) -> Self {
+        Attachment {
+            id: Some(value.id),
+            question_id: value.fk_question_id,
+            answer_id: value.fk_answer_id,
+            file_name: value.file_name.clone(),
+            mime_type: value.mime_type.clone(),
+            hash: value.hash.clone(),
+            size: value.size,
+            created_at: value.created_at.map(|created| created.and_utc()),
+            data: None,
+        }
+    }
+}
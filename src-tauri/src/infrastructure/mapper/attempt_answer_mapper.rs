@@ -0,0 +1,38 @@
+use crate::domain::entities::attempt_answer_entity::{AttemptAnswerEntity, NewAttemptAnswer};
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use chrono::Utc;
+
+impl<'a> From<&'a AttemptAnswer> for NewAttemptAnswer {
+    fn from(value: &'a AttemptAnswer) -> Self {
+        NewAttemptAnswer {
+            fk_attempt_id: value
+                .attempt_id
+                .expect("attempt_id is required for NewAttemptAnswer"),
+            fk_question_id: value.question_id,
+            fk_answer_id: value.answer_id,
+            assigned_option_id: value.assigned_option_id,
+            created_at: Some(Utc::now().naive_utc()),
+            time_spent_seconds: value.time_spent_seconds,
+            text_response: value.text_response.clone(),
+            position: value.position,
+            blank: value.blank,
+        }
+    }
+}
+
+impl<'a> From<&'a AttemptAnswerEntity> for AttemptAnswer {
+    fn from(value: &'a AttemptAnswerEntity) -> Self {
+        AttemptAnswer {
+            id: Some(value.id),
+            attempt_id: Some(value.fk_attempt_id),
+            question_id: value.fk_question_id,
+            answer_id: value.fk_answer_id,
+            assigned_option_id: value.assigned_option_id,
+            time_spent_seconds: value.time_spent_seconds,
+            created_at: value.created_at.map(|created| created.and_utc()),
+            text_response: value.text_response.clone(),
+            position: value.position,
+            blank: value.blank,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::domain::entities::bookmark_entity::{BookmarkEntity, NewBookmark};
+use crate::domain::model::bookmark::Bookmark;
+use chrono::Utc;
+
+impl<'a> From<&'a Bookmark> for NewBookmark {
+    fn from(value: &'a Bookmark) -> Self {
+        NewBookmark {
+            fk_profile_id: value.profile_id,
+            fk_question_id: value.question_id,
+            created_at: Some(Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl<'a> From<&'a BookmarkEntity> for Bookmark {
+    fn from(value: &'a BookmarkEntity) -> Self {
+        Bookmark {
+            id: Some(value.id),
+            profile_id: value.fk_profile_id,
+            question_id: value.fk_question_id,
+            created_at: value.created_at.map(|created| created.and_utc()),
+            question: None,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::application::crud::enum_converter_trait::EnumConverterTrait;
+use crate::domain::entities::exam_attempt_entity::{
+    ExamAttemptEntity, NewExamAttempt, UpdateExamAttempt,
+};
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::exam_attempt::ExamAttempt;
+use chrono::Utc;
+
+impl<'a> From<&'a ExamAttempt> for NewExamAttempt<'a> {
+    fn from(value: &'a ExamAttempt) -> Self {
+        NewExamAttempt {
+            fk_exam_id: value.exam_id,
+            mode: value.mode.convert_to_string(),
+            started_at: value.started_at.naive_utc(),
+            created_at: Some(Utc::now().naive_utc()),
+            deadline_at: value.deadline_at.map(|deadline| deadline.naive_utc()),
+            fk_profile_id: value.profile_id,
+            shuffle_seed: value.shuffle_seed,
+            fk_draw_id: value.draw_id,
+            content_revision: value.content_revision.as_deref(),
+        }
+    }
+}
+
+impl<'a> From<&'a ExamAttempt> for UpdateExamAttempt {
+    fn from(value: &'a ExamAttempt) -> Self {
+        UpdateExamAttempt {
+            finished_at: value.finished_at.map(|finished| finished.naive_utc()),
+            score: value.score,
+            updated_at: Some(Utc::now().naive_utc()),
+            expired: value.expired,
+        }
+    }
+}
+
+impl<'a> From<&'a ExamAttemptEntity> for ExamAttempt {
+    fn from(value: &'a ExamAttemptEntity) -> Self {
+        ExamAttempt {
+            id: Some(value.id),
+            exam_id: value.fk_exam_id,
+            profile_id: value.fk_profile_id,
+            mode: AttemptMode::convert_from_string(&value.mode),
+            started_at: value.started_at.and_utc(),
+            finished_at: value.finished_at.map(|finished| finished.and_utc()),
+            score: value.score,
+            created_at: value.created_at.map(|created| created.and_utc()),
+            updated_at: value.updated_at.map(|updated| updated.and_utc()),
+            deadline_at: value.deadline_at.map(|deadline| deadline.and_utc()),
+            expired: value.expired,
+            shuffle_seed: value.shuffle_seed,
+            draw_id: value.fk_draw_id,
+            content_revision: value.content_revision.clone(),
+            question_ids: vec![],
+            answers: vec![],
+        }
+    }
+}
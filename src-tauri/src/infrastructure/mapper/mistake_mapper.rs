@@ -0,0 +1,43 @@
+use crate::domain::entities::mistake_entity::{MistakeEntity, NewMistake, UpdateMistake};
+use crate::domain::model::mistake::Mistake;
+use chrono::Utc;
+
+impl<'a> From<&'a Mistake> for NewMistake {
+    fn from(value: &'a Mistake) -> Self {
+        NewMistake {
+            fk_profile_id: value.profile_id,
+            fk_question_id: value.question_id,
+            miss_count: value.miss_count,
+            correct_streak: value.correct_streak,
+            last_missed_at: value.last_missed_at.naive_utc(),
+            created_at: Some(Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl<'a> From<&'a Mistake> for UpdateMistake {
+    fn from(value: &'a Mistake) -> Self {
+        UpdateMistake {
+            miss_count: value.miss_count,
+            correct_streak: value.correct_streak,
+            last_missed_at: value.last_missed_at.naive_utc(),
+            updated_at: Some(Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl<'a> From<&'a MistakeEntity> for Mistake {
+    fn from(value: &'a MistakeEntity) -> Self {
+        Mistake {
+            id: Some(value.id),
+            profile_id: value.fk_profile_id,
+            question_id: value.fk_question_id,
+            miss_count: value.miss_count,
+            correct_streak: value.correct_streak,
+            last_missed_at: value.last_missed_at.and_utc(),
+            created_at: value.created_at.map(|created| created.and_utc()),
+            updated_at: value.updated_at.map(|updated| updated.and_utc()),
+            question: None,
+        }
+    }
+}
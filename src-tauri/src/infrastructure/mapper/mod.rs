@@ -1,8 +1,18 @@
 mod answer_mapper;
 mod assignment_option_mapper;
+mod attachment_mapper;
+mod attempt_answer_mapper;
+mod bookmark_mapper;
 mod category_mapper;
+mod exam_attempt_mapper;
 mod exam_mapper;
+mod mistake_mapper;
+mod profile_mapper;
+mod question_draw_mapper;
 mod question_mapper;
+mod review_state_mapper;
+mod setting_mapper;
+mod source_reference_mapper;
 
 #[cfg(test)]
 mod tests;
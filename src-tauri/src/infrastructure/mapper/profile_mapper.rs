@@ -0,0 +1,34 @@
+use crate::domain::entities::profile_entity::{NewProfile, ProfileEntity, UpdateProfile};
+use crate::domain::model::profile::Profile;
+use chrono::Utc;
+
+impl<'a> From<&'a Profile> for NewProfile<'a> {
+    fn from(value: &'a Profile) -> Self {
+        NewProfile {
+            name: &value.name,
+            active: value.active,
+            created_at: Some(Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl<'a> From<&'a Profile> for UpdateProfile<'a> {
+    fn from(value: &'a Profile) -> Self {
+        UpdateProfile {
+            name: &value.name,
+            updated_at: Some(Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl<'a> From<&'a ProfileEntity> for Profile {
+    fn from(value: &'a ProfileEntity) -> Self {
+        Profile {
+            id: Some(value.id),
+            name: value.name.clone(),
+            active: value.active,
+            created_at: value.created_at.map(|created| created.and_utc()),
+            updated_at: value.updated_at.map(|updated| updated.and_utc()),
+        }
+    }
+}
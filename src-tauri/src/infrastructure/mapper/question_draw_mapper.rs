@@ -0,0 +1,30 @@
+use crate::domain::entities::question_draw_entity::{NewQuestionDraw, QuestionDrawEntity};
+use crate::domain::model::question_draw::QuestionDraw;
+use chrono::Utc;
+
+impl<'a> From<&'a QuestionDraw> for NewQuestionDraw {
+    fn from(value: &'a QuestionDraw) -> Self {
+        NewQuestionDraw {
+            fk_exam_id: value.exam_id,
+            seed: value.seed,
+            stratified: value.stratified,
+            created_at: Some(Utc::now().naive_utc()),
+            fk_profile_id: value.profile_id,
+        }
+    }
+}
+
+impl<'a> From<&'a QuestionDrawEntity> for QuestionDraw {
+    fn from(value: &'a QuestionDrawEntity) -> Self {
+        QuestionDraw {
+            id: Some(value.id),
+            exam_id: value.fk_exam_id,
+            profile_id: value.fk_profile_id,
+            seed: value.seed,
+            stratified: value.stratified,
+            question_ids: vec![],
+            created_at: value.created_at.map(|created| created.and_utc()),
+            questions: vec![],
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::domain::entities::question_entity::{NewQuestion, QuestionEntity, UpdateQuestion};
+use crate::domain::model::ordering_scoring::OrderingScoring;
 use crate::domain::model::question::Question;
 use crate::domain::model::question_type::QuestionType;
 use chrono::Utc;
@@ -9,6 +10,10 @@ pub fn question_type_to_string(question_type: &QuestionType) -> &str {
         QuestionType::Assignment => "Assignment",
         QuestionType::MultipleChoice => "MultipleChoice",
         QuestionType::SingleChoice => "SingleChoice",
+        QuestionType::ShortAnswer => "ShortAnswer",
+        QuestionType::Numeric => "Numeric",
+        QuestionType::Ordering => "Ordering",
+        QuestionType::Cloze => "Cloze",
     }
 }
 
@@ -17,10 +22,42 @@ pub fn question_type_to_enum(question_type: &str) -> QuestionType {
         "Assignment" => QuestionType::Assignment,
         "MultipleChoice" => QuestionType::MultipleChoice,
         "SingleChoice" => QuestionType::SingleChoice,
+        "ShortAnswer" => QuestionType::ShortAnswer,
+        "Numeric" => QuestionType::Numeric,
+        "Ordering" => QuestionType::Ordering,
+        "Cloze" => QuestionType::Cloze,
         &_ => QuestionType::SingleChoice,
     }
 }
 
+pub fn ordering_scoring_to_string(ordering_scoring: &OrderingScoring) -> &str {
+    match ordering_scoring {
+        OrderingScoring::AllOrNothing => "AllOrNothing",
+        OrderingScoring::CorrectlyPlaced => "CorrectlyPlaced",
+        OrderingScoring::KendallTau => "KendallTau",
+    }
+}
+
+pub fn ordering_scoring_to_enum(ordering_scoring: &str) -> OrderingScoring {
+    match ordering_scoring {
+        "CorrectlyPlaced" => OrderingScoring::CorrectlyPlaced,
+        "KendallTau" => OrderingScoring::KendallTau,
+        &_ => OrderingScoring::AllOrNothing,
+    }
+}
+
+/// Only ordering questions are graded with an ordering scoring.
+fn extract_ordering_scoring(question: &Question) -> Option<&str> {
+    if question.r#type == QuestionType::Ordering {
+        question
+            .ordering_scoring
+            .as_ref()
+            .map(ordering_scoring_to_string)
+    } else {
+        None
+    }
+}
+
 fn extract_category_id(category: &Option<Category>) -> Option<i32> {
     if let Some(cat) = &category {
        cat.id
@@ -39,6 +76,8 @@ impl<'a> From<&'a Question> for NewQuestion<'a> {
             created_at: Some(Utc::now().naive_utc()),
             fk_exam_id: value.exam_id.expect("Exam ID is not set"),
             fk_category_id: extract_category_id(&value.category),
+            ordering_scoring: extract_ordering_scoring(value),
+            explanation: value.explanation.as_deref(),
         }
     }
 }
@@ -57,6 +96,8 @@ impl<'a> From<&'a Question> for UpdateQuestion<'a> {
                 None
             },
             updated_at: Some(Utc::now().naive_utc()),
+            ordering_scoring: Some(extract_ordering_scoring(value)),
+            explanation: Some(value.explanation.as_deref()),
         }
     }
 }
@@ -75,6 +116,12 @@ impl<'a> From<&'a QuestionEntity> for Question {
             updated_at: value.updated_at.map(|updated| updated.and_utc()),
             options: None,
             exam_id: Option::from(value.fk_exam_id),
+            ordering_scoring: value
+                .ordering_scoring
+                .as_deref()
+                .map(ordering_scoring_to_enum),
+            explanation: value.explanation.clone(),
+            sources: None,
         }
     }
 }
@@ -0,0 +1,53 @@
+use crate::domain::entities::review_state_entity::{
+    NewReviewState, ReviewStateEntity, UpdateReviewState,
+};
+use crate::domain::model::review_state::ReviewState;
+use chrono::Utc;
+
+impl<'a> From<&'a ReviewState> for NewReviewState {
+    fn from(value: &'a ReviewState) -> Self {
+        NewReviewState {
+            fk_question_id: value.question_id,
+            ease_factor: value.ease_factor,
+            interval_days: value.interval_days,
+            repetitions: value.repetitions,
+            lapses: value.lapses,
+            due_at: value.due_at.naive_utc(),
+            last_reviewed_at: value.last_reviewed_at.map(|reviewed| reviewed.naive_utc()),
+            created_at: Some(Utc::now().naive_utc()),
+            fk_profile_id: value.profile_id,
+        }
+    }
+}
+
+impl<'a> From<&'a ReviewState> for UpdateReviewState {
+    fn from(value: &'a ReviewState) -> Self {
+        UpdateReviewState {
+            ease_factor: value.ease_factor,
+            interval_days: value.interval_days,
+            repetitions: value.repetitions,
+            lapses: value.lapses,
+            due_at: value.due_at.naive_utc(),
+            last_reviewed_at: value.last_reviewed_at.map(|reviewed| reviewed.naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl<'a> From<&'a ReviewStateEntity> for ReviewState {
+    fn from(value: &'a ReviewStateEntity) -> Self {
+        ReviewState {
+            id: Some(value.id),
+            question_id: value.fk_question_id,
+            profile_id: value.fk_profile_id,
+            ease_factor: value.ease_factor,
+            interval_days: value.interval_days,
+            repetitions: value.repetitions,
+            lapses: value.lapses,
+            due_at: value.due_at.and_utc(),
+            last_reviewed_at: value.last_reviewed_at.map(|reviewed| reviewed.and_utc()),
+            created_at: value.created_at.map(|created| created.and_utc()),
+            updated_at: value.updated_at.map(|updated| updated.and_utc()),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::domain::entities::setting_entity::SettingEntity;
+use crate::domain::model::setting::Setting;
+use chrono::Utc;
+
+impl<'a> From<&'a Setting> for SettingEntity {
+    fn from(value: &'a Setting) -> Self {
+        SettingEntity {
+            fk_profile_id: value.profile_id.unwrap_or_default(),
+            key: value.key.clone(),
+            value: value.value.clone(),
+            updated_at: Some(Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl<'a> From<&'a SettingEntity> for Setting {
+    fn from(value: &'a SettingEntity) -> Self {
+        Setting {
+            profile_id: Some(value.fk_profile_id),
+            key: value.key.clone(),
+            value: value.value.clone(),
+            updated_at: value.updated_at.map(|updated| updated.and_utc()),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::domain::entities::source_reference_entity::{NewSourceReference, SourceReferenceEntity};
+use crate::domain::model::source_reference::SourceReference;
+
+impl<'a> NewSourceReference<'a> {
+    /// Row of the source at `position` within the sources of the question.
+    pub fn from_model(value: &'a SourceReference, question_id: i32, position: i32) -> Self {
+        NewSourceReference {
+            fk_question_id: question_id,
+            title: value.title.trim(),
+            chapter: value.chapter.as_deref().map(str::trim),
+            page: value.page.as_deref().map(str::trim),
+            url: value.url.as_deref().map(str::trim),
+            position,
+        }
+    }
+}
+
+impl<'a> From<&'a SourceReferenceEntity> for SourceReference {
+    fn from(value: &'a SourceReferenceEntity) -> Self {
+        SourceReference {
+            id: Some(value.id),
+            question_id: Some(value.fk_question_id),
+            title: value.title.clone(),
+            chapter: value.chapter.clone(),
+            page: value.page.clone(),
+            url: value.url.clone(),
+        }
+    }
+}
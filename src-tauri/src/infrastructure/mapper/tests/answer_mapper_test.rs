@@ -2,8 +2,96 @@
 mod tests {
     use crate::domain::entities::answer_entity::{AnswerEntity, NewAnswer, UpdateAnswer};
     use crate::domain::model::answer::Answer;
+    use crate::domain::model::match_mode::MatchMode;
+    use crate::domain::model::numeric_answer::NumericAnswer;
+    use crate::domain::model::tolerance_mode::ToleranceMode;
+    use crate::infrastructure::mapper::answer_mapper::{
+        match_mode_to_enum, match_mode_to_string, tolerance_mode_to_enum, tolerance_mode_to_string,
+    };
     use chrono::DateTime;
 
+    #[test]
+    fn match_mode_string_mapping() {
+        for match_mode in [
+            MatchMode::Exact,
+            MatchMode::CaseInsensitive,
+            MatchMode::WhitespaceNormalized,
+            MatchMode::Regex,
+        ] {
+            assert_eq!(
+                match_mode_to_enum(match_mode_to_string(&match_mode)),
+                match_mode
+            );
+        }
+        assert_eq!(
+            match_mode_to_string(&MatchMode::CaseInsensitive),
+            "CaseInsensitive"
+        );
+        // fallback defaults to Exact
+        assert_eq!(match_mode_to_enum("Unknown"), MatchMode::Exact);
+    }
+
+    #[test]
+    fn tolerance_mode_string_mapping() {
+        assert_eq!(
+            tolerance_mode_to_string(&ToleranceMode::Relative),
+            "Relative"
+        );
+        assert_eq!(tolerance_mode_to_enum("Relative"), ToleranceMode::Relative);
+        assert_eq!(tolerance_mode_to_enum("Absolute"), ToleranceMode::Absolute);
+        // fallback defaults to Absolute
+        assert_eq!(tolerance_mode_to_enum("Unknown"), ToleranceMode::Absolute);
+    }
+
+    #[test]
+    fn numeric_answer_is_flattened_and_restored() {
+        let model = Answer {
+            id: None,
+            answer_text: "950".to_string(),
+            description: None,
+            is_correct: Some(true),
+            assigned_option_id: None,
+            created_at: None,
+            updated_at: None,
+            question_id: Some(5),
+            match_mode: None,
+            numeric: Some(NumericAnswer {
+                expected_value: 950.0,
+                tolerance: 0.05,
+                tolerance_mode: ToleranceMode::Relative,
+                unit: Some("Mbit/s".to_string()),
+            }),
+            position: None,
+            blank: None,
+        };
+
+        let new_answer = NewAnswer::from(&model);
+        assert_eq!(new_answer.numeric_value, Some(950.0));
+        assert_eq!(new_answer.tolerance, Some(0.05));
+        assert_eq!(new_answer.tolerance_mode, Some("Relative"));
+        assert_eq!(new_answer.unit, Some("Mbit/s"));
+        assert_eq!(UpdateAnswer::from(&model).unit, Some(Some("Mbit/s")));
+
+        let entity = AnswerEntity {
+            id: 1,
+            answer_text: "950".to_string(),
+            description: None,
+            is_correct: Some(true),
+            created_at: None,
+            updated_at: None,
+            assigned_option_id: None,
+            fk_question_id: 5,
+            match_mode: None,
+            numeric_value: new_answer.numeric_value,
+            tolerance: new_answer.tolerance,
+            tolerance_mode: Some("Relative".to_string()),
+            unit: Some("Mbit/s".to_string()),
+            position: None,
+            blank: None,
+        };
+        assert_eq!(Answer::from(&entity).numeric, model.numeric);
+    }
+
     #[test]
     fn new_answer_from_model_sets_fields() {
         let model = Answer {
@@ -15,6 +103,10 @@ mod tests {
             created_at: None,
             updated_at: None,
             question_id: Some(5),
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
         };
 
         let new_answer = NewAnswer::from(&model);
@@ -23,6 +115,7 @@ mod tests {
         assert_eq!(new_answer.is_correct, Some(true));
         assert_eq!(new_answer.assigned_option_id, Some(10));
         assert_eq!(new_answer.fk_question_id, 5);
+        assert_eq!(new_answer.match_mode, None);
         assert!(new_answer.created_at.is_some());
     }
 
@@ -38,6 +131,10 @@ mod tests {
             created_at: None,
             updated_at: None,
             question_id: None,
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
         };
         let _ = NewAnswer::from(&model);
     }
@@ -53,6 +150,10 @@ mod tests {
             created_at: None,
             updated_at: None,
             question_id: Some(8),
+            match_mode: Some(MatchMode::Regex),
+            numeric: None,
+            position: None,
+            blank: None,
         };
 
         let update_answer = UpdateAnswer::from(&model);
@@ -61,6 +162,9 @@ mod tests {
         assert_eq!(update_answer.is_correct, Some(false));
         assert_eq!(update_answer.assigned_option_id, None);
         assert_eq!(update_answer.fk_question_id, Some(8));
+        assert_eq!(update_answer.match_mode, Some("Regex"));
+        // a removed unit is cleared
+        assert_eq!(update_answer.unit, Some(None));
         assert!(update_answer.updated_at.is_some());
     }
 
@@ -77,6 +181,13 @@ mod tests {
             updated_at: Some(updated.naive_utc()),
             assigned_option_id: Some(12),
             fk_question_id: 3,
+            match_mode: Some("WhitespaceNormalized".to_string()),
+            numeric_value: None,
+            tolerance: None,
+            tolerance_mode: None,
+            unit: None,
+            position: Some(2),
+            blank: Some(1),
         };
 
         let model = Answer::from(&entity);
@@ -88,5 +199,9 @@ mod tests {
         assert_eq!(model.created_at.unwrap(), created.to_utc());
         assert_eq!(model.updated_at.unwrap(), updated.to_utc());
         assert_eq!(model.question_id, Some(3));
+        assert_eq!(model.match_mode, Some(MatchMode::WhitespaceNormalized));
+        assert_eq!(model.numeric, None);
+        assert_eq!(model.position, Some(2));
+        assert_eq!(model.blank, Some(1));
     }
 }
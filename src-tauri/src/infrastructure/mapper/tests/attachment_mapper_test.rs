@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::entities::attachment_entity::{AttachmentEntity, NewAttachment};
+    use crate::domain::model::attachment::Attachment;
+    use chrono::DateTime;
+
+    #[test]
+    fn new_attachment_from_model_sets_fields() {
+        let model = Attachment {
+            id: None,
+            question_id: None,
+            answer_id: Some(5),
+            file_name: " notes.pdf ".into(),
+            mime_type: "Application/PDF".into(),
+            hash: "abc".into(),
+            size: 12,
+            created_at: None,
+            data: Some("ignored".into()),
+        };
+        let new_row = NewAttachment::from(&model);
+        assert_eq!(new_row.fk_question_id, None);
+        assert_eq!(new_row.fk_answer_id, Some(5));
+        assert_eq!(new_row.file_name, "notes.pdf");
+        assert_eq!(new_row.mime_type, "application/pdf");
+        assert_eq!(new_row.hash, "abc");
+        assert_eq!(new_row.size, 12);
+        assert!(new_row.created_at.is_some());
+    }
+
+    #[test]
+    fn model_from_entity_maps_all_fields() {
+        let created = DateTime::from_timestamp(1_700_000_000, 0)
+            .unwrap()
+            .naive_utc();
+        let entity = AttachmentEntity {
+            id: 1,
+            fk_question_id: Some(3),
+            fk_answer_id: None,
+            file_name: "diagram.png".into(),
+            mime_type: "image/png".into(),
+            hash: "abc".into(),
+            size: 42,
+            created_at: Some(created),
+        };
+        let model = Attachment::from(&entity);
+        assert_eq!(model.id, Some(1));
+        assert_eq!(model.question_id, Some(3));
+        assert_eq!(model.answer_id, None);
+        assert_eq!(model.file_name, "diagram.png");
+        assert_eq!(model.mime_type, "image/png");
+        assert_eq!(model.hash, "abc");
+        assert_eq!(model.size, 42);
+        assert_eq!(model.created_at, Some(created.and_utc()));
+        assert!(model.data.is_none());
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::entities::attempt_answer_entity::{AttemptAnswerEntity, NewAttemptAnswer};
+    use crate::domain::model::attempt_answer::AttemptAnswer;
+
+    #[test]
+    fn new_attempt_answer_from_model_sets_fields() {
+        let model = AttemptAnswer {
+            id: None,
+            attempt_id: Some(1),
+            question_id: 2,
+            answer_id: Some(3),
+            assigned_option_id: Some(4),
+            time_spent_seconds: None,
+            created_at: None,
+            text_response: Some("ls -la".into()),
+            position: None,
+            blank: None,
+        };
+        let new_row = NewAttemptAnswer::from(&model);
+        assert_eq!(new_row.fk_attempt_id, 1);
+        assert_eq!(new_row.fk_question_id, 2);
+        assert_eq!(new_row.fk_answer_id, Some(3));
+        assert_eq!(new_row.assigned_option_id, Some(4));
+        assert_eq!(new_row.text_response.as_deref(), Some("ls -la"));
+        assert!(new_row.created_at.is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt_id is required for NewAttemptAnswer")]
+    fn new_attempt_answer_panics_without_attempt_id() {
+        let model = AttemptAnswer {
+            id: None,
+            attempt_id: None,
+            question_id: 2,
+            answer_id: Some(3),
+            assigned_option_id: None,
+            time_spent_seconds: None,
+            created_at: None,
+            text_response: None,
+            position: None,
+            blank: None,
+        };
+        let _ = NewAttemptAnswer::from(&model);
+    }
+
+    #[test]
+    fn model_from_entity_maps_all_fields() {
+        let entity = AttemptAnswerEntity {
+            id: 5,
+            fk_attempt_id: 1,
+            fk_question_id: 2,
+            fk_answer_id: Some(3),
+            assigned_option_id: None,
+            created_at: None,
+            time_spent_seconds: Some(12),
+            text_response: Some("pwd".into()),
+            position: Some(3),
+            blank: Some(2),
+        };
+        let model = AttemptAnswer::from(&entity);
+        assert_eq!(model.id, Some(5));
+        assert_eq!(model.attempt_id, Some(1));
+        assert_eq!(model.question_id, 2);
+        assert_eq!(model.answer_id, Some(3));
+        assert_eq!(model.assigned_option_id, None);
+        assert_eq!(model.time_spent_seconds, Some(12));
+        assert_eq!(model.text_response.as_deref(), Some("pwd"));
+        assert_eq!(model.position, Some(3));
+        assert_eq!(model.blank, Some(2));
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::entities::bookmark_entity::{BookmarkEntity, NewBookmark};
+    use crate::domain::model::bookmark::Bookmark;
+    use chrono::DateTime;
+
+    #[test]
+    fn new_bookmark_from_model_sets_fields() {
+        let new_row = NewBookmark::from(&Bookmark::new(4, 8));
+        assert_eq!(new_row.fk_profile_id, 4);
+        assert_eq!(new_row.fk_question_id, 8);
+        assert!(new_row.created_at.is_some());
+    }
+
+    #[test]
+    fn model_from_entity_maps_all_fields() {
+        let created = DateTime::from_timestamp(1_700_000_000, 0)
+            .unwrap()
+            .naive_utc();
+        let entity = BookmarkEntity {
+            id: 1,
+            fk_profile_id: 5,
+            fk_question_id: 8,
+            created_at: Some(created),
+        };
+        let model = Bookmark::from(&entity);
+        assert_eq!(model.id, Some(1));
+        assert_eq!(model.profile_id, 5);
+        assert_eq!(model.question_id, 8);
+        assert_eq!(model.created_at, Some(created.and_utc()));
+        assert!(model.question.is_none());
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::entities::exam_attempt_entity::{
+        ExamAttemptEntity, NewExamAttempt, UpdateExamAttempt,
+    };
+    use crate::domain::model::attempt_mode::AttemptMode;
+    use crate::domain::model::exam_attempt::ExamAttempt;
+    use chrono::{DateTime, Utc};
+
+    fn sample_attempt() -> ExamAttempt {
+        ExamAttempt {
+            id: Some(3),
+            exam_id: 7,
+            profile_id: 4,
+            mode: AttemptMode::Certification,
+            started_at: DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            finished_at: None,
+            score: None,
+            created_at: None,
+            updated_at: None,
+            deadline_at: None,
+            expired: false,
+            shuffle_seed: Some(21),
+            draw_id: Some(8),
+            content_revision: Some("abc".into()),
+            question_ids: vec![2, 1],
+            answers: vec![],
+        }
+    }
+
+    #[test]
+    fn new_attempt_from_model_sets_fields() {
+        let model = sample_attempt();
+        let new_row = NewExamAttempt::from(&model);
+        assert_eq!(new_row.fk_exam_id, 7);
+        assert_eq!(new_row.fk_profile_id, 4);
+        assert_eq!(new_row.shuffle_seed, Some(21));
+        assert_eq!(new_row.fk_draw_id, Some(8));
+        assert_eq!(new_row.content_revision, Some("abc"));
+        assert_eq!(new_row.mode, "Certification");
+        assert_eq!(new_row.started_at, model.started_at.naive_utc());
+        assert!(new_row.created_at.is_some());
+    }
+
+    #[test]
+    fn update_attempt_from_model_sets_result_fields() {
+        let mut model = sample_attempt();
+        model.finished_at = Some(Utc::now());
+        model.score = Some(42);
+        let update_row = UpdateExamAttempt::from(&model);
+        assert_eq!(update_row.score, Some(42));
+        assert!(!update_row.expired);
+        assert!(update_row.finished_at.is_some());
+        assert!(update_row.updated_at.is_some());
+    }
+
+    #[test]
+    fn model_from_entity_maps_all_fields() {
+        let started = DateTime::from_timestamp(1_700_000_000, 0)
+            .unwrap()
+            .naive_utc();
+        let finished = DateTime::from_timestamp(1_700_000_600, 0)
+            .unwrap()
+            .naive_utc();
+        let entity = ExamAttemptEntity {
+            id: 1,
+            fk_exam_id: 2,
+            mode: "Simulation".into(),
+            started_at: started,
+            finished_at: Some(finished),
+            score: Some(10),
+            created_at: Some(started),
+            updated_at: None,
+            deadline_at: Some(finished),
+            expired: true,
+            fk_profile_id: 5,
+            shuffle_seed: Some(99),
+            fk_draw_id: Some(6),
+            content_revision: Some("def".into()),
+        };
+        let model = ExamAttempt::from(&entity);
+        assert_eq!(model.id, Some(1));
+        assert_eq!(model.exam_id, 2);
+        assert_eq!(model.profile_id, 5);
+        assert_eq!(model.shuffle_seed, Some(99));
+        assert_eq!(model.draw_id, Some(6));
+        assert_eq!(model.content_revision.as_deref(), Some("def"));
+        assert_eq!(model.mode, AttemptMode::Simulation);
+        assert_eq!(model.started_at, started.and_utc());
+        assert_eq!(model.finished_at, Some(finished.and_utc()));
+        assert_eq!(model.score, Some(10));
+        assert_eq!(model.deadline_at, Some(finished.and_utc()));
+        assert!(model.expired);
+        assert!(model.is_finished());
+        assert!(model.answers.is_empty());
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::entities::mistake_entity::{MistakeEntity, NewMistake, UpdateMistake};
+    use crate::domain::model::mistake::Mistake;
+    use chrono::DateTime;
+
+    fn sample_mistake() -> Mistake {
+        let mut mistake = Mistake::new(4, 8, DateTime::from_timestamp(1_700_000_000, 0).unwrap());
+        mistake.miss_count = 3;
+        mistake.correct_streak = 1;
+        mistake
+    }
+
+    #[test]
+    fn new_mistake_from_model_sets_fields() {
+        let model = sample_mistake();
+        let new_row = NewMistake::from(&model);
+        assert_eq!(new_row.fk_profile_id, 4);
+        assert_eq!(new_row.fk_question_id, 8);
+        assert_eq!(new_row.miss_count, 3);
+        assert_eq!(new_row.correct_streak, 1);
+        assert_eq!(new_row.last_missed_at, model.last_missed_at.naive_utc());
+        assert!(new_row.created_at.is_some());
+    }
+
+    #[test]
+    fn update_mistake_from_model_sets_fields() {
+        let update_row = UpdateMistake::from(&sample_mistake());
+        assert_eq!(update_row.miss_count, 3);
+        assert_eq!(update_row.correct_streak, 1);
+        assert!(update_row.updated_at.is_some());
+    }
+
+    #[test]
+    fn model_from_entity_maps_all_fields() {
+        let missed = DateTime::from_timestamp(1_700_000_000, 0)
+            .unwrap()
+            .naive_utc();
+        let entity = MistakeEntity {
+            id: 1,
+            fk_profile_id: 5,
+            fk_question_id: 8,
+            miss_count: 2,
+            correct_streak: 0,
+            last_missed_at: missed,
+            created_at: Some(missed),
+            updated_at: None,
+        };
+        let model = Mistake::from(&entity);
+        assert_eq!(model.id, Some(1));
+        assert_eq!(model.profile_id, 5);
+        assert_eq!(model.question_id, 8);
+        assert_eq!(model.miss_count, 2);
+        assert_eq!(model.last_missed_at, missed.and_utc());
+        assert_eq!(model.created_at, Some(missed.and_utc()));
+        assert!(model.question.is_none());
+    }
+}
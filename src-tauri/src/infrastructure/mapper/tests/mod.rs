@@ -1,5 +1,15 @@
 pub mod answer_mapper_test;
 pub mod assignment_option_mapper_test;
+pub mod attachment_mapper_test;
+pub mod attempt_answer_mapper_test;
+pub mod bookmark_mapper_test;
 pub mod category_mapper_test;
+pub mod exam_attempt_mapper_test;
 pub mod exam_mapper_test;
+pub mod mistake_mapper_test;
+pub mod profile_mapper_test;
+pub mod question_draw_mapper_test;
 pub mod question_mapper_test;
+pub mod review_state_mapper_test;
+pub mod setting_mapper_test;
+pub mod source_reference_mapper_test;
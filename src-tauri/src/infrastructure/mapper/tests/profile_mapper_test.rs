@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::entities::profile_entity::{NewProfile, ProfileEntity, UpdateProfile};
+    use crate::domain::model::profile::Profile;
+    use chrono::DateTime;
+
+    #[test]
+    fn new_profile_from_model_sets_fields() {
+        let mut model = Profile::new("Alice");
+        model.active = true;
+        let new_row = NewProfile::from(&model);
+        assert_eq!(new_row.name, "Alice");
+        assert!(new_row.active);
+        assert!(new_row.created_at.is_some());
+    }
+
+    #[test]
+    fn update_profile_from_model_sets_fields() {
+        let model = Profile::new("Bob");
+        let update_row = UpdateProfile::from(&model);
+        assert_eq!(update_row.name, "Bob");
+        assert!(update_row.updated_at.is_some());
+    }
+
+    #[test]
+    fn model_from_entity_maps_all_fields() {
+        let created = DateTime::from_timestamp(1_700_000_000, 0)
+            .unwrap()
+            .naive_utc();
+        let entity = ProfileEntity {
+            id: 2,
+            name: "Carol".into(),
+            active: true,
+            created_at: Some(created),
+            updated_at: None,
+        };
+        let model = Profile::from(&entity);
+        assert_eq!(model.id, Some(2));
+        assert_eq!(model.name, "Carol");
+        assert!(model.active);
+        assert_eq!(model.created_at, Some(created.and_utc()));
+        assert!(model.updated_at.is_none());
+    }
+}
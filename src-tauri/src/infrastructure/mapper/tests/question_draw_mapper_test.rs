@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::entities::question_draw_entity::{NewQuestionDraw, QuestionDrawEntity};
+    use crate::domain::model::question_draw::QuestionDraw;
+
+    #[test]
+    fn new_question_draw_from_model_sets_fields() {
+        let model = QuestionDraw {
+            id: None,
+            exam_id: 4,
+            profile_id: 2,
+            seed: 9_007_199_254_740_991,
+            stratified: true,
+            question_ids: vec![3, 1, 2],
+            created_at: None,
+            questions: vec![],
+        };
+        let new_row = NewQuestionDraw::from(&model);
+        assert_eq!(new_row.fk_exam_id, 4);
+        assert_eq!(new_row.fk_profile_id, 2);
+        assert_eq!(new_row.seed, 9_007_199_254_740_991);
+        assert!(new_row.stratified);
+        assert!(new_row.created_at.is_some());
+    }
+
+    #[test]
+    fn model_from_entity_maps_all_fields() {
+        let entity = QuestionDrawEntity {
+            id: 2,
+            fk_exam_id: 4,
+            seed: 42,
+            stratified: false,
+            created_at: None,
+            fk_profile_id: 3,
+        };
+        let model = QuestionDraw::from(&entity);
+        assert_eq!(model.id, Some(2));
+        assert_eq!(model.exam_id, 4);
+        assert_eq!(model.profile_id, 3);
+        assert_eq!(model.seed, 42);
+        assert!(!model.stratified);
+        assert!(model.question_ids.is_empty());
+        assert!(model.questions.is_empty());
+    }
+}
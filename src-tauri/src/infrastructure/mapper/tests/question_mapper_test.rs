@@ -2,9 +2,13 @@
 mod tests {
     use crate::domain::entities::question_entity::{NewQuestion, QuestionEntity, UpdateQuestion};
     use crate::domain::model::category::Category;
+    use crate::domain::model::ordering_scoring::OrderingScoring;
     use crate::domain::model::question::Question;
     use crate::domain::model::question_type::QuestionType;
-    use crate::infrastructure::mapper::question_mapper::{question_type_to_enum, question_type_to_string};
+    use crate::infrastructure::mapper::question_mapper::{
+        ordering_scoring_to_enum, ordering_scoring_to_string, question_type_to_enum,
+        question_type_to_string,
+    };
     use chrono::DateTime;
 
     #[test]
@@ -34,6 +38,29 @@ mod tests {
             question_type_to_enum("SingleChoice"),
             QuestionType::SingleChoice
         ));
+        assert_eq!(
+            question_type_to_string(&QuestionType::ShortAnswer),
+            "ShortAnswer"
+        );
+        assert!(matches!(
+            question_type_to_enum("ShortAnswer"),
+            QuestionType::ShortAnswer
+        ));
+        assert_eq!(question_type_to_string(&QuestionType::Numeric), "Numeric");
+        assert_eq!(question_type_to_string(&QuestionType::Ordering), "Ordering");
+        assert!(matches!(
+            question_type_to_enum("Ordering"),
+            QuestionType::Ordering
+        ));
+        assert_eq!(question_type_to_string(&QuestionType::Cloze), "Cloze");
+        assert!(matches!(
+            question_type_to_enum("Cloze"),
+            QuestionType::Cloze
+        ));
+        assert!(matches!(
+            question_type_to_enum("Numeric"),
+            QuestionType::Numeric
+        ));
         // fallback defaults to SingleChoice
         assert!(matches!(
             question_type_to_enum("Unknown"),
@@ -41,6 +68,25 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn ordering_scoring_string_mapping() {
+        for ordering_scoring in [
+            OrderingScoring::AllOrNothing,
+            OrderingScoring::CorrectlyPlaced,
+            OrderingScoring::KendallTau,
+        ] {
+            assert_eq!(
+                ordering_scoring_to_enum(ordering_scoring_to_string(&ordering_scoring)),
+                ordering_scoring
+            );
+        }
+        // fallback defaults to AllOrNothing
+        assert_eq!(
+            ordering_scoring_to_enum("Unknown"),
+            OrderingScoring::AllOrNothing
+        );
+    }
+
     #[test]
     fn new_question_from_model_sets_fields() {
         let model = Question {
@@ -55,6 +101,9 @@ mod tests {
             updated_at: None,
             options: None,
             exam_id: Some(2),
+            ordering_scoring: None,
+            explanation: Some("Rust is a systems language".into()),
+            sources: None,
         };
 
         let new_q = NewQuestion::from(&model);
@@ -64,6 +113,7 @@ mod tests {
         assert_eq!(new_q.question_typ, "MultipleChoice");
         assert_eq!(new_q.fk_exam_id, 2);
         assert_eq!(new_q.fk_category_id, None);
+        assert_eq!(new_q.explanation, Some("Rust is a systems language"));
         assert!(new_q.created_at.is_some());
     }
 
@@ -82,6 +132,9 @@ mod tests {
             updated_at: None,
             options: None,
             exam_id: None,
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
         };
         let _ = NewQuestion::from(&model);
     }
@@ -105,6 +158,9 @@ mod tests {
             updated_at: None,
             options: None,
             exam_id: Some(3),
+            ordering_scoring: Some(OrderingScoring::KendallTau),
+            explanation: None,
+            sources: None,
         };
 
         let upd = UpdateQuestion::from(&model);
@@ -113,7 +169,19 @@ mod tests {
         assert_eq!(upd.points_per_correct_answer, None);
         assert_eq!(upd.question_typ, Some("Assignment"));
         assert_eq!(upd.fk_category_id, Some(42));
+        assert_eq!(upd.explanation, Some(None));
+        // ordering scorings of other question types are cleared
+        assert_eq!(upd.ordering_scoring, Some(None));
         assert!(upd.updated_at.is_some());
+
+        let ordering = Question {
+            r#type: QuestionType::Ordering,
+            ..model
+        };
+        assert_eq!(
+            UpdateQuestion::from(&ordering).ordering_scoring,
+            Some(Some("KendallTau"))
+        );
     }
 
     #[test]
@@ -131,6 +199,9 @@ mod tests {
             updated_at: None,
             options: None,
             exam_id: Some(1),
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
         };
         let _ = UpdateQuestion::from(&model);
     }
@@ -149,6 +220,8 @@ mod tests {
             updated_at: Some(updated.naive_utc()),
             fk_exam_id: 7,
             fk_category_id: Some(2),
+            ordering_scoring: Some("KendallTau".into()),
+            explanation: Some("Because **ownership**".into()),
         };
 
         let model = Question::from(&entity);
@@ -162,5 +235,8 @@ mod tests {
         assert_eq!(model.exam_id.unwrap(), 7);
         assert!(model.category.is_none());
         assert!(model.options.is_none());
+        assert_eq!(model.ordering_scoring, Some(OrderingScoring::KendallTau));
+        assert_eq!(model.explanation.as_deref(), Some("Because **ownership**"));
+        assert!(model.sources.is_none());
     }
 }
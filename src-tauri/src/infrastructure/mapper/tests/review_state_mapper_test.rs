@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::entities::review_state_entity::{
+        NewReviewState, ReviewStateEntity, UpdateReviewState,
+    };
+    use crate::domain::model::review_state::ReviewState;
+    use chrono::DateTime;
+
+    fn sample_state() -> ReviewState {
+        let mut state = ReviewState::new(4, 8, DateTime::from_timestamp(1_700_000_000, 0).unwrap());
+        state.interval_days = 6;
+        state.repetitions = 2;
+        state.lapses = 1;
+        state
+    }
+
+    #[test]
+    fn new_review_state_from_model_sets_fields() {
+        let model = sample_state();
+        let new_row = NewReviewState::from(&model);
+        assert_eq!(new_row.fk_question_id, 8);
+        assert_eq!(new_row.fk_profile_id, 4);
+        assert_eq!(new_row.ease_factor, 2.5);
+        assert_eq!(new_row.interval_days, 6);
+        assert_eq!(new_row.due_at, model.due_at.naive_utc());
+        assert!(new_row.created_at.is_some());
+    }
+
+    #[test]
+    fn update_review_state_from_model_sets_fields() {
+        let update_row = UpdateReviewState::from(&sample_state());
+        assert_eq!(update_row.repetitions, 2);
+        assert_eq!(update_row.lapses, 1);
+        assert!(update_row.updated_at.is_some());
+    }
+
+    #[test]
+    fn model_from_entity_maps_all_fields() {
+        let due = DateTime::from_timestamp(1_700_000_000, 0)
+            .unwrap()
+            .naive_utc();
+        let entity = ReviewStateEntity {
+            id: 1,
+            fk_question_id: 8,
+            ease_factor: 2.36,
+            interval_days: 1,
+            repetitions: 1,
+            lapses: 0,
+            due_at: due,
+            last_reviewed_at: Some(due),
+            created_at: None,
+            updated_at: None,
+            fk_profile_id: 5,
+        };
+        let model = ReviewState::from(&entity);
+        assert_eq!(model.id, Some(1));
+        assert_eq!(model.question_id, 8);
+        assert_eq!(model.profile_id, 5);
+        assert_eq!(model.ease_factor, 2.36);
+        assert_eq!(model.due_at, due.and_utc());
+        assert_eq!(model.last_reviewed_at, Some(due.and_utc()));
+    }
+}
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::entities::setting_entity::SettingEntity;
+    use crate::domain::model::setting::Setting;
+
+    #[test]
+    fn entity_from_model_sets_fields() {
+        let model = Setting {
+            profile_id: Some(2),
+            key: "attempt_grace_period_seconds".into(),
+            value: "45".into(),
+            updated_at: None,
+        };
+        let entity = SettingEntity::from(&model);
+        assert_eq!(entity.fk_profile_id, 2);
+        assert_eq!(entity.key, "attempt_grace_period_seconds");
+        assert_eq!(entity.value, "45");
+        assert!(entity.updated_at.is_some());
+    }
+
+    #[test]
+    fn model_from_entity_maps_all_fields() {
+        let entity = SettingEntity {
+            fk_profile_id: 3,
+            key: "theme".into(),
+            value: "dark".into(),
+            updated_at: None,
+        };
+        let model = Setting::from(&entity);
+        assert_eq!(model.profile_id, Some(3));
+        assert_eq!(model.key, "theme");
+        assert_eq!(model.value, "dark");
+        assert!(model.updated_at.is_none());
+    }
+}
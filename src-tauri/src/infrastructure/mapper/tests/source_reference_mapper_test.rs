@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::entities::source_reference_entity::{
+        NewSourceReference, SourceReferenceEntity,
+    };
+    use crate::domain::model::source_reference::SourceReference;
+
+    #[test]
+    fn new_source_reference_from_model_sets_fields() {
+        let model = SourceReference {
+            id: None,
+            question_id: None,
+            title: " Computer Networks ".into(),
+            chapter: Some("5 The Network Layer".into()),
+            page: Some(" 42-45 ".into()),
+            url: None,
+        };
+        let new_row = NewSourceReference::from_model(&model, 3, 2);
+        assert_eq!(new_row.fk_question_id, 3);
+        assert_eq!(new_row.title, "Computer Networks");
+        assert_eq!(new_row.chapter, Some("5 The Network Layer"));
+        assert_eq!(new_row.page, Some("42-45"));
+        assert_eq!(new_row.url, None);
+        assert_eq!(new_row.position, 2);
+    }
+
+    #[test]
+    fn model_from_entity_maps_all_fields() {
+        let entity = SourceReferenceEntity {
+            id: 1,
+            fk_question_id: 3,
+            title: "RFC 791".into(),
+            chapter: None,
+            page: None,
+            url: Some("https://www.rfc-editor.org/rfc/rfc791".into()),
+            position: 1,
+        };
+        let model = SourceReference::from(&entity);
+        assert_eq!(model.id, Some(1));
+        assert_eq!(model.question_id, Some(3));
+        assert_eq!(model.title, "RFC 791");
+        assert_eq!(model.chapter, None);
+        assert_eq!(model.page, None);
+        assert_eq!(
+            model.url.as_deref(),
+            Some("https://www.rfc-editor.org/rfc/rfc791")
+        );
+    }
+}
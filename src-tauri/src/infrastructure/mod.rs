@@ -1,3 +1,4 @@
+pub(crate) mod anki;
 mod filter;
 mod macros;
 mod mapper;
@@ -1,8 +1,17 @@
 pub(crate) mod sqlite_answer_crud_repository;
 pub(crate) mod sqlite_assignment_option_crud_repository;
+pub(crate) mod sqlite_attachment_repository;
+pub(crate) mod sqlite_attempt_crud_repository;
+pub(crate) mod sqlite_bookmark_repository;
 pub(crate) mod sqlite_category_crud_repository;
 pub(crate) mod sqlite_exam_crud_repository;
+pub(crate) mod sqlite_mistake_repository;
+pub(crate) mod sqlite_profile_repository;
 pub(crate) mod sqlite_question_crud_repository;
+pub(crate) mod sqlite_question_draw_repository;
+pub(crate) mod sqlite_review_state_repository;
+pub(crate) mod sqlite_setting_repository;
+pub(crate) mod sqlite_source_reference_repository;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,150 @@
+use crate::application::crud::attachment_repository_trait::AttachmentRepository;
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::domain::entities::attachment_entity::{
+    AttachmentEntity, NewAttachment, NewAttachmentBlob,
+};
+use crate::domain::model::attachment::Attachment;
+use chrono::Utc;
+use diesel::dsl::not;
+use diesel::prelude::*;
+
+pub struct SQLiteAttachmentRepository<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SQLiteAttachmentRepository<'a> {
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+}
+
+impl<'a> AttachmentRepository for SQLiteAttachmentRepository<'a> {
+    fn save_content(&mut self, content_hash: &str, content: &[u8]) -> CRUDResult<()> {
+        use crate::schema::attachment_blob::dsl::*;
+
+        diesel::insert_into(attachment_blob)
+            .values(NewAttachmentBlob {
+                hash: content_hash,
+                data: content,
+                size: content.len() as i32,
+                created_at: Some(Utc::now().naive_utc()),
+            })
+            .on_conflict_do_nothing()
+            .execute(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(())
+    }
+
+    fn find_content(&mut self, content_hash: &str) -> CRUDResult<Option<Vec<u8>>> {
+        use crate::schema::attachment_blob::dsl::*;
+
+        attachment_blob
+            .find(content_hash)
+            .select(data)
+            .first::<Vec<u8>>(self.conn)
+            .optional()
+            .map_err(|e| CRUDError::new(e.to_string(), None))
+    }
+
+    fn create(&mut self, entity: &Attachment) -> CRUDResult<Attachment> {
+        use crate::schema::attachment::dsl::*;
+
+        let created = diesel::insert_into(attachment)
+            .values(NewAttachment::from(entity))
+            .returning(AttachmentEntity::as_returning())
+            .get_result(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(Attachment::from(&created))
+    }
+
+    fn find_by_id(&mut self, attachment_id: i32) -> CRUDResult<Option<Attachment>> {
+        use crate::schema::attachment::dsl::*;
+
+        let found = attachment
+            .find(attachment_id)
+            .select(AttachmentEntity::as_select())
+            .first(self.conn)
+            .optional()
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(found.as_ref().map(Attachment::from))
+    }
+
+    fn find_by_question_ids(&mut self, question_ids: &[i32]) -> CRUDResult<Vec<Attachment>> {
+        use crate::schema::answer;
+        use crate::schema::attachment::dsl::*;
+
+        let answer_ids = answer::table
+            .filter(answer::fk_question_id.eq_any(question_ids))
+            .select(answer::id.nullable());
+
+        let attachments = attachment
+            .filter(
+                fk_question_id
+                    .eq_any(question_ids.iter().map(|q| Some(*q)).collect::<Vec<_>>())
+                    .or(fk_answer_id.eq_any(answer_ids)),
+            )
+            .order(id.asc())
+            .select(AttachmentEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(attachments.iter().map(Attachment::from).collect())
+    }
+
+    fn find_by_answer_id(&mut self, answer_id: i32) -> CRUDResult<Vec<Attachment>> {
+        use crate::schema::attachment::dsl::*;
+
+        let attachments = attachment
+            .filter(fk_answer_id.eq(answer_id))
+            .order(id.asc())
+            .select(AttachmentEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(attachments.iter().map(Attachment::from).collect())
+    }
+
+    fn delete(&mut self, attachment_id: i32) -> CRUDResult<usize> {
+        use crate::schema::attachment::dsl::*;
+
+        diesel::delete(attachment.find(attachment_id))
+            .execute(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))
+    }
+
+    fn delete_orphans(&mut self) -> CRUDResult<usize> {
+        use crate::schema::{answer, attachment, attachment_blob, question};
+
+        // Foreign key cascades only apply while `PRAGMA foreign_keys` is on, so owners
+        // removed without them, including answers of deleted questions, count as gone.
+        diesel::delete(
+            attachment::table.filter(attachment::fk_question_id.is_not_null().and(not(
+                attachment::fk_question_id.eq_any(question::table.select(question::id.nullable())),
+            ))),
+        )
+        .execute(self.conn)
+        .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        let owned_answer_ids = answer::table
+            .filter(answer::fk_question_id.eq_any(question::table.select(question::id)))
+            .select(answer::id.nullable());
+        diesel::delete(
+            attachment::table.filter(
+                attachment::fk_answer_id
+                    .is_not_null()
+                    .and(not(attachment::fk_answer_id.eq_any(owned_answer_ids))),
+            ),
+        )
+        .execute(self.conn)
+        .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        diesel::delete(attachment_blob::table.filter(not(
+            attachment_blob::hash.eq_any(attachment::table.select(attachment::hash)),
+        )))
+        .execute(self.conn)
+        .map_err(|e| CRUDError::new(e.to_string(), None))
+    }
+}
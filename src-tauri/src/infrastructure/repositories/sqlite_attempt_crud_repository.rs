@@ -0,0 +1,252 @@
+use crate::application::crud::attempt_repository_trait::AttemptRepository;
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDRepository, CRUDResult};
+use crate::domain::entities::attempt_answer_entity::{AttemptAnswerEntity, NewAttemptAnswer};
+use crate::domain::entities::exam_attempt_entity::{
+    AttemptQuestionEntity, ExamAttemptEntity, NewAttemptQuestion, NewExamAttempt, UpdateExamAttempt,
+};
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::exam_attempt::ExamAttempt;
+use crate::domain::model::page_options::PageOptions;
+use crate::domain::model::paged_result::PagedResult;
+use crate::pagination_repository_impl;
+use diesel::prelude::*;
+
+const LOG_TAG: &str = "sqlite_attempt_crud_repository";
+
+pub struct SQLiteAttemptCrudRepository<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SQLiteAttemptCrudRepository<'a> {
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Attempt with its answers and its question order.
+    fn load_relations(&mut self, attempt: &ExamAttemptEntity) -> CRUDResult<ExamAttempt> {
+        let answers: Vec<AttemptAnswerEntity> = AttemptAnswerEntity::belonging_to(attempt)
+            .select(AttemptAnswerEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+        let questions: Vec<AttemptQuestionEntity> = AttemptQuestionEntity::belonging_to(attempt)
+            .order(crate::schema::attempt_question::position.asc())
+            .select(AttemptQuestionEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        let mut exam_attempt = ExamAttempt::from(attempt);
+        exam_attempt.answers = answers.iter().map(AttemptAnswer::from).collect();
+        exam_attempt.question_ids = questions.iter().map(|q| q.fk_question_id).collect();
+        Ok(exam_attempt)
+    }
+}
+
+impl<'a> CRUDRepository<ExamAttempt> for SQLiteAttemptCrudRepository<'a> {
+    fn create(&mut self, entity: &ExamAttempt) -> CRUDResult<ExamAttempt> {
+        use crate::schema::{attempt_question, exam_attempt};
+
+        let created_row: ExamAttemptEntity = diesel::insert_into(exam_attempt::table)
+            .values(NewExamAttempt::from(entity))
+            .returning(ExamAttemptEntity::as_returning())
+            .get_result(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        let questions: Vec<NewAttemptQuestion> = entity
+            .question_ids
+            .iter()
+            .enumerate()
+            .map(|(index, question_id)| NewAttemptQuestion {
+                fk_attempt_id: created_row.id,
+                fk_question_id: *question_id,
+                position: index as i32,
+            })
+            .collect();
+        if !questions.is_empty() {
+            diesel::insert_into(attempt_question::table)
+                .values(&questions)
+                .execute(self.conn)
+                .map_err(|e| CRUDError::new(e.to_string(), None))?;
+        }
+
+        let mut created = ExamAttempt::from(&created_row);
+        created.question_ids = entity.question_ids.clone();
+        Ok(created)
+    }
+
+    fn update(&mut self, entity: &ExamAttempt) -> CRUDResult<ExamAttempt> {
+        use crate::schema::exam_attempt::dsl::*;
+
+        let Some(attempt_id) = entity.id else {
+            return Err(CRUDError::new("Id is required to update an attempt", None));
+        };
+
+        let updated_row: ExamAttemptEntity = diesel::update(exam_attempt.find(attempt_id))
+            .set(UpdateExamAttempt::from(entity))
+            .returning(ExamAttemptEntity::as_returning())
+            .get_result(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        self.load_relations(&updated_row)
+    }
+
+    fn delete(&mut self, id: i32) -> CRUDResult<usize> {
+        use crate::schema::exam_attempt;
+        let size = diesel::delete(exam_attempt::table)
+            .filter(exam_attempt::id.eq(id))
+            .execute(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(size)
+    }
+
+    fn find_by_id(&mut self, _id: i32) -> CRUDResult<Option<ExamAttempt>> {
+        use crate::schema::exam_attempt::dsl::*;
+
+        let result = exam_attempt
+            .filter(id.eq(_id))
+            .limit(1)
+            .select(ExamAttemptEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        if result.is_empty() {
+            return Err(CRUDError::new(
+                format!("Entity with id: {_id} not found"),
+                None,
+            ));
+        }
+
+        if result.len() > 1 {
+            return Err(CRUDError::new("More than one entry was found", None));
+        }
+
+        match result.first() {
+            Some(attempt) => Ok(Some(self.load_relations(attempt)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn find_all(
+        &mut self,
+        page_options: Option<PageOptions>,
+    ) -> CRUDResult<PagedResult<ExamAttempt>> {
+        pagination_repository_impl!(
+            exam_attempt,
+            ExamAttemptEntity,
+            crate::schema::exam_attempt::table
+        );
+        let result = exam_attempt::find_all(self.conn, page_options)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        let attempts: Result<Vec<ExamAttempt>, CRUDError> =
+            result.data.iter().map(|a| self.load_relations(a)).collect();
+
+        Ok(PagedResult::new(
+            attempts?,
+            result.total_elements,
+            result.current_page,
+            result.total_pages,
+        ))
+    }
+}
+
+impl<'a> AttemptRepository<ExamAttempt> for SQLiteAttemptCrudRepository<'a> {
+    fn find_by_exam_id(&mut self, profile_id: i32, exam_id: i32) -> CRUDResult<Vec<ExamAttempt>> {
+        use crate::schema::exam_attempt::dsl::*;
+
+        let attempts = exam_attempt
+            .filter(fk_profile_id.eq(profile_id))
+            .filter(fk_exam_id.eq(exam_id))
+            .order(started_at.desc())
+            .select(ExamAttemptEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        attempts.iter().map(|a| self.load_relations(a)).collect()
+    }
+
+    fn find_open(&mut self, profile_id: i32) -> CRUDResult<Vec<ExamAttempt>> {
+        use crate::schema::exam_attempt::dsl::*;
+
+        let attempts = exam_attempt
+            .filter(fk_profile_id.eq(profile_id))
+            .filter(finished_at.is_null())
+            .order(started_at.desc())
+            .select(ExamAttemptEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        attempts.iter().map(|a| self.load_relations(a)).collect()
+    }
+
+    fn find_finished(&mut self, profile_id: i32) -> CRUDResult<Vec<ExamAttempt>> {
+        use crate::schema::exam_attempt::dsl::*;
+
+        let attempts = exam_attempt
+            .filter(fk_profile_id.eq(profile_id))
+            .filter(finished_at.is_not_null())
+            .order(started_at.desc())
+            .select(ExamAttemptEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        attempts.iter().map(|a| self.load_relations(a)).collect()
+    }
+
+    fn find_finished_by_exam_id(&mut self, _exam_id: i32) -> CRUDResult<Vec<ExamAttempt>> {
+        use crate::schema::exam_attempt::dsl::*;
+
+        let attempts = exam_attempt
+            .filter(fk_exam_id.eq(_exam_id))
+            .filter(finished_at.is_not_null())
+            .order(started_at.desc())
+            .select(ExamAttemptEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        attempts.iter().map(|a| self.load_relations(a)).collect()
+    }
+
+    fn find_answers_by_attempt_id(&mut self, attempt_id: i32) -> CRUDResult<Vec<AttemptAnswer>> {
+        use crate::schema::attempt_answer::dsl::*;
+
+        let answers = attempt_answer
+            .filter(fk_attempt_id.eq(attempt_id))
+            .select(AttemptAnswerEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(answers.iter().map(AttemptAnswer::from).collect())
+    }
+
+    fn replace_answers_for_question(
+        &mut self,
+        attempt_id: i32,
+        question_id: i32,
+        answers: &[AttemptAnswer],
+    ) -> CRUDResult<Vec<AttemptAnswer>> {
+        use crate::schema::attempt_answer::dsl::*;
+
+        let removed = diesel::delete(attempt_answer)
+            .filter(fk_attempt_id.eq(attempt_id))
+            .filter(fk_question_id.eq(question_id))
+            .execute(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+        log::debug!(
+            "{LOG_TAG} Removed {removed} previous answers of question {question_id} in attempt {attempt_id}"
+        );
+
+        let mut created: Vec<AttemptAnswerEntity> = Vec::with_capacity(answers.len());
+        for answer in answers {
+            created.push(
+                diesel::insert_into(attempt_answer)
+                    .values(NewAttemptAnswer::from(answer))
+                    .returning(AttemptAnswerEntity::as_returning())
+                    .get_result(self.conn)
+                    .map_err(|e| CRUDError::new(e.to_string(), None))?,
+            );
+        }
+
+        Ok(created.iter().map(AttemptAnswer::from).collect())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::application::crud::bookmark_repository_trait::BookmarkRepository;
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::domain::entities::bookmark_entity::{BookmarkEntity, NewBookmark};
+use crate::domain::model::bookmark::Bookmark;
+use diesel::prelude::*;
+
+pub struct SQLiteBookmarkRepository<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SQLiteBookmarkRepository<'a> {
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+}
+
+impl<'a> BookmarkRepository for SQLiteBookmarkRepository<'a> {
+    fn find_by_profile_id(&mut self, profile_id: i32) -> CRUDResult<Vec<Bookmark>> {
+        use crate::schema::bookmark::dsl::*;
+
+        let bookmarks = bookmark
+            .filter(fk_profile_id.eq(profile_id))
+            .order((created_at.desc(), id.desc()))
+            .select(BookmarkEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(bookmarks.iter().map(Bookmark::from).collect())
+    }
+
+    fn find_by_question_id(
+        &mut self,
+        profile_id: i32,
+        question_id: i32,
+    ) -> CRUDResult<Option<Bookmark>> {
+        use crate::schema::bookmark::dsl::*;
+
+        let found = bookmark
+            .filter(fk_profile_id.eq(profile_id))
+            .filter(fk_question_id.eq(question_id))
+            .select(BookmarkEntity::as_select())
+            .first(self.conn)
+            .optional()
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(found.as_ref().map(Bookmark::from))
+    }
+
+    fn create(&mut self, entry: &Bookmark) -> CRUDResult<Bookmark> {
+        use crate::schema::bookmark::dsl::*;
+
+        let created: BookmarkEntity = diesel::insert_into(bookmark)
+            .values(NewBookmark::from(entry))
+            .returning(BookmarkEntity::as_returning())
+            .get_result(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(Bookmark::from(&created))
+    }
+
+    fn delete(&mut self, bookmark_id: i32) -> CRUDResult<usize> {
+        use crate::schema::bookmark::dsl::*;
+
+        diesel::delete(bookmark.find(bookmark_id))
+            .execute(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))
+    }
+}
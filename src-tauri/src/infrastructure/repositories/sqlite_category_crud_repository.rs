@@ -152,4 +152,18 @@ impl CategoryRepository<Category> for SQLiteCategoryCrudRepository<'_> {
             result.total_pages,
         ))
     }
-}
\ No newline at end of file
+
+    fn find_by_name(&mut self, category_name: &str) -> CRUDResult<Option<Category>> {
+        use crate::schema::category::dsl::*;
+
+        let result = category
+            .filter(name.eq(category_name))
+            .order(id.asc())
+            .select(CategoryEntity::as_select())
+            .first(self.conn)
+            .optional()
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(result.as_ref().map(Category::from))
+    }
+}
@@ -6,6 +6,7 @@ use crate::domain::entities::assignment_option_entity::AssignmentOptionEntity;
 use crate::domain::entities::category_entity::CategoryEntity;
 use crate::domain::entities::exam_entity::ExamEntity;
 use crate::domain::entities::question_entity::QuestionEntity;
+use crate::domain::entities::source_reference_entity::SourceReferenceEntity;
 use crate::domain::model::answer::Answer;
 use crate::domain::model::assignment_option::AssignmentOption;
 use crate::domain::model::category::Category;
@@ -15,6 +16,7 @@ use crate::domain::model::filter_option::FilterTree;
 use crate::domain::model::page_options::PageOptions;
 use crate::domain::model::paged_result::PagedResult;
 use crate::domain::model::question::Question;
+use crate::domain::model::source_reference::SourceReference;
 use crate::domain::model::status_type::StatusType;
 use crate::domain::traits::validation::Validation;
 use crate::infrastructure::filter::exam_entity_column_resolver::ExamEntityColumnResolver;
@@ -261,6 +263,12 @@ impl ExamRepository<Exam> for SQLiteExamCrudRepository<'_> {
                         .load(self.conn)
                         .map_err(|e| CRUDError::new(e.to_string(), None))
                         .unwrap();
+                let sources: Vec<SourceReferenceEntity> = SourceReferenceEntity::belonging_to(q)
+                    .order(crate::schema::source_reference::position.asc())
+                    .select(SourceReferenceEntity::as_select())
+                    .load(self.conn)
+                    .map_err(|e| CRUDError::new(e.to_string(), None))
+                    .unwrap();
 
                 let mut question = Question::new(
                     Question::from(q),
@@ -274,6 +282,7 @@ impl ExamRepository<Exam> for SQLiteExamCrudRepository<'_> {
                 if let Some(category_entity) = c {
                     question.category = Option::from(Category::from(category_entity));
                 }
+                question.sources = Some(sources.iter().map(SourceReference::from).collect());
 
                 question
             })
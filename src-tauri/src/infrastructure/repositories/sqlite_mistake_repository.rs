@@ -0,0 +1,73 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::application::crud::mistake_repository_trait::MistakeRepository;
+use crate::domain::entities::mistake_entity::{MistakeEntity, NewMistake, UpdateMistake};
+use crate::domain::model::mistake::Mistake;
+use diesel::prelude::*;
+
+pub struct SQLiteMistakeRepository<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SQLiteMistakeRepository<'a> {
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+}
+
+impl<'a> MistakeRepository for SQLiteMistakeRepository<'a> {
+    fn find_by_profile_id(&mut self, profile_id: i32) -> CRUDResult<Vec<Mistake>> {
+        use crate::schema::mistake::dsl::*;
+
+        let mistakes = mistake
+            .filter(fk_profile_id.eq(profile_id))
+            .order((miss_count.desc(), last_missed_at.desc()))
+            .select(MistakeEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(mistakes.iter().map(Mistake::from).collect())
+    }
+
+    fn find_by_question_ids(
+        &mut self,
+        profile_id: i32,
+        question_ids: &[i32],
+    ) -> CRUDResult<Vec<Mistake>> {
+        use crate::schema::mistake::dsl::*;
+
+        let mistakes = mistake
+            .filter(fk_profile_id.eq(profile_id))
+            .filter(fk_question_id.eq_any(question_ids))
+            .select(MistakeEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(mistakes.iter().map(Mistake::from).collect())
+    }
+
+    fn save(&mut self, entry: &Mistake) -> CRUDResult<Mistake> {
+        use crate::schema::mistake::dsl::*;
+
+        let saved: MistakeEntity = match entry.id {
+            Some(mistake_id) => diesel::update(mistake.find(mistake_id))
+                .set(UpdateMistake::from(entry))
+                .returning(MistakeEntity::as_returning())
+                .get_result(self.conn),
+            None => diesel::insert_into(mistake)
+                .values(NewMistake::from(entry))
+                .returning(MistakeEntity::as_returning())
+                .get_result(self.conn),
+        }
+        .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(Mistake::from(&saved))
+    }
+
+    fn delete(&mut self, mistake_id: i32) -> CRUDResult<usize> {
+        use crate::schema::mistake::dsl::*;
+
+        diesel::delete(mistake.find(mistake_id))
+            .execute(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))
+    }
+}
@@ -0,0 +1,122 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::application::crud::profile_repository_trait::ProfileRepository;
+use crate::domain::entities::profile_entity::{NewProfile, ProfileEntity, UpdateProfile};
+use crate::domain::model::profile::Profile;
+use chrono::Utc;
+use diesel::prelude::*;
+
+pub struct SQLiteProfileRepository<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SQLiteProfileRepository<'a> {
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+}
+
+impl<'a> ProfileRepository for SQLiteProfileRepository<'a> {
+    fn find_all(&mut self) -> CRUDResult<Vec<Profile>> {
+        use crate::schema::profile::dsl::*;
+
+        let profiles = profile
+            .order(name.asc())
+            .select(ProfileEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(profiles.iter().map(Profile::from).collect())
+    }
+
+    fn find_by_id(&mut self, _id: i32) -> CRUDResult<Option<Profile>> {
+        use crate::schema::profile::dsl::*;
+
+        let result = profile
+            .find(_id)
+            .select(ProfileEntity::as_select())
+            .first(self.conn)
+            .optional()
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(result.as_ref().map(Profile::from))
+    }
+
+    fn find_by_name(&mut self, _name: &str) -> CRUDResult<Option<Profile>> {
+        use crate::schema::profile::dsl::*;
+
+        let result = profile
+            .filter(name.eq(_name))
+            .select(ProfileEntity::as_select())
+            .first(self.conn)
+            .optional()
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(result.as_ref().map(Profile::from))
+    }
+
+    fn find_active(&mut self) -> CRUDResult<Option<Profile>> {
+        use crate::schema::profile::dsl::*;
+
+        let result = profile
+            .filter(active.eq(true))
+            .select(ProfileEntity::as_select())
+            .first(self.conn)
+            .optional()
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(result.as_ref().map(Profile::from))
+    }
+
+    fn create(&mut self, entity: &Profile) -> CRUDResult<Profile> {
+        use crate::schema::profile;
+
+        let created_row: ProfileEntity = diesel::insert_into(profile::table)
+            .values(NewProfile::from(entity))
+            .returning(ProfileEntity::as_returning())
+            .get_result(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(Profile::from(&created_row))
+    }
+
+    fn update(&mut self, entity: &Profile) -> CRUDResult<Profile> {
+        use crate::schema::profile::dsl::*;
+
+        let Some(profile_id) = entity.id else {
+            return Err(CRUDError::new("Id is required to update a profile", None));
+        };
+
+        let updated_row: ProfileEntity = diesel::update(profile.find(profile_id))
+            .set(UpdateProfile::from(entity))
+            .returning(ProfileEntity::as_returning())
+            .get_result(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(Profile::from(&updated_row))
+    }
+
+    fn delete(&mut self, _id: i32) -> CRUDResult<usize> {
+        use crate::schema::profile::dsl::*;
+
+        diesel::delete(profile.find(_id))
+            .execute(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))
+    }
+
+    fn activate(&mut self, _id: i32) -> CRUDResult<Profile> {
+        use crate::schema::profile::dsl::*;
+
+        diesel::update(profile.filter(id.ne(_id)))
+            .set(active.eq(false))
+            .execute(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        let activated: ProfileEntity = diesel::update(profile.find(_id))
+            .set((active.eq(true), updated_at.eq(Some(Utc::now().naive_utc()))))
+            .returning(ProfileEntity::as_returning())
+            .get_result(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(Profile::from(&activated))
+    }
+}
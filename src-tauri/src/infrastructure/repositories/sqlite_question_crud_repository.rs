@@ -1,15 +1,22 @@
 use crate::application::crud::crud_repository_trait::{CRUDError, CRUDRepository, CRUDResult};
 use crate::application::crud::question_repository_trait::QuestionRepository;
+use crate::domain::entities::answer_entity::AnswerEntity;
+use crate::domain::entities::assignment_option_entity::AssignmentOptionEntity;
 use crate::domain::entities::category_entity::CategoryEntity;
 use crate::domain::entities::question_entity::{NewQuestion, QuestionEntity};
+use crate::domain::entities::source_reference_entity::SourceReferenceEntity;
+use crate::domain::model::answer::Answer;
+use crate::domain::model::assignment_option::AssignmentOption;
 use crate::domain::model::category::Category;
 use crate::domain::model::page_options::PageOptions;
 use crate::domain::model::paged_result::PagedResult;
 use crate::domain::model::question::Question;
+use crate::domain::model::source_reference::SourceReference;
 use crate::domain::traits::validation::Validation;
 use crate::pagination_repository_impl;
 use crate::schema::question::fk_exam_id;
-use diesel::{ExpressionMethods, NullableExpressionMethods};
+use crate::schema::source_reference;
+use diesel::{BelongingToDsl, ExpressionMethods, NullableExpressionMethods};
 use diesel::{QueryDsl, RunQueryDsl, SelectableHelper, SqliteConnection};
 
 pub struct SQLiteQuestionCrudRepository<'a> {
@@ -20,6 +27,36 @@ impl<'a> SQLiteQuestionCrudRepository<'a> {
     pub fn new(conn: &'a mut SqliteConnection) -> Self {
         Self { conn }
     }
+
+    fn load_relations(
+        &mut self,
+        rows: &[(QuestionEntity, Option<CategoryEntity>)],
+    ) -> CRUDResult<Vec<Question>> {
+        rows.iter()
+            .map(|(q, c)| {
+                let answers: Vec<AnswerEntity> = AnswerEntity::belonging_to(q)
+                    .load(self.conn)
+                    .map_err(|e| CRUDError::new(e.to_string(), None))?;
+                let options: Vec<AssignmentOptionEntity> = AssignmentOptionEntity::belonging_to(q)
+                    .load(self.conn)
+                    .map_err(|e| CRUDError::new(e.to_string(), None))?;
+                let sources: Vec<SourceReferenceEntity> = SourceReferenceEntity::belonging_to(q)
+                    .order(source_reference::position.asc())
+                    .select(SourceReferenceEntity::as_select())
+                    .load(self.conn)
+                    .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+                let mut loaded = Question::new(
+                    Question::from(q),
+                    answers.iter().map(Answer::from).collect(),
+                    options.iter().map(AssignmentOption::from).collect(),
+                );
+                loaded.category = c.as_ref().map(Category::from);
+                loaded.sources = Some(sources.iter().map(SourceReference::from).collect());
+                Ok(loaded)
+            })
+            .collect()
+    }
 }
 
 impl<'a> CRUDRepository<Question> for SQLiteQuestionCrudRepository<'a> {
@@ -136,4 +173,52 @@ impl<'a> QuestionRepository for SQLiteQuestionCrudRepository<'a> {
             result.total_pages,
         ))
     }
+
+    fn find_with_relations(
+        &mut self,
+        exam_id: Option<i32>,
+        category_id: Option<i32>,
+    ) -> CRUDResult<Vec<Question>> {
+        use crate::schema::category;
+        use crate::schema::question::dsl::*;
+
+        let mut query = question
+            .left_join(category::table)
+            .select((
+                QuestionEntity::as_select(),
+                Option::<CategoryEntity>::as_select(),
+            ))
+            .order(id.asc())
+            .into_boxed();
+        if let Some(exam_id) = exam_id {
+            query = query.filter(fk_exam_id.eq(exam_id));
+        }
+        if let Some(category_id) = category_id {
+            query = query.filter(fk_category_id.eq(category_id));
+        }
+
+        let rows: Vec<(QuestionEntity, Option<CategoryEntity>)> = query
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        self.load_relations(&rows)
+    }
+
+    fn find_by_ids_with_relations(&mut self, question_ids: &[i32]) -> CRUDResult<Vec<Question>> {
+        use crate::schema::category;
+        use crate::schema::question::dsl::*;
+
+        let rows: Vec<(QuestionEntity, Option<CategoryEntity>)> = question
+            .left_join(category::table)
+            .filter(id.eq_any(question_ids))
+            .select((
+                QuestionEntity::as_select(),
+                Option::<CategoryEntity>::as_select(),
+            ))
+            .order(id.asc())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        self.load_relations(&rows)
+    }
 }
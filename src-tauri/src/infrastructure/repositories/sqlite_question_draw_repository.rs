@@ -0,0 +1,92 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::application::crud::question_draw_repository_trait::QuestionDrawRepository;
+use crate::domain::entities::question_draw_entity::{
+    NewQuestionDraw, NewQuestionDrawItem, QuestionDrawEntity, QuestionDrawItemEntity,
+};
+use crate::domain::model::question_draw::QuestionDraw;
+use diesel::prelude::*;
+
+pub struct SQLiteQuestionDrawRepository<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SQLiteQuestionDrawRepository<'a> {
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    fn load_items(&mut self, draw: &QuestionDrawEntity) -> CRUDResult<QuestionDraw> {
+        use crate::schema::question_draw_item::dsl::position;
+
+        let items: Vec<QuestionDrawItemEntity> = QuestionDrawItemEntity::belonging_to(draw)
+            .order(position.asc())
+            .select(QuestionDrawItemEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        let mut question_draw = QuestionDraw::from(draw);
+        question_draw.question_ids = items.iter().map(|i| i.fk_question_id).collect();
+        Ok(question_draw)
+    }
+}
+
+impl<'a> QuestionDrawRepository for SQLiteQuestionDrawRepository<'a> {
+    fn create(&mut self, draw: &QuestionDraw) -> CRUDResult<QuestionDraw> {
+        use crate::schema::{question_draw, question_draw_item};
+
+        let created_row: QuestionDrawEntity = diesel::insert_into(question_draw::table)
+            .values(NewQuestionDraw::from(draw))
+            .returning(QuestionDrawEntity::as_returning())
+            .get_result(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        let items: Vec<NewQuestionDrawItem> = draw
+            .question_ids
+            .iter()
+            .enumerate()
+            .map(|(index, question_id)| NewQuestionDrawItem {
+                fk_draw_id: created_row.id,
+                fk_question_id: *question_id,
+                position: index as i32,
+            })
+            .collect();
+        if !items.is_empty() {
+            diesel::insert_into(question_draw_item::table)
+                .values(&items)
+                .execute(self.conn)
+                .map_err(|e| CRUDError::new(e.to_string(), None))?;
+        }
+
+        self.load_items(&created_row)
+    }
+
+    fn find_by_id(&mut self, _id: i32) -> CRUDResult<Option<QuestionDraw>> {
+        use crate::schema::question_draw::dsl::*;
+
+        let result = question_draw
+            .find(_id)
+            .select(QuestionDrawEntity::as_select())
+            .first(self.conn)
+            .optional()
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        match result {
+            Some(draw) => Ok(Some(self.load_items(&draw)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn find_by_exam_id(&mut self, profile_id: i32, exam_id: i32) -> CRUDResult<Vec<QuestionDraw>> {
+        use crate::schema::question_draw::dsl::*;
+
+        let draws = question_draw
+            .filter(fk_profile_id.eq(profile_id))
+            .filter(fk_exam_id.eq(exam_id))
+            .order(id.desc())
+            .select(QuestionDrawEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        draws.iter().map(|d| self.load_items(d)).collect()
+    }
+}
@@ -0,0 +1,72 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::application::crud::review_state_repository_trait::ReviewStateRepository;
+use crate::domain::entities::review_state_entity::{
+    NewReviewState, ReviewStateEntity, UpdateReviewState,
+};
+use crate::domain::model::review_state::ReviewState;
+use diesel::prelude::*;
+
+pub struct SQLiteReviewStateRepository<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SQLiteReviewStateRepository<'a> {
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+}
+
+impl<'a> ReviewStateRepository for SQLiteReviewStateRepository<'a> {
+    fn find_by_question_id(
+        &mut self,
+        profile_id: i32,
+        question_id: i32,
+    ) -> CRUDResult<Option<ReviewState>> {
+        use crate::schema::review_state::dsl::*;
+
+        let result = review_state
+            .filter(fk_profile_id.eq(profile_id))
+            .filter(fk_question_id.eq(question_id))
+            .select(ReviewStateEntity::as_select())
+            .first(self.conn)
+            .optional()
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(result.as_ref().map(ReviewState::from))
+    }
+
+    fn find_by_question_ids(
+        &mut self,
+        profile_id: i32,
+        question_ids: &[i32],
+    ) -> CRUDResult<Vec<ReviewState>> {
+        use crate::schema::review_state::dsl::*;
+
+        let states = review_state
+            .filter(fk_profile_id.eq(profile_id))
+            .filter(fk_question_id.eq_any(question_ids))
+            .select(ReviewStateEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(states.iter().map(ReviewState::from).collect())
+    }
+
+    fn save(&mut self, state: &ReviewState) -> CRUDResult<ReviewState> {
+        use crate::schema::review_state::dsl::*;
+
+        let saved: ReviewStateEntity = match state.id {
+            Some(state_id) => diesel::update(review_state.find(state_id))
+                .set(UpdateReviewState::from(state))
+                .returning(ReviewStateEntity::as_returning())
+                .get_result(self.conn),
+            None => diesel::insert_into(review_state)
+                .values(NewReviewState::from(state))
+                .returning(ReviewStateEntity::as_returning())
+                .get_result(self.conn),
+        }
+        .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(ReviewState::from(&saved))
+    }
+}
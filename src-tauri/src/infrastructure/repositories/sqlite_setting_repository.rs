@@ -0,0 +1,66 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::application::crud::setting_repository_trait::SettingRepository;
+use crate::domain::entities::setting_entity::SettingEntity;
+use crate::domain::model::setting::Setting;
+use diesel::prelude::*;
+
+pub struct SQLiteSettingRepository<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SQLiteSettingRepository<'a> {
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+}
+
+impl<'a> SettingRepository for SQLiteSettingRepository<'a> {
+    fn find_all(&mut self, profile_id: i32) -> CRUDResult<Vec<Setting>> {
+        use crate::schema::setting::dsl::*;
+
+        let settings = setting
+            .filter(fk_profile_id.eq(profile_id))
+            .order(key.asc())
+            .select(SettingEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(settings.iter().map(Setting::from).collect())
+    }
+
+    fn find_by_key(&mut self, profile_id: i32, _key: &str) -> CRUDResult<Option<Setting>> {
+        use crate::schema::setting::dsl::*;
+
+        let result = setting
+            .find((profile_id, _key))
+            .select(SettingEntity::as_select())
+            .first(self.conn)
+            .optional()
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(result.as_ref().map(Setting::from))
+    }
+
+    fn save(&mut self, entity: &Setting) -> CRUDResult<Setting> {
+        use crate::schema::setting::dsl::*;
+
+        if entity.profile_id.is_none() {
+            return Err(CRUDError::new(
+                "Profile id is required to save a setting",
+                None,
+            ));
+        }
+
+        let row = SettingEntity::from(entity);
+        let saved: SettingEntity = diesel::insert_into(setting)
+            .values(&row)
+            .on_conflict((fk_profile_id, key))
+            .do_update()
+            .set(&row)
+            .returning(SettingEntity::as_returning())
+            .get_result(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(Setting::from(&saved))
+    }
+}
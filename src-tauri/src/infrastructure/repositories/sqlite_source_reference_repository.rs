@@ -0,0 +1,58 @@
+use crate::application::crud::crud_repository_trait::{CRUDError, CRUDResult};
+use crate::application::crud::source_reference_repository_trait::SourceReferenceRepository;
+use crate::domain::entities::source_reference_entity::{NewSourceReference, SourceReferenceEntity};
+use crate::domain::model::source_reference::SourceReference;
+use diesel::prelude::*;
+
+pub struct SQLiteSourceReferenceRepository<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SQLiteSourceReferenceRepository<'a> {
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+}
+
+impl<'a> SourceReferenceRepository for SQLiteSourceReferenceRepository<'a> {
+    fn find_by_question_id(&mut self, question_id: i32) -> CRUDResult<Vec<SourceReference>> {
+        use crate::schema::source_reference::dsl::*;
+
+        let sources = source_reference
+            .filter(fk_question_id.eq(question_id))
+            .order((position.asc(), id.asc()))
+            .select(SourceReferenceEntity::as_select())
+            .load(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        Ok(sources.iter().map(SourceReference::from).collect())
+    }
+
+    fn replace_for_question(
+        &mut self,
+        question_id: i32,
+        sources: &[SourceReference],
+    ) -> CRUDResult<Vec<SourceReference>> {
+        use crate::schema::source_reference::dsl::*;
+
+        diesel::delete(source_reference.filter(fk_question_id.eq(question_id)))
+            .execute(self.conn)
+            .map_err(|e| CRUDError::new(e.to_string(), None))?;
+
+        let mut created: Vec<SourceReference> = Vec::with_capacity(sources.len());
+        for (index, source) in sources.iter().enumerate() {
+            let entity = diesel::insert_into(source_reference)
+                .values(NewSourceReference::from_model(
+                    source,
+                    question_id,
+                    index as i32 + 1,
+                ))
+                .returning(SourceReferenceEntity::as_returning())
+                .get_result(self.conn)
+                .map_err(|e| CRUDError::new(e.to_string(), None))?;
+            created.push(SourceReference::from(&entity));
+        }
+
+        Ok(created)
+    }
+}
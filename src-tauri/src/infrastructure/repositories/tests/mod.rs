@@ -1,5 +1,14 @@
 pub(crate) mod sqlite_answer_crud_repository_test;
 pub(crate) mod sqlite_exam_crud_repository_test;
 pub(crate) mod sqlite_assignment_option_crud_repository_test;
+mod sqlite_attempt_crud_repository_test;
 mod sqlite_category_crud_repository_test;
+mod sqlite_profile_repository_test;
 mod sqlite_question_crud_repository_test;
+mod sqlite_question_draw_repository_test;
+mod sqlite_setting_repository_test;
+mod sqlite_review_state_repository_test;
+mod sqlite_mistake_repository_test;
+mod sqlite_bookmark_repository_test;
+mod sqlite_attachment_repository_test;
+mod sqlite_source_reference_repository_test;
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod answer_repository_tests {
+    use crate::domain::model::numeric_answer::NumericAnswer;
     use crate::domain::model::question::Question;
     use crate::domain::model::question_type::QuestionType;
+    use crate::domain::model::tolerance_mode::ToleranceMode;
     use crate::infrastructure::repositories::sqlite_question_crud_repository::SQLiteQuestionCrudRepository;
     use diesel::{Connection, SqliteConnection};
     use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
@@ -28,6 +30,10 @@ mod answer_repository_tests {
             created_at: None,
             updated_at: None,
             question_id: Some(question.id.unwrap()),
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
         };
 
         let created = repo.create(&answer).unwrap();
@@ -55,6 +61,10 @@ mod answer_repository_tests {
                 created_at: None,
                 updated_at: None,
                 question_id: Some(question.id.unwrap()),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             })
             .unwrap();
 
@@ -86,6 +96,10 @@ mod answer_repository_tests {
                 created_at: None,
                 updated_at: None,
                 question_id: Some(question.id.unwrap()),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             })
             .unwrap();
         let _a2 = repo
@@ -98,6 +112,10 @@ mod answer_repository_tests {
                 created_at: None,
                 updated_at: None,
                 question_id: Some(question.id.unwrap()),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             })
             .unwrap();
 
@@ -125,6 +143,10 @@ mod answer_repository_tests {
                 created_at: None,
                 updated_at: None,
                 question_id: Some(question.id.unwrap()),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             })
             .unwrap();
 
@@ -152,6 +174,10 @@ mod answer_repository_tests {
                 created_at: None,
                 updated_at: None,
                 question_id: Some(qid),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             })
             .unwrap();
         let _ = repo
@@ -164,6 +190,10 @@ mod answer_repository_tests {
                 created_at: None,
                 updated_at: None,
                 question_id: Some(qid),
+                match_mode: None,
+                numeric: None,
+                position: None,
+                blank: None,
             })
             .unwrap();
 
@@ -172,6 +202,77 @@ mod answer_repository_tests {
         assert_eq!(list.len(), 2);
     }
 
+    #[test]
+    fn it_should_persist_ordering_positions() {
+        let mut conn = get_connection();
+        let mut question_repo = SQLiteQuestionCrudRepository::new(&mut conn);
+        let question = create_question(&mut question_repo);
+        let qid = question.id.unwrap();
+
+        let mut repo = SQLiteAnswerCrudRepository::new(&mut conn);
+        for (text, position) in [("Plan the change", 2), ("Request the change", 1)] {
+            let _ = repo
+                .create(&Answer {
+                    id: None,
+                    answer_text: text.to_string(),
+                    description: None,
+                    is_correct: None,
+                    assigned_option_id: None,
+                    created_at: None,
+                    updated_at: None,
+                    question_id: Some(qid),
+                    match_mode: None,
+                    numeric: None,
+                    position: Some(position),
+                    blank: None,
+                })
+                .unwrap();
+        }
+
+        let mut list = repo.get_all_for_question(qid).unwrap();
+        list[0].position = Some(3);
+        let updated = repo.update(&list[0]).unwrap();
+        drop_connection();
+        assert_eq!(list[1].position, Some(1));
+        assert_eq!(updated.position, Some(3));
+    }
+
+    #[test]
+    fn it_should_clear_the_unit_of_numeric_answers() {
+        let mut conn = get_connection();
+        let mut question_repo = SQLiteQuestionCrudRepository::new(&mut conn);
+        let question = create_question(&mut question_repo);
+
+        let mut repo = SQLiteAnswerCrudRepository::new(&mut conn);
+        let mut created = repo
+            .create(&Answer {
+                id: None,
+                answer_text: "950".to_string(),
+                description: None,
+                is_correct: Some(true),
+                assigned_option_id: None,
+                created_at: None,
+                updated_at: None,
+                question_id: Some(question.id.unwrap()),
+                match_mode: None,
+                numeric: Some(NumericAnswer {
+                    expected_value: 950.0,
+                    tolerance: 0.05,
+                    tolerance_mode: ToleranceMode::Relative,
+                    unit: Some("Mbit/s".to_string()),
+                }),
+                position: None,
+                blank: None,
+            })
+            .unwrap();
+
+        created.numeric.as_mut().unwrap().unit = None;
+        repo.update(&created).unwrap();
+        let found = repo.find_by_id(created.id.unwrap()).unwrap().unwrap();
+        drop_connection();
+        assert_eq!(found.numeric.unwrap().unit, None);
+    }
+
     #[test]
     fn it_should_remove_all_for_question() {
         let mut conn = get_connection();
@@ -189,6 +290,10 @@ mod answer_repository_tests {
             created_at: None,
             updated_at: None,
             question_id: Some(qid),
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
         }).unwrap();
 
         let deleted = repo.remove_all_for_question(qid).unwrap();
@@ -210,6 +315,10 @@ mod answer_repository_tests {
             created_at: None,
             updated_at: None,
             question_id: None,
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
         };
         let result = repo.create(&answer);
         assert!(result.is_err());
@@ -230,6 +339,10 @@ mod answer_repository_tests {
             created_at: None,
             updated_at: None,
             question_id: Some(question.id.unwrap()),
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
         }).unwrap();
 
         created.answer_text = "abc".to_string();
@@ -250,6 +363,10 @@ mod answer_repository_tests {
             created_at: None,
             updated_at: None,
             question_id: None,
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
         };
         let result = repo.update(&answer);
         assert!(result.is_err());
@@ -277,6 +394,9 @@ mod answer_repository_tests {
             updated_at: None,
             options: None,
             exam_id: Option::from(1),
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
         };
 
         repository.create(&question).unwrap()
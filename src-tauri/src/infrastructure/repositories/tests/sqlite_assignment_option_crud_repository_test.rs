@@ -235,6 +235,9 @@ mod assignment_option_repository_tests {
             updated_at: None,
             options: None,
             exam_id: Some(1),
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
         };
 
         repository.create(&question).unwrap()
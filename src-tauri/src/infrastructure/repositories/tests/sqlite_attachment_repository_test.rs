@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod attachment_repository_tests {
+    use crate::application::crud::attachment_repository_trait::AttachmentRepository;
+    use crate::application::crud::crud_repository_trait::CRUDRepository;
+    use crate::domain::model::answer::Answer;
+    use crate::domain::model::attachment::Attachment;
+    use crate::domain::model::question::Question;
+    use crate::domain::model::question_type::QuestionType;
+    use crate::infrastructure::repositories::sqlite_answer_crud_repository::SQLiteAnswerCrudRepository;
+    use crate::infrastructure::repositories::sqlite_attachment_repository::SQLiteAttachmentRepository;
+    use crate::infrastructure::repositories::sqlite_question_crud_repository::SQLiteQuestionCrudRepository;
+    use diesel::{Connection, SqliteConnection};
+    use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+    pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+    #[test]
+    fn it_should_store_equal_content_once() {
+        let mut conn = get_connection();
+        let question_id = create_question(&mut conn);
+        let mut repo = SQLiteAttachmentRepository::new(&mut conn);
+
+        let hash = Attachment::content_hash(b"content");
+        repo.save_content(&hash, b"content").unwrap();
+        repo.save_content(&hash, b"content").unwrap();
+        let first = repo
+            .create(&attachment(Some(question_id), None, &hash))
+            .unwrap();
+        let second = repo
+            .create(&attachment(Some(question_id), None, &hash))
+            .unwrap();
+
+        assert_ne!(first.id, second.id);
+        assert_eq!(first.hash, second.hash);
+        assert!(first.created_at.is_some());
+        assert_eq!(repo.find_content(&hash).unwrap(), Some(b"content".to_vec()));
+        assert_eq!(repo.find_content("unknown").unwrap(), None);
+    }
+
+    #[test]
+    fn it_should_find_attachments_of_questions_and_their_answers() {
+        let mut conn = get_connection();
+        let question_id = create_question(&mut conn);
+        let other_question_id = create_question(&mut conn);
+        let answer_id = create_answer(&mut conn, question_id);
+        let mut repo = SQLiteAttachmentRepository::new(&mut conn);
+
+        let hash = Attachment::content_hash(b"content");
+        repo.save_content(&hash, b"content").unwrap();
+        let on_question = repo
+            .create(&attachment(Some(question_id), None, &hash))
+            .unwrap();
+        let on_answer = repo
+            .create(&attachment(None, Some(answer_id), &hash))
+            .unwrap();
+        repo.create(&attachment(Some(other_question_id), None, &hash))
+            .unwrap();
+
+        let found = repo.find_by_question_ids(&[question_id]).unwrap();
+        assert_eq!(
+            found.iter().map(|a| a.id).collect::<Vec<_>>(),
+            vec![on_question.id, on_answer.id]
+        );
+        assert_eq!(repo.find_by_answer_id(answer_id).unwrap().len(), 1);
+        assert_eq!(
+            repo.find_by_id(on_answer.id.unwrap())
+                .unwrap()
+                .unwrap()
+                .answer_id,
+            Some(answer_id)
+        );
+        assert!(repo.find_by_id(999).unwrap().is_none());
+    }
+
+    #[test]
+    fn it_should_delete_orphaned_attachments_and_content() {
+        let mut conn = get_connection();
+        let question_id = create_question(&mut conn);
+        let other_question_id = create_question(&mut conn);
+        let mut repo = SQLiteAttachmentRepository::new(&mut conn);
+
+        let shared = Attachment::content_hash(b"shared");
+        let single = Attachment::content_hash(b"single");
+        repo.save_content(&shared, b"shared").unwrap();
+        repo.save_content(&single, b"single").unwrap();
+        repo.create(&attachment(Some(question_id), None, &shared))
+            .unwrap();
+        repo.create(&attachment(Some(question_id), None, &single))
+            .unwrap();
+        let kept = repo
+            .create(&attachment(Some(other_question_id), None, &shared))
+            .unwrap();
+        assert_eq!(repo.delete_orphans().unwrap(), 0);
+
+        SQLiteQuestionCrudRepository::new(&mut conn)
+            .delete(question_id)
+            .unwrap();
+        let mut repo = SQLiteAttachmentRepository::new(&mut conn);
+        assert_eq!(repo.delete_orphans().unwrap(), 1);
+
+        assert!(repo
+            .find_by_question_ids(&[question_id])
+            .unwrap()
+            .is_empty());
+        assert!(repo.find_content(&single).unwrap().is_none());
+        assert!(repo.find_content(&shared).unwrap().is_some());
+
+        assert_eq!(repo.delete(kept.id.unwrap()).unwrap(), 1);
+        assert_eq!(repo.delete_orphans().unwrap(), 1);
+        assert!(repo.find_content(&shared).unwrap().is_none());
+    }
+
+    fn attachment(question_id: Option<i32>, answer_id: Option<i32>, hash: &str) -> Attachment {
+        Attachment {
+            id: None,
+            question_id,
+            answer_id,
+            file_name: "diagram.png".into(),
+            mime_type: "image/png".into(),
+            hash: hash.into(),
+            size: 7,
+            created_at: None,
+            data: None,
+        }
+    }
+
+    fn create_question(conn: &mut SqliteConnection) -> i32 {
+        let question = Question {
+            id: None,
+            question_text: "Question with attachments".to_string(),
+            points_total: 1,
+            r#type: QuestionType::SingleChoice,
+            answers: vec![],
+            points_per_correct_answer: None,
+            category: None,
+            created_at: None,
+            updated_at: None,
+            options: None,
+            exam_id: Some(1),
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+        };
+        SQLiteQuestionCrudRepository::new(conn)
+            .create(&question)
+            .unwrap()
+            .id
+            .unwrap()
+    }
+
+    fn create_answer(conn: &mut SqliteConnection, question_id: i32) -> i32 {
+        let answer = Answer {
+            id: None,
+            answer_text: "Answer with attachment".into(),
+            description: None,
+            is_correct: Some(true),
+            assigned_option_id: None,
+            created_at: None,
+            updated_at: None,
+            question_id: Some(question_id),
+            match_mode: None,
+            numeric: None,
+            position: None,
+            blank: None,
+        };
+        SQLiteAnswerCrudRepository::new(conn)
+            .create(&answer)
+            .unwrap()
+            .id
+            .unwrap()
+    }
+
+    fn get_connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        connection.run_pending_migrations(MIGRATIONS).unwrap();
+        connection
+    }
+}
@@ -0,0 +1,243 @@
+#[cfg(test)]
+mod attempt_repository_tests {
+    use crate::application::crud::attempt_repository_trait::AttemptRepository;
+    use crate::application::crud::crud_repository_trait::CRUDRepository;
+    use crate::domain::model::attempt_answer::AttemptAnswer;
+    use crate::domain::model::attempt_mode::AttemptMode;
+    use crate::domain::model::exam_attempt::ExamAttempt;
+    use crate::domain::model::page_options::PageOptions;
+    use crate::infrastructure::repositories::sqlite_attempt_crud_repository::SQLiteAttemptCrudRepository;
+    use chrono::Utc;
+    use diesel::{Connection, SqliteConnection};
+    use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+    pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+    #[test]
+    fn it_should_create_attempt() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        let created = repo.create(&sample_attempt(1)).unwrap();
+        assert!(created.id.unwrap() > 0);
+        assert_eq!(created.exam_id, 1);
+        assert_eq!(created.mode, AttemptMode::Simulation);
+        assert!(created.finished_at.is_none());
+        assert!(created.created_at.is_some());
+    }
+
+    #[test]
+    fn it_should_keep_revision_and_question_order() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        let created = repo
+            .create(&ExamAttempt {
+                content_revision: Some("revision".into()),
+                question_ids: vec![7, 3, 5],
+                ..sample_attempt(1)
+            })
+            .unwrap();
+        assert_eq!(created.question_ids, vec![7, 3, 5]);
+
+        let found = repo.find_by_id(created.id.unwrap()).unwrap().unwrap();
+        assert_eq!(found.content_revision.as_deref(), Some("revision"));
+        assert_eq!(found.question_ids, vec![7, 3, 5]);
+    }
+
+    #[test]
+    fn it_should_update_attempt() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        let mut created = repo.create(&sample_attempt(1)).unwrap();
+        created.finished_at = Some(Utc::now());
+        created.score = Some(12);
+        let updated = repo.update(&created).unwrap();
+
+        assert_eq!(updated.score, Some(12));
+        assert!(updated.is_finished());
+        assert!(updated.updated_at.is_some());
+    }
+
+    #[test]
+    fn it_should_fail_update_when_id_is_missing() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        assert!(repo.update(&sample_attempt(1)).is_err());
+    }
+
+    #[test]
+    fn it_should_find_by_id_with_answers() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        let created = repo.create(&sample_attempt(1)).unwrap();
+        let attempt_id = created.id.unwrap();
+        repo.replace_answers_for_question(attempt_id, 4, &[sample_answer(attempt_id, 4, 9)])
+            .unwrap();
+
+        let found = repo.find_by_id(attempt_id).unwrap().unwrap();
+        assert_eq!(found.answers.len(), 1);
+        assert_eq!(found.answers[0].answer_id, Some(9));
+    }
+
+    #[test]
+    fn it_should_fail_find_by_id_not_found() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        assert!(repo.find_by_id(999).is_err());
+    }
+
+    #[test]
+    fn it_should_find_all_paged() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        repo.create(&sample_attempt(1)).unwrap();
+        repo.create(&sample_attempt(1)).unwrap();
+        repo.create(&sample_attempt(2)).unwrap();
+
+        let result = repo
+            .find_all(Some(PageOptions {
+                page: 1,
+                elements_per_page: 2,
+            }))
+            .unwrap();
+        assert_eq!(result.data.len(), 2);
+        assert_eq!(result.total_elements, 3);
+        assert_eq!(result.total_pages, 2);
+    }
+
+    #[test]
+    fn it_should_find_by_exam_id() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        repo.create(&sample_attempt(1)).unwrap();
+        repo.create(&sample_attempt(1)).unwrap();
+        repo.create(&sample_attempt(2)).unwrap();
+
+        assert_eq!(repo.find_by_exam_id(1, 1).unwrap().len(), 2);
+        assert_eq!(repo.find_by_exam_id(1, 2).unwrap().len(), 1);
+        assert!(repo.find_by_exam_id(1, 3).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_should_find_open_attempts_of_profile() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        let open = repo.create(&sample_attempt(1)).unwrap();
+        let mut finished = repo.create(&sample_attempt(1)).unwrap();
+        finished.finished_at = Some(Utc::now());
+        repo.update(&finished).unwrap();
+        let mut other_profile = sample_attempt(1);
+        other_profile.profile_id = 2;
+        repo.create(&other_profile).unwrap();
+
+        let found = repo.find_open(1).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, open.id);
+        assert_eq!(repo.find_open(2).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn it_should_find_finished_attempts_of_profile() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        repo.create(&sample_attempt(1)).unwrap();
+        let mut finished = repo.create(&sample_attempt(1)).unwrap();
+        finished.finished_at = Some(Utc::now());
+        repo.update(&finished).unwrap();
+
+        let found = repo.find_finished(1).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, finished.id);
+        assert!(repo.find_finished(2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_should_replace_answers_for_question() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        let attempt_id = repo.create(&sample_attempt(1)).unwrap().id.unwrap();
+        repo.replace_answers_for_question(
+            attempt_id,
+            4,
+            &[
+                sample_answer(attempt_id, 4, 1),
+                sample_answer(attempt_id, 4, 2),
+            ],
+        )
+        .unwrap();
+        repo.replace_answers_for_question(attempt_id, 5, &[sample_answer(attempt_id, 5, 7)])
+            .unwrap();
+
+        let replaced = repo
+            .replace_answers_for_question(attempt_id, 4, &[sample_answer(attempt_id, 4, 3)])
+            .unwrap();
+        assert_eq!(replaced.len(), 1);
+
+        let answers = repo.find_answers_by_attempt_id(attempt_id).unwrap();
+        assert_eq!(answers.len(), 2);
+        assert!(answers.iter().any(|a| a.answer_id == Some(3)));
+        assert!(answers.iter().any(|a| a.answer_id == Some(7)));
+    }
+
+    #[test]
+    fn it_should_delete_attempt() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteAttemptCrudRepository::new(&mut conn);
+
+        let attempt_id = repo.create(&sample_attempt(1)).unwrap().id.unwrap();
+        assert_eq!(repo.delete(attempt_id).unwrap(), 1);
+        assert_eq!(repo.delete(attempt_id).unwrap(), 0);
+    }
+
+    fn sample_attempt(exam_id: i32) -> ExamAttempt {
+        ExamAttempt {
+            id: None,
+            exam_id,
+            profile_id: 1,
+            mode: AttemptMode::Simulation,
+            started_at: Utc::now(),
+            finished_at: None,
+            score: None,
+            created_at: None,
+            updated_at: None,
+            deadline_at: None,
+            expired: false,
+            shuffle_seed: None,
+            draw_id: None,
+            content_revision: None,
+            question_ids: vec![],
+            answers: vec![],
+        }
+    }
+
+    fn sample_answer(attempt_id: i32, question_id: i32, answer_id: i32) -> AttemptAnswer {
+        AttemptAnswer {
+            id: None,
+            attempt_id: Some(attempt_id),
+            question_id,
+            answer_id: Some(answer_id),
+            assigned_option_id: None,
+            time_spent_seconds: None,
+            created_at: None,
+            text_response: None,
+            position: None,
+            blank: None,
+        }
+    }
+
+    fn get_connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        connection.run_pending_migrations(MIGRATIONS).unwrap();
+        connection
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod bookmark_repository_tests {
+    use crate::application::crud::bookmark_repository_trait::BookmarkRepository;
+    use crate::domain::model::bookmark::Bookmark;
+    use crate::infrastructure::repositories::sqlite_bookmark_repository::SQLiteBookmarkRepository;
+    use diesel::{Connection, SqliteConnection};
+    use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+    pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+    #[test]
+    fn it_should_create_and_find_bookmarks_of_a_profile() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteBookmarkRepository::new(&mut conn);
+
+        let created = repo.create(&Bookmark::new(1, 3)).unwrap();
+        assert!(created.id.is_some());
+        assert!(created.created_at.is_some());
+        repo.create(&Bookmark::new(1, 4)).unwrap();
+        repo.create(&Bookmark::new(2, 3)).unwrap();
+
+        let bookmarks = repo.find_by_profile_id(1).unwrap();
+        assert_eq!(bookmarks.len(), 2);
+        assert_eq!(bookmarks[0].question_id, 4);
+        assert_eq!(
+            repo.find_by_question_id(2, 3).unwrap().unwrap().profile_id,
+            2
+        );
+        assert!(repo.find_by_question_id(2, 4).unwrap().is_none());
+    }
+
+    #[test]
+    fn it_should_keep_one_bookmark_per_question_and_delete_it() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteBookmarkRepository::new(&mut conn);
+
+        let created = repo.create(&Bookmark::new(1, 3)).unwrap();
+        assert!(repo.create(&Bookmark::new(1, 3)).is_err());
+
+        assert_eq!(repo.delete(created.id.unwrap()).unwrap(), 1);
+        assert!(repo.find_by_profile_id(1).unwrap().is_empty());
+    }
+
+    fn get_connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        connection.run_pending_migrations(MIGRATIONS).unwrap();
+        connection
+    }
+}
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod category_repository_tests {
+    use crate::application::crud::category_repository_trait::CategoryRepository;
     use crate::application::crud::crud_repository_trait::CRUDRepository;
     use crate::domain::model::category::Category;
     use crate::infrastructure::repositories::sqlite_category_crud_repository::SQLiteCategoryCrudRepository;
@@ -128,4 +129,15 @@ mod category_repository_tests {
         assert!(result.is_err());
         assert!(format!("{}", result.unwrap_err()).contains("Id of Category can not be null!"));
     }
+
+    #[test]
+    fn it_should_find_category_by_name() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteCategoryCrudRepository::new(&mut conn);
+        let created = create_category(&mut repo);
+
+        let found = repo.find_by_name("Networking").unwrap();
+        assert_eq!(found.unwrap().id, created.id);
+        assert!(repo.find_by_name("networking").unwrap().is_none());
+    }
 }
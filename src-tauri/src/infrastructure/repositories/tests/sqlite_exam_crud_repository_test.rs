@@ -241,6 +241,9 @@ fn add_question_to_exam(conn: &mut SqliteConnection, exam_id: i32, text: &str) {
         updated_at: None,
         options: None,
         exam_id: Some(exam_id),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
     };
     q_repo.create(&q).unwrap();
 }
@@ -310,6 +313,9 @@ fn find_by_id_with_relations_test() {
         updated_at: None,
         options: None,
         exam_id: Some(exam.id.unwrap()),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
     };
     let created_question = question_repo.create(&question).unwrap();
 
@@ -341,6 +347,10 @@ fn find_by_id_with_relations_test() {
         created_at: None,
         updated_at: None,
         question_id: Some(created_question.id.unwrap()),
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
     };
     let a2 = DomainAnswer {
         id: None,
@@ -351,6 +361,10 @@ fn find_by_id_with_relations_test() {
         created_at: None,
         updated_at: None,
         question_id: Some(created_question.id.unwrap()),
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
     };
     let _ = answer_repo.create(&a1).unwrap();
     let _ = answer_repo.create(&a2).unwrap();
@@ -415,6 +429,9 @@ fn it_should_get_overall_statistics() {
             updated_at: None,
             options: None,
             exam_id: Some(exam1.id.unwrap()),
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
         };
         q_repo.create(&q).unwrap();
     }
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod mistake_repository_tests {
+    use crate::application::crud::mistake_repository_trait::MistakeRepository;
+    use crate::domain::model::mistake::Mistake;
+    use crate::infrastructure::repositories::sqlite_mistake_repository::SQLiteMistakeRepository;
+    use chrono::Utc;
+    use diesel::{Connection, SqliteConnection};
+    use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+    pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+    #[test]
+    fn it_should_create_and_update_mistake() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteMistakeRepository::new(&mut conn);
+
+        let mut created = repo.save(&Mistake::new(1, 3, Utc::now())).unwrap();
+        assert!(created.id.is_some());
+        assert!(created.created_at.is_some());
+
+        created.record_miss(Utc::now());
+        let updated = repo.save(&created).unwrap();
+        assert_eq!(updated.id, created.id);
+        assert_eq!(updated.miss_count, 2);
+        assert!(updated.updated_at.is_some());
+    }
+
+    #[test]
+    fn it_should_find_mistakes_of_a_profile_most_missed_first() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteMistakeRepository::new(&mut conn);
+
+        repo.save(&Mistake::new(1, 3, Utc::now())).unwrap();
+        let mut frequent = Mistake::new(1, 4, Utc::now());
+        frequent.miss_count = 4;
+        repo.save(&frequent).unwrap();
+        repo.save(&Mistake::new(2, 5, Utc::now())).unwrap();
+
+        let mistakes = repo.find_by_profile_id(1).unwrap();
+        assert_eq!(mistakes.len(), 2);
+        assert_eq!(mistakes[0].question_id, 4);
+        assert_eq!(repo.find_by_question_ids(1, &[3, 5]).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn it_should_keep_one_mistake_per_question_and_delete_it() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteMistakeRepository::new(&mut conn);
+
+        let created = repo.save(&Mistake::new(1, 3, Utc::now())).unwrap();
+        assert!(repo.save(&Mistake::new(1, 3, Utc::now())).is_err());
+
+        assert_eq!(repo.delete(created.id.unwrap()).unwrap(), 1);
+        assert!(repo.find_by_profile_id(1).unwrap().is_empty());
+    }
+
+    fn get_connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        connection.run_pending_migrations(MIGRATIONS).unwrap();
+        connection
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod profile_repository_tests {
+    use crate::application::crud::profile_repository_trait::ProfileRepository;
+    use crate::domain::model::profile::Profile;
+    use crate::infrastructure::repositories::sqlite_profile_repository::SQLiteProfileRepository;
+    use diesel::{Connection, SqliteConnection};
+    use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+    pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+    #[test]
+    fn it_should_provide_active_default_profile() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteProfileRepository::new(&mut conn);
+
+        let active = repo.find_active().unwrap().unwrap();
+        assert_eq!(active.id, Some(1));
+        assert_eq!(active.name, "Default");
+        assert_eq!(repo.find_all().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn it_should_create_rename_and_delete_profile() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteProfileRepository::new(&mut conn);
+
+        let mut created = repo.create(&Profile::new("Alice")).unwrap();
+        assert!(created.id.is_some());
+        assert!(!created.active);
+        assert!(repo.find_by_name("Alice").unwrap().is_some());
+
+        created.name = "Bob".into();
+        let renamed = repo.update(&created).unwrap();
+        assert_eq!(renamed.name, "Bob");
+        assert!(renamed.updated_at.is_some());
+        assert!(repo.find_by_name("Alice").unwrap().is_none());
+
+        assert_eq!(repo.delete(created.id.unwrap()).unwrap(), 1);
+        assert!(repo.find_by_id(created.id.unwrap()).unwrap().is_none());
+    }
+
+    #[test]
+    fn it_should_reject_duplicate_names() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteProfileRepository::new(&mut conn);
+
+        assert!(repo.create(&Profile::new("Default")).is_err());
+    }
+
+    #[test]
+    fn it_should_activate_exactly_one_profile() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteProfileRepository::new(&mut conn);
+
+        let created = repo.create(&Profile::new("Alice")).unwrap();
+        let activated = repo.activate(created.id.unwrap()).unwrap();
+        assert!(activated.active);
+
+        let active: Vec<Profile> = repo
+            .find_all()
+            .unwrap()
+            .into_iter()
+            .filter(|p| p.active)
+            .collect();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id, created.id);
+    }
+
+    fn get_connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        connection.run_pending_migrations(MIGRATIONS).unwrap();
+        connection
+    }
+}
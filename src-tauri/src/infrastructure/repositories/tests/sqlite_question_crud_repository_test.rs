@@ -157,6 +157,45 @@ mod question_repository_tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    pub fn it_should_find_with_relations_by_exam_and_category() {
+        let mut conn = get_connection();
+        let mut repository = SQLiteQuestionCrudRepository::new(&mut conn);
+
+        create_question(&mut repository);
+        create_question(&mut repository);
+        let mut other_exam_question = create_question_struct();
+        other_exam_question.exam_id = Some(2);
+        repository.create(&other_exam_question).unwrap();
+
+        let result = repository.find_with_relations(Some(1), None).unwrap();
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|q| q.options.is_some()));
+        assert_eq!(repository.find_with_relations(None, None).unwrap().len(), 3);
+        assert!(repository
+            .find_with_relations(None, Some(5))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    pub fn it_should_find_by_ids_with_relations() {
+        let mut conn = get_connection();
+        let mut repository = SQLiteQuestionCrudRepository::new(&mut conn);
+
+        let first = create_question(&mut repository);
+        create_question(&mut repository);
+        let third = create_question(&mut repository);
+
+        let result = repository
+            .find_by_ids_with_relations(&[third.id.unwrap(), first.id.unwrap(), 999])
+            .unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].id, first.id);
+        assert_eq!(result[1].id, third.id);
+        assert!(result.iter().all(|q| q.options.is_some()));
+    }
+
     fn create_question_struct() -> Question {
         Question {
             id: None,
@@ -170,6 +209,9 @@ mod question_repository_tests {
             updated_at: None,
             options: None,
             exam_id: Some(1),
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
         }
     }
 
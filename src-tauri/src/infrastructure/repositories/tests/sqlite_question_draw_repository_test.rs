@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod question_draw_repository_tests {
+    use crate::application::crud::question_draw_repository_trait::QuestionDrawRepository;
+    use crate::domain::model::question_draw::QuestionDraw;
+    use crate::infrastructure::repositories::sqlite_question_draw_repository::SQLiteQuestionDrawRepository;
+    use diesel::{Connection, SqliteConnection};
+    use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+    pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+    #[test]
+    fn it_should_create_draw_keeping_question_order() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteQuestionDrawRepository::new(&mut conn);
+
+        let created = repo.create(&sample_draw(1, vec![5, 2, 9])).unwrap();
+        assert!(created.id.unwrap() > 0);
+        assert_eq!(created.seed, 1234);
+        assert!(created.stratified);
+        assert_eq!(created.question_ids, vec![5, 2, 9]);
+        assert!(created.created_at.is_some());
+    }
+
+    #[test]
+    fn it_should_find_draw_by_id() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteQuestionDrawRepository::new(&mut conn);
+
+        let created = repo.create(&sample_draw(1, vec![3, 1])).unwrap();
+        let found = repo.find_by_id(created.id.unwrap()).unwrap().unwrap();
+        assert_eq!(found.question_ids, vec![3, 1]);
+        assert!(repo.find_by_id(999).unwrap().is_none());
+    }
+
+    #[test]
+    fn it_should_find_draws_by_exam_most_recent_first() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteQuestionDrawRepository::new(&mut conn);
+
+        let first = repo.create(&sample_draw(1, vec![1])).unwrap();
+        let second = repo.create(&sample_draw(1, vec![2])).unwrap();
+        repo.create(&sample_draw(2, vec![3])).unwrap();
+        repo.create(&QuestionDraw {
+            profile_id: 2,
+            ..sample_draw(1, vec![4])
+        })
+        .unwrap();
+
+        let draws = repo.find_by_exam_id(1, 1).unwrap();
+        assert_eq!(draws.len(), 2);
+        assert_eq!(draws[0].id, second.id);
+        assert_eq!(draws[1].id, first.id);
+    }
+
+    #[test]
+    fn it_should_create_draw_without_questions() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteQuestionDrawRepository::new(&mut conn);
+
+        let created = repo.create(&sample_draw(1, vec![])).unwrap();
+        assert!(created.question_ids.is_empty());
+    }
+
+    fn sample_draw(exam_id: i32, question_ids: Vec<i32>) -> QuestionDraw {
+        QuestionDraw {
+            id: None,
+            exam_id,
+            profile_id: 1,
+            seed: 1234,
+            stratified: true,
+            question_ids,
+            created_at: None,
+            questions: vec![],
+        }
+    }
+
+    fn get_connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        connection.run_pending_migrations(MIGRATIONS).unwrap();
+        connection
+    }
+}
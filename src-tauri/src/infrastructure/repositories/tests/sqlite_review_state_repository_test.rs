@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod review_state_repository_tests {
+    use crate::application::crud::review_state_repository_trait::ReviewStateRepository;
+    use crate::domain::model::review_state::ReviewState;
+    use crate::infrastructure::repositories::sqlite_review_state_repository::SQLiteReviewStateRepository;
+    use chrono::Utc;
+    use diesel::{Connection, SqliteConnection};
+    use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+    pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+    #[test]
+    fn it_should_create_and_update_state() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteReviewStateRepository::new(&mut conn);
+
+        let mut created = repo.save(&ReviewState::new(1, 3, Utc::now())).unwrap();
+        assert!(created.id.is_some());
+        assert!(created.created_at.is_some());
+
+        created.repetitions = 2;
+        created.lapses = 1;
+        let updated = repo.save(&created).unwrap();
+        assert_eq!(updated.id, created.id);
+        assert_eq!(updated.repetitions, 2);
+        assert_eq!(updated.lapses, 1);
+        assert!(updated.updated_at.is_some());
+    }
+
+    #[test]
+    fn it_should_find_state_by_question() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteReviewStateRepository::new(&mut conn);
+
+        repo.save(&ReviewState::new(1, 3, Utc::now())).unwrap();
+        repo.save(&ReviewState::new(1, 4, Utc::now())).unwrap();
+
+        assert!(repo.find_by_question_id(1, 3).unwrap().is_some());
+        assert!(repo.find_by_question_id(1, 5).unwrap().is_none());
+        assert_eq!(repo.find_by_question_ids(1, &[3, 4, 5]).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn it_should_keep_one_state_per_question() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteReviewStateRepository::new(&mut conn);
+
+        repo.save(&ReviewState::new(1, 3, Utc::now())).unwrap();
+        assert!(repo.save(&ReviewState::new(1, 3, Utc::now())).is_err());
+    }
+
+    fn get_connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        connection.run_pending_migrations(MIGRATIONS).unwrap();
+        connection
+    }
+}
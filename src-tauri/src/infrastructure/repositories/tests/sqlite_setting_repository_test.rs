@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod setting_repository_tests {
+    use crate::application::crud::setting_repository_trait::SettingRepository;
+    use crate::domain::model::setting::Setting;
+    use crate::infrastructure::repositories::sqlite_setting_repository::SQLiteSettingRepository;
+    use diesel::{Connection, SqliteConnection};
+    use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+    pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+    #[test]
+    fn it_should_insert_and_replace_setting() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteSettingRepository::new(&mut conn);
+
+        let saved = repo.save(&setting("grace", "30")).unwrap();
+        assert_eq!(saved.value, "30");
+        assert!(saved.updated_at.is_some());
+
+        repo.save(&setting("grace", "45")).unwrap();
+        let found = repo.find_by_key(1, "grace").unwrap().unwrap();
+        assert_eq!(found.value, "45");
+        assert_eq!(repo.find_all(1).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn it_should_return_none_for_unknown_key() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteSettingRepository::new(&mut conn);
+
+        assert!(repo.find_by_key(1, "unknown").unwrap().is_none());
+    }
+
+    #[test]
+    fn it_should_list_settings_by_key() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteSettingRepository::new(&mut conn);
+
+        repo.save(&setting("b", "2")).unwrap();
+        repo.save(&setting("a", "1")).unwrap();
+        let keys: Vec<String> = repo
+            .find_all(1)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.key)
+            .collect();
+        assert_eq!(keys, vec!["a", "b"]);
+    }
+
+    fn setting(key: &str, value: &str) -> Setting {
+        Setting {
+            profile_id: Some(1),
+            key: key.into(),
+            value: value.into(),
+            updated_at: None,
+        }
+    }
+
+    fn get_connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        connection.run_pending_migrations(MIGRATIONS).unwrap();
+        connection
+    }
+}
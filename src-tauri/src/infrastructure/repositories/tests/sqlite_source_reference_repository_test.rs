@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod source_reference_repository_tests {
+    use crate::application::crud::source_reference_repository_trait::SourceReferenceRepository;
+    use crate::domain::model::source_reference::SourceReference;
+    use crate::infrastructure::repositories::sqlite_source_reference_repository::SQLiteSourceReferenceRepository;
+    use diesel::{Connection, SqliteConnection};
+    use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+    pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+
+    #[test]
+    fn it_should_replace_sources_in_order() {
+        let mut conn = get_connection();
+        let mut repo = SQLiteSourceReferenceRepository::new(&mut conn);
+
+        let created = repo
+            .replace_for_question(3, &[source("Second edition"), source("Errata")])
+            .unwrap();
+        assert_eq!(created.len(), 2);
+        assert!(created.iter().all(|s| s.id.is_some()));
+        assert!(created.iter().all(|s| s.question_id == Some(3)));
+
+        repo.replace_for_question(4, &[source("Other question")])
+            .unwrap();
+        repo.replace_for_question(3, &[source("Errata"), source("Third edition")])
+            .unwrap();
+
+        let titles: Vec<String> = repo
+            .find_by_question_id(3)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.title)
+            .collect();
+        assert_eq!(titles, vec!["Errata", "Third edition"]);
+        assert_eq!(repo.find_by_question_id(4).unwrap().len(), 1);
+
+        assert!(repo.replace_for_question(3, &[]).unwrap().is_empty());
+        assert!(repo.find_by_question_id(3).unwrap().is_empty());
+    }
+
+    fn source(title: &str) -> SourceReference {
+        SourceReference {
+            id: None,
+            question_id: None,
+            title: title.into(),
+            chapter: Some("1".into()),
+            page: Some("10".into()),
+            url: None,
+        }
+    }
+
+    fn get_connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        connection.run_pending_migrations(MIGRATIONS).unwrap();
+        connection
+    }
+}
@@ -2,14 +2,37 @@ use crate::presentation::answer_invoke_handler::{create_answer, delete_answer, u
 use crate::presentation::assignment_option_invoke_handler::{
     delete_assignment_option, update_assignment_option,
 };
+use crate::presentation::attachment_invoke_handler::{
+    delete_attachment, get_attachment, get_attachments, upload_attachment,
+};
+use crate::presentation::attempt_invoke_handler::{
+    answer_question, delete_attempt, get_attempt, get_attempt_questions, get_attempt_score,
+    get_attempts_by_exam_id, get_open_attempts, resume_attempt, start_attempt, submit_attempt,
+};
+use crate::presentation::backup_invoke_handler::{create_backup, restore_backup};
+use crate::presentation::bookmark_invoke_handler::{add_bookmark, get_bookmarks, remove_bookmark};
 use crate::presentation::category_invoke_handlers::{
     create_category, delete_category, get_categories, get_category_by_id, search_categories,
     update_category,
 };
-use crate::presentation::exam_invoke_handler::{create_exam, delete_exam, find_exam_with_relations, get_exam, get_exam_overall_statistics, get_exams, search_exams, update_exam, validate_exam};
+use crate::presentation::exam_invoke_handler::{create_exam, delete_exam, export_exam, export_exam_to_anki, export_exam_to_gift, export_exam_to_moodle_xml, export_exam_to_qti, find_exam_with_relations, get_exam, get_exam_overall_statistics, get_exams, get_question_statistics, import_exam_bundle, import_exam_text, import_gift, import_moodle_xml, import_qti_package, import_spreadsheet, render_exam, score_exam, search_exams, update_exam, validate_exam};
+use crate::presentation::mistake_invoke_handler::{
+    get_mistake_notebook, get_mistake_practice_set, record_mistake_practice,
+};
+use crate::presentation::practice_invoke_handler::generate_adaptive_practice_set;
+use crate::presentation::profile_invoke_handler::{
+    create_profile, delete_profile, get_active_profile, get_profiles, rename_profile,
+    switch_profile,
+};
+use crate::presentation::question_draw_invoke_handler::{
+    draw_exam_questions, get_question_draws_by_exam_id, replay_question_draw,
+};
 use crate::presentation::question_invoke_handler::{
-    create_question, delete_question, get_question, get_questions_by_exam_id, update_question,
+    create_question, delete_question, get_question, get_questions_by_exam_id, score_question,
+    update_question,
 };
+use crate::presentation::review_invoke_handler::{get_due_questions, record_review};
+use crate::presentation::setting_invoke_handler::{get_settings, update_setting};
 use crate::tauri_plugin_log_initialize::initialize_log_builder;
 
 mod application;
@@ -52,6 +75,57 @@ pub fn run() {
             delete_assignment_option,
             search_categories,
             validate_exam,
+            start_attempt,
+            answer_question,
+            submit_attempt,
+            get_attempt,
+            get_attempts_by_exam_id,
+            delete_attempt,
+            get_attempt_score,
+            score_question,
+            score_exam,
+            draw_exam_questions,
+            replay_question_draw,
+            get_question_draws_by_exam_id,
+            get_settings,
+            update_setting,
+            get_due_questions,
+            record_review,
+            get_question_statistics,
+            get_profiles,
+            get_active_profile,
+            create_profile,
+            rename_profile,
+            delete_profile,
+            switch_profile,
+            get_attempt_questions,
+            get_open_attempts,
+            resume_attempt,
+            get_mistake_notebook,
+            get_mistake_practice_set,
+            record_mistake_practice,
+            get_bookmarks,
+            add_bookmark,
+            remove_bookmark,
+            generate_adaptive_practice_set,
+            upload_attachment,
+            get_attachments,
+            get_attachment,
+            delete_attachment,
+            import_exam_text,
+            export_exam,
+            import_exam_bundle,
+            import_qti_package,
+            export_exam_to_qti,
+            import_gift,
+            export_exam_to_gift,
+            import_moodle_xml,
+            export_exam_to_moodle_xml,
+            import_spreadsheet,
+            export_exam_to_anki,
+            render_exam,
+            create_backup,
+            restore_backup,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,31 @@
+use crate::application::crud::crud_repository_trait::CRUDError;
+use crate::application::usecase::attachment_use_case::AttachmentUseCase;
+use crate::domain::model::attachment::Attachment;
+use crate::presentation::sqlite_connection::get_connection;
+
+#[tauri::command]
+pub fn upload_attachment(attachment: Attachment) -> Result<Attachment, CRUDError> {
+    let mut conn = get_connection()?;
+    AttachmentUseCase::upload_attachment(&mut conn, attachment)
+}
+
+#[tauri::command]
+pub fn get_attachments(
+    question_id: Option<i32>,
+    answer_id: Option<i32>,
+) -> Result<Vec<Attachment>, CRUDError> {
+    let mut conn = get_connection()?;
+    AttachmentUseCase::get_attachments(&mut conn, question_id, answer_id)
+}
+
+#[tauri::command]
+pub fn get_attachment(attachment_id: i32) -> Result<Attachment, CRUDError> {
+    let mut conn = get_connection()?;
+    AttachmentUseCase::get_attachment(&mut conn, attachment_id)
+}
+
+#[tauri::command]
+pub fn delete_attachment(attachment_id: i32) -> Result<usize, CRUDError> {
+    let mut conn = get_connection()?;
+    AttachmentUseCase::delete_attachment(&mut conn, attachment_id)
+}
@@ -0,0 +1,77 @@
+use crate::application::crud::crud_repository_trait::CRUDError;
+use crate::application::usecase::attempt_use_case::AttemptUseCase;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::exam_attempt::ExamAttempt;
+use crate::domain::model::exam_score::ExamScore;
+use crate::domain::model::question::Question;
+use crate::domain::model::resumed_attempt::ResumedAttempt;
+use crate::presentation::sqlite_connection::get_connection;
+
+#[tauri::command]
+pub fn start_attempt(
+    exam_id: i32,
+    mode: AttemptMode,
+    draw_id: Option<i32>,
+) -> Result<ExamAttempt, CRUDError> {
+    let mut conn = get_connection()?;
+    AttemptUseCase::start_attempt(&mut conn, exam_id, mode, draw_id)
+}
+
+#[tauri::command]
+pub fn answer_question(
+    attempt_id: i32,
+    question_id: i32,
+    answers: Vec<AttemptAnswer>,
+) -> Result<Vec<AttemptAnswer>, CRUDError> {
+    let mut conn = get_connection()?;
+    AttemptUseCase::answer_question(&mut conn, attempt_id, question_id, answers)
+}
+
+#[tauri::command]
+pub fn submit_attempt(attempt_id: i32) -> Result<ExamAttempt, CRUDError> {
+    let mut conn = get_connection()?;
+    AttemptUseCase::submit_attempt(&mut conn, attempt_id)
+}
+
+#[tauri::command]
+pub fn get_attempt_score(attempt_id: i32) -> Result<ExamScore, CRUDError> {
+    let mut conn = get_connection()?;
+    AttemptUseCase::get_attempt_score(&mut conn, attempt_id)
+}
+
+#[tauri::command]
+pub fn get_attempt_questions(attempt_id: i32) -> Result<Vec<Question>, CRUDError> {
+    let mut conn = get_connection()?;
+    AttemptUseCase::get_attempt_questions(&mut conn, attempt_id)
+}
+
+#[tauri::command]
+pub fn get_open_attempts() -> Result<Vec<ExamAttempt>, CRUDError> {
+    let mut conn = get_connection()?;
+    AttemptUseCase::get_open_attempts(&mut conn)
+}
+
+#[tauri::command]
+pub fn resume_attempt(id: i32) -> Result<ResumedAttempt, CRUDError> {
+    let mut conn = get_connection()?;
+    AttemptUseCase::resume_attempt(&mut conn, id)
+}
+
+#[tauri::command]
+pub fn get_attempt(id: i32) -> Result<Option<ExamAttempt>, CRUDError> {
+    let mut conn = get_connection()?;
+    AttemptUseCase::get_attempt_by_id(&mut conn, id)
+}
+
+#[tauri::command]
+pub fn get_attempts_by_exam_id(exam_id: i32) -> Result<Vec<ExamAttempt>, CRUDError> {
+    let mut conn = get_connection()?;
+    AttemptUseCase::get_attempts_by_exam_id(&mut conn, exam_id)
+}
+
+#[tauri::command]
+pub fn delete_attempt(id: i32) -> Result<usize, CRUDError> {
+    let mut conn = get_connection()?;
+    AttemptUseCase::delete_attempt(&mut conn, id)
+}
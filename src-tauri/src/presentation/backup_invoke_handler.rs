@@ -0,0 +1,23 @@
+use crate::application::crud::crud_repository_trait::CRUDError;
+use crate::application::usecase::backup_use_case::BackupUseCase;
+use crate::domain::model::backup::Backup;
+use crate::presentation::sqlite_connection::{get_connection, get_database_path, MIGRATIONS};
+use std::path::Path;
+
+#[tauri::command]
+pub fn create_backup(path: String) -> Result<Backup, CRUDError> {
+    let mut conn = get_connection()?;
+    BackupUseCase::create_backup(&mut conn, Path::new(&path))
+}
+
+#[tauri::command]
+pub fn restore_backup(path: String) -> Result<Backup, CRUDError> {
+    let database_path = get_database_path()?;
+    if database_path == ":memory:" {
+        return Err(CRUDError::new(
+            "An in-memory database can't be restored",
+            None,
+        ));
+    }
+    BackupUseCase::restore_backup(Path::new(&database_path), Path::new(&path), MIGRATIONS)
+}
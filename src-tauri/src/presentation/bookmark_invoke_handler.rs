@@ -0,0 +1,22 @@
+use crate::application::crud::crud_repository_trait::CRUDError;
+use crate::application::usecase::bookmark_use_case::BookmarkUseCase;
+use crate::domain::model::bookmark::Bookmark;
+use crate::presentation::sqlite_connection::get_connection;
+
+#[tauri::command]
+pub fn get_bookmarks() -> Result<Vec<Bookmark>, CRUDError> {
+    let mut conn = get_connection()?;
+    BookmarkUseCase::get_bookmarks(&mut conn)
+}
+
+#[tauri::command]
+pub fn add_bookmark(question_id: i32) -> Result<Bookmark, CRUDError> {
+    let mut conn = get_connection()?;
+    BookmarkUseCase::add_bookmark(&mut conn, question_id)
+}
+
+#[tauri::command]
+pub fn remove_bookmark(question_id: i32) -> Result<usize, CRUDError> {
+    let mut conn = get_connection()?;
+    BookmarkUseCase::remove_bookmark(&mut conn, question_id)
+}
@@ -1,12 +1,21 @@
 use crate::application::crud::crud_repository_trait::CRUDError;
 use crate::application::usecase::exam_use_case::ExamUseCase;
+use crate::domain::model::attempt_answer::AttemptAnswer;
 use crate::domain::model::exam::Exam;
 use crate::domain::model::exam_overall_statistics::ExamOverallStatistics;
+use crate::domain::model::exam_score::ExamScore;
+use crate::domain::model::export_report::ExportReport;
 use crate::domain::model::filter_option::FilterTree;
+use crate::domain::model::import_report::ImportReport;
 use crate::domain::model::page_options::PageOptions;
 use crate::domain::model::paged_result::PagedResult;
+use crate::domain::model::print_options::PrintOptions;
+use crate::domain::model::question_statistics::QuestionStatistics;
+use crate::domain::model::spreadsheet_import_report::SpreadsheetImportReport;
+use crate::domain::model::spreadsheet_mapping::SpreadsheetMapping;
 use crate::domain::validation::extended_validation_error::ExtendedValidationError;
 use crate::presentation::sqlite_connection::get_connection;
+use std::path::Path;
 
 #[tauri::command]
 pub fn search_exams(
@@ -60,7 +69,108 @@ pub fn get_exam_overall_statistics() -> Result<ExamOverallStatistics, CRUDError>
     ExamUseCase::get_exam_overall_statistics(&mut conn)
 }
 
+#[tauri::command]
+pub fn get_question_statistics(exam_id: i32) -> Result<Vec<QuestionStatistics>, CRUDError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::get_question_statistics(&mut conn, exam_id)
+}
+
 #[tauri::command]
 pub fn validate_exam(exam: Exam) -> Result<(), ExtendedValidationError> {
     ExamUseCase::validate_exam(&exam)
 }
+
+#[tauri::command]
+pub fn import_exam_text(content: String) -> Result<Exam, ExtendedValidationError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::import_exam_text(&mut conn, &content)
+}
+
+#[tauri::command]
+pub fn export_exam(id: i32, path: String) -> Result<ExportReport, CRUDError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::export_exam(&mut conn, id, Path::new(&path))
+}
+
+#[tauri::command]
+pub fn import_exam_bundle(content: String) -> Result<Exam, ExtendedValidationError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::import_exam_bundle(&mut conn, &content)
+}
+
+#[tauri::command]
+pub fn import_qti_package(content: Vec<u8>) -> Result<ImportReport, ExtendedValidationError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::import_qti_package(&mut conn, &content)
+}
+
+#[tauri::command]
+pub fn export_exam_to_qti(id: i32, path: String) -> Result<ExportReport, CRUDError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::export_exam_to_qti(&mut conn, id, Path::new(&path))
+}
+
+#[tauri::command]
+pub fn import_gift(content: String) -> Result<ImportReport, ExtendedValidationError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::import_gift(&mut conn, &content)
+}
+
+#[tauri::command]
+pub fn export_exam_to_gift(id: i32, path: String) -> Result<ExportReport, CRUDError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::export_exam_to_gift(&mut conn, id, Path::new(&path))
+}
+
+#[tauri::command]
+pub fn import_moodle_xml(content: String) -> Result<ImportReport, ExtendedValidationError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::import_moodle_xml(&mut conn, &content)
+}
+
+#[tauri::command]
+pub fn export_exam_to_moodle_xml(id: i32, path: String) -> Result<ExportReport, CRUDError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::export_exam_to_moodle_xml(&mut conn, id, Path::new(&path))
+}
+
+#[tauri::command]
+pub fn export_exam_to_anki(id: i32, path: String) -> Result<ExportReport, CRUDError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::export_exam_to_anki(&mut conn, id, Path::new(&path))
+}
+
+#[tauri::command]
+pub fn render_exam(
+    id: i32,
+    path: String,
+    options: Option<PrintOptions>,
+) -> Result<ExportReport, CRUDError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::render_exam(
+        &mut conn,
+        id,
+        Path::new(&path),
+        &options.unwrap_or_default(),
+    )
+}
+
+#[tauri::command]
+pub fn import_spreadsheet(
+    exam_id: i32,
+    path: String,
+    mapping: Option<SpreadsheetMapping>,
+) -> Result<SpreadsheetImportReport, ExtendedValidationError> {
+    let mut conn = get_connection()?;
+    ExamUseCase::import_spreadsheet(
+        &mut conn,
+        exam_id,
+        Path::new(&path),
+        &mapping.unwrap_or_default(),
+    )
+}
+
+#[tauri::command]
+pub fn score_exam(exam: Exam, responses: Vec<AttemptAnswer>) -> ExamScore {
+    ExamUseCase::score_exam(&exam, &responses)
+}
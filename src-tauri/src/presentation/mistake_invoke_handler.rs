@@ -0,0 +1,32 @@
+use crate::application::crud::crud_repository_trait::CRUDError;
+use crate::application::usecase::mistake_use_case::MistakeUseCase;
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::mistake_group::MistakeGroup;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_score::QuestionScore;
+use crate::presentation::sqlite_connection::get_connection;
+
+#[tauri::command]
+pub fn get_mistake_notebook() -> Result<Vec<MistakeGroup>, CRUDError> {
+    let mut conn = get_connection()?;
+    MistakeUseCase::get_mistake_notebook(&mut conn)
+}
+
+#[tauri::command]
+pub fn get_mistake_practice_set(
+    exam_id: Option<i32>,
+    category_id: Option<i32>,
+    limit: Option<usize>,
+) -> Result<Vec<Question>, CRUDError> {
+    let mut conn = get_connection()?;
+    MistakeUseCase::get_mistake_practice_set(&mut conn, exam_id, category_id, limit)
+}
+
+#[tauri::command]
+pub fn record_mistake_practice(
+    question_id: i32,
+    answers: Vec<AttemptAnswer>,
+) -> Result<QuestionScore, CRUDError> {
+    let mut conn = get_connection()?;
+    MistakeUseCase::record_practice_answer(&mut conn, question_id, answers)
+}
@@ -1,8 +1,18 @@
 pub mod answer_invoke_handler;
 pub mod assignment_option_invoke_handler;
+pub mod attachment_invoke_handler;
+pub mod attempt_invoke_handler;
+pub mod backup_invoke_handler;
+pub mod bookmark_invoke_handler;
 pub mod category_invoke_handlers;
 pub mod exam_invoke_handler;
+pub mod mistake_invoke_handler;
+pub mod practice_invoke_handler;
+pub mod profile_invoke_handler;
+pub mod question_draw_invoke_handler;
 pub mod question_invoke_handler;
+pub mod review_invoke_handler;
+pub mod setting_invoke_handler;
 mod sqlite_connection;
 
 #[cfg(test)]
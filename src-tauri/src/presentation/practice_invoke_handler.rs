@@ -0,0 +1,14 @@
+use crate::application::crud::crud_repository_trait::CRUDError;
+use crate::application::usecase::practice_use_case::PracticeUseCase;
+use crate::domain::model::adaptive_practice_set::AdaptivePracticeSet;
+use crate::presentation::sqlite_connection::get_connection;
+
+#[tauri::command]
+pub fn generate_adaptive_practice_set(
+    exam_id: i32,
+    count: Option<usize>,
+    seed: Option<i64>,
+) -> Result<AdaptivePracticeSet, CRUDError> {
+    let mut conn = get_connection()?;
+    PracticeUseCase::generate_adaptive_practice_set(&mut conn, exam_id, count, seed)
+}
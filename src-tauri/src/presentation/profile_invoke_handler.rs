@@ -0,0 +1,40 @@
+use crate::application::crud::crud_repository_trait::CRUDError;
+use crate::application::usecase::profile_use_case::ProfileUseCase;
+use crate::domain::model::profile::Profile;
+use crate::presentation::sqlite_connection::get_connection;
+
+#[tauri::command]
+pub fn get_profiles() -> Result<Vec<Profile>, CRUDError> {
+    let mut conn = get_connection()?;
+    ProfileUseCase::get_profiles(&mut conn)
+}
+
+#[tauri::command]
+pub fn get_active_profile() -> Result<Profile, CRUDError> {
+    let mut conn = get_connection()?;
+    ProfileUseCase::get_active_profile(&mut conn)
+}
+
+#[tauri::command]
+pub fn create_profile(name: String) -> Result<Profile, CRUDError> {
+    let mut conn = get_connection()?;
+    ProfileUseCase::create_profile(&mut conn, &name)
+}
+
+#[tauri::command]
+pub fn rename_profile(id: i32, name: String) -> Result<Profile, CRUDError> {
+    let mut conn = get_connection()?;
+    ProfileUseCase::rename_profile(&mut conn, id, &name)
+}
+
+#[tauri::command]
+pub fn delete_profile(id: i32) -> Result<usize, CRUDError> {
+    let mut conn = get_connection()?;
+    ProfileUseCase::delete_profile(&mut conn, id)
+}
+
+#[tauri::command]
+pub fn switch_profile(id: i32) -> Result<Profile, CRUDError> {
+    let mut conn = get_connection()?;
+    ProfileUseCase::switch_profile(&mut conn, id)
+}
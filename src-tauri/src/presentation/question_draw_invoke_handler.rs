@@ -0,0 +1,26 @@
+use crate::application::crud::crud_repository_trait::CRUDError;
+use crate::application::usecase::question_draw_use_case::QuestionDrawUseCase;
+use crate::domain::model::question_draw::QuestionDraw;
+use crate::presentation::sqlite_connection::get_connection;
+
+#[tauri::command]
+pub fn draw_exam_questions(
+    exam_id: i32,
+    seed: Option<i64>,
+    stratify: Option<bool>,
+) -> Result<QuestionDraw, CRUDError> {
+    let mut conn = get_connection()?;
+    QuestionDrawUseCase::draw_questions(&mut conn, exam_id, seed, stratify.unwrap_or(false))
+}
+
+#[tauri::command]
+pub fn replay_question_draw(draw_id: i32) -> Result<QuestionDraw, CRUDError> {
+    let mut conn = get_connection()?;
+    QuestionDrawUseCase::replay_draw(&mut conn, draw_id)
+}
+
+#[tauri::command]
+pub fn get_question_draws_by_exam_id(exam_id: i32) -> Result<Vec<QuestionDraw>, CRUDError> {
+    let mut conn = get_connection()?;
+    QuestionDrawUseCase::get_draws_by_exam_id(&mut conn, exam_id)
+}
@@ -1,8 +1,10 @@
 use crate::application::crud::crud_repository_trait::CRUDError;
 use crate::application::usecase::question_use_case::QuestionUseCase;
+use crate::domain::model::attempt_answer::AttemptAnswer;
 use crate::domain::model::page_options::PageOptions;
 use crate::domain::model::paged_result::PagedResult;
 use crate::domain::model::question::Question;
+use crate::domain::model::question_score::QuestionScore;
 use crate::presentation::sqlite_connection::get_connection;
 
 #[tauri::command]
@@ -37,3 +39,8 @@ pub fn delete_question(id: i32) -> Result<usize, CRUDError> {
     let mut conn = get_connection()?;
     QuestionUseCase::delete_question(&mut conn, id)
 }
+
+#[tauri::command]
+pub fn score_question(question: Question, response: Vec<AttemptAnswer>) -> QuestionScore {
+    QuestionUseCase::score_question(&question, &response)
+}
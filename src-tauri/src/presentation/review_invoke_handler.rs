@@ -0,0 +1,21 @@
+use crate::application::crud::crud_repository_trait::CRUDError;
+use crate::application::usecase::review_use_case::ReviewUseCase;
+use crate::domain::model::question::Question;
+use crate::domain::model::review_state::ReviewState;
+use crate::presentation::sqlite_connection::get_connection;
+
+#[tauri::command]
+pub fn get_due_questions(
+    exam_id: Option<i32>,
+    category_id: Option<i32>,
+    limit: Option<usize>,
+) -> Result<Vec<Question>, CRUDError> {
+    let mut conn = get_connection()?;
+    ReviewUseCase::get_due_questions(&mut conn, exam_id, category_id, limit)
+}
+
+#[tauri::command]
+pub fn record_review(question_id: i32, grade: i32) -> Result<ReviewState, CRUDError> {
+    let mut conn = get_connection()?;
+    ReviewUseCase::record_review(&mut conn, question_id, grade)
+}
@@ -0,0 +1,16 @@
+use crate::application::crud::crud_repository_trait::CRUDError;
+use crate::application::usecase::setting_use_case::SettingUseCase;
+use crate::domain::model::setting::Setting;
+use crate::presentation::sqlite_connection::get_connection;
+
+#[tauri::command]
+pub fn get_settings() -> Result<Vec<Setting>, CRUDError> {
+    let mut conn = get_connection()?;
+    SettingUseCase::get_settings(&mut conn)
+}
+
+#[tauri::command]
+pub fn update_setting(setting: Setting) -> Result<Setting, CRUDError> {
+    let mut conn = get_connection()?;
+    SettingUseCase::update_setting(&mut conn, setting)
+}
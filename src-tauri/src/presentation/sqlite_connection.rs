@@ -6,8 +6,38 @@ use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
 
 pub fn get_connection() -> Result<SqliteConnection, CRUDError> {
-    let mut db_path = String::from(":memory:");
     log::info!("Start connecting to database");
+    let db_path = get_database_path()?;
+
+    let mut conn = SqliteConnection::establish(&db_path).map_err(|e| {
+        CRUDError::new(
+            format!("Unable to connect to database: {}", e.to_string()),
+            None,
+        )
+    })?;
+    
+    conn.run_pending_migrations(MIGRATIONS).map_err(|e| {
+        CRUDError::new(format!("Unable to run pending migrations: {}", e.to_string()), None)
+    })?;
+
+    log::info!("Database connection established successfully, turning on foreign keys");
+
+    diesel::sql_query("PRAGMA foreign_keys = ON;")
+        .execute(&mut conn)
+        .map_err(|e| {
+            CRUDError::new(
+                format!("Unable to set foreign_keys = ON :{}", e.to_string()),
+                None,
+            )
+        })?;
+
+    log::info!("Foreign keys turned on successfully");
+    Ok(conn)
+}
+
+/// Location of the database, created with its directories in production if missing.
+pub fn get_database_path() -> Result<String, CRUDError> {
+    let mut db_path = String::from(":memory:");
 
     if cfg!(test) {
         db_path = std::env::var("TEST_DB_PATH").unwrap_or_else(|_| ":memory:".to_string());
@@ -55,28 +85,5 @@ pub fn get_connection() -> Result<SqliteConnection, CRUDError> {
         db_path = format!("{}/.open-xam/db/open-xam.db", home_path.display());
     }
 
-    let mut conn = SqliteConnection::establish(&db_path).map_err(|e| {
-        CRUDError::new(
-            format!("Unable to connect to database: {}", e.to_string()),
-            None,
-        )
-    })?;
-    
-    conn.run_pending_migrations(MIGRATIONS).map_err(|e| {
-        CRUDError::new(format!("Unable to run pending migrations: {}", e.to_string()), None)
-    })?;
-
-    log::info!("Database connection established successfully, turning on foreign keys");
-
-    diesel::sql_query("PRAGMA foreign_keys = ON;")
-        .execute(&mut conn)
-        .map_err(|e| {
-            CRUDError::new(
-                format!("Unable to set foreign_keys = ON :{}", e.to_string()),
-                None,
-            )
-        })?;
-
-    log::info!("Foreign keys turned on successfully");
-    Ok(conn)
+    Ok(db_path)
 }
@@ -19,6 +19,10 @@ fn test_answer_invoke_handlers() {
         created_at: None,
         updated_at: None,
         question_id: Some(1),
+        match_mode: None,
+        numeric: None,
+        position: None,
+        blank: None,
     };
     
     // This will probably fail due to missing question or validation
@@ -0,0 +1,90 @@
+use crate::domain::model::attachment::Attachment;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::tests::fixtures::answer;
+use crate::presentation::attachment_invoke_handler::*;
+use crate::presentation::exam_invoke_handler::create_exam;
+use crate::presentation::question_invoke_handler::delete_question;
+use crate::presentation::tests::test_utils::{setup, teardown};
+use serial_test::serial;
+
+fn sample_exam() -> Exam {
+    Exam {
+        id: None,
+        name: "Exam for Attachments".into(),
+        description: Some("Description".into()),
+        points_to_succeeded: Some(1),
+        duration: None,
+        status_type: None,
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![Question {
+            id: None,
+            question_text: "Which diagram shows a ring?".into(),
+            points_total: 1,
+            r#type: QuestionType::SingleChoice,
+            answers: vec![
+                answer("The first diagram", true),
+                answer("The second diagram", false),
+            ],
+            points_per_correct_answer: None,
+            category: None,
+            created_at: None,
+            updated_at: None,
+            options: None,
+            exam_id: None,
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+        }],
+    }
+}
+
+fn sample_attachment(question_id: Option<i32>, answer_id: Option<i32>) -> Attachment {
+    Attachment {
+        id: None,
+        question_id,
+        answer_id,
+        file_name: "ring.svg".into(),
+        mime_type: "image/svg+xml".into(),
+        hash: String::new(),
+        size: 0,
+        created_at: None,
+        data: Some(Attachment::encode_data(b"<svg/>")),
+    }
+}
+
+#[test]
+#[serial]
+fn test_attachment_invoke_handlers() {
+    let db_path = setup("test_attachment_invoke");
+
+    let exam = create_exam(sample_exam()).expect("Failed to create exam");
+    let question = &exam.questions[0];
+    let question_id = question.id.unwrap();
+
+    let on_question = upload_attachment(sample_attachment(Some(question_id), None))
+        .expect("Failed to upload attachment");
+    upload_attachment(sample_attachment(None, question.answers[0].id))
+        .expect("Failed to upload attachment");
+    assert!(upload_attachment(sample_attachment(None, None)).is_err());
+
+    let attachments = get_attachments(Some(question_id), None).expect("Failed to get attachments");
+    assert_eq!(attachments.len(), 2);
+
+    let fetched = get_attachment(on_question.id.unwrap()).expect("Failed to get attachment");
+    assert_eq!(fetched.decode_data().unwrap(), Some(b"<svg/>".to_vec()));
+
+    assert_eq!(delete_attachment(on_question.id.unwrap()).unwrap(), 1);
+    assert!(get_attachment(on_question.id.unwrap()).is_err());
+
+    delete_question(question_id).expect("Failed to delete question");
+    assert!(get_attachments(Some(question_id), None)
+        .expect("Failed to get attachments")
+        .is_empty());
+
+    teardown(db_path);
+}
@@ -0,0 +1,112 @@
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures::answer;
+use crate::presentation::attempt_invoke_handler::*;
+use crate::presentation::exam_invoke_handler::create_exam;
+use crate::presentation::tests::test_utils::{setup, teardown};
+use serial_test::serial;
+
+fn sample_exam() -> Exam {
+    Exam {
+        id: None,
+        name: "Exam for Attempts".into(),
+        description: Some("Description".into()),
+        points_to_succeeded: Some(5),
+        duration: Some(60),
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![Question {
+            id: None,
+            question_text: "What is Rust?".into(),
+            points_total: 10,
+            r#type: QuestionType::SingleChoice,
+            answers: vec![answer("A language", true), answer("A fruit", false)],
+            points_per_correct_answer: Some(10),
+            category: None,
+            created_at: None,
+            updated_at: None,
+            options: None,
+            exam_id: None,
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+        }],
+    }
+}
+
+#[test]
+#[serial]
+fn test_attempt_invoke_handlers() {
+    let db_path = setup("test_attempt_invoke");
+
+    let exam = create_exam(sample_exam()).expect("Failed to create exam");
+    let question = &exam.questions[0];
+
+    let attempt = start_attempt(exam.id.unwrap(), AttemptMode::Simulation, None)
+        .expect("Failed to start attempt");
+    let attempt_id = attempt.id.unwrap();
+
+    let questions = get_attempt_questions(attempt_id).expect("Failed to get attempt questions");
+    assert_eq!(questions.len(), exam.questions.len());
+
+    let saved = answer_question(
+        attempt_id,
+        question.id.unwrap(),
+        vec![AttemptAnswer {
+            id: None,
+            attempt_id: None,
+            question_id: question.id.unwrap(),
+            answer_id: question.answers[0].id,
+            assigned_option_id: None,
+            time_spent_seconds: None,
+            created_at: None,
+            text_response: None,
+            position: None,
+            blank: None,
+        }],
+    )
+    .expect("Failed to answer question");
+    assert_eq!(saved.len(), 1);
+
+    let open = get_open_attempts().expect("Failed to list open attempts");
+    assert_eq!(open.len(), 1);
+    let resumed = resume_attempt(attempt_id).expect("Failed to resume attempt");
+    assert_eq!(resumed.attempt.answers.len(), 1);
+
+    let score = get_attempt_score(attempt_id).expect("Failed to score attempt");
+    assert_eq!(score.points_earned, 10);
+    assert_eq!(score.passed, Some(true));
+
+    let submitted = submit_attempt(attempt_id).expect("Failed to submit attempt");
+    assert_eq!(submitted.score, Some(10));
+
+    let found = get_attempt(attempt_id)
+        .expect("Failed to get attempt")
+        .unwrap();
+    assert!(found.is_finished());
+
+    let attempts = get_attempts_by_exam_id(exam.id.unwrap()).expect("Failed to list attempts");
+    assert_eq!(attempts.len(), 1);
+
+    assert_eq!(
+        delete_attempt(attempt_id).expect("Failed to delete attempt"),
+        1
+    );
+
+    teardown(db_path);
+}
+
+#[test]
+#[serial]
+fn test_start_attempt_for_unknown_exam() {
+    let db_path = setup("test_attempt_unknown_exam");
+    assert!(start_attempt(999, AttemptMode::Certification, None).is_err());
+    teardown(db_path);
+}
@@ -0,0 +1,34 @@
+use crate::domain::model::setting::{Setting, ATTEMPT_GRACE_PERIOD_SECONDS};
+use crate::presentation::backup_invoke_handler::*;
+use crate::presentation::setting_invoke_handler::{get_settings, update_setting};
+use crate::presentation::tests::test_utils::{setup, teardown};
+use serial_test::serial;
+
+fn grace_period(value: &str) -> Setting {
+    Setting {
+        profile_id: None,
+        key: ATTEMPT_GRACE_PERIOD_SECONDS.into(),
+        value: value.into(),
+        updated_at: None,
+    }
+}
+
+#[test]
+#[serial]
+fn test_backup_invoke_handlers() {
+    let db_path = setup("test_backup_invoke");
+    let backup_path = db_path.replace(".db", ".backup");
+
+    update_setting(grace_period("10")).expect("Failed to update setting");
+    let backup = create_backup(backup_path.clone()).expect("Failed to create backup");
+    assert_eq!(backup.path, backup_path);
+
+    update_setting(grace_period("60")).expect("Failed to update setting");
+    restore_backup(backup_path.clone()).expect("Failed to restore backup");
+    assert_eq!(get_settings().unwrap()[0].value, "10");
+
+    assert!(restore_backup(db_path.replace(".db", ".missing")).is_err());
+
+    std::fs::remove_file(&backup_path).unwrap();
+    teardown(db_path);
+}
@@ -0,0 +1,65 @@
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures::answer;
+use crate::presentation::bookmark_invoke_handler::*;
+use crate::presentation::exam_invoke_handler::create_exam;
+use crate::presentation::tests::test_utils::{setup, teardown};
+use serial_test::serial;
+
+fn sample_exam() -> Exam {
+    Exam {
+        id: None,
+        name: "Exam for Bookmarks".into(),
+        description: Some("Description".into()),
+        points_to_succeeded: Some(1),
+        duration: None,
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![Question {
+            id: None,
+            question_text: "What is Rust?".into(),
+            points_total: 1,
+            r#type: QuestionType::SingleChoice,
+            answers: vec![answer("A language", true), answer("A fruit", false)],
+            points_per_correct_answer: None,
+            category: None,
+            created_at: None,
+            updated_at: None,
+            options: None,
+            exam_id: None,
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+        }],
+    }
+}
+
+#[test]
+#[serial]
+fn test_bookmark_invoke_handlers() {
+    let db_path = setup("test_bookmark_invoke");
+
+    let exam = create_exam(sample_exam()).expect("Failed to create exam");
+    let question_id = exam.questions[0].id.unwrap();
+
+    let bookmark = add_bookmark(question_id).expect("Failed to add bookmark");
+    assert_eq!(bookmark.question_id, question_id);
+    assert!(add_bookmark(999).is_err());
+
+    let bookmarks = get_bookmarks().expect("Failed to get bookmarks");
+    assert_eq!(bookmarks.len(), 1);
+    assert!(bookmarks[0].question.is_some());
+
+    assert_eq!(
+        remove_bookmark(question_id).expect("Failed to remove bookmark"),
+        1
+    );
+    assert!(get_bookmarks().expect("Failed to get bookmarks").is_empty());
+
+    teardown(db_path);
+}
@@ -1,5 +1,6 @@
 use crate::domain::model::answer::Answer;
 use crate::domain::model::exam::Exam;
+use crate::domain::model::print_options::PrintOptions;
 use crate::domain::model::question::Question;
 use crate::domain::model::question_type::QuestionType;
 use crate::domain::model::status_type::StatusType;
@@ -35,6 +36,10 @@ fn sample_exam() -> Exam {
                         created_at: None,
                         updated_at: None,
                         question_id: None,
+                        match_mode: None,
+                        numeric: None,
+                        position: None,
+                        blank: None,
                     },
                     Answer {
                         id: None,
@@ -45,6 +50,10 @@ fn sample_exam() -> Exam {
                         created_at: None,
                         updated_at: None,
                         question_id: None,
+                        match_mode: None,
+                        numeric: None,
+                        position: None,
+                        blank: None,
                     },
                 ],
                 points_per_correct_answer: Some(10),
@@ -53,6 +62,9 @@ fn sample_exam() -> Exam {
                 updated_at: None,
                 options: None,
                 exam_id: None,
+                ordering_scoring: None,
+                explanation: None,
+                sources: None,
             }
         ],
     }
@@ -92,6 +104,12 @@ fn test_exam_invoke_handlers() {
     let stats = get_exam_overall_statistics().expect("Failed to get statistics");
     assert_eq!(stats.exam_count, 1);
 
+    // Test question statistics without attempts
+    let question_stats =
+        get_question_statistics(created.id.unwrap()).expect("Failed to get question statistics");
+    assert_eq!(question_stats.len(), 1);
+    assert_eq!(question_stats[0].responses, 0);
+
     // Test find with relations
     let found_with_rel = find_exam_with_relations(created.id.unwrap()).expect("Failed to find with relations");
     assert!(found_with_rel.is_some());
@@ -117,3 +135,143 @@ fn test_get_exam_not_found() {
     teardown(db_path);
 
 }
+
+#[test]
+#[serial]
+fn test_import_exam_text() {
+    let db_path = setup("test_import_exam_text");
+    let imported = import_exam_text(
+        "Q: Select one P: 2\nWhich language is this?\n[X] (a) Rust code\n[ ] (b) Python code"
+            .into(),
+    )
+    .expect("Failed to import exam");
+    assert_eq!(imported.questions.len(), 1);
+    assert_eq!(imported.questions[0].answers.len(), 2);
+
+    let err = import_exam_text("Q: Missing points\nWhich one?".into()).unwrap_err();
+    assert_eq!(err.nested_errors[0].line, Some(1));
+    teardown(db_path);
+}
+
+#[test]
+#[serial]
+fn test_export_and_import_exam_bundle() {
+    let db_path = setup("test_export_exam_bundle");
+    let created = create_exam(sample_exam()).expect("Failed to create exam");
+    let path = db_path.replace(".db", ".json");
+
+    let exported = export_exam(created.id.unwrap(), path.clone()).expect("Failed to export");
+    assert_eq!(exported.path, path);
+    assert!(exported.skipped.is_empty());
+    let content = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let imported = import_exam_bundle(content).expect("Failed to import bundle");
+    assert_ne!(imported.id, created.id);
+    assert_eq!(imported.name, created.name);
+    assert_eq!(imported.questions.len(), created.questions.len());
+    teardown(db_path);
+}
+
+#[test]
+#[serial]
+fn test_export_and_import_qti_package() {
+    let db_path = setup("test_export_qti_package");
+    let created = create_exam(sample_exam()).expect("Failed to create exam");
+    let path = db_path.replace(".db", ".zip");
+
+    let exported =
+        export_exam_to_qti(created.id.unwrap(), path.clone()).expect("Failed to export");
+    assert_eq!(exported.path, path);
+    assert!(exported.skipped.is_empty());
+    let content = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let report = import_qti_package(content).expect("Failed to import package");
+    assert_ne!(report.exam.id, created.id);
+    assert_eq!(report.exam.name, created.name);
+    assert_eq!(report.exam.questions.len(), created.questions.len());
+    teardown(db_path);
+}
+
+#[test]
+#[serial]
+fn test_export_and_import_gift_and_moodle_xml() {
+    let db_path = setup("test_export_gift_and_moodle_xml");
+    let created = create_exam(sample_exam()).expect("Failed to create exam");
+    let gift_path = db_path.replace(".db", ".gift");
+    let moodle_path = db_path.replace(".db", ".xml");
+
+    export_exam_to_gift(created.id.unwrap(), gift_path.clone()).expect("Failed to export GIFT");
+    export_exam_to_moodle_xml(created.id.unwrap(), moodle_path.clone())
+        .expect("Failed to export Moodle XML");
+    let gift = std::fs::read_to_string(&gift_path).unwrap();
+    let moodle = std::fs::read_to_string(&moodle_path).unwrap();
+    std::fs::remove_file(&gift_path).unwrap();
+    std::fs::remove_file(&moodle_path).unwrap();
+
+    let report = import_gift(gift).expect("Failed to import GIFT");
+    assert_eq!(report.exam.questions.len(), created.questions.len());
+    let report = import_moodle_xml(moodle).expect("Failed to import Moodle XML");
+    assert_eq!(report.exam.questions.len(), created.questions.len());
+    teardown(db_path);
+}
+
+#[test]
+#[serial]
+fn test_import_spreadsheet() {
+    let db_path = setup("test_import_spreadsheet");
+    let created = create_exam(sample_exam()).expect("Failed to create exam");
+    let path = db_path.replace(".db", ".csv");
+    std::fs::write(
+        &path,
+        "Question,Answers,Correct\nWhich port does HTTPS use?,Port 443|Port 80,x|\n",
+    )
+    .unwrap();
+
+    let report =
+        import_spreadsheet(created.id.unwrap(), path.clone(), None).expect("Failed to import");
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(report.created.len(), 1);
+    assert_eq!(report.created[0].exam_id, created.id);
+    teardown(db_path);
+}
+
+#[test]
+#[serial]
+fn test_export_exam_to_anki() {
+    let db_path = setup("test_export_exam_to_anki");
+    let created = create_exam(sample_exam()).expect("Failed to create exam");
+    let path = db_path.replace(".db", ".apkg");
+
+    let report = export_exam_to_anki(created.id.unwrap(), path.clone()).expect("Failed to export");
+    let data = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(report.skipped.is_empty());
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data)).unwrap();
+    assert!(archive.by_name("collection.anki2").is_ok());
+    teardown(db_path);
+}
+
+#[test]
+#[serial]
+fn test_render_exam() {
+    let db_path = setup("test_render_exam");
+    let created = create_exam(sample_exam()).expect("Failed to create exam");
+    let path = db_path.replace(".db", ".md");
+
+    let options = PrintOptions {
+        answer_key: true,
+        ..PrintOptions::default()
+    };
+    render_exam(created.id.unwrap(), path.clone(), Some(options)).expect("Failed to render");
+    let markdown = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(markdown.starts_with(&format!("# {}\n", created.name)));
+    assert!(markdown.contains("*Answer key*"));
+    assert!(markdown.contains("- [X] "));
+    teardown(db_path);
+}
@@ -0,0 +1,89 @@
+use crate::domain::model::attempt_answer::AttemptAnswer;
+use crate::domain::model::attempt_mode::AttemptMode;
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures::answer;
+use crate::presentation::attempt_invoke_handler::{answer_question, start_attempt, submit_attempt};
+use crate::presentation::exam_invoke_handler::create_exam;
+use crate::presentation::mistake_invoke_handler::*;
+use crate::presentation::tests::test_utils::{setup, teardown};
+use serial_test::serial;
+
+fn sample_exam() -> Exam {
+    Exam {
+        id: None,
+        name: "Exam for Mistakes".into(),
+        description: Some("Description".into()),
+        points_to_succeeded: Some(1),
+        duration: None,
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![Question {
+            id: None,
+            question_text: "What is Rust?".into(),
+            points_total: 1,
+            r#type: QuestionType::SingleChoice,
+            answers: vec![answer("A language", true), answer("A fruit", false)],
+            points_per_correct_answer: None,
+            category: None,
+            created_at: None,
+            updated_at: None,
+            options: None,
+            exam_id: None,
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+        }],
+    }
+}
+
+fn pick(question: &Question, answer_index: usize) -> Vec<AttemptAnswer> {
+    vec![AttemptAnswer {
+        id: None,
+        attempt_id: None,
+        question_id: question.id.unwrap(),
+        answer_id: question.answers[answer_index].id,
+        assigned_option_id: None,
+        time_spent_seconds: None,
+        created_at: None,
+        text_response: None,
+        position: None,
+        blank: None,
+    }]
+}
+
+#[test]
+#[serial]
+fn test_mistake_invoke_handlers() {
+    let db_path = setup("test_mistake_invoke");
+
+    let exam = create_exam(sample_exam()).expect("Failed to create exam");
+    let question = &exam.questions[0];
+
+    let attempt = start_attempt(exam.id.unwrap(), AttemptMode::Simulation, None)
+        .expect("Failed to start attempt");
+    let attempt_id = attempt.id.unwrap();
+    answer_question(attempt_id, question.id.unwrap(), pick(question, 1))
+        .expect("Failed to answer question");
+    submit_attempt(attempt_id).expect("Failed to submit attempt");
+
+    let notebook = get_mistake_notebook().expect("Failed to get mistake notebook");
+    assert_eq!(notebook.len(), 1);
+    assert_eq!(notebook[0].mistakes.len(), 1);
+
+    let practice =
+        get_mistake_practice_set(exam.id, None, Some(5)).expect("Failed to get practice set");
+    assert_eq!(practice.len(), 1);
+
+    let score = record_mistake_practice(question.id.unwrap(), pick(question, 0))
+        .expect("Failed to record practice");
+    assert!(score.is_fully_correct());
+    assert!(record_mistake_practice(999, vec![]).is_err());
+
+    teardown(db_path);
+}
@@ -1,8 +1,18 @@
 pub(crate) mod answer_invoke_handler_test;
 pub(crate) mod assignment_option_invoke_handler_test;
+pub(crate) mod attachment_invoke_handler_test;
+pub(crate) mod attempt_invoke_handler_test;
+pub(crate) mod backup_invoke_handler_test;
+pub(crate) mod bookmark_invoke_handler_test;
 pub(crate) mod category_invoke_handlers_test;
 pub(crate) mod exam_invoke_handler_test;
+pub(crate) mod mistake_invoke_handler_test;
+pub(crate) mod practice_invoke_handler_test;
+pub(crate) mod profile_invoke_handler_test;
+pub(crate) mod question_draw_invoke_handler_test;
 pub(crate) mod question_invoke_handler_test;
+pub(crate) mod review_invoke_handler_test;
+pub(crate) mod setting_invoke_handler_test;
 
 #[cfg(test)]
 pub mod test_utils {
@@ -0,0 +1,66 @@
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures::answer;
+use crate::presentation::exam_invoke_handler::create_exam;
+use crate::presentation::practice_invoke_handler::*;
+use crate::presentation::tests::test_utils::{setup, teardown};
+use serial_test::serial;
+
+fn sample_question(text: &str) -> Question {
+    Question {
+        id: None,
+        question_text: text.into(),
+        points_total: 1,
+        r#type: QuestionType::SingleChoice,
+        answers: vec![answer("A language", true), answer("A fruit", false)],
+        points_per_correct_answer: None,
+        category: None,
+        created_at: None,
+        updated_at: None,
+        options: None,
+        exam_id: None,
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
+    }
+}
+
+fn sample_exam() -> Exam {
+    Exam {
+        id: None,
+        name: "Exam for Practice".into(),
+        description: Some("Description".into()),
+        points_to_succeeded: Some(1),
+        duration: None,
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![
+            sample_question("What is Rust?"),
+            sample_question("What is Cargo?"),
+        ],
+    }
+}
+
+#[test]
+#[serial]
+fn test_practice_invoke_handlers() {
+    let db_path = setup("test_practice_invoke");
+
+    let exam = create_exam(sample_exam()).expect("Failed to create exam");
+
+    let set = generate_adaptive_practice_set(exam.id.unwrap(), Some(1), Some(3))
+        .expect("Failed to generate practice set");
+    assert_eq!(set.seed, 3);
+    assert_eq!(set.questions.len(), 1);
+    assert_eq!(set.category_mastery.len(), 1);
+    assert_eq!(set.category_mastery[0].responses, 0);
+
+    assert!(generate_adaptive_practice_set(999, None, None).is_err());
+
+    teardown(db_path);
+}
@@ -0,0 +1,28 @@
+use crate::presentation::profile_invoke_handler::*;
+use crate::presentation::tests::test_utils::{setup, teardown};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_profile_invoke_handlers() {
+    let db_path = setup("test_profile_invoke");
+
+    let default = get_active_profile().expect("Failed to get active profile");
+    assert_eq!(default.name, "Default");
+
+    let created = create_profile("Alice".into()).expect("Failed to create profile");
+    let id = created.id.unwrap();
+    assert_eq!(get_profiles().expect("Failed to get profiles").len(), 2);
+
+    let renamed = rename_profile(id, "Bob".into()).expect("Failed to rename profile");
+    assert_eq!(renamed.name, "Bob");
+
+    let switched = switch_profile(id).expect("Failed to switch profile");
+    assert!(switched.active);
+    assert!(delete_profile(id).is_err());
+
+    switch_profile(default.id.unwrap()).expect("Failed to switch back");
+    assert_eq!(delete_profile(id).expect("Failed to delete profile"), 1);
+
+    teardown(db_path);
+}
@@ -0,0 +1,82 @@
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures::answer;
+use crate::presentation::exam_invoke_handler::create_exam;
+use crate::presentation::question_draw_invoke_handler::*;
+use crate::presentation::tests::test_utils::{setup, teardown};
+use serial_test::serial;
+
+fn sample_question(text: &str) -> Question {
+    Question {
+        id: None,
+        question_text: text.into(),
+        points_total: 1,
+        r#type: QuestionType::SingleChoice,
+        answers: vec![
+            answer("Correct answer", true),
+            answer("Wrong answer", false),
+        ],
+        points_per_correct_answer: None,
+        category: None,
+        created_at: None,
+        updated_at: None,
+        options: None,
+        exam_id: None,
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
+    }
+}
+
+fn sample_exam() -> Exam {
+    Exam {
+        id: None,
+        name: "Exam for Draws".into(),
+        description: Some("Description".into()),
+        points_to_succeeded: Some(1),
+        duration: Some(30),
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: Some(2),
+        questions: vec![
+            sample_question("First question?"),
+            sample_question("Second question?"),
+            sample_question("Third question?"),
+            sample_question("Fourth question?"),
+        ],
+    }
+}
+
+#[test]
+#[serial]
+fn test_question_draw_invoke_handlers() {
+    let db_path = setup("test_question_draw_invoke");
+
+    let exam = create_exam(sample_exam()).expect("Failed to create exam");
+    let exam_id = exam.id.unwrap();
+
+    let draw = draw_exam_questions(exam_id, Some(7), None).expect("Failed to draw questions");
+    assert_eq!(draw.seed, 7);
+    assert!(!draw.stratified);
+    assert_eq!(draw.questions.len(), 2);
+
+    let replayed = replay_question_draw(draw.id.unwrap()).expect("Failed to replay draw");
+    assert_eq!(replayed.question_ids, draw.question_ids);
+
+    let draws = get_question_draws_by_exam_id(exam_id).expect("Failed to list draws");
+    assert_eq!(draws.len(), 1);
+
+    teardown(db_path);
+}
+
+#[test]
+#[serial]
+fn test_draw_questions_for_unknown_exam() {
+    let db_path = setup("test_question_draw_unknown_exam");
+    assert!(draw_exam_questions(999, None, Some(true)).is_err());
+    teardown(db_path);
+}
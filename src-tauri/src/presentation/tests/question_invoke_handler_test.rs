@@ -36,6 +36,9 @@ fn sample_question(exam_id: i32) -> Question {
         updated_at: None,
         options: None,
         exam_id: Some(exam_id),
+        ordering_scoring: None,
+        explanation: None,
+        sources: None,
     }
 }
 
@@ -0,0 +1,62 @@
+use crate::domain::model::exam::Exam;
+use crate::domain::model::question::Question;
+use crate::domain::model::question_type::QuestionType;
+use crate::domain::model::status_type::StatusType;
+use crate::domain::model::tests::fixtures::answer;
+use crate::presentation::exam_invoke_handler::create_exam;
+use crate::presentation::review_invoke_handler::*;
+use crate::presentation::tests::test_utils::{setup, teardown};
+use serial_test::serial;
+
+fn sample_exam() -> Exam {
+    Exam {
+        id: None,
+        name: "Exam for Reviews".into(),
+        description: Some("Description".into()),
+        points_to_succeeded: Some(1),
+        duration: None,
+        status_type: Some(StatusType::Active),
+        created_at: None,
+        updated_at: None,
+        category: None,
+        max_questions_real_exam: None,
+        questions: vec![Question {
+            id: None,
+            question_text: "What is Rust?".into(),
+            points_total: 1,
+            r#type: QuestionType::SingleChoice,
+            answers: vec![answer("A language", true), answer("A fruit", false)],
+            points_per_correct_answer: None,
+            category: None,
+            created_at: None,
+            updated_at: None,
+            options: None,
+            exam_id: None,
+            ordering_scoring: None,
+            explanation: None,
+            sources: None,
+        }],
+    }
+}
+
+#[test]
+#[serial]
+fn test_review_invoke_handlers() {
+    let db_path = setup("test_review_invoke");
+
+    let exam = create_exam(sample_exam()).expect("Failed to create exam");
+    let question_id = exam.questions[0].id.unwrap();
+
+    let due = get_due_questions(exam.id, None, Some(10)).expect("Failed to get due questions");
+    assert_eq!(due.len(), 1);
+
+    let state = record_review(question_id, 4).expect("Failed to record review");
+    assert_eq!(state.interval_days, 1);
+
+    let due = get_due_questions(exam.id, None, None).expect("Failed to get due questions");
+    assert!(due.is_empty());
+
+    assert!(record_review(question_id, 9).is_err());
+
+    teardown(db_path);
+}
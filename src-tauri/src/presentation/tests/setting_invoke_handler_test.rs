@@ -0,0 +1,32 @@
+use crate::domain::model::setting::{Setting, ATTEMPT_GRACE_PERIOD_SECONDS};
+use crate::presentation::setting_invoke_handler::*;
+use crate::presentation::tests::test_utils::{setup, teardown};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_setting_invoke_handlers() {
+    let db_path = setup("test_setting_invoke");
+
+    let saved = update_setting(Setting {
+        profile_id: None,
+        key: ATTEMPT_GRACE_PERIOD_SECONDS.into(),
+        value: "10".into(),
+        updated_at: None,
+    })
+    .expect("Failed to update setting");
+    assert_eq!(saved.value, "10");
+
+    let settings = get_settings().expect("Failed to get settings");
+    assert_eq!(settings.len(), 1);
+
+    assert!(update_setting(Setting {
+        profile_id: None,
+        key: "".into(),
+        value: "10".into(),
+        updated_at: None,
+    })
+    .is_err());
+
+    teardown(db_path);
+}
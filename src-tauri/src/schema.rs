@@ -10,6 +10,22 @@ diesel::table! {
         updated_at -> Nullable<Timestamp>,
         assigned_option_id -> Nullable<Integer>,
         fk_question_id -> Integer,
+        match_mode -> Nullable<Text>,
+        numeric_value -> Nullable<Double>,
+        tolerance -> Nullable<Double>,
+        tolerance_mode -> Nullable<Text>,
+        unit -> Nullable<Text>,
+        position -> Nullable<Integer>,
+        blank -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    attempt_question (id) {
+        id -> Integer,
+        fk_attempt_id -> Integer,
+        fk_question_id -> Integer,
+        position -> Integer,
     }
 }
 
@@ -22,6 +38,52 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    attachment (id) {
+        id -> Integer,
+        fk_question_id -> Nullable<Integer>,
+        fk_answer_id -> Nullable<Integer>,
+        file_name -> Text,
+        mime_type -> Text,
+        hash -> Text,
+        size -> Integer,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    attachment_blob (hash) {
+        hash -> Text,
+        data -> Binary,
+        size -> Integer,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    attempt_answer (id) {
+        id -> Integer,
+        fk_attempt_id -> Integer,
+        fk_question_id -> Integer,
+        fk_answer_id -> Nullable<Integer>,
+        assigned_option_id -> Nullable<Integer>,
+        created_at -> Nullable<Timestamp>,
+        time_spent_seconds -> Nullable<Integer>,
+        text_response -> Nullable<Text>,
+        position -> Nullable<Integer>,
+        blank -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    bookmark (id) {
+        id -> Integer,
+        fk_profile_id -> Integer,
+        fk_question_id -> Integer,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     category (id) {
         id -> Integer,
@@ -46,6 +108,48 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    exam_attempt (id) {
+        id -> Integer,
+        fk_exam_id -> Integer,
+        mode -> Text,
+        started_at -> Timestamp,
+        finished_at -> Nullable<Timestamp>,
+        score -> Nullable<Integer>,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+        deadline_at -> Nullable<Timestamp>,
+        expired -> Bool,
+        fk_profile_id -> Integer,
+        shuffle_seed -> Nullable<BigInt>,
+        fk_draw_id -> Nullable<Integer>,
+        content_revision -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    mistake (id) {
+        id -> Integer,
+        fk_profile_id -> Integer,
+        fk_question_id -> Integer,
+        miss_count -> Integer,
+        correct_streak -> Integer,
+        last_missed_at -> Timestamp,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    profile (id) {
+        id -> Integer,
+        name -> Text,
+        active -> Bool,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     question (id) {
         id -> Integer,
@@ -57,19 +161,113 @@ diesel::table! {
         updated_at -> Nullable<Timestamp>,
         fk_exam_id -> Integer,
         fk_category_id -> Nullable<Integer>,
+        ordering_scoring -> Nullable<Text>,
+        explanation -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    question_draw (id) {
+        id -> Integer,
+        fk_exam_id -> Integer,
+        seed -> BigInt,
+        stratified -> Bool,
+        created_at -> Nullable<Timestamp>,
+        fk_profile_id -> Integer,
+    }
+}
+
+diesel::table! {
+    question_draw_item (id) {
+        id -> Integer,
+        fk_draw_id -> Integer,
+        fk_question_id -> Integer,
+        position -> Integer,
+    }
+}
+
+diesel::table! {
+    review_state (id) {
+        id -> Integer,
+        fk_question_id -> Integer,
+        ease_factor -> Double,
+        interval_days -> Integer,
+        repetitions -> Integer,
+        lapses -> Integer,
+        due_at -> Timestamp,
+        last_reviewed_at -> Nullable<Timestamp>,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+        fk_profile_id -> Integer,
+    }
+}
+
+diesel::table! {
+    setting (fk_profile_id, key) {
+        fk_profile_id -> Integer,
+        key -> Text,
+        value -> Text,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    source_reference (id) {
+        id -> Integer,
+        fk_question_id -> Integer,
+        title -> Text,
+        chapter -> Nullable<Text>,
+        page -> Nullable<Text>,
+        url -> Nullable<Text>,
+        position -> Integer,
     }
 }
 
 diesel::joinable!(answer -> question (fk_question_id));
 diesel::joinable!(assignment_option -> question (fk_question_id));
+diesel::joinable!(attachment -> answer (fk_answer_id));
+diesel::joinable!(attachment -> attachment_blob (hash));
+diesel::joinable!(attachment -> question (fk_question_id));
+diesel::joinable!(attempt_answer -> answer (fk_answer_id));
+diesel::joinable!(attempt_answer -> exam_attempt (fk_attempt_id));
+diesel::joinable!(attempt_answer -> question (fk_question_id));
+diesel::joinable!(attempt_question -> exam_attempt (fk_attempt_id));
+diesel::joinable!(bookmark -> profile (fk_profile_id));
+diesel::joinable!(bookmark -> question (fk_question_id));
 diesel::joinable!(exam -> category (fk_category_id));
+diesel::joinable!(exam_attempt -> exam (fk_exam_id));
+diesel::joinable!(exam_attempt -> profile (fk_profile_id));
+diesel::joinable!(exam_attempt -> question_draw (fk_draw_id));
+diesel::joinable!(mistake -> profile (fk_profile_id));
+diesel::joinable!(mistake -> question (fk_question_id));
 diesel::joinable!(question -> category (fk_category_id));
 diesel::joinable!(question -> exam (fk_exam_id));
+diesel::joinable!(question_draw -> exam (fk_exam_id));
+diesel::joinable!(question_draw -> profile (fk_profile_id));
+diesel::joinable!(question_draw_item -> question (fk_question_id));
+diesel::joinable!(question_draw_item -> question_draw (fk_draw_id));
+diesel::joinable!(review_state -> profile (fk_profile_id));
+diesel::joinable!(review_state -> question (fk_question_id));
+diesel::joinable!(setting -> profile (fk_profile_id));
+diesel::joinable!(source_reference -> question (fk_question_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     answer,
     assignment_option,
+    attachment,
+    attachment_blob,
+    attempt_answer,
+    attempt_question,
+    bookmark,
     category,
     exam,
+    exam_attempt,
+    mistake,
+    profile,
     question,
+    question_draw,
+    question_draw_item,
+    review_state,
+    setting,
+    source_reference,
 );
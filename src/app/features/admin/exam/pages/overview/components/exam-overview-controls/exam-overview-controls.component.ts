@@ -93,10 +93,10 @@ export class ExamOverviewControlsComponent implements OnDestroy {
                     this.fileErrors.update((prev) => [...prev, examResult]);
                 }
                 this.uploadedFiles.update((prev) => {
-                    const {errorType, success} = examResult;
+                    const {errorType, success, message} = examResult;
                     prev[index].status = {
                         status: !success ? errorType! : 'success',
-                        error: !success ? 'Validation failed' : '',
+                        error: !success ? message ?? 'Validation failed' : '',
                         progress: 100,
                     }
 
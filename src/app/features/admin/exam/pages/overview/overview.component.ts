@@ -4,8 +4,8 @@ import {FormsModule} from '@angular/forms';
 import {ActivatedRoute, Router, RouterModule} from '@angular/router';
 import {FaIconComponent} from '@fortawesome/angular-fontawesome';
 import {faAdd, faFileExport, faPencil, faTrash} from '@fortawesome/free-solid-svg-icons';
-import {BaseDirectory, writeTextFile} from '@tauri-apps/plugin-fs';
-import {BehaviorSubject, Observable} from 'rxjs';
+import {documentDir, join} from '@tauri-apps/api/path';
+import {BehaviorSubject, lastValueFrom, Observable} from 'rxjs';
 import {BadgeComponent} from '../../../../../shared/components/badge/badge.component';
 import {ButtonComponent} from '../../../../../shared/components/button/button.component';
 import {CardComponent} from '../../../../../shared/components/card/card.component';
@@ -167,35 +167,26 @@ export class OverviewComponent {
     public async exportExams(exam: IExam) {
         try {
             this.logger.logInfo(`Start export of exam: ${exam.name} ID: ${exam.id}`, LOG_TAG);
-            const examToExport = {...exam};
-            examToExport.id = 0;
-            examToExport.createdAt = null;
-            examToExport.updatedAt = null;
-            examToExport.questions.forEach(q => {
-                q.id = null;
-                q.examId = null;
-                q.createdAt = null;
-                q.updatedAt = null;
-                q.answers.forEach(a => {
-                    a.id = null;
-                    a.createdAt = null;
-                    a.updatedAt = null;
-                    a.questionId = null;
-                });
-                q.options?.forEach(o => {
-                    o.rowId = null;
-                    o.questionId = null;
-                });
-            })
             const fileName = `exam_${exam.id}_${new Date().getTime()}.json`;
-            await writeTextFile(fileName, JSON.stringify(examToExport, null, 2), {
-                createNew: true,
-                baseDir: BaseDirectory.Document
-            });
+            const path = await join(await documentDir(), fileName);
+            const report = await lastValueFrom(this._examService.exportExam(exam.id!, path));
+            if (!report) {
+                throw new Error(`Export of exam ${exam.id} failed`);
+            }
             this.logger.logInfo(`Exam: ${exam.name} was exported successfully and saved to Documents: ${fileName}`, LOG_TAG);
             this._toast.addSuccessToast(
                 $localize`:@@ox.general.success:Success`,
                 $localize`:@@ox.administration.overview.exportExam.successMessage:The exam was exported successfully to Documents: ${fileName}.`);
+            if (report.skipped.length) {
+                report.skipped.forEach(skipped => this.logger.logWarn(`Question ${skipped.index}: ${skipped.message}`, LOG_TAG));
+                const count = report.skipped.length;
+                this._toast.add({
+                    title: $localize`:@@ox.general.warning:Warning`,
+                    message: $localize`:@@ox.administration.overview.exportExam.missingAttachments:${count} attachments could not be exported because their content is missing.`,
+                    type: 'warning',
+                    date: new Date()
+                });
+            }
         } catch (e) {
             this.logger.logError(LOG_TAG, e);
             this._toast.addErrorToast(
@@ -1,4 +1,6 @@
 import {IAnswer} from '../interfaces/answer.interface';
+import {INumericAnswer} from '../interfaces/numeric-answer.interface';
+import {MatchMode} from '../match-mode.enum';
 
 export default class Answer implements IAnswer {
 
@@ -9,6 +11,10 @@ export default class Answer implements IAnswer {
     createdAt: Date | undefined | null;
     updatedAt: Date | undefined | null;
     assignedOptionId?: number | null;
+    matchMode?: MatchMode | null;
+    numeric?: INumericAnswer | null;
+    position?: number | null;
+    blank?: number | null;
     questionId?: number | null;
 
     constructor(answer: IAnswer) {
@@ -19,6 +25,10 @@ export default class Answer implements IAnswer {
         this.createdAt = answer.createdAt ?? null;
         this.updatedAt = answer.updatedAt ?? null;
         this.assignedOptionId = answer.assignedOptionId ?? null;
+        this.matchMode = answer.matchMode ?? null;
+        this.numeric = answer.numeric ?? null;
+        this.position = answer.position ?? null;
+        this.blank = answer.blank ?? null;
         this.questionId = answer.questionId ?? null;
     }
 }
\ No newline at end of file
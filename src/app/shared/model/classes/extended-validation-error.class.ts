@@ -7,12 +7,16 @@ export default class ExtendedValidationError implements IExtendedValidationError
     message: string;
     errors: IValidationResult[];
     nestedErrors: IExtendedValidationError[];
+    line?: number;
+    column?: number;
 
     constructor(extendedValidationError: IExtendedValidationError) {
         this.index = extendedValidationError.index;
         this.message = extendedValidationError.message;
         this.errors = extendedValidationError.errors?.length ? extendedValidationError.errors.map(error => new ValidationResult(error)) : [];
         this.nestedErrors = extendedValidationError.nestedErrors?.length ? extendedValidationError.nestedErrors.map(nestedError => new ExtendedValidationError(nestedError)) : [];
+        this.line = extendedValidationError.line;
+        this.column = extendedValidationError.column;
     }
 
     public static isExtendedValidationErrorInstance(error: any): error is ExtendedValidationError {
@@ -20,6 +24,6 @@ export default class ExtendedValidationError implements IExtendedValidationError
     }
 
     public static isExtendedValidationErrorType(error: unknown): boolean {
-        return error instanceof Object && ('index' in error || 'message' in error);
+        return error instanceof Object && 'index' in error && 'nestedErrors' in error;
     }
 }
\ No newline at end of file
@@ -14,10 +14,10 @@ export default class IpcErrorHandler {
             return error;
         } else if (error instanceof Error) {
             return error.message;
-        } else if (CurdError.isCrudeErrorInstance(error) || CurdError.isCrudErrorType(error)) {
-            return this.formatCrudError(error as CrudErrorInterface);
         } else if (ExtendedValidationError.isExtendedValidationErrorInstance(error) || ExtendedValidationError.isExtendedValidationErrorType(error)) {
             return this.formatExtendedValidationError(error as IExtendedValidationError);
+        } else if (CurdError.isCrudeErrorInstance(error) || CurdError.isCrudErrorType(error)) {
+            return this.formatCrudError(error as CrudErrorInterface);
         }
 
         return JSON.stringify(error);
@@ -28,7 +28,8 @@ export default class IpcErrorHandler {
         indentLevel: number = 0
     ): string {
         const indent = "  ".repeat(indentLevel);
-        let result = `${indent}- Index ${error.index}: ${error.message}`;
+        const location = error.line ? `Line ${error.line}, column ${error.column ?? 1}` : `Index ${error.index}`;
+        let result = `${indent}- ${location}: ${error.message}`;
 
         if (error.errors && error.errors.length > 0) {
             for (const validationError of error.errors) {
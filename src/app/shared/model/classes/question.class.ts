@@ -6,6 +6,8 @@ import {IQuestion} from "../interfaces/question.interface";
 import {QuestionType} from "../question-type.enum";
 import {IAssignmentOption} from "../interfaces/assignment-option.interface";
 import Answer from "./answer.class";
+import {OrderingScoring} from "../ordering-scoring.enum";
+import {ISourceReference} from "../interfaces/source-reference.interface";
 
 export default class Question implements IQuestion {
   id?: number | undefined | null;
@@ -18,6 +20,9 @@ export default class Question implements IQuestion {
   createdAt?: Date | undefined | null;
   updatedAt?: Date | undefined | null;
   options?: IAssignmentOption[] | undefined;
+  orderingScoring?: OrderingScoring | null;
+  explanation?: string | null;
+  sources?: ISourceReference[];
   examId?: number;
 
   constructor(question: IQuestion) {
@@ -35,5 +40,8 @@ export default class Question implements IQuestion {
     this.createdAt = question.createdAt ?? null;
     this.updatedAt = question.updatedAt ?? null;
     this.examId = question.examId ?? 0;
+    this.orderingScoring = question.orderingScoring ?? null;
+    this.explanation = question.explanation ?? null;
+    this.sources = question.sources?.map((source) => ({...source}));
   }
 }
\ No newline at end of file
@@ -1,3 +1,6 @@
+import {INumericAnswer} from "./numeric-answer.interface";
+import {MatchMode} from "../match-mode.enum";
+
 export interface IAnswer {
   id?: number | null;
   answerText: string;
@@ -6,5 +9,9 @@ export interface IAnswer {
   createdAt?: Date | null;
   updatedAt?: Date | null;
   assignedOptionId?: number | null;
+  matchMode?: MatchMode | null;
+  numeric?: INumericAnswer | null;
+  position?: number | null;
+  blank?: number | null;
   questionId?: number | null;
 }
@@ -0,0 +1,14 @@
+/**
+ * Image or file attached to either a question or an answer.
+ */
+export interface IAttachment {
+    id?: number | null;
+    questionId?: number | null;
+    answerId?: number | null;
+    fileName: string;
+    mimeType: string;
+    hash?: string;          // Hex encoded SHA-256 of the content, set by the backend
+    size?: number;          // Bytes, set by the backend
+    createdAt?: Date | null;
+    data?: string | null;   // Base64 content, required on upload and only returned by getAttachment
+}
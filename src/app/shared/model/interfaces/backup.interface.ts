@@ -0,0 +1,8 @@
+/**
+ * Copy of the whole database, including attachments and settings.
+ */
+export interface IBackup {
+    path: string;
+    size: number;           // Bytes
+    schemaVersion: string;  // Latest migration of the backup when it was created
+}
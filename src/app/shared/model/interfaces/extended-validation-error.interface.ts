@@ -5,4 +5,6 @@ export interface IExtendedValidationError {
     message: string;
     errors: IValidationResult[];
     nestedErrors: IExtendedValidationError[];
+    line?: number;
+    column?: number;
 }
\ No newline at end of file
@@ -3,4 +3,5 @@ export interface IExamImportResult {
     invalidCacheId?: number;
     errorType?: 'validation-error' | 'error';
     id: string;
+    message?: string;
 }
\ No newline at end of file
@@ -0,0 +1,6 @@
+import { IExtendedValidationError } from '../extended-validation-error.interface';
+
+export interface IExportReport {
+    path: string;           // Written file
+    skipped: IExtendedValidationError[];   // Questions the format can't express, index = question position
+}
@@ -0,0 +1,7 @@
+import { IExam } from '../exam.interface';
+import { IExtendedValidationError } from '../extended-validation-error.interface';
+
+export interface IImportReport {
+    exam: IExam;            // Created exam
+    skipped: IExtendedValidationError[];   // Items without an equivalent, index = position in the file
+}
@@ -0,0 +1,8 @@
+import { IQuestion } from '../question.interface';
+import { IExtendedValidationError } from '../extended-validation-error.interface';
+
+export interface ISpreadsheetImportReport {
+    created: IQuestion[];                   // Created questions in row order
+    skipped: IExtendedValidationError[];    // Rows with a question the exam already has, line = row
+    invalid: IExtendedValidationError[];    // Rows that were not imported, problems in nestedErrors
+}
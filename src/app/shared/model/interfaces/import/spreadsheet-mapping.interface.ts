@@ -0,0 +1,16 @@
+/**
+ * Header names of the spreadsheet columns, matched case-insensitively. Omitted fields use the
+ * documented defaults (`Question`, `Type`, `Points`, `Answers`, `Correct`, `Category`, `Options`,
+ * `Explanation` and `|` as separator).
+ */
+export interface ISpreadsheetMapping {
+    questionText?: string;
+    type?: string;
+    points?: string;
+    answers?: string;
+    correct?: string;
+    category?: string;
+    options?: string;
+    explanation?: string;
+    separator?: string;     // Separates answers, correct flags and options within a cell
+}
@@ -0,0 +1,8 @@
+import {ToleranceMode} from "../tolerance-mode.enum";
+
+export interface INumericAnswer {
+    expectedValue: number;
+    tolerance?: number;
+    toleranceMode?: ToleranceMode;
+    unit?: string | null;
+}
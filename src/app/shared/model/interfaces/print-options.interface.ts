@@ -0,0 +1,13 @@
+import { PrintFormat } from '../print-format.enum';
+
+/**
+ * Content of a printable exam. Omitted fields use the defaults: Markdown, stored order,
+ * no solutions, with points and cover page.
+ */
+export interface IPrintOptions {
+    format?: PrintFormat;
+    variant?: number;       // Number of the shuffled variant, starting at 1
+    answerKey?: boolean;    // Solutions instead of the blank answer sheet
+    showPoints?: boolean;
+    coverPage?: boolean;    // Number of questions, total points, duration and points to pass
+}
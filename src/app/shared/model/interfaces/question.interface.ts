@@ -2,6 +2,8 @@ import {IAnswer} from './answer.interface';
 import {ICategory} from "./category.interface";
 import {IAssignmentOption} from "./assignment-option.interface";
 import {QuestionType} from "../question-type.enum";
+import {OrderingScoring} from "../ordering-scoring.enum";
+import {ISourceReference} from "./source-reference.interface";
 export interface IQuestion {
     id?: number | null;
     questionText: string;
@@ -13,5 +15,8 @@ export interface IQuestion {
     createdAt?: Date | null;
     updatedAt?: Date | null;
     options?: IAssignmentOption[];
+    orderingScoring?: OrderingScoring | null;
+    explanation?: string | null;
+    sources?: ISourceReference[];
     examId?: number | null;
 }
\ No newline at end of file
@@ -0,0 +1,8 @@
+export interface ISourceReference {
+    id?: number | null;
+    questionId?: number | null;
+    title: string;
+    chapter?: string | null;
+    page?: string | null;
+    url?: string | null;
+}
@@ -0,0 +1,6 @@
+export enum MatchMode {
+    EXACT = 'EXACT',
+    CASE_INSENSITIVE = 'CASE_INSENSITIVE',
+    WHITESPACE_NORMALIZED = 'WHITESPACE_NORMALIZED',
+    REGEX = 'REGEX'
+}
@@ -0,0 +1,5 @@
+export enum OrderingScoring {
+    ALL_OR_NOTHING = 'ALL_OR_NOTHING',
+    CORRECTLY_PLACED = 'CORRECTLY_PLACED',
+    KENDALL_TAU = 'KENDALL_TAU'
+}
@@ -0,0 +1,5 @@
+export enum PrintFormat {
+    MARKDOWN = 'MARKDOWN',
+    HTML = 'HTML',
+    PDF = 'PDF'
+}
@@ -2,7 +2,11 @@ export enum QuestionType {
   SINGLE_CHOICE = 'SINGLE_CHOICE',
   ASSIGNMENT = 'ASSIGNMENT',
   MULTIPLE_CHOICE = 'MULTIPLE_CHOICE',
-  CODE = 'CODE'
+  CODE = 'CODE',
+  SHORT_ANSWER = 'SHORT_ANSWER',
+  NUMERIC = 'NUMERIC',
+  ORDERING = 'ORDERING',
+  CLOZE = 'CLOZE'
 }
 
 export const questionTypesSelectOptions = () => {
@@ -25,5 +29,13 @@ export const mapQuestionTypeToText = (q: QuestionType) => {
       return $localize`:@@ox.questionType.MULTIPLE_CHOICE:Multiple choice`;
     case QuestionType.CODE:
       return $localize`:@@ox.questionType.CODE:Source Code`;
+    case QuestionType.SHORT_ANSWER:
+      return $localize`:@@ox.questionType.SHORT_ANSWER:Short answer`;
+    case QuestionType.NUMERIC:
+      return $localize`:@@ox.questionType.NUMERIC:Numeric`;
+    case QuestionType.ORDERING:
+      return $localize`:@@ox.questionType.ORDERING:Ordering`;
+    case QuestionType.CLOZE:
+      return $localize`:@@ox.questionType.CLOZE:Cloze`;
   }
 } 
\ No newline at end of file
@@ -0,0 +1,4 @@
+export enum ToleranceMode {
+    ABSOLUTE = 'ABSOLUTE',
+    RELATIVE = 'RELATIVE'
+}
@@ -0,0 +1,112 @@
+import {TestBed} from '@angular/core/testing';
+import {lastValueFrom, firstValueFrom} from 'rxjs';
+import {mockIPC} from '@tauri-apps/api/mocks';
+
+import {AttachmentService} from './attachment.service';
+import {IAttachment} from "../model/interfaces/attachment.interface";
+
+describe('AttachmentService', () => {
+  let service: AttachmentService;
+  const attachment: IAttachment = {
+    id: 1,
+    questionId: 2,
+    fileName: 'diagram.png',
+    mimeType: 'image/png',
+    hash: 'f0e4c2f76c58916ec258f246851bea091d14d4247a2fc3e18694461b1816e13b',
+    size: 7
+  };
+
+  beforeEach(() => {
+    TestBed.configureTestingModule({});
+    service = TestBed.inject(AttachmentService);
+  });
+
+  it('should be created', () => {
+    expect(service).toBeTruthy();
+  });
+
+  describe('uploadAttachment', () => {
+    it('should pass the attachment to the backend', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'upload_attachment') {
+          received = args;
+          return Promise.resolve(attachment);
+        }
+        return Promise.resolve(null);
+      });
+
+      const upload: IAttachment = {questionId: 2, fileName: 'diagram.png', mimeType: 'image/png', data: 'ZGlhZ3JhbQ=='};
+      const result = await lastValueFrom(service.uploadAttachment(upload));
+      expect(result).toEqual(attachment);
+      expect(received).toEqual({attachment: upload});
+    });
+
+    it('should handle a missing question', async () => {
+      mockIPC((cmd) => {
+        if (cmd === 'upload_attachment') {
+          return Promise.reject({message: 'Question not found'});
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.uploadAttachment({questionId: 99, fileName: 'diagram.png', mimeType: 'image/png', data: 'ZGlhZ3JhbQ=='}));
+      expect(result).toBeNull();
+
+      const errors = await firstValueFrom(service.errors$);
+      expect(errors.length).toBe(1);
+      expect(errors[0]).toEqual('Question not found');
+    });
+  });
+
+  describe('getAttachments', () => {
+    it('should pass the owner to the backend', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'get_attachments') {
+          received = args;
+          return Promise.resolve([attachment]);
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.getAttachments(2));
+      expect(result).toEqual([attachment]);
+      expect(received).toEqual({questionId: 2, answerId: null});
+    });
+  });
+
+  describe('getAttachment', () => {
+    it('should return the attachment with its content', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'get_attachment') {
+          received = args;
+          return Promise.resolve({...attachment, data: 'ZGlhZ3JhbQ=='});
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.getAttachment(1));
+      expect(result?.data).toBe('ZGlhZ3JhbQ==');
+      expect(received).toEqual({attachmentId: 1});
+    });
+  });
+
+  describe('deleteAttachment', () => {
+    it('should return the number of deleted attachments', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'delete_attachment') {
+          received = args;
+          return Promise.resolve(1);
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.deleteAttachment(1));
+      expect(result).toBe(1);
+      expect(received).toEqual({attachmentId: 1});
+    });
+  });
+});
@@ -0,0 +1,42 @@
+import { Injectable } from '@angular/core';
+import { Observable } from 'rxjs';
+import { BaseService } from "./base-service";
+import { IAttachment } from "../model/interfaces/attachment.interface";
+
+@Injectable({
+  providedIn: 'root',
+})
+export class AttachmentService extends BaseService {
+
+    /**
+     * Stores the attachment of a question or an answer. The base64 encoded `data` is required.
+     */
+    public uploadAttachment(attachment: IAttachment): Observable<IAttachment | null> {
+        this.setInitialStates();
+        return this.invoke$<IAttachment>('upload_attachment', {attachment});
+    }
+
+    /**
+     * Attachments of a question or an answer, without their content.
+     */
+    public getAttachments(questionId?: number, answerId?: number): Observable<IAttachment[] | null> {
+        this.setInitialStates();
+        return this.invoke$<IAttachment[]>('get_attachments', {
+            questionId: questionId ?? null,
+            answerId: answerId ?? null
+        });
+    }
+
+    /**
+     * Single attachment including its base64 encoded content.
+     */
+    public getAttachment(attachmentId: number): Observable<IAttachment | null> {
+        this.setInitialStates();
+        return this.invoke$<IAttachment>('get_attachment', {attachmentId});
+    }
+
+    public deleteAttachment(attachmentId: number): Observable<number | null> {
+        this.setInitialStates();
+        return this.invoke$<number>('delete_attachment', {attachmentId});
+    }
+}
@@ -0,0 +1,68 @@
+import {TestBed} from '@angular/core/testing';
+import {lastValueFrom, firstValueFrom} from 'rxjs';
+import {mockIPC} from '@tauri-apps/api/mocks';
+
+import {BackupService} from './backup.service';
+import {IBackup} from "../model/interfaces/backup.interface";
+
+describe('BackupService', () => {
+  let service: BackupService;
+  const backup: IBackup = {path: '/home/user/open-xam.backup', size: 4096, schemaVersion: '20261018210000'};
+
+  beforeEach(() => {
+    TestBed.configureTestingModule({});
+    service = TestBed.inject(BackupService);
+  });
+
+  it('should be created', () => {
+    expect(service).toBeTruthy();
+  });
+
+  describe('createBackup', () => {
+    it('should pass the path to the backend', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'create_backup') {
+          received = args;
+          return Promise.resolve(backup);
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.createBackup(backup.path));
+      expect(result).toEqual(backup);
+      expect(received).toEqual({path: backup.path});
+    });
+  });
+
+  describe('restoreBackup', () => {
+    it('should return the restored backup', async () => {
+      mockIPC((cmd) => {
+        if (cmd === 'restore_backup') {
+          return Promise.resolve(backup);
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.restoreBackup(backup.path));
+      expect(result).toEqual(backup);
+    });
+
+    it('should handle a rejected backup', async () => {
+      const errorPayload = {message: 'Backup is damaged'};
+      mockIPC((cmd) => {
+        if (cmd === 'restore_backup') {
+          return Promise.reject(errorPayload);
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.restoreBackup(backup.path));
+      expect(result).toBeNull();
+
+      const errors = await firstValueFrom(service.errors$);
+      expect(errors.length).toBe(1);
+      expect(errors[0]).toEqual('Backup is damaged');
+    });
+  });
+});
@@ -0,0 +1,24 @@
+import { Injectable } from '@angular/core';
+import { Observable } from 'rxjs';
+import { BaseService } from "./base-service";
+import { IBackup } from "../model/interfaces/backup.interface";
+
+@Injectable({
+  providedIn: 'root',
+})
+export class BackupService extends BaseService {
+
+    public createBackup(path: string): Observable<IBackup | null> {
+        this.setInitialStates();
+        return this.invoke$<IBackup>('create_backup', {path});
+    }
+
+    /**
+     * Replaces the whole database with the backup. The backup is checked first, a damaged
+     * backup or one of a newer version is rejected and the database stays unchanged.
+     */
+    public restoreBackup(path: string): Observable<IBackup | null> {
+        this.setInitialStates();
+        return this.invoke$<IBackup>('restore_backup', {path});
+    }
+}
@@ -27,6 +27,11 @@ describe('ExamImportService', () => {
           useValue: {
             validateExam: jest.fn(),
             createExam: jest.fn(),
+            importExamText: jest.fn(),
+            importExamBundle: jest.fn(),
+            importQtiPackage: jest.fn(),
+            importGift: jest.fn(),
+            importMoodleXml: jest.fn(),
           },
         },
       ],
@@ -85,13 +90,8 @@ describe('ExamImportService', () => {
   });
 
   it('should import TXT exam successfully', async () => {
-    jest
-        .spyOn<any, any>(service['_importer'], 'importFromTxt')
-        .mockReturnValue(mockExam);
-
     cacheService.clearInvalidFiles.mockReturnValue(of(void 0));
-    examService.validateExam.mockReturnValue(of(true));
-    examService.createExam.mockReturnValue(of(mockExam));
+    examService.importExamText.mockReturnValue(of(mockExam));
 
     const file = {
       id: 'file-1',
@@ -103,7 +103,109 @@ describe('ExamImportService', () => {
     const result = await service.importExam(file);
 
     expect(result).toEqual({ success: true, id: 'file-1' });
-    expect(examService.createExam).toHaveBeenCalledWith(mockExam);
+    expect(examService.importExamText).toHaveBeenCalledWith('test');
+    expect(examService.validateExam).not.toHaveBeenCalled();
+    expect(examService.createExam).not.toHaveBeenCalled();
+  });
+
+  it('should report the backend diagnostics when the TXT import fails', async () => {
+    cacheService.clearInvalidFiles.mockReturnValue(of(void 0));
+    examService.importExamText.mockReturnValue(of(null));
+    Object.defineProperty(examService, 'errors$', {
+      value: of(['- Line 3, column 2: Unknown marker `[?]`, expected `[X]` or `[ ]`']),
+    });
+
+    const file = {
+      id: 'file-5',
+      name: 'exam.txt',
+      type: 'text/plain',
+      data: 'test',
+    } as any;
+
+    const result = await service.importExam(file);
+
+    expect(result).toEqual({
+      success: false,
+      errorType: 'error',
+      id: 'file-5',
+      message: '- Line 3, column 2: Unknown marker `[?]`, expected `[X]` or `[ ]`',
+    });
+  });
+
+  it('should import exported exam bundles in the backend', async () => {
+    const spy = jest.spyOn<any, any>(service['_importer'], 'importExamFromJSON');
+    cacheService.clearInvalidFiles.mockReturnValue(of(void 0));
+    examService.importExamBundle.mockReturnValue(of(mockExam));
+
+    const data = JSON.stringify({format: 'open-xam/exam', schemaVersion: 1, exam: mockExam});
+    const file = {
+      id: 'file-6',
+      name: 'exam_1.json',
+      type: 'application/json',
+      data,
+    } as any;
+
+    const result = await service.importExam(file);
+
+    expect(result).toEqual({ success: true, id: 'file-6' });
+    expect(examService.importExamBundle).toHaveBeenCalledWith(data);
+    expect(spy).not.toHaveBeenCalled();
+  });
+
+  it('should import QTI packages in the backend', async () => {
+    cacheService.clearInvalidFiles.mockReturnValue(of(void 0));
+    examService.importQtiPackage.mockReturnValue(of({
+      exam: mockExam,
+      skipped: [{index: 2, message: 'Item `items/text_entry.xml` was not imported', errors: [], nestedErrors: []}],
+    }));
+
+    const file = {
+      id: 'file-7',
+      name: 'rust_basics.zip',
+      type: 'application/zip',
+      data: new Uint8Array([80, 75, 3, 4]).buffer,
+    } as any;
+
+    const result = await service.importExam(file);
+
+    expect(result).toEqual({ success: true, id: 'file-7' });
+    expect(examService.importQtiPackage).toHaveBeenCalledWith([80, 75, 3, 4]);
+  });
+
+  it('should import GIFT files by their extension in the backend', async () => {
+    cacheService.clearInvalidFiles.mockReturnValue(of(void 0));
+    examService.importGift.mockReturnValue(of({exam: mockExam, skipped: []}));
+    const data = '::Title::Pick one {=right answer ~wrong answer}';
+
+    const file = {
+      id: 'file-8',
+      name: 'questions.GIFT',
+      type: '',
+      data,
+    } as any;
+
+    const result = await service.importExam(file);
+
+    expect(result).toEqual({ success: true, id: 'file-8' });
+    expect(examService.importGift).toHaveBeenCalledWith(data);
+  });
+
+  it('should import Moodle XML files in the backend', async () => {
+    cacheService.clearInvalidFiles.mockReturnValue(of(void 0));
+    examService.importMoodleXml.mockReturnValue(of({exam: mockExam, skipped: []}));
+    const data = '<quiz></quiz>';
+
+    const file = {
+      id: 'file-9',
+      name: 'questions.xml',
+      type: 'text/xml',
+      data,
+    } as any;
+
+    const result = await service.importExam(file);
+
+    expect(result).toEqual({ success: true, id: 'file-9' });
+    expect(examService.importMoodleXml).toHaveBeenCalledWith(data);
   });
 
   it('should fail on unsupported file type', async () => {
@@ -111,9 +213,9 @@ describe('ExamImportService', () => {
 
     const file = {
       id: 'x',
-      name: 'exam.xml',
-      type: 'application/xml',
-      data: '<xml />',
+      name: 'exam.pdf',
+      type: 'application/pdf',
+      data: '%PDF-1.7',
     } as any;
 
     const result = await service.importExam(file);
@@ -4,9 +4,12 @@ import {ExamService} from "./exam.service";
 import ExamImporter from "../util/ExamImporter";
 import {ReadFile} from "../model/interfaces/upload/file-upload.interfac";
 import {IExam} from "../model/interfaces/exam.interface";
-import {lastValueFrom} from "rxjs";
+import {firstValueFrom, lastValueFrom, map, Observable} from "rxjs";
 import Logger from "../util/Logger";
 import {IExamImportResult} from "../model/interfaces/import/exam-import-result.interface";
+import {IImportReport} from "../model/interfaces/import/import-report.interface";
+
+const GIFT_MIME_TYPE = 'application/x-gift';
 
 @Injectable({
     providedIn: 'root',
@@ -32,15 +35,39 @@ export class ExamImportService {
             await lastValueFrom(this._cacheService.clearInvalidFiles());
 
             let importedExam: IExam | undefined;
-            switch (file.type) {
+            switch (this._fileType(file)) {
                 case 'application/json': {
-                    importedExam = this._importer.importExamFromJSON(file.data);
+                    const content = this._importer.bufferToString(file.data);
+                    if (this._importer.isExamBundle(content)) {
+                        return await this._importInBackend(file, this._examService.importExamBundle(content));
+                    }
+                    importedExam = this._importer.importExamFromJSON(content);
                 }
                     break;
                 case 'text/plain': {
-                    importedExam = this._importer.importFromTxt(file.data)
+                    const content = this._importer.bufferToString(file.data);
+                    return await this._importInBackend(file, this._examService.importExamText(content));
+                }
+                case 'application/zip':
+                case 'application/x-zip-compressed': {
+                    const content = Array.from(new Uint8Array(file.data as ArrayBuffer));
+                    return await this._importInBackend(file, this._examService.importQtiPackage(content).pipe(
+                        map(report => this._logSkipped(file, report))
+                    ));
+                }
+                case GIFT_MIME_TYPE: {
+                    const content = this._importer.bufferToString(file.data);
+                    return await this._importInBackend(file, this._examService.importGift(content).pipe(
+                        map(report => this._logSkipped(file, report))
+                    ));
+                }
+                case 'text/xml':
+                case 'application/xml': {
+                    const content = this._importer.bufferToString(file.data);
+                    return await this._importInBackend(file, this._examService.importMoodleXml(content).pipe(
+                        map(report => this._logSkipped(file, report))
+                    ));
                 }
-                    break;
                 default: {
                     throw new Error(`Unsupported file type: ${file.type}`);
                 }
@@ -70,6 +97,36 @@ export class ExamImportService {
         }
     }
 
+    /**
+     * Browsers don't know a MIME type for GIFT files, they are recognized by their extension.
+     */
+    private _fileType(file: ReadFile): string {
+        return file.name.toLowerCase().endsWith('.gift') ? GIFT_MIME_TYPE : file.type;
+    }
+
+    /**
+     * Text files, exported bundles, QTI packages and Moodle files are parsed, validated and saved by the backend, which
+     * reports every problem of the file in its error message.
+     */
+    private async _importInBackend(file: ReadFile, import$: Observable<IExam | null>): Promise<IExamImportResult> {
+        const createdExam = await lastValueFrom(import$);
+        if (!createdExam) {
+            const errors = await firstValueFrom(this._examService.errors$);
+            const message = errors.at(-1);
+            this.logger.logError(`Failed to import exam from file: ${file.name}\n${message}`);
+            return {success: false, errorType: 'error', id: file.id, message};
+        }
+
+        return {success: true, id: file.id};
+    }
+
+    private _logSkipped(file: ReadFile, report: IImportReport | null): IExam | null {
+        for (const skipped of report?.skipped ?? []) {
+            this.logger.logWarn(`${file.name}: ${skipped.message}`);
+        }
+        return report?.exam ?? null;
+    }
+
     private async _validateExam(exam: IExam, fileId: string): Promise<IExamImportResult> {
         const validated = await lastValueFrom(this._examService.validateExam(exam))
             .catch(e => {
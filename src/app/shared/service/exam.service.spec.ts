@@ -10,6 +10,7 @@ import {PageOptions} from "../model/classes/page-options.class";
 import {IExamOverallStatistics} from "../model/interfaces/exam-overall-statistics.interface";
 import Exam from "../model/classes/exam.class";
 import {PagedResult} from "../model/classes/paged-result.class";
+import {PrintFormat} from "../model/print-format.enum";
 
 describe('ExamService', () => {
   let service: ExamService;
@@ -182,4 +183,141 @@ describe('ExamService', () => {
       expect(errors.length).toBe(0);
     });
   });
+
+  describe('exportExam', () => {
+    it('should return the report of the export', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'export_exam') {
+          received = args;
+          return Promise.resolve({path: (args as any).path, skipped: []});
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.exportExam(1, '/home/user/Documents/exam_1.json'));
+      expect(result).toEqual({path: '/home/user/Documents/exam_1.json', skipped: []});
+      expect(received).toEqual({id: 1, path: '/home/user/Documents/exam_1.json'});
+    });
+  });
+
+  describe('exportExamToQti', () => {
+    it('should return the report of the export', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'export_exam_to_qti') {
+          received = args;
+          return Promise.resolve({path: (args as any).path, skipped: []});
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.exportExamToQti(1, '/home/user/Documents/exam_1.zip'));
+      expect(result).toEqual({path: '/home/user/Documents/exam_1.zip', skipped: []});
+      expect(received).toEqual({id: 1, path: '/home/user/Documents/exam_1.zip'});
+    });
+  });
+
+  describe('exportExamToGift', () => {
+    it('should return the report of the export', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'export_exam_to_gift') {
+          received = args;
+          return Promise.resolve({path: (args as any).path, skipped: []});
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.exportExamToGift(1, '/home/user/Documents/exam_1.gift'));
+      expect(result).toEqual({path: '/home/user/Documents/exam_1.gift', skipped: []});
+      expect(received).toEqual({id: 1, path: '/home/user/Documents/exam_1.gift'});
+    });
+  });
+
+  describe('exportExamToMoodleXml', () => {
+    it('should return the report of the export', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'export_exam_to_moodle_xml') {
+          received = args;
+          return Promise.resolve({path: (args as any).path, skipped: []});
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.exportExamToMoodleXml(1, '/home/user/Documents/exam_1.xml'));
+      expect(result).toEqual({path: '/home/user/Documents/exam_1.xml', skipped: []});
+      expect(received).toEqual({id: 1, path: '/home/user/Documents/exam_1.xml'});
+    });
+  });
+
+  describe('exportExamToAnki', () => {
+    it('should return the report of the export', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'export_exam_to_anki') {
+          received = args;
+          return Promise.resolve({path: (args as any).path, skipped: []});
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.exportExamToAnki(1, '/home/user/Documents/exam_1.apkg'));
+      expect(result).toEqual({path: '/home/user/Documents/exam_1.apkg', skipped: []});
+      expect(received).toEqual({id: 1, path: '/home/user/Documents/exam_1.apkg'});
+    });
+  });
+
+  describe('renderExam', () => {
+    it('should pass the print options to the backend', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'render_exam') {
+          received = args;
+          return Promise.resolve({path: (args as any).path, skipped: []});
+        }
+        return Promise.resolve(null);
+      });
+
+      const options = {format: PrintFormat.PDF, variant: 2, answerKey: true};
+      const result = await lastValueFrom(service.renderExam(1, '/home/user/Documents/exam_1.pdf', options));
+      expect(result).toEqual({path: '/home/user/Documents/exam_1.pdf', skipped: []});
+      expect(received).toEqual({id: 1, path: '/home/user/Documents/exam_1.pdf', options});
+    });
+  });
+
+  describe('importSpreadsheet', () => {
+    it('should pass the exam id, path and mapping to the backend', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'import_spreadsheet') {
+          received = args;
+          return Promise.resolve({created: [], skipped: [], invalid: []});
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.importSpreadsheet(1, '/home/user/questions.csv', {questionText: 'Frage'}));
+      expect(result).toEqual({created: [], skipped: [], invalid: []});
+      expect(received).toEqual({examId: 1, path: '/home/user/questions.csv', mapping: {questionText: 'Frage'}});
+    });
+  });
+
+  describe('importExamText', () => {
+    it('should pass the content to the backend', async () => {
+      let received: any;
+      mockIPC((cmd, args) => {
+        if (cmd === 'import_exam_text') {
+          received = args;
+          return Promise.resolve({name: 'Imported Certificate', questions: []});
+        }
+        return Promise.resolve(null);
+      });
+
+      const result = await lastValueFrom(service.importExamText('Q: Question P: 1'));
+      expect(result?.name).toBe('Imported Certificate');
+      expect(received).toEqual({content: 'Q: Question P: 1'});
+    });
+  });
 });
@@ -6,8 +6,13 @@ import { IExam } from '../model/interfaces/exam.interface';
 import { IFilterTree } from '../model/interfaces/filter/filter.types';
 import { IPageOptions } from '../model/interfaces/page-options.interface';
 import { IPagedResult } from '../model/interfaces/paged-result.interface';
+import { IPrintOptions } from '../model/interfaces/print-options.interface';
 import { BaseService } from './base-service';
 import { IExamOverallStatistics } from '../model/interfaces/exam-overall-statistics.interface';
+import { IExportReport } from '../model/interfaces/import/export-report.interface';
+import { IImportReport } from '../model/interfaces/import/import-report.interface';
+import { ISpreadsheetImportReport } from '../model/interfaces/import/spreadsheet-import-report.interface';
+import { ISpreadsheetMapping } from '../model/interfaces/import/spreadsheet-mapping.interface';
 
 @Injectable({
     providedIn: 'root'
@@ -59,6 +64,107 @@ export class ExamService extends BaseService {
         return this.invoke$<boolean>('validate_exam', {exam});
     }
 
+    /**
+     * Parses an exam in the text format, validates and saves it. Parse and validation errors
+     * are reported with the line and column of the offending part of the text.
+     */
+    public importExamText(content: string): Observable<IExam | null> {
+        this.setInitialStates();
+        return this.invoke$<IExam>('import_exam_text', {content});
+    }
+
+    /**
+     * Writes the exam as a versioned JSON bundle to the absolute path. Attachments whose
+     * content is missing are listed in the report instead of being exported.
+     */
+    public exportExam(id: number, path: string): Observable<IExportReport | null> {
+        this.setInitialStates();
+        return this.invoke$<IExportReport>('export_exam', {id, path});
+    }
+
+    public importExamBundle(content: string): Observable<IExam | null> {
+        this.setInitialStates();
+        return this.invoke$<IExam>('import_exam_bundle', {content});
+    }
+
+    /**
+     * Imports the bytes of a QTI 2.1 package. Items without an equivalent question type are
+     * listed in the report instead of being imported.
+     */
+    public importQtiPackage(content: number[]): Observable<IImportReport | null> {
+        this.setInitialStates();
+        return this.invoke$<IImportReport>('import_qti_package', {content});
+    }
+
+    /**
+     * Writes the exam as QTI 2.1 package to the absolute path, including the attachments.
+     */
+    public exportExamToQti(id: number, path: string): Observable<IExportReport | null> {
+        this.setInitialStates();
+        return this.invoke$<IExportReport>('export_exam_to_qti', {id, path});
+    }
+
+    /**
+     * Imports the questions of a Moodle GIFT file. Questions without an equivalent question type
+     * are listed in the report instead of being imported.
+     */
+    public importGift(content: string): Observable<IImportReport | null> {
+        this.setInitialStates();
+        return this.invoke$<IImportReport>('import_gift', {content});
+    }
+
+    /**
+     * Writes the exam as Moodle GIFT file to the absolute path.
+     */
+    public exportExamToGift(id: number, path: string): Observable<IExportReport | null> {
+        this.setInitialStates();
+        return this.invoke$<IExportReport>('export_exam_to_gift', {id, path});
+    }
+
+    /**
+     * Imports the questions of a Moodle XML quiz file, see `importGift`.
+     */
+    public importMoodleXml(content: string): Observable<IImportReport | null> {
+        this.setInitialStates();
+        return this.invoke$<IImportReport>('import_moodle_xml', {content});
+    }
+
+    /**
+     * Writes the exam as Moodle XML quiz file to the absolute path.
+     */
+    public exportExamToMoodleXml(id: number, path: string): Observable<IExportReport | null> {
+        this.setInitialStates();
+        return this.invoke$<IExportReport>('export_exam_to_moodle_xml', {id, path});
+    }
+
+    /**
+     * Writes the exam as Anki package (`.apkg`) with one flashcard per question to the
+     * absolute path. Images become Anki media, other attachments are listed in the report.
+     */
+    public exportExamToAnki(id: number, path: string): Observable<IExportReport | null> {
+        this.setInitialStates();
+        return this.invoke$<IExportReport>('export_exam_to_anki', {id, path});
+    }
+
+    /**
+     * Writes a printable version of the exam to the absolute path, as Markdown, HTML or PDF
+     * depending on the options. Call it once with and once without `answerKey` for a
+     * variant and its answer key.
+     */
+    public renderExam(id: number, path: string, options?: IPrintOptions): Observable<IExportReport | null> {
+        this.setInitialStates();
+        return this.invoke$<IExportReport>('render_exam', {id, path, options: options ?? null});
+    }
+
+    /**
+     * Adds the questions of the CSV or XLSX file at the absolute path to the exam. Without a
+     * mapping the columns are expected under their default headers.
+     */
+    public importSpreadsheet(examId: number, path: string, mapping?: ISpreadsheetMapping): Observable<ISpreadsheetImportReport | null> {
+        this.setInitialStates();
+        return this.invoke$<ISpreadsheetImportReport>('import_spreadsheet', {examId, path, mapping: mapping ?? null});
+    }
+
     private mapToPagedResult<T>(res: IPagedResult<T> | null): PagedResult<T> {
         if (!res) {
             return PagedResult.default();